@@ -1,5 +1,112 @@
-
 // (Benchmarking Guide)[https://nickb.dev/blog/guidelines-on-benchmarking-and-rust/]
 
-// #[bench] 
-// fn eccentric_anomaly_precision() {}
\ No newline at end of file
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rust_solar::{
+    anomaly::{elliptical_halley, elliptical_newton, Anomaly, HALLEY_ECCENTRICITY},
+    kepler::{Body, Date, TimeZone},
+    orbit::{Hemisphere, SolarLongitude, Type},
+    planets::mars::{Mars, Martian},
+};
+
+/// The eccentricities of earth, mars, mercury, and two orbits past the fast path
+const ECCENTRICITIES: [f64; 5] = [0.0167, 0.0934, 0.2056, 0.5, 0.9];
+
+/// 1000 sols, spread over about a mars year and a half
+fn days() -> Vec<f64> {
+    (0..1000).map(|i| i as f64 * 1.03).collect()
+}
+
+fn eccentric(c: &mut Criterion) {
+    let days = days();
+    let mut group = c.benchmark_group("elliptical eccentric anomaly, 1000 sols");
+
+    for e in ECCENTRICITIES {
+        group.bench_with_input(BenchmarkId::from_parameter(e), &e, |b, e| {
+            b.iter(|| {
+                black_box(&days)
+                    .iter()
+                    .map(|day| {
+                        Anomaly.eccentric(
+                            Type::Elliptical,
+                            *day,
+                            *e,
+                            Mars.perihelion(),
+                            Mars.orbital_period(),
+                            Mars.semimajor(),
+                        )
+                    })
+                    .sum::<f64>()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn solvers(c: &mut Criterion) {
+    let means: Vec<f64> = (0..1000).map(|i| i as f64 * 0.0063).collect();
+    let mut group = c.benchmark_group("elliptical kepler equation, 1000 mean anomalies");
+
+    for e in ECCENTRICITIES
+        .into_iter()
+        .filter(|e| *e < HALLEY_ECCENTRICITY)
+    {
+        group.bench_with_input(BenchmarkId::new("newton", e), &e, |b, e| {
+            b.iter(|| {
+                black_box(&means)
+                    .iter()
+                    .map(|mean| elliptical_newton(*mean, *e).unwrap())
+                    .sum::<f64>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("halley", e), &e, |b, e| {
+            b.iter(|| {
+                black_box(&means)
+                    .iter()
+                    .map(|mean| elliptical_halley(*mean, *e))
+                    .sum::<f64>()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn mars(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mars");
+
+    group.bench_function("Date::compute", |b| {
+        b.iter(|| {
+            Date::default().compute(
+                black_box(2_459_264.371527778),
+                Mars.epoch(),
+                Mars.epoch_year(),
+                Mars.rotational_period(),
+                Mars.perihelion(),
+                Mars.semimajor(),
+                Mars.orbital_eccentricity(),
+                Mars.orbital_period(),
+                Hemisphere::North,
+            )
+        })
+    });
+    group.bench_function("SolarLongitude.compute", |b| {
+        b.iter(|| {
+            SolarLongitude.compute(
+                Type::Elliptical,
+                black_box(321.5),
+                Mars.orbital_eccentricity(),
+                Mars.perihelion(),
+                Mars.orbital_period(),
+                Mars.semimajor(),
+            )
+        })
+    });
+    group.bench_function("Martian::at", |b| {
+        b.iter(|| Martian::MTCp2.at(black_box(2_459_264.371527778)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, eccentric, solvers, mars);
+criterion_main!(benches);
@@ -0,0 +1,39 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rust_solar::{kepler::Body, planets::mars::Mars};
+
+/// 100k julian dates, a quarter of a day apart
+fn julian_dates() -> Vec<f64> {
+    (0..100_000)
+        .map(|i| 2_451_545.0 + i as f64 * 0.25)
+        .collect()
+}
+
+fn to_dates(c: &mut Criterion) {
+    let julian_dates = julian_dates();
+    let mut group = c.benchmark_group("mars 100k dates");
+
+    group.bench_function("looped to_date", |b| {
+        b.iter(|| {
+            black_box(&julian_dates)
+                .iter()
+                .map(|julian_date| Mars.to_date(*julian_date))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("to_dates", |b| {
+        b.iter(|| Mars.to_dates(black_box(&julian_dates)))
+    });
+    group.bench_function("to_ls_batch", |b| {
+        b.iter(|| Mars.to_ls_batch(black_box(&julian_dates)))
+    });
+    group.bench_function("dates_between", |b| {
+        b.iter(|| {
+            Mars.dates_between(black_box(2_451_545.0), 2_451_545.0 + 25_000.0, 0.25)
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, to_dates);
+criterion_main!(benches);
@@ -0,0 +1,81 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rayon::ThreadPoolBuilder;
+use rust_solar::{
+    bodies::{ephemeris_table, registry},
+    kepler::Body,
+    planets::mars::Mars,
+};
+
+/// 50 years of daily julian dates
+fn julian_dates() -> Vec<f64> {
+    (0..365 * 50).map(|i| 2_451_545.0 + i as f64).collect()
+}
+
+/// The amount of threads from 1 up to the amount of cores, doubling each time
+fn threads() -> Vec<usize> {
+    let cores = std::thread::available_parallelism().map_or(1, |cores| cores.get());
+
+    (0..)
+        .map(|power| 1 << power)
+        .take_while(|threads| *threads < cores)
+        .chain([cores])
+        .collect()
+}
+
+fn to_dates_par(c: &mut Criterion) {
+    let julian_dates = julian_dates();
+    let mut group = c.benchmark_group("mars 50 years");
+
+    group.bench_function("to_dates", |b| {
+        b.iter(|| Mars.to_dates(black_box(&julian_dates)))
+    });
+
+    for threads in threads() {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("to_dates_par", threads),
+            &threads,
+            |b, _| b.iter(|| pool.install(|| Mars.to_dates_par(black_box(&julian_dates)))),
+        );
+    }
+
+    group.finish();
+}
+
+fn ephemeris(c: &mut Criterion) {
+    let julian_dates = julian_dates();
+    let mut group = c.benchmark_group("every body 50 years");
+
+    group.bench_function("serial", |b| {
+        b.iter(|| {
+            registry()
+                .iter()
+                .map(|body| body.to_dates(black_box(&julian_dates)))
+                .collect::<Vec<_>>()
+        })
+    });
+
+    for threads in threads() {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("ephemeris_table", threads),
+            &threads,
+            |b, _| {
+                b.iter(|| pool.install(|| ephemeris_table(registry(), black_box(&julian_dates))))
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, to_dates_par, ephemeris);
+criterion_main!(benches);
@@ -0,0 +1,36 @@
+fn main() {
+    #[cfg(feature = "ffi")]
+    header();
+}
+
+/// This function generates the C header of the `ffi` module
+///
+/// * The crate is only built as an rlib, so a C or C++ program links to a library built next to the header with
+///   `cargo rustc --release --features ffi --crate-type cdylib` (or `--crate-type staticlib`).
+/// * The crate types aren't in `Cargo.toml`, a staticlib can't be built for the targets without std.
+#[cfg(feature = "ffi")]
+fn header() {
+    use std::{env, path::PathBuf};
+
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").expect("Cargo to set the manifest dir");
+    let header = env::var_os("RUST_SOLAR_HEADER")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            PathBuf::from(env::var("OUT_DIR").expect("Cargo to set the out dir"))
+                .join("rust_solar.h")
+        });
+
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-env-changed=RUST_SOLAR_HEADER");
+
+    let config = cbindgen::Config::from_root_or_default(&crate_dir);
+
+    // Only the ffi module is parsed, the rest of the crate isn't part of the C api
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(PathBuf::from(&crate_dir).join("src/ffi.rs"))
+        .generate()
+        .expect("The ffi module to be readable by cbindgen")
+        .write_to_file(header);
+}
@@ -0,0 +1,222 @@
+//! A command line tool for checking dates by hand, like against Mars24
+//!
+//! ```text
+//! cargo run --example solar -- date mars --jd 2460000.5
+//! cargo run --example solar -- now mars --zone MTC+2
+//! cargo run --example solar -- ls mars --date 2024-03-01
+//! cargo run --example solar -- bodies
+//! ```
+
+use std::{env, process::ExitCode};
+
+use rust_solar::{
+    bodies,
+    julian::{greg2jd, jd_now, CalendarSystem},
+    kepler::{Body, TimeZone},
+};
+
+/// How to call the tool, printed on a usage error
+const USAGE: &str = "\
+usage: solar date <body> --jd <julian date>
+       solar now <body> [--zone <timezone>]
+       solar ls <body> --date <YYYY-MM-DD[THH:MM:SS]>
+       solar bodies";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match run(&args) {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("solar: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// This function runs a command and returns what to print, or the message of what went wrong
+fn run(args: &[String]) -> Result<String, String> {
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match args.as_slice() {
+        ["date", body, rest @ ..] => {
+            let jd = option(rest, "--jd")?.ok_or("--jd is missing")?;
+
+            date(find(body)?, parse_jd(jd)?)
+        }
+        ["now", body, rest @ ..] => now(find(body)?, option(rest, "--zone")?, jd_now()),
+        ["ls", body, rest @ ..] => {
+            let date = option(rest, "--date")?.ok_or("--date is missing")?;
+
+            ls(find(body)?, parse_date(date)?)
+        }
+        ["bodies"] => Ok(list()),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+/// This function finds a body of the registry by its name, ignoring case
+fn find(name: &str) -> Result<&'static dyn Body, String> {
+    bodies::find(name).ok_or_else(|| format!("unknown body: {name}"))
+}
+
+/// This function finds the value of the only option of a command, anything else is an error
+fn option<'a>(args: &[&'a str], name: &str) -> Result<Option<&'a str>, String> {
+    match args {
+        [] => Ok(None),
+        [arg] if *arg == name => Err(format!("{name} needs a value")),
+        [arg, value] if *arg == name => Ok(Some(value)),
+        [arg, ..] => Err(format!("unexpected argument: {arg}")),
+    }
+}
+
+/// This function parses a julian date
+fn parse_jd(text: &str) -> Result<f64, String> {
+    text.parse()
+        .map_err(|_| format!("expected a julian date, got {text}"))
+}
+
+/// This function parses a gregorian date (UTC) into a julian date, the time is midnight when it's left out
+fn parse_date(text: &str) -> Result<f64, String> {
+    let malformed = || format!("expected YYYY-MM-DD[THH:MM:SS], got {text}");
+    let (date, time) = text.split_once('T').unwrap_or((text, "00:00:00"));
+    let (sign, date) = match date.strip_prefix('-') {
+        Some(date) => (-1, date),
+        None => (1, date),
+    };
+
+    let date: Vec<&str> = date.split('-').collect();
+    let time: Vec<&str> = time.split(':').collect();
+
+    let [year, month, day] = date.as_slice() else {
+        return Err(malformed());
+    };
+    let [hour, minute, second] = time.as_slice() else {
+        return Err(malformed());
+    };
+
+    let year: i32 = year.parse().map_err(|_| malformed())?;
+
+    greg2jd(
+        sign * year,
+        month.parse().map_err(|_| malformed())?,
+        day.parse().map_err(|_| malformed())?,
+        hour.parse().map_err(|_| malformed())?,
+        minute.parse().map_err(|_| malformed())?,
+        second.parse().map_err(|_| malformed())?,
+        CalendarSystem::Gregorian,
+    )
+    .map_err(|error| error.to_string())
+}
+
+/// This function finds a timezone of a body by its code or name, `MTC+2` is read as `MTCp2`
+fn zone(body: &dyn Body, code: &str) -> Result<Box<dyn TimeZone>, String> {
+    body.timezone(code)
+        .or_else(|| body.timezone(&code.replace('+', "p").replace('-', "n")))
+        .ok_or_else(|| format!("unknown timezone of {}: {code}", body.name()))
+}
+
+/// `solar date`, the date of a julian date (UTC)
+fn date(body: &dyn Body, jd: f64) -> Result<String, String> {
+    body.try_to_date(jd)
+        .map(|date| date.to_string())
+        .map_err(|error| error.to_string())
+}
+
+/// `solar now`, the date right now and the time when a timezone is given
+fn now(body: &dyn Body, code: Option<&str>, jd: f64) -> Result<String, String> {
+    let date = date(body, jd)?;
+
+    match code {
+        Some(code) => Ok(body.to_datetime(jd, zone(body, code)?.as_ref()).to_string()),
+        None => Ok(date),
+    }
+}
+
+/// `solar ls`, the solar longitude of a julian date (UTC) in degrees
+fn ls(body: &dyn Body, jd: f64) -> Result<String, String> {
+    body.try_to_date(jd)
+        .map(|date| format!("Ls {:.3}", date.ls))
+        .map_err(|error| error.to_string())
+}
+
+/// `solar bodies`, every body of the registry and its year
+fn list() -> String {
+    bodies::all()
+        .iter()
+        .map(|body| {
+            format!(
+                "{:<8} {:.2} sols a year",
+                body.name(),
+                body.orbital_period()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rust_solar::planets::mars::Mars;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    pub fn dates_match_the_library() {
+        assert_eq!(
+            Ok(Mars.to_date(2460000.5).to_string()),
+            run(&args("date MARS --jd 2460000.5"))
+        );
+        assert_eq!(Ok(2451544.5), parse_date("2000-01-01"));
+        assert_eq!(Ok(2451545.0), parse_date("2000-01-01T12:00:00"));
+        assert_eq!(
+            Ok(format!("Ls {:.3}", Mars.to_date(2460370.5).ls)),
+            run(&args("ls mars --date 2024-03-01"))
+        );
+    }
+
+    #[test]
+    pub fn zones_are_found_by_offset() {
+        let time = now(&Mars, Some("MTC+2"), 2451545.0).unwrap();
+
+        assert!(time.ends_with("HT (+05:00)"), "{time}");
+        assert_eq!(now(&Mars, Some("HT"), 2451545.0), Ok(time));
+        assert!(zone(&Mars, "MTC+9").is_err());
+    }
+
+    #[test]
+    pub fn bad_input_is_reported() {
+        assert_eq!(
+            Err("expected a finite number".to_string()),
+            run(&args("date mars --jd NaN"))
+        );
+        assert_eq!(
+            Err("unknown body: vulcan".to_string()),
+            run(&args("date vulcan --jd 2451545"))
+        );
+        assert_eq!(
+            Err("invalid date: the month is outside of 1 to 12".to_string()),
+            run(&args("ls mars --date 2024-13-01"))
+        );
+        assert_eq!(Err(USAGE.to_string()), run(&args("")));
+        assert!(run(&args("date mars")).is_err());
+        assert!(run(&args("date mars --jd")).is_err());
+        assert!(run(&args("ls mars --date yesterday")).is_err());
+        assert!(run(&args("now mars --zone HT --jd 2451545")).is_err());
+    }
+
+    #[test]
+    pub fn every_body_is_listed() {
+        let list = list();
+
+        assert_eq!(bodies::all().len(), list.lines().count());
+        assert!(list.lines().any(|line| line.starts_with("Mars ")));
+    }
+}
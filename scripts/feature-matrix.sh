@@ -0,0 +1,42 @@
+#!/bin/sh
+# Builds and tests the body features on their own and together, with and without std
+#
+#   ./scripts/feature-matrix.sh [extra cargo arguments, like --offline]
+#
+# * Every body builds alone, so the registry and the bindings only lean on the bodies they ask for.
+# * The embedded build is checked on `thumbv7em-none-eabi`, where std can't sneak in through a dependency
+# * The registry tests run for each body, they check that only the enabled bodies are registered
+#   and that their constants agree, see `kepler::validate`
+# * The doctests run for each body too, the ones that use a body are gated on its feature
+set -eu
+
+cd "$(dirname "$0")/.."
+
+BODIES="mars earth jupiter saturn luna ceres"
+GROUPS="planets moons asteroids full"
+
+run() {
+    echo "+ cargo $*"
+    cargo "$@"
+}
+
+# no bodies at all
+run build --no-default-features "$@"
+run build --no-default-features --features std "$@"
+
+for features in $BODIES $GROUPS; do
+    run build --no-default-features --features "$features" "$@"
+    run clippy --no-default-features --features "$features" --lib "$@" -- -D warnings
+    run test --no-default-features --features "std,$features" --test bodies-ut --test consistency-ut "$@"
+    run test --doc --no-default-features --features "std,$features" "$@"
+done
+
+# the embedded build, mars alone without std
+run test --no-default-features --features mars --tests "$@"
+# a target without std at all, so the float math really goes through libm
+# (rustup target add thumbv7em-none-eabi)
+run build --no-default-features --features full --target thumbv7em-none-eabi "$@"
+run clippy --all-targets --no-default-features --features full "$@" -- -D warnings
+run test --no-default-features --features "std,mars" "$@"
+
+run test --all-features "$@"
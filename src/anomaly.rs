@@ -1,12 +1,140 @@
-use crate::orbit::{self, MeanMotion, Perihelion, SemiAxis};
+use num_traits::Float;
+
+use crate::{
+    conversions::cast,
+    error::{Error, Result},
+    orbit::{self, MeanMotion, Perihelion, SemiAxis},
+};
+
+/// The most newton iterations an anomaly takes before it gives up, see [`Error::NoConvergence`]
+pub const MAX_ITERATIONS: u32 = 100;
+
+/// The step where the newton iterations stop, `1e-7` unless the float is too coarse for it (like `f32`)
+pub fn tolerance<F: Float>() -> F {
+    cast::<F>(1.0e-7).max(F::epsilon() * cast(16.0))
+}
+
+/// The eccentricity below which the elliptical anomaly takes the fast path, see [`elliptical_halley`]
+pub const HALLEY_ECCENTRICITY: f64 = 0.3;
+
+/// Why a straight or unknown orbit has no anomaly, see [`Error::InvalidOrbit`]
+const NO_ANOMALY: &str = "the orbit is straight or of an unknown shape, so it has no anomaly";
+
+/// (EKE) This function solves the elliptical kepler equation with newton steps, until a step is within the [`tolerance`]
+///
+/// > $$E_{n+1}=E_{n}-{\frac {E_{n}-e\sin(E_{n})-M}{1-e\cos(E_{n})}}$$
+///
+/// * It fails with [`Error::NoConvergence`] after [`MAX_ITERATIONS`] steps
+pub fn elliptical_newton<F: Float>(mean_anomaly: F, orbital_eccentricity: F) -> Result<F> {
+    // Initial En which allows for precesion
+    let mut zdx: F = cast(10.0);
+
+    // Initial Eccentric Anomaly
+    let mut zx0 = mean_anomaly + orbital_eccentricity * mean_anomaly.sin();
+
+    // Newtons Iterative step
+    let mut iterations = 0;
+
+    while zdx.abs() > tolerance() {
+        iterate(&mut iterations)?;
+
+        let x0 = -(zx0 - orbital_eccentricity * zx0.sin() - mean_anomaly);
+        let x1 = F::one() - orbital_eccentricity * zx0.cos();
+
+        // En = - ((En - e * En.sin() - M(t)) / 1 - e * En.cos() )
+        // the En at its first increment En = E0
+        zdx = x0 / x1;
+
+        // En = En + En+1
+        zx0 = zx0 + zdx;
+
+        #[cfg(feature = "log")]
+        log::trace!(
+            "eccentric anomaly step: {}",
+            zdx.to_f64().unwrap_or(f64::NAN)
+        );
+    }
+
+    Ok(zx0)
+}
+
+/// (EKE) This function solves the elliptical kepler equation with three halley steps, and no checks between them
+///
+/// > $$E_{n+1}=E_{n}-{\frac {2 f f'}{2 f'^2 - f f''}}$$
+///
+/// * It's only as close as [`elliptical_newton`] under [`HALLEY_ECCENTRICITY`], the first guess is too far off above it.
+/// * The sine and cosine are only taken of the mean anomaly, each step turns them by its own angle instead.
+pub fn elliptical_halley<F: Float>(mean_anomaly: F, orbital_eccentricity: F) -> F {
+    let two: F = cast(2.0);
+    let (sin, cos) = mean_anomaly.sin_cos();
+
+    // a newton step from the mean anomaly, under half a radian
+    let step = orbital_eccentricity * sin / (F::one() - orbital_eccentricity * cos);
+    let (mut zx0, mut sin, mut cos) = advance::<F, 6>((mean_anomaly, sin, cos), step);
+
+    // then each step is under a twentieth of a radian
+    for _ in 0..3 {
+        let f = zx0 - orbital_eccentricity * sin - mean_anomaly;
+        let f1 = F::one() - orbital_eccentricity * cos;
+        let f2 = orbital_eccentricity * sin;
+        let step = -two * f * f1 / (two * f1 * f1 - f * f2);
+
+        (zx0, sin, cos) = advance::<F, 4>((zx0, sin, cos), step);
+    }
+
+    zx0
+}
+
+/// This function moves an angle and its sine and cosine by a small step,
+/// the sine and cosine of the step come from the first `TERMS` terms of their series
+fn advance<F: Float, const TERMS: usize>((angle, sin, cos): (F, F, F), step: F) -> (F, F, F) {
+    let squared = step * step;
+    let (mut step_sin, mut step_cos) = (F::one(), F::one());
+
+    for n in (1..TERMS).rev() {
+        let n = (2 * n) as f64;
+
+        step_sin = F::one() - squared * cast(1.0 / ((n + 1.0) * n)) * step_sin;
+        step_cos = F::one() - squared * cast(1.0 / (n * (n - 1.0))) * step_cos;
+    }
+
+    step_sin = step * step_sin;
+
+    (
+        angle + step,
+        sin * step_cos + cos * step_sin,
+        cos * step_cos - sin * step_sin,
+    )
+}
+
+/// This function counts a newton iteration, and fails once there have been [`MAX_ITERATIONS`] of them
+fn iterate(iterations: &mut u32) -> Result<()> {
+    if *iterations == MAX_ITERATIONS {
+        return Err(Error::NoConvergence(MAX_ITERATIONS));
+    }
+
+    *iterations += 1;
+
+    Ok(())
+}
+
+/// This function checks the anomaly a solver settled on
+fn settled<F: Float>(anomaly: F) -> Result<F> {
+    match anomaly.is_finite() {
+        true => Ok(anomaly),
+        false => Err(Error::NoConvergence(MAX_ITERATIONS)),
+    }
+}
 
 #[derive(Debug, Clone, Copy)]
 /// This represents ways of describing an object in its orbit
+///
+/// * The methods work in any float precision (ex; `f32` or `f64`), see [`tolerance`]
 pub struct Anomaly;
 
 impl Anomaly {
     /// (Mean Anomaly) Calculates the period since the last periapsis.
-    pub fn mean(self, day: f64, peri: Perihelion, orbital_period: f64) -> f64 {
+    pub fn mean<F: Float>(self, day: F, peri: Perihelion<F>, orbital_period: F) -> F {
         MeanMotion.by(day, peri, orbital_period).abs()
     }
 
@@ -26,76 +154,208 @@ impl Anomaly {
     /// > $$D = D/\sqrt{2q}$$
     /// > $$M = qD + (D^3/6)$$
     ///
-    pub fn eccentric(
+    /// * An elliptical orbit under [`HALLEY_ECCENTRICITY`] takes [`elliptical_halley`], any other takes [`elliptical_newton`]
+    /// * It fails with [`Error::NoConvergence`] when the newton steps don't settle,
+    ///   and with [`Error::InvalidOrbit`] when the shape is straight or unknown.
+    ///
+    pub fn try_eccentric<F: Float>(
         self,
-        shape: crate::orbit::Type,
-        day: f64,
-        orbital_eccentricity: f64,
-        peri: Perihelion,
-        orbital_period: f64,
-        major_axis: f64,
-    ) -> f64 {
-        match shape {
+        shape: orbit::Type,
+        day: F,
+        orbital_eccentricity: F,
+        peri: Perihelion<F>,
+        orbital_period: F,
+        major_axis: F,
+    ) -> Result<F> {
+        Elements::new(shape, orbital_eccentricity, major_axis).eccentric(MeanMotion.by(
+            day,
+            peri,
+            orbital_period,
+        ))
+    }
+
+    /// (Eccentric Anomaly) This method is [`Anomaly::try_eccentric`], but NaN when it fails
+    pub fn eccentric<F: Float>(
+        self,
+        shape: orbit::Type,
+        day: F,
+        orbital_eccentricity: F,
+        peri: Perihelion<F>,
+        orbital_period: F,
+        major_axis: F,
+    ) -> F {
+        self.try_eccentric(
+            shape,
+            day,
+            orbital_eccentricity,
+            peri,
+            orbital_period,
+            major_axis,
+        )
+        .unwrap_or_else(|_| F::nan())
+    }
+
+    /// (True Anomaly) Calculates the angle between the periapsis and the body's current position.
+    ///
+    /// * Elliptical Eccentric Anomaly
+    /// > $$\nu =2\,\operatorname {arctan} \left(\,{\sqrt {{1+e\,} \over {1-e\,}}}\tan {E \over 2}\,\right)$$
+    ///
+    /// * Hyperbolic (Eccentric) Anomaly
+    /// >  $$(\frac{e+1}{e-1})^{1/2}  \tanh(\frac{H}{2})$$
+    ///
+    /// * Parabolic (Eccentric) Anomaly
+    /// >  $$D = D/\sqrt{2q}$$
+    ///
+    /// * Circular (Eccentric) Anomaly
+    /// >  $$nt = M(t)$$
+    /// >  $$M = M_0 + nt$$
+    ///
+    /// * It fails the same way as [`Anomaly::try_eccentric`]
+    ///
+    pub fn try_truly<F: Float>(
+        self,
+        shape: orbit::Type,
+        day: F,
+        orbital_eccentricity: F,
+        peri: Perihelion<F>,
+        orbital_period: F,
+        major_axis: F,
+    ) -> Result<F> {
+        Elements::new(shape, orbital_eccentricity, major_axis).truly(MeanMotion.by(
+            day,
+            peri,
+            orbital_period,
+        ))
+    }
+
+    /// (True Anomaly) This method is [`Anomaly::try_truly`], but NaN when it fails
+    pub fn truly<F: Float>(
+        self,
+        shape: orbit::Type,
+        day: F,
+        orbital_eccentricity: F,
+        peri: Perihelion<F>,
+        orbital_period: F,
+        major_axis: F,
+    ) -> F {
+        self.try_truly(
+            shape,
+            day,
+            orbital_eccentricity,
+            peri,
+            orbital_period,
+            major_axis,
+        )
+        .unwrap_or_else(|_| F::nan())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// This is the part of the anomalies that only depends on the orbit,
+/// so it's derived once for a body instead of once a date, see [`crate::orbit::Orbit`]
+pub(crate) struct Elements<F> {
+    shape: orbit::Type,
+    orbital_eccentricity: F,
+    /// Semi-Latus Rectum ( semi-major-axis * (1.0 - eccentricity^2)), see [`SemiAxis::minor`]
+    /// (squared by a product, `powf` costs more than the rest of the elements)
+    semiminor: F,
+    /// The factor between the tangents of the half eccentric and the half true anomaly
+    true_factor: F,
+}
+
+impl<F: Float> Elements<F> {
+    /// This method derives the elements of an orbit, see [`orbit::Type::shape`]
+    #[inline]
+    pub(crate) fn new(shape: orbit::Type, orbital_eccentricity: F, major_axis: F) -> Self {
+        let true_factor = match shape {
+            // (e+1/e-1)^1/2
+            orbit::Type::Hyperbolic => ((orbital_eccentricity + F::one())
+                / (orbital_eccentricity - F::one()))
+            .powf(cast(0.5)),
+            // ((1+e)/(1-e))^1/2
+            orbit::Type::Elliptical => {
+                ((F::one() + orbital_eccentricity) / (F::one() - orbital_eccentricity)).sqrt()
+            }
+            _ => F::one(),
+        };
+
+        Self {
+            shape,
+            orbital_eccentricity,
+            semiminor: SemiAxis(major_axis).major()
+                * (F::one() - orbital_eccentricity * orbital_eccentricity),
+            true_factor,
+        }
+    }
+
+    /// (Eccentric Anomaly) This method solves the anomaly of a mean motion, see [`Anomaly::try_eccentric`]
+    pub(crate) fn eccentric(&self, mean_motion: F) -> Result<F> {
+        let orbital_eccentricity = self.orbital_eccentricity;
+
+        match self.shape {
             orbit::Type::Circular => {
                 // Mean Anomaly
-                let xref = self.mean(day, peri, orbital_period);
+                let xref = mean_motion.abs();
 
                 // v = M = E
-                xref
+                Ok(xref)
             }
             orbit::Type::Parabolic => {
                 // Initial Pn which allows for precesion
-                let mut pdx = 10.0;
+                let mut pdx: F = cast(10.0);
 
                 // Mean Anomaly
-                let xref = self.mean(day, peri, orbital_period);
+                let xref = mean_motion.abs();
 
                 // Initial Parabolic Anomaly
                 let mut px0 = xref;
 
+                // (Perifocal Distance) q = p/2
+                let q = self.semiminor / cast(2.0);
+
                 // Newtons Iterative Step
-                while pdx > 1.0e-7 {
-                    let x0 = px0.powf(3.0);
-                    let x1 = 6.0;
+                let mut iterations = 0;
 
-                    pdx = x0 / x1;
+                while pdx > tolerance() {
+                    iterate(&mut iterations)?;
 
-                    // Semi-Latus Rectum ( semji-major-axis * (1.0 - eccentricity^2))
-                    let p =
-                        SemiAxis(major_axis).major() * (1.0_f64 - orbital_eccentricity.powf(2.0));
+                    let x0 = px0.powf(cast(3.0));
+                    let x1: F = cast(6.0);
 
-                    // (Perifocal Distance) q = p/2
-                    let q = p / 2.0;
+                    pdx = x0 / x1;
 
                     // M = qD + (D^3 / 6)
                     px0 = (q * px0) + pdx;
                 }
 
-                let mean_motion = MeanMotion.by(day, peri, orbital_period);
                 // makes sure that the mean motion isn't negative
-                if mean_motion < 0.0 {
+                if mean_motion < F::zero() {
                     px0 = -px0;
                 }
 
-                px0
+                settled(px0)
             }
             orbit::Type::Hyperbolic => {
                 // Initial Hn which allows for precesion
-                let mut hdx = 10.0;
+                let mut hdx: F = cast(10.0);
 
                 // Mean Anomaly
-                let xref = self.mean(day, peri, orbital_period);
+                let xref = mean_motion.abs();
 
                 // Initial Hyperbolic Anomaly
                 let mut hx0 = xref;
 
                 // Newtons Iterative Step
-                while hdx > 1.0e-7 {
+                let mut iterations = 0;
+
+                while hdx > tolerance() {
+                    iterate(&mut iterations)?;
+
                     // M-esinh(Hk)+Hk
                     let x0 = (xref - orbital_eccentricity) * hx0.sinh() + hx0;
 
                     // ecosh(Hk)-1
-                    let x1 = orbital_eccentricity * hx0.cosh() - 1.0;
+                    let x1 = orbital_eccentricity * hx0.cosh() - F::one();
 
                     // (M-esinh(Hk)+Hk)/(ecosh(Hk)-1)
                     hdx = x0 / x1;
@@ -104,141 +364,56 @@ impl Anomaly {
                     hx0 = hx0 + hdx;
                 }
 
-                let mean_motion = MeanMotion.by(day, peri, orbital_period);
-
                 // makes sure that the mean motion isn't negative
-                if mean_motion < 0.0 {
+                if mean_motion < F::zero() {
                     hx0 = -hx0;
                 }
 
-                hx0
+                settled(hx0)
             }
             orbit::Type::Elliptical => {
-                // Initial En which allows for precesion
-                let mut zdx: f64 = 10.0;
-
                 // Mean Anomaly
-                let xref = self.mean(day, peri, orbital_period);
-
-                // Initial Eccentric Anomaly
-                let mut zx0 = xref + orbital_eccentricity * xref.sin();
+                let xref = mean_motion.abs();
 
-                // Newtons Iterative step
-                while zdx > 1.0e-7 {
-                    let x0 = -(zx0 - orbital_eccentricity * zx0.sin() - xref);
-                    let x1 = 1.0 - orbital_eccentricity * zx0.cos();
-
-                    // En = - ((En - e * En.sin() - M(t)) / 1 - e * En.cos() )
-                    // the En at its first increment En = E0
-                    zdx = x0 / x1;
-
-                    // En = En + En+1
-                    zx0 = zx0 + zdx;
-                }
-
-                let mean_motion = MeanMotion.by(day, peri, orbital_period);
+                // Eccentric Anomaly, most planets are round enough for the fast path
+                let mut zx0 = match orbital_eccentricity < cast(HALLEY_ECCENTRICITY) {
+                    true => elliptical_halley(xref, orbital_eccentricity),
+                    false => elliptical_newton(xref, orbital_eccentricity)?,
+                };
 
                 // makes sure that the mean motion isn't negative
-                if mean_motion < 0.0 {
+                if mean_motion < F::zero() {
                     zx0 = -zx0;
                 }
 
-                // println!("zx0: {:?}", zx0);
+                #[cfg(feature = "log")]
+                log::trace!("eccentric anomaly: {}", zx0.to_f64().unwrap_or(f64::NAN));
 
-                zx0
+                settled(zx0)
             }
-            _ => 0.0,
+            _ => Err(Error::InvalidOrbit(NO_ANOMALY)),
         }
     }
 
-    /// (True Anomaly) Calculates the angle between the periapsis and the body's current position.
-    ///
-    /// * Elliptical Eccentric Anomaly
-    /// > $$\nu =2\,\operatorname {arctan} \left(\,{\sqrt {{1+e\,} \over {1-e\,}}}\tan {E \over 2}\,\right)$$
-    ///
-    /// * Hyperbolic (Eccentric) Anomaly
-    /// >  $$(\frac{e+1}{e-1})^{1/2}  \tanh(\frac{H}{2})$$
-    ///
-    /// * Parabolic (Eccentric) Anomaly
-    /// >  $$D = D/\sqrt{2q}$$
-    ///
-    /// * Circular (Eccentric) Anomaly
-    /// >  $$nt = M(t)$$
-    /// >  $$M = M_0 + nt$$
-    ///
-    pub fn truly(
-        self,
-        shape: crate::orbit::Type,
-        day: f64,
-        orbital_eccentricity: f64,
-        peri: Perihelion,
-        orbital_period: f64,
-        major_axis: f64,
-    ) -> f64 {
-        match shape {
-            orbit::Type::Circular => {
-                let mut theta: f64 = self.eccentric(
-                    shape,
-                    day,
-                    orbital_eccentricity,
-                    peri,
-                    orbital_period,
-                    major_axis,
-                );
+    /// (True Anomaly) This method solves the anomaly of a mean motion, see [`Anomaly::try_truly`]
+    pub(crate) fn truly(&self, mean_motion: F) -> Result<F> {
+        let theta = self.eccentric(mean_motion)?;
 
-                let mean_motion = MeanMotion.by(day, peri, orbital_period);
-
-                theta = theta + mean_motion;
-
-                theta
-            }
+        match self.shape {
+            orbit::Type::Circular => Ok(theta + mean_motion),
             orbit::Type::Parabolic => {
-                let theta: f64 = self.eccentric(
-                    shape,
-                    day,
-                    orbital_eccentricity,
-                    peri,
-                    orbital_period,
-                    major_axis,
-                );
-                let p = 0.0;
-                let q = p / 2.0_f64;
-
-                theta / (2.0_f64 * q).sqrt()
-            }
-            orbit::Type::Hyperbolic => {
-                let theta: f64 = self.eccentric(
-                    shape,
-                    day,
-                    orbital_eccentricity,
-                    peri,
-                    orbital_period,
-                    major_axis,
-                );
-
-                // tan v/2 = (e+1/e-1)^1/2 * tanh(F/2)
-                // `where F = H`
-                ((orbital_eccentricity + 1.0) / (orbital_eccentricity - 1.0)).powf(0.5)
-                    * (theta / 2.0).tanh()
+                let p = F::zero();
+                let q = p / cast(2.0);
+
+                Ok(theta / (cast::<F>(2.0) * q).sqrt())
             }
+            // tan v/2 = (e+1/e-1)^1/2 * tanh(F/2)
+            // `where F = H`
+            orbit::Type::Hyperbolic => Ok(self.true_factor * (theta / cast(2.0)).tanh()),
             orbit::Type::Elliptical => {
-                let theta: f64 = self.eccentric(
-                    shape,
-                    day,
-                    orbital_eccentricity,
-                    peri,
-                    orbital_period,
-                    major_axis,
-                );
-
-                // println!("zx0: {:?}", theta);
-
-                let mean_motion =
-                    ((1.0 + orbital_eccentricity) / (1.0 - orbital_eccentricity)).sqrt();
-
-                2.0 * (mean_motion * (theta / 2.0).tan()).atan()
+                Ok(cast::<F>(2.0) * (self.true_factor * (theta / cast(2.0)).tan()).atan())
             }
-            _ => 0.0,
+            _ => Err(Error::InvalidOrbit(NO_ANOMALY)),
         }
     }
 }
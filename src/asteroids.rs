@@ -0,0 +1,3 @@
+/// This module contains ceres calculations
+#[cfg(feature = "ceres")]
+pub mod ceres;
@@ -0,0 +1,98 @@
+use crate::{
+    julian::TimeScale,
+    kepler::{Body, Date, Discovery, Time},
+    orbit::Perihelion,
+};
+
+/// This is the mean radius of ceres in kilometers
+pub const CERES_RADIUS_KM: f64 = 469.7;
+
+/// A.D 2015 November 5, 00:00:00 UTC (Equinox observed by Dawn)
+pub const CERES_EPOCH_JD: f64 = 2_457_331.5;
+
+#[derive(Debug, Copy, Clone)]
+/// This structure represents the largest body in the asteroid belt
+pub struct Ceres;
+
+impl Body for Ceres {
+    fn name(&self) -> &str {
+        "Ceres"
+    }
+
+    /// See [`CERES_EPOCH_JD`]
+    fn epoch(&self) -> f64 {
+        CERES_EPOCH_JD
+    }
+
+    /// The [`CERES_EPOCH_JD`] is the start of a UTC calendar day
+    fn epoch_scale(&self) -> TimeScale {
+        TimeScale::UTC
+    }
+
+    /// A.D 1801 January 1, found by Giuseppe Piazzi from Palermo
+    fn discovery(&self) -> Option<Discovery> {
+        Some(Discovery {
+            jd: 2_378_861.5,
+            year: 1801,
+            discoverer: "Giuseppe Piazzi",
+        })
+    }
+
+    fn orbital_eccentricity(&self) -> f64 {
+        0.0785
+    }
+
+    /// 1680.22 earth days
+    fn orbital_period(&self) -> f64 {
+        4_443.9
+    }
+
+    /// 9.07417 hours
+    fn rotational_period(&self) -> f64 {
+        32_667.0
+    }
+
+    /// The perihelion was in 2018 April, around 2338 sols after the equinox.
+    ///
+    /// * The solar longitude of the perihelion is approximated.
+    fn perihelion(&self) -> Perihelion {
+        Perihelion {
+            month: (2_265.0, 2_579.0),
+            ls: (180.0, 210.0),
+            perihelion: 187.0,
+        }
+    }
+
+    fn semimajor(&self) -> f64 {
+        2.767
+    }
+
+    fn axial_tilt(&self) -> f64 {
+        4.0
+    }
+
+    fn mass_kg(&self) -> Option<f64> {
+        Some(9.3835e20)
+    }
+
+    /// See [`CERES_RADIUS_KM`]
+    fn mean_radius_km(&self) -> Option<f64> {
+        Some(CERES_RADIUS_KM)
+    }
+
+    fn inclination(&self) -> f64 {
+        10.59
+    }
+
+    fn ascending_node(&self) -> f64 {
+        80.31
+    }
+
+    fn longitude_of_perihelion(&self) -> f64 {
+        153.9
+    }
+
+    fn to_time(&self, _date: Date) -> Time {
+        Time::default().compute()
+    }
+}
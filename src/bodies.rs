@@ -0,0 +1,138 @@
+/// This module contains the loading of body definition files
+#[cfg(feature = "config")]
+mod config;
+
+#[cfg(feature = "config")]
+pub use config::{load_json, load_toml};
+
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+
+#[cfg(feature = "std")]
+use crate::kepler::CustomBody;
+#[cfg(feature = "rayon")]
+use crate::kepler::Date;
+use crate::kepler::Body;
+
+/// This macro assembles the registry from the bodies whose features are on,
+/// so a body is only registered (and built) with its feature, see `Cargo.toml`
+macro_rules! registry {
+    ($($feature:literal => $body:path),+ $(,)?) => {
+        /// Every built-in body of the enabled features
+        static REGISTRY: &[&dyn Body] = &[$(
+            #[cfg(feature = $feature)]
+            &$body,
+        )+];
+    };
+}
+
+// add new bodies here to register them, next to their feature
+registry! {
+    "ceres" => crate::asteroids::ceres::Ceres,
+    "earth" => crate::planets::earth::Earth,
+    "jupiter" => crate::planets::jupiter::Jupiter,
+    "luna" => crate::moons::luna::Luna,
+    "mars" => crate::planets::mars::Mars,
+    "saturn" => crate::planets::saturn::Saturn,
+}
+
+/// The bodies registered at runtime, see [`register`]
+#[cfg(feature = "std")]
+static REGISTERED: RwLock<Vec<&'static dyn Body>> = RwLock::new(Vec::new());
+
+/// This function returns all of the built-in bodies, only the ones whose features are on
+pub fn registry() -> &'static [&'static dyn Body] {
+    REGISTRY
+}
+
+/// This function registers a custom body next to the built-in bodies
+///
+/// * The body lives for the rest of the program, so register each body once
+/// * A built-in body or an earlier body with the same name is found first, see [`find`]
+/// * This needs the `std` feature, without it only the built-in bodies exist
+///
+/// ```rust
+/// use rust_solar::{bodies::{find, register}, kepler::CustomBody, orbit::Perihelion};
+///
+/// register(
+///     CustomBody::builder()
+///         .name("Vulcan")
+///         .orbital_eccentricity(0.02)
+///         .orbital_period(120.0)
+///         .rotational_period(100_000.0)
+///         .semimajor(0.3)
+///         .perihelion(Perihelion::new((40.0, 50.0), (90.0, 120.0), 100.0).unwrap())
+///         .build()
+///         .unwrap(),
+/// );
+///
+/// assert_eq!(120.0, find("vulcan").unwrap().orbital_period());
+/// ```
+#[cfg(feature = "std")]
+pub fn register(body: CustomBody) -> &'static dyn Body {
+    let body: &'static dyn Body = Box::leak(Box::new(body));
+
+    REGISTERED
+        .write()
+        .expect("Body registry to be writable")
+        .push(body);
+
+    body
+}
+
+/// This function returns the built-in bodies followed by the registered bodies, see [`register`]
+pub fn all() -> Vec<&'static dyn Body> {
+    #[cfg(feature = "std")]
+    let registered = REGISTERED
+        .read()
+        .expect("Body registry to be readable")
+        .clone();
+    #[cfg(not(feature = "std"))]
+    let registered = Vec::new();
+
+    registry().iter().copied().chain(registered).collect()
+}
+
+/// This function finds a built-in or a registered body by its name, ignoring case
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::bodies::find;
+///
+/// assert_eq!(668.6, find("mars").unwrap().orbital_period());
+/// # }
+/// ```
+pub fn find(name: &str) -> Option<&'static dyn Body> {
+    all()
+        .into_iter()
+        .find(|body| body.name().eq_ignore_ascii_case(name))
+}
+
+/// This function converts the same julian dates (UTC) on many bodies, on every core
+///
+/// * Each row is [`Body::to_dates`] of a body, in the same order as the bodies.
+/// * The rows are the same whatever the amount of threads, see [`Body::to_dates_par`]
+///
+/// ```rust
+/// use rust_solar::bodies::{ephemeris_table, registry};
+///
+/// let table = ephemeris_table(registry(), &[2451545.0, 2451546.0]);
+///
+/// assert_eq!(registry().len(), table.len());
+/// assert_eq!(2, table[0].len());
+/// ```
+///
+/// # Panics
+///
+/// When a julian date or a body can't be computed, see [`Body::try_to_date`]
+#[cfg(feature = "rayon")]
+pub fn ephemeris_table(bodies: &[&dyn Body], julian_dates: &[f64]) -> Vec<Vec<Date>> {
+    use rayon::prelude::*;
+
+    bodies
+        .par_iter()
+        .map(|body| body.to_dates_par(julian_dates))
+        .collect()
+}
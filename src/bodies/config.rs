@@ -0,0 +1,285 @@
+use std::{collections::BTreeSet, fs, path::Path, sync::Mutex};
+
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{Map, Value};
+
+use crate::{
+    clock::ZoneInfo,
+    error::{ConfigError, Error},
+    kepler::CustomBody,
+    orbit::Perihelion,
+};
+
+/// The codes and the names of the loaded timezones, see [`intern`]
+static ZONE_TEXT: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+/// Every field a body definition can have
+const FIELDS: [&str; 10] = [
+    "name",
+    "epoch",
+    "epoch_year",
+    "orbital_eccentricity",
+    "orbital_period",
+    "rotational_period",
+    "semimajor",
+    "perihelion",
+    "axial_tilt",
+    "timezones",
+];
+
+/// This function loads the bodies of a TOML file
+///
+/// * The bodies are an array of tables named `bodies`, see `examples/bodies.toml`
+/// * The bodies own their names and their timezones, so they're freed with the bodies.
+/// * The codes and the names of the timezones are kept once for the rest of the program, a reload shares them
+///
+/// ```toml
+/// [[bodies]]
+/// name = "Vulcan"
+/// orbital_eccentricity = 0.02
+/// orbital_period = 120.0
+/// rotational_period = 100000.0
+/// semimajor = 0.3
+/// perihelion = { month = [40.0, 50.0], ls = [90.0, 120.0], perihelion = 100.0 }
+/// ```
+pub fn load_toml(path: impl AsRef<Path>) -> Result<Vec<CustomBody>, ConfigError> {
+    let text = fs::read_to_string(path).map_err(|error| ConfigError::Io(error.to_string()))?;
+    let file: toml::Value =
+        toml::from_str(&text).map_err(|error| ConfigError::Parse(error.to_string()))?;
+
+    load(serde_json::to_value(file).map_err(|error| ConfigError::Parse(error.to_string()))?)
+}
+
+/// This function loads the bodies of a JSON document, with the same fields as [`load_toml`]
+///
+/// ```rust
+/// use rust_solar::{bodies::load_json, kepler::Body};
+///
+/// let bodies = load_json(r#"{
+///     "bodies": [{
+///         "name": "Vulcan",
+///         "orbital_eccentricity": 0.02,
+///         "orbital_period": 120.0,
+///         "rotational_period": 100000.0,
+///         "semimajor": 0.3,
+///         "perihelion": { "month": [40.0, 50.0], "ls": [90.0, 120.0], "perihelion": 100.0 }
+///     }]
+/// }"#).unwrap();
+///
+/// assert_eq!("Vulcan", bodies[0].name());
+/// ```
+pub fn load_json(json: &str) -> Result<Vec<CustomBody>, ConfigError> {
+    load(serde_json::from_str(json).map_err(|error| ConfigError::Parse(error.to_string()))?)
+}
+
+/// The perihelion of a body definition, see [`Perihelion`]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PerihelionDefinition {
+    month: (f64, f64),
+    ls: (f64, f64),
+    perihelion: f64,
+}
+
+/// The timezone of a body definition, see [`ZoneInfo`]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ZoneDefinition {
+    code: String,
+    name: String,
+    offset: f64,
+    east: f64,
+    west: f64,
+}
+
+/// A body definition and the name it's reported by
+struct Entry<'a> {
+    label: String,
+    fields: &'a Map<String, Value>,
+}
+
+impl Entry<'_> {
+    /// This method reads an optional field
+    fn optional<T: DeserializeOwned>(&self, field: &str) -> Result<Option<T>, ConfigError> {
+        self.fields
+            .get(field)
+            .map(|value| T::deserialize(value).map_err(|error| self.error(field, error)))
+            .transpose()
+    }
+
+    /// This method reads a field that must be there
+    fn required<T: DeserializeOwned>(&self, field: &str) -> Result<T, ConfigError> {
+        self.optional(field)?
+            .ok_or_else(|| self.error(field, "the field is missing"))
+    }
+
+    /// This method reads a number that must be finite and pass a check
+    fn number(
+        &self,
+        field: &str,
+        check: fn(f64) -> bool,
+        reason: &str,
+    ) -> Result<Option<f64>, ConfigError> {
+        match self.optional::<f64>(field)? {
+            Some(value) if !value.is_finite() => Err(self.error(field, "expected a finite number")),
+            Some(value) if !check(value) => Err(self.error(field, reason)),
+            value => Ok(value),
+        }
+    }
+
+    /// This method reports an error of the library on a field of the entry
+    fn invalid(&self, field: &str, source: Error) -> ConfigError {
+        ConfigError::Invalid {
+            entry: self.label.clone(),
+            field: field.to_string(),
+            source,
+        }
+    }
+
+    /// This method reports a field of the entry
+    fn error(&self, field: &str, reason: impl ToString) -> ConfigError {
+        ConfigError::Field {
+            entry: self.label.clone(),
+            field: field.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// This function loads every body of a parsed file
+fn load(file: Value) -> Result<Vec<CustomBody>, ConfigError> {
+    let Some(Value::Array(bodies)) = file.get("bodies") else {
+        return Err(ConfigError::Parse(
+            "expected a list named `bodies`".to_string(),
+        ));
+    };
+
+    bodies
+        .iter()
+        .enumerate()
+        .map(|(i, body)| load_body(i, body))
+        .collect()
+}
+
+/// This function loads a body definition, `i` is its position in the list
+fn load_body(i: usize, body: &Value) -> Result<CustomBody, ConfigError> {
+    let label = match body.get("name") {
+        Some(Value::String(name)) => name.clone(),
+        _ => format!("#{}", i + 1),
+    };
+
+    let Value::Object(fields) = body else {
+        return Err(ConfigError::Field {
+            entry: label,
+            field: String::new(),
+            reason: "expected a table of fields".to_string(),
+        });
+    };
+
+    let entry = Entry { label, fields };
+
+    if let Some(field) = fields.keys().find(|key| !FIELDS.contains(&key.as_str())) {
+        return Err(entry.error(field, "the field is unknown"));
+    }
+
+    let any = |_| true;
+    let positive = |value| value > 0.0;
+
+    let name = entry.optional::<String>("name")?;
+    let epoch = entry.number("epoch", any, "")?;
+    let epoch_year = entry.number("epoch_year", any, "")?;
+    let orbital_eccentricity = entry
+        .number(
+            "orbital_eccentricity",
+            |value| value >= 0.0,
+            "the eccentricity is negative",
+        )?
+        .ok_or_else(|| entry.error("orbital_eccentricity", "the field is missing"))?;
+    let orbital_period = entry
+        .number(
+            "orbital_period",
+            positive,
+            "the orbital period isn't positive",
+        )?
+        .ok_or_else(|| entry.error("orbital_period", "the field is missing"))?;
+    let rotational_period = entry
+        .number(
+            "rotational_period",
+            positive,
+            "the rotational period isn't positive",
+        )?
+        .ok_or_else(|| entry.error("rotational_period", "the field is missing"))?;
+    let semimajor = entry
+        .number("semimajor", positive, "the semimajor axis isn't positive")?
+        .ok_or_else(|| entry.error("semimajor", "the field is missing"))?;
+    let axial_tilt = entry.number("axial_tilt", any, "")?;
+
+    let perihelion: PerihelionDefinition = entry.required("perihelion")?;
+    let perihelion = Perihelion::new(perihelion.month, perihelion.ls, perihelion.perihelion)
+        .map_err(|error| entry.invalid("perihelion", error))?;
+
+    let timezones = entry
+        .optional::<Vec<ZoneDefinition>>("timezones")?
+        .unwrap_or_default();
+
+    if timezones
+        .iter()
+        .any(|zone| !zone.offset.is_finite() || !zone.east.is_finite() || !zone.west.is_finite())
+    {
+        return Err(entry.error("timezones", "expected finite offsets and longitudes"));
+    }
+
+    let mut builder = CustomBody::builder()
+        .orbital_eccentricity(orbital_eccentricity)
+        .orbital_period(orbital_period)
+        .rotational_period(rotational_period)
+        .semimajor(semimajor)
+        .perihelion(perihelion)
+        .timezones(timezones.into_iter().map(zone_info).collect::<Vec<_>>());
+
+    if let Some(name) = name {
+        builder = builder.name(name);
+    }
+
+    if let Some(epoch) = epoch {
+        builder = builder.epoch(epoch);
+    }
+
+    if let Some(epoch_year) = epoch_year {
+        builder = builder.epoch_year(epoch_year);
+    }
+
+    if let Some(axial_tilt) = axial_tilt {
+        builder = builder.axial_tilt(axial_tilt);
+    }
+
+    builder.build().map_err(|error| entry.invalid("", error))
+}
+
+/// This function turns a timezone definition into a timezone, see [`intern`]
+fn zone_info(zone: ZoneDefinition) -> ZoneInfo {
+    ZoneInfo {
+        code: intern(zone.code),
+        name: intern(zone.name),
+        offset: zone.offset,
+        east: zone.east,
+        west: zone.west,
+    }
+}
+
+/// This function returns a text that lives for the rest of the program, the same one for equal texts
+///
+/// * A time carries the code and the name of its timezone without allocating, see [`crate::kepler::Time`],
+///   so they're kept for good, but each one only once, loading a file again doesn't keep any more of them.
+fn intern(text: String) -> &'static str {
+    let mut interned = ZONE_TEXT.lock().expect("Timezone texts to be lockable");
+
+    if let Some(text) = interned.get(text.as_str()) {
+        return text;
+    }
+
+    let text: &'static str = text.leak();
+
+    interned.insert(text);
+    text
+}
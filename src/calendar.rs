@@ -0,0 +1,667 @@
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    conversions::Days,
+    error::{Error, Result},
+    julian::TimeScale,
+    kepler::{Body, Date, Eras},
+    orbit::{Orbit, Season},
+};
+
+/// This is a sol of a [`MonthGrid`], with where the body is on its orbit in the middle of the sol
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GridSol {
+    /// The sol of the year, counted from 1, see [`Date::day`]
+    pub day: u32,
+    /// The solar longitude in degrees
+    pub ls: f64,
+    /// The season, from the season table of the body when it has one
+    pub season: Season,
+}
+
+/// This is the layout of a month of a body in weeks, to print a calendar, see [`month_grid`]
+///
+/// * The weeks run on through the years, see [`Date::week_since_epoch`],
+///   so a month can start in the middle of a week.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MonthGrid {
+    /// The year of the month
+    pub year: f64,
+    /// The month, counted from 1
+    pub month: u32,
+    /// The sols in a week
+    pub week_len: u8,
+    /// The weeks of the month, each one is `week_len` long, with `None` where the sol is in another month
+    pub rows: Vec<Vec<Option<u32>>>,
+    /// The sols of the month, in order
+    pub sols: Vec<GridSol>,
+}
+
+/// This function lays out the sols of a month of a body in weeks
+///
+/// * A sol is in the month of its middle, like [`Body::to_julian_midsol`], so the first sol of a year that starts
+///   just before the solar longitude of 0 is still in the first month.
+/// * The months are equal slices of solar longitude, so they have more sols near the aphelion,
+///   and the last sol of the year is only a fraction of a sol.
+/// * The months of a year hold each of its sols once (ex; 669 sols for the 668.6 of mars).
+/// * It fails with [`Error::InvalidDate`] when the week is empty or the month is outside of the year.
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::{calendar::month_grid, planets::mars::Mars};
+///
+/// let grid = month_grid(&Mars, 36.0, 1, 7).unwrap();
+///
+/// assert_eq!(Some(&1), grid.rows[0].iter().flatten().next());
+/// assert!(grid.rows.iter().all(|week| week.len() == 7));
+/// # }
+/// ```
+pub fn month_grid(
+    body: &(impl Body + ?Sized),
+    year: f64,
+    month: u32,
+    week_len: u8,
+) -> Result<MonthGrid> {
+    if !year.is_finite() {
+        return Err(Error::NonFinite);
+    }
+
+    if week_len == 0 {
+        return Err(Error::InvalidDate("the week has no sols"));
+    }
+
+    let mut months = months_of_year(body, year)?;
+
+    if month == 0 || month as usize > months.len() {
+        return Err(Error::InvalidDate("the month is outside of the year"));
+    }
+
+    let sols = months.swap_remove(month as usize - 1);
+
+    let week_len_sols = usize::from(week_len);
+    let lead = match sols.first() {
+        Some(first) => {
+            let date = Date {
+                year,
+                day: f64::from(first.day),
+                ..Date::default()
+            };
+
+            usize::from(date.sol_of_week(body, week_len)? - 1)
+        }
+        None => 0,
+    };
+    let mut cells = vec![None; lead];
+
+    cells.extend(sols.iter().map(|sol| Some(sol.day)));
+
+    let rows = cells
+        .chunks(week_len_sols)
+        .map(|week| {
+            let mut week = week.to_vec();
+
+            week.resize(week_len_sols, None);
+            week
+        })
+        .collect();
+
+    Ok(MonthGrid {
+        year,
+        month,
+        week_len,
+        rows,
+        sols,
+    })
+}
+
+/// This function sorts the sols of a year into its months, see [`month_grid`] for which month a sol is in
+pub(crate) fn months_of_year(body: &(impl Body + ?Sized), year: f64) -> Result<Vec<Vec<GridSol>>> {
+    let months = (360.0 / body.perihelion().avg_ls()).ceil() as usize;
+    let period = body.orbital_period();
+    let mut sorted = vec![Vec::new(); months];
+
+    for day in 1..=period.ceil() as u32 {
+        // the middle of the sol, where the last sol of the year ends at the end of the year
+        let middle = (f64::from(day - 1) + f64::from(day).min(period)) / 2.0;
+        let date = Date::from_ordinal(year, 1.0 + middle, body)?;
+
+        if let Some(month) = sorted.get_mut(date.month as usize - 1) {
+            month.push(GridSol {
+                day,
+                ls: date.ls,
+                season: date.season,
+            });
+        }
+    }
+
+    Ok(sorted)
+}
+
+/// This trait is a calendar of whole sols, where the years may have different lengths
+///
+/// * The sols are counted from the start of the year 0 of the calendar, so a calendar doesn't depend on a body.
+/// * A calendar only needs the sols of its years, the conversions between sols and ordinals follow from them.
+///
+/// ```rust
+/// use rust_solar::calendar::{Calendar, IntercalatedCalendar, LeapRule};
+///
+/// let darian = IntercalatedCalendar { base_sols: 668, rule: LeapRule::DARIAN };
+///
+/// assert!(darian.is_leap_year(1));
+/// assert_eq!(669, darian.sols_in_year(1));
+/// // the year 0 is a leap year of the 500 years
+/// assert_eq!(Ok((1, 1)), darian.to_ordinal(669));
+/// ```
+pub trait Calendar: Send + Sync {
+    /// The name of the calendar
+    fn name(&self) -> &'static str;
+
+    /// This method returns the sols of a year, with its leap sols
+    fn sols_in_year(&self, year: i64) -> u32;
+
+    /// This method returns whether a year has a leap sol, no year has one by default
+    fn is_leap_year(&self, _year: i64) -> bool {
+        false
+    }
+
+    /// This method returns the sols from the start of the year 0 to the start of a year, negative before it
+    ///
+    /// * By default it adds up the years one by one, a calendar with a rule should override it.
+    fn year_start(&self, year: i64) -> i64 {
+        let sols = |years: core::ops::Range<i64>| -> i64 {
+            years.map(|year| i64::from(self.sols_in_year(year))).sum()
+        };
+
+        match year >= 0 {
+            true => sols(0..year),
+            false => -sols(year..0),
+        }
+    }
+
+    /// This method returns the sols from the start of the year 0 to the start of a sol of a year, counted from 1
+    ///
+    /// * It fails with [`Error::InvalidDate`] when the sol is outside of the year.
+    fn to_sols(&self, year: i64, ordinal: u32) -> Result<i64> {
+        if ordinal == 0 || ordinal > self.sols_in_year(year) {
+            return Err(Error::InvalidDate("the day is outside of the year"));
+        }
+
+        Ok(self.year_start(year) + i64::from(ordinal) - 1)
+    }
+
+    /// This method returns the year and the sol of the year (counted from 1) of the sols from the start of the year 0,
+    /// the inverse of [`Calendar::to_sols`]
+    ///
+    /// * It fails with [`Error::InvalidDate`] when the year 0 has no sols.
+    fn to_ordinal(&self, sols: i64) -> Result<(i64, u32)> {
+        let length = i64::from(self.sols_in_year(0));
+
+        if length == 0 {
+            return Err(Error::InvalidDate("the year has no sols"));
+        }
+
+        let mut year = sols.div_euclid(length);
+
+        while self.year_start(year) > sols {
+            year -= 1;
+        }
+
+        while self.year_start(year + 1) <= sols {
+            year += 1;
+        }
+
+        Ok((year, (sols - self.year_start(year) + 1) as u32))
+    }
+
+    /// This method returns the month of a sol of a year (counted from 1), a calendar has a single month by default
+    fn month(&self, _year: i64, _ordinal: u32) -> u32 {
+        1
+    }
+
+    /// This method returns a year of the calendar and the julian date (UTC) where it starts on a body
+    ///
+    /// * By default the year of the [`Body::epoch_year`] starts at the [`Body::epoch`].
+    fn epoch(&self, body: &dyn Body) -> (i64, f64) {
+        (
+            body.epoch_year() as i64,
+            body.epoch_jd().to_scale(TimeScale::UTC).value,
+        )
+    }
+
+    /// This method computes the date of a julian date (UTC) on a body in the calendar, see [`CalendarView`]
+    ///
+    /// * The sols are the sols of the body since the [`Calendar::epoch`].
+    /// * The solar longitude and the season are the body's, only the year, the month and the sol are the calendar's.
+    fn to_date(&self, body: &dyn Body, julian_date: f64) -> Result<Date> {
+        let planet = body.try_to_date(julian_date)?;
+        let (epoch_year, epoch) = self.epoch(body);
+        let elapsed = Days(julian_date - epoch).sols(body.rotational_period_seconds());
+        let whole = elapsed.floor();
+        let (year, ordinal) = self.to_ordinal(self.year_start(epoch_year) + whole as i64)?;
+
+        Ok(Date {
+            era: match year > 0 {
+                true => Eras::AD,
+                false => Eras::BD,
+            },
+            year: year as f64,
+            month: f64::from(self.month(year, ordinal)),
+            day: f64::from(ordinal),
+            sol: f64::from(ordinal - 1) + (elapsed - whole),
+            ..planet
+        })
+    }
+
+    /// This method returns the julian date (UTC) where the sol of a date of the calendar begins, the inverse of
+    /// [`Calendar::to_date`]
+    ///
+    /// * It fails with [`Error::InvalidDate`] when the sol is outside of the year.
+    fn to_julian(&self, body: &dyn Body, date: &Date) -> Result<f64> {
+        if !date.year.is_finite() || !date.day.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        if date.day < 1.0 {
+            return Err(Error::InvalidDate("the day is outside of the year"));
+        }
+
+        let (epoch_year, epoch) = self.epoch(body);
+        let sols = self.to_sols(date.year as i64, date.day as u32)? - self.year_start(epoch_year);
+
+        Ok(epoch + (body.rotational_period_seconds() * sols as f64).to_days().0)
+    }
+}
+
+/// This is a cycle of years of a [`LeapRule`], the years where `year % every == offset`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LeapCycle {
+    /// The years of the cycle
+    pub every: u32,
+    /// The year of the cycle that matches, from 0 up to `every`
+    pub offset: u32,
+    /// Whether the years that match have a leap sol
+    pub leap: bool,
+}
+
+/// This is the rule of the leap years of an [`IntercalatedCalendar`], a list of cycles where the later ones win
+///
+/// * A year has no leap sol unless a cycle matches it, the last cycle that matches decides
+///   (ex; every 2 years except every 10 years is a leap cycle of 2 years, then a cycle of 10 years that isn't leap).
+/// * It's created through [`LeapRule::new`], so its cycles are always valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LeapRule {
+    cycles: &'static [LeapCycle],
+}
+
+impl LeapRule {
+    /// The rule of the darian calendar of mars, the odd years and every 10 years, except every 100 years,
+    /// except every 500 years
+    ///
+    /// * It has 296 leap years every 500 years, 668.592 sols a year.
+    pub const DARIAN: Self = Self {
+        cycles: &[
+            LeapCycle {
+                every: 2,
+                offset: 1,
+                leap: true,
+            },
+            LeapCycle {
+                every: 10,
+                offset: 0,
+                leap: true,
+            },
+            LeapCycle {
+                every: 100,
+                offset: 0,
+                leap: false,
+            },
+            LeapCycle {
+                every: 500,
+                offset: 0,
+                leap: true,
+            },
+        ],
+    };
+
+    /// This method creates a rule from its cycles, the later ones win
+    ///
+    /// * It fails with [`Error::InvalidDate`] when a cycle is empty or its offset is outside of it.
+    pub fn new(cycles: &'static [LeapCycle]) -> Result<Self> {
+        if cycles
+            .iter()
+            .any(|cycle| cycle.every == 0 || cycle.offset >= cycle.every)
+        {
+            return Err(Error::InvalidDate(
+                "the leap cycle is empty or its offset is outside of it",
+            ));
+        }
+
+        Ok(Self { cycles })
+    }
+
+    /// This method returns the cycles of the rule
+    pub fn cycles(&self) -> &'static [LeapCycle] {
+        self.cycles
+    }
+
+    /// This method returns whether a year has a leap sol
+    pub fn is_leap(&self, year: i64) -> bool {
+        self.cycles
+            .iter()
+            .rev()
+            .find(|cycle| year.rem_euclid(i64::from(cycle.every)) == i64::from(cycle.offset))
+            .is_some_and(|cycle| cycle.leap)
+    }
+
+    /// This method returns the years after which the rule repeats, the least common multiple of its cycles
+    pub fn period(&self) -> i64 {
+        self.cycles.iter().fold(1, |period, cycle| {
+            let every = i64::from(cycle.every);
+
+            period / gcd(period, every) * every
+        })
+    }
+
+    /// This method counts the leap years from the year 0 up to a year, the year itself isn't counted
+    fn leaps_before(&self, year: i64) -> i64 {
+        let period = self.period();
+        let leaps = |years: i64| (0..years).filter(|year| self.is_leap(*year)).count() as i64;
+
+        year.div_euclid(period) * leaps(period) + leaps(year.rem_euclid(period))
+    }
+}
+
+/// This function returns the greatest common divisor of two positive numbers
+fn gcd(a: i64, b: i64) -> i64 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+/// This is a calendar of years of whole sols, with a leap sol in the years of a rule
+///
+/// * The leap sols keep the years near the orbit of a body (ex; 668 sols and [`LeapRule::DARIAN`] for mars).
+///
+/// ```rust
+/// use rust_solar::calendar::{Calendar, IntercalatedCalendar, LeapRule};
+///
+/// let darian = IntercalatedCalendar { base_sols: 668, rule: LeapRule::DARIAN };
+///
+/// assert_eq!(Ok(darian.year_start(10)), darian.to_sols(9, 669).map(|sols| sols + 1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct IntercalatedCalendar {
+    /// The sols of a year without a leap sol
+    pub base_sols: u32,
+    /// The years with a leap sol
+    pub rule: LeapRule,
+}
+
+impl Calendar for IntercalatedCalendar {
+    fn name(&self) -> &'static str {
+        "Intercalated"
+    }
+
+    fn sols_in_year(&self, year: i64) -> u32 {
+        self.base_sols + u32::from(self.is_leap_year(year))
+    }
+
+    fn is_leap_year(&self, year: i64) -> bool {
+        self.rule.is_leap(year)
+    }
+
+    fn year_start(&self, year: i64) -> i64 {
+        year * i64::from(self.base_sols) + self.rule.leaps_before(year)
+    }
+}
+
+/// The sols of a darian year without a leap sol
+const DARIAN_SOLS: u32 = 668;
+
+/// The sols of a quarter of a darian year, 5 months of 28 sols and one of 27
+const DARIAN_QUARTER_SOLS: u32 = 167;
+
+/// The darian year that starts at the [`DARIAN_EPOCH`]
+const DARIAN_EPOCH_YEAR: i64 = 219;
+
+/// The julian date (UTC) where the darian year 219 starts, the MSD 52292 (2021 February 5)
+///
+/// * The year 0 starts at the MSD -94129 of the telescopic epoch (1609 March 12), 146421 sols earlier,
+///   but a recent epoch keeps the sols on the midnights of the MTC, since a sol of a body is rounded.
+/// * It's the TT of the MSD less the 69.184 seconds of UTC today.
+const DARIAN_EPOCH: f64 = 2459251.5746111;
+
+/// This is the darian calendar of mars, 24 months of 28 sols where every sixth month has 27 sols
+///
+/// * The years are counted from the telescopic epoch of 1609, the year 0, so they are about 183 years ahead of the
+///   mars years of [`Body::to_date`] (ex; the mars year 36 is mostly the darian year 219).
+/// * The years follow [`LeapRule::DARIAN`], the leap sol ends the last month.
+/// * The months are whole sols, so they drift from the solar longitude within a year, unlike [`LsCalendar`].
+///
+/// ```rust
+/// use rust_solar::calendar::{Calendar, DarianCalendar};
+///
+/// assert_eq!(6, DarianCalendar.month(36, 167));
+/// assert_eq!(7, DarianCalendar.month(36, 168));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DarianCalendar;
+
+impl DarianCalendar {
+    /// The years of the calendar
+    const YEARS: IntercalatedCalendar = IntercalatedCalendar {
+        base_sols: DARIAN_SOLS,
+        rule: LeapRule::DARIAN,
+    };
+}
+
+impl Calendar for DarianCalendar {
+    fn name(&self) -> &'static str {
+        "Darian"
+    }
+
+    fn sols_in_year(&self, year: i64) -> u32 {
+        Self::YEARS.sols_in_year(year)
+    }
+
+    fn is_leap_year(&self, year: i64) -> bool {
+        Self::YEARS.is_leap_year(year)
+    }
+
+    fn year_start(&self, year: i64) -> i64 {
+        Self::YEARS.year_start(year)
+    }
+
+    fn epoch(&self, _body: &dyn Body) -> (i64, f64) {
+        (DARIAN_EPOCH_YEAR, DARIAN_EPOCH)
+    }
+
+    fn month(&self, _year: i64, ordinal: u32) -> u32 {
+        let sol = ordinal.saturating_sub(1);
+        let quarter = (sol / DARIAN_QUARTER_SOLS).min(3);
+        let within = sol - quarter * DARIAN_QUARTER_SOLS;
+
+        6 * quarter + (within / 28).min(5) + 1
+    }
+}
+
+/// This is the calendar of a body where the months are equal slices of solar longitude, like [`Body::to_date`]
+///
+/// * The last sol of a year is only a fraction of a sol, but it's still a sol of the calendar
+///   (ex; 669 sols for the 668.6 of mars), see [`Date::week_since_epoch`].
+/// * The dates are the body's, see [`Body::try_to_date`] and [`Body::to_julian`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LsCalendar {
+    orbit: Orbit,
+    orbital_period: f64,
+}
+
+impl LsCalendar {
+    /// This method creates the calendar of a body
+    pub fn new(body: &(impl Body + ?Sized)) -> Result<Self> {
+        Ok(Self {
+            orbit: Orbit::new(
+                body.orbital_eccentricity(),
+                body.perihelion(),
+                body.orbital_period(),
+                body.semimajor(),
+            )?,
+            orbital_period: body.orbital_period(),
+        })
+    }
+}
+
+impl Calendar for LsCalendar {
+    fn name(&self) -> &'static str {
+        "Ls"
+    }
+
+    fn sols_in_year(&self, _year: i64) -> u32 {
+        self.orbital_period.ceil() as u32
+    }
+
+    /// The month at the start of the sol
+    fn month(&self, _year: i64, ordinal: u32) -> u32 {
+        self.orbit
+            .ls(f64::from(ordinal.saturating_sub(1)))
+            .map_or(1, |ls| self.orbit.month(ls) as u32)
+    }
+
+    fn to_date(&self, body: &dyn Body, julian_date: f64) -> Result<Date> {
+        body.try_to_date(julian_date)
+    }
+
+    fn to_julian(&self, body: &dyn Body, date: &Date) -> Result<f64> {
+        body.to_julian(date)
+    }
+}
+
+/// This is a body seen through a calendar, so many calendars can show the same body without changing it
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::{calendar::{CalendarView, DarianCalendar}, planets::mars::Mars};
+///
+/// let darian = CalendarView::new(&Mars, &DarianCalendar);
+/// let date = darian.to_date(2451545.0).unwrap();
+/// let start = darian.to_julian(&date).unwrap();
+///
+/// assert!(start <= 2451545.0);
+/// assert_eq!(date.day, darian.to_date(start + 0.01).unwrap().day);
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct CalendarView<'a> {
+    body: &'a dyn Body,
+    calendar: &'a dyn Calendar,
+}
+
+impl<'a> CalendarView<'a> {
+    /// This method shows a body through a calendar
+    pub fn new(body: &'a dyn Body, calendar: &'a dyn Calendar) -> Self {
+        Self { body, calendar }
+    }
+
+    /// This method returns the name of the calendar
+    pub fn name(&self) -> &'static str {
+        self.calendar.name()
+    }
+
+    /// This method computes the date of a julian date (UTC) in the calendar, see [`Calendar::to_date`]
+    pub fn to_date(&self, julian_date: f64) -> Result<Date> {
+        self.calendar.to_date(self.body, julian_date)
+    }
+
+    /// This method returns the julian date (UTC) where the sol of a date begins, see [`Calendar::to_julian`]
+    pub fn to_julian(&self, date: &Date) -> Result<f64> {
+        self.calendar.to_julian(self.body, date)
+    }
+}
+
+impl fmt::Debug for CalendarView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CalendarView")
+            .field("body", &self.body.name())
+            .field("calendar", &self.calendar.name())
+            .finish()
+    }
+}
+
+/// This is a body with the calendars it's compared in, see [`Calendars::compare`]
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::{calendar::{Calendars, DarianCalendar, LsCalendar}, planets::mars::Mars};
+///
+/// let ls = LsCalendar::new(&Mars).unwrap();
+/// let calendars = Calendars::new(&Mars).register(&DarianCalendar).register(&ls);
+/// let dates = calendars.compare(2451545.0).unwrap();
+///
+/// assert_eq!(vec!["Darian", "Ls"], dates.iter().map(|(name, _)| *name).collect::<Vec<_>>());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Calendars<'a> {
+    body: &'a dyn Body,
+    calendars: Vec<&'a dyn Calendar>,
+}
+
+impl<'a> Calendars<'a> {
+    /// This method starts the calendars of a body, without any of them
+    pub fn new(body: &'a dyn Body) -> Self {
+        Self {
+            body,
+            calendars: Vec::new(),
+        }
+    }
+
+    /// This method adds a calendar, the dates are compared in the order of the calendars
+    pub fn register(mut self, calendar: &'a dyn Calendar) -> Self {
+        self.calendars.push(calendar);
+        self
+    }
+
+    /// This method returns a view of the body through each calendar
+    pub fn views(&self) -> impl Iterator<Item = CalendarView<'a>> + '_ {
+        self.calendars
+            .iter()
+            .map(|calendar| CalendarView::new(self.body, *calendar))
+    }
+
+    /// This method computes the date of a julian date (UTC) in each calendar, with the name of the calendar
+    pub fn compare(&self, julian_date: f64) -> Result<Vec<(&'static str, Date)>> {
+        self.views()
+            .map(|view| Ok((view.name(), view.to_date(julian_date)?)))
+            .collect()
+    }
+}
+
+impl fmt::Debug for Calendars<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Calendars")
+            .field("body", &self.body.name())
+            .field(
+                "calendars",
+                &self
+                    .calendars
+                    .iter()
+                    .map(|calendar| calendar.name())
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
@@ -0,0 +1,433 @@
+use alloc::string::{String, ToString};
+use core::{fmt, time::Duration};
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    conversions::normalize_degrees,
+    julian::{SplitJd, TimeScale},
+    kepler::{is_single_sol_year, sol_fraction, Body, DateTime, Time, TimeZone},
+};
+
+/// This is the calibration of a body's sol count, like the mars sol date (MSD)
+///
+/// > $$sol = \frac{JD_{TT} - epoch}{length} + offset - alignment$$
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClockCalibration {
+    /// This is the julian date (TT) where the sol count is the offset
+    pub epoch_tt: f64,
+    /// This is the length of a sol in earth days
+    pub sol_length: f64,
+    /// This is the sol count at the epoch
+    pub sol_offset: f64,
+    /// This is the fraction of a sol that moves midnight onto the prime meridian
+    pub alignment: f64,
+}
+
+impl ClockCalibration {
+    /// This method creates a calibration that counts sols from the epoch of a body
+    ///
+    /// * When the year of the body is a single sol, the sun stands still over it, so the sol is infinite and
+    ///   every longitude keeps the time of day it had at the epoch, see [`crate::kepler::LOCKED_TOLERANCE`]
+    pub fn from_body(body: &(impl Body + ?Sized)) -> Self {
+        let sol_length = match is_single_sol_year(body.orbital_period()) {
+            true => f64::INFINITY,
+            false => body.rotational_period_seconds().to_days().0,
+        };
+
+        Self {
+            epoch_tt: body.epoch_jd().to_scale(TimeScale::TT).value,
+            sol_length,
+            sol_offset: 0.0,
+            alignment: 0.0,
+        }
+    }
+
+    /// This method counts the sols of a julian date (UTC), with their fraction
+    ///
+    /// * A [`SplitJd`] keeps the precision of the fraction, which a `f64` julian date loses next to the epoch.
+    pub fn sol_date(&self, julian_date: impl Into<SplitJd>) -> f64 {
+        let jd_tt = julian_date.into().utc_to_tt();
+
+        (jd_tt - SplitJd::from(self.epoch_tt)) / self.sol_length + self.sol_offset - self.alignment
+    }
+}
+
+/// This is a clock that tells the time of any body, given its calibration
+///
+/// * Timezones of a body only need their offset from the prime meridian, see [`crate::planets::mars::Martian`]
+///
+#[derive(Debug, Clone, Copy)]
+pub struct BodyClock<B: Body> {
+    /// This is the body the clock is on
+    pub body: B,
+    /// This is the calibration of the sol count
+    pub calibration: ClockCalibration,
+}
+
+impl<B: Body> BodyClock<B> {
+    /// This method creates a clock for a body
+    pub const fn new(body: B, calibration: ClockCalibration) -> Self {
+        Self { body, calibration }
+    }
+
+    /// This method creates a clock that counts sols from the epoch of the body
+    pub fn from_body(body: B) -> Self {
+        let calibration = ClockCalibration::from_body(&body);
+
+        Self { body, calibration }
+    }
+
+    /// This method counts the sols at an offset from the prime meridian, with their fraction
+    ///
+    /// * The whole part is the local sol and the fraction is the local time of the sol.
+    pub fn sol_date(&self, julian_date: impl Into<SplitJd>, offset_hours: f64) -> f64 {
+        self.calibration.sol_date(julian_date) + offset_hours / 24.0
+    }
+
+    /// This method returns the sols the offset is ahead (1) or behind (-1) of the prime meridian
+    ///
+    /// * The sols are counted on the millisecond the time is rounded to, so a time that rounds up to
+    ///   midnight is already on the next sol, see [`Time::from_sol_fraction`]
+    pub fn sol_carry(&self, julian_date: impl Into<SplitJd>, offset_hours: f64) -> i64 {
+        let prime = self.calibration.sol_date(julian_date);
+        let local = prime + offset_hours / 24.0;
+
+        (whole_sols(local) - whole_sols(prime)) as i64
+    }
+
+    /// This method returns the time at an offset from the prime meridian in hours, from 0 up to 24
+    ///
+    /// * It's continuous, see [`crate::kepler::TimeZone::fractional_hours`]
+    pub fn fractional_hours(&self, julian_date: impl Into<SplitJd>, offset_hours: f64) -> f64 {
+        sol_fraction(self.sol_date(julian_date, offset_hours)) * 24.0
+    }
+
+    /// This method returns the time at an offset from the prime meridian
+    pub fn at(&self, julian_date: impl Into<SplitJd>, offset_hours: f64) -> Time {
+        Time::from_sol_fraction(self.sol_date(julian_date, offset_hours).rem_euclid(1.0))
+    }
+
+    /// This method returns the times of a timezone on the clock, every interval from a julian date (UTC), see [`Ticks`]
+    ///
+    /// * The interval is in seconds of the clock, a 86400th of the sol (ex; about 1.0275 earth seconds on mars).
+    pub fn ticks<Z: TimeZone>(&self, zone: Z, start_jd: f64, interval_seconds: f64) -> Ticks<Z> {
+        Ticks {
+            zone,
+            start_jd,
+            step_days: interval_seconds / 86_400.0 * self.calibration.sol_length,
+            index: 0,
+        }
+    }
+}
+
+/// The whole sols of a sol date, after its fraction is rounded to the millisecond of the clock
+fn whole_sols(sol_date: f64) -> f64 {
+    const MS_PER_SOL: f64 = 86_400_000.0;
+
+    ((sol_date * MS_PER_SOL).round() / MS_PER_SOL).floor()
+}
+
+/// This is an endless iterator of the julian dates (UTC) and the times of a timezone, an interval apart
+///
+/// * Each julian date is the start plus the interval times the ticks so far, so the ticks don't drift.
+/// * It's made by the `ticks` method of a timezone (ex; [`crate::planets::mars::Martian`]), see [`BodyClock::ticks`]
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::planets::mars::Martian;
+///
+/// // a tick each hour of the clock
+/// let hours: Vec<_> = Martian::MTC.ticks(2451545.0, 3_600.0).take(3).map(|(_, time)| time.hour).collect();
+///
+/// assert_eq!(vec![14, 15, 16], hours);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Ticks<Z: TimeZone> {
+    zone: Z,
+    start_jd: f64,
+    step_days: f64,
+    index: u64,
+}
+
+impl<Z: TimeZone> Ticks<Z> {
+    /// This method returns the julian date (UTC) of a tick, counted from 0 at the start
+    pub fn julian_date(&self, index: u64) -> f64 {
+        self.start_jd + index as f64 * self.step_days
+    }
+
+    /// This method returns the earth time between ticks, see [`Ticks::into_stream`]
+    ///
+    /// # Panics
+    ///
+    /// When the interval isn't finite
+    pub fn earth_interval(&self) -> Duration {
+        Duration::from_secs_f64(self.step_days.abs() * 86_400.0)
+    }
+
+    /// This method turns the ticks into an async stream, which waits for the earth time between ticks
+    ///
+    /// * The first tick is right away, and a late tick delays the ones after it.
+    /// * It must be called within a tokio runtime, and needs the `tokio` feature.
+    ///
+    /// # Panics
+    ///
+    /// When the interval isn't finite, see [`Ticks::earth_interval`]
+    #[cfg(feature = "tokio")]
+    pub fn into_stream(self) -> TickStream<Z> {
+        // a tokio interval can't be empty
+        let period = self.earth_interval().max(Duration::from_nanos(1));
+        let mut interval = tokio::time::interval(period);
+
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        TickStream {
+            ticks: self,
+            interval,
+        }
+    }
+}
+
+impl<Z: TimeZone> Iterator for Ticks<Z> {
+    type Item = (f64, Time);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let julian_date = self.julian_date(self.index + n as u64);
+
+        self.index += n as u64 + 1;
+        Some((julian_date, self.zone.at(julian_date)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// This is an async stream of [`Ticks`], which waits for the earth time between ticks, see [`Ticks::into_stream`]
+///
+/// * This needs the `tokio` feature.
+#[cfg(feature = "tokio")]
+#[derive(Debug)]
+pub struct TickStream<Z: TimeZone> {
+    ticks: Ticks<Z>,
+    interval: tokio::time::Interval,
+}
+
+#[cfg(feature = "tokio")]
+impl<Z: TimeZone + Unpin> futures_core::Stream for TickStream<Z> {
+    type Item = (f64, Time);
+
+    fn poll_next(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Option<Self::Item>> {
+        let stream = self.get_mut();
+
+        stream.interval.poll_tick(cx).map(|_| stream.ticks.next())
+    }
+}
+
+/// This is a clock that runs the time of a body faster or slower than real time, for simulations
+///
+/// * The rate is how many times faster than real time the clock runs (ex; 100.0), a negative rate rewinds.
+/// * It doesn't read the system clock, the real time that passed is given to it, see [`RunningClock`] for that.
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use core::time::Duration;
+///
+/// use rust_solar::{clock::SimulationClock, planets::mars::{Mars, Martian}};
+///
+/// let clock = SimulationClock { body: Mars, zone: Martian::MTC, epoch_jd: 2451545.0, rate: 86_400.0 };
+///
+/// // a day each second
+/// assert_eq!(2451547.0, clock.current_jd(Duration::from_secs(2)));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SimulationClock<B: Body, Z: TimeZone> {
+    /// This is the body the clock is on
+    pub body: B,
+    /// This is the timezone of the times of the clock
+    pub zone: Z,
+    /// This is the julian date (UTC) the clock starts at
+    pub epoch_jd: f64,
+    /// This is how many times faster than real time the clock runs, negative to rewind
+    pub rate: f64,
+}
+
+impl<B: Body, Z: TimeZone> SimulationClock<B, Z> {
+    /// This method returns the julian date (UTC) of the clock, after an amount of real time
+    ///
+    /// > $$JD = epoch + \frac{elapsed \times rate}{86400}$$
+    pub fn current_jd(&self, real_elapsed: Duration) -> f64 {
+        self.epoch_jd + real_elapsed.as_secs_f64() * self.rate / 86_400.0
+    }
+
+    /// This method returns the date and the time of the clock in its timezone, after an amount of real time
+    pub fn datetime(&self, real_elapsed: Duration) -> DateTime {
+        self.body
+            .to_datetime(self.current_jd(real_elapsed), &self.zone)
+    }
+
+    /// This method starts the clock from now, so the real time that passed is read from the system clock
+    #[cfg(feature = "std")]
+    pub fn start(self) -> RunningClock<B, Z> {
+        RunningClock {
+            clock: self,
+            started: std::time::Instant::now(),
+        }
+    }
+}
+
+/// This is a [`SimulationClock`] that was started, so it reads the real time that passed from the system clock
+///
+/// * This needs the `std` feature.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy)]
+pub struct RunningClock<B: Body, Z: TimeZone> {
+    /// This is the clock that runs
+    pub clock: SimulationClock<B, Z>,
+    started: std::time::Instant,
+}
+
+#[cfg(feature = "std")]
+impl<B: Body, Z: TimeZone> RunningClock<B, Z> {
+    /// This method returns the real time since the clock started
+    pub fn real_elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// This method returns the julian date (UTC) of the clock now, see [`SimulationClock::current_jd`]
+    pub fn current_jd(&self) -> f64 {
+        self.clock.current_jd(self.real_elapsed())
+    }
+
+    /// This method returns the date and the time of the clock now, see [`SimulationClock::datetime`]
+    pub fn datetime(&self) -> DateTime {
+        self.clock.datetime(self.real_elapsed())
+    }
+}
+
+/// This is the information of a timezone, see [`crate::define_timezones`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ZoneInfo {
+    /// This is the abbreviation of the timezone
+    pub code: &'static str,
+    /// This is the name of the timezone
+    pub name: &'static str,
+    /// This is the offset from the prime meridian in hours
+    pub offset: f64,
+    /// This is the longitude where the timezone starts, in degrees east
+    pub east: f64,
+    /// This is the longitude where the timezone ends, in degrees east
+    pub west: f64,
+}
+
+impl ZoneInfo {
+    /// This method checks if a longitude in degrees east is within the timezone
+    ///
+    /// * The longitude is either from -180 to 180 or from 0 to 360.
+    pub fn contains(&self, lon_east_deg: f64) -> bool {
+        let lon = normalize_degrees(lon_east_deg + 180.0) - 180.0;
+
+        self.east <= lon && lon < self.west
+    }
+
+    /// This method returns the offset from the prime meridian in `±HH:MM` notation (ex; +02:30 or -12:30)
+    ///
+    /// * The offset is rounded to the minute, so the half hours are exact.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::planets::mars::Martian;
+    ///
+    /// assert_eq!("-12:30", Martian::MTCn5.info().offset_string());
+    /// assert_eq!("+00:00", Martian::MTC.info().offset_string());
+    /// # }
+    /// ```
+    pub fn offset_string(&self) -> String {
+        Offset(self.offset).to_string()
+    }
+
+    /// This method checks if an offset in hours is the offset of the timezone, to the minute
+    pub fn has_offset(&self, hours: f64) -> bool {
+        offset_minutes(self.offset) == offset_minutes(hours)
+    }
+
+    /// This method names a time after the timezone, and logs the timezone when the `log` feature is enabled
+    #[doc(hidden)]
+    pub fn stamp(&self, time: Time, offset_name: &'static str) -> Time {
+        #[cfg(feature = "log")]
+        log::debug!("{}: East: {:?}, West: {:?}", self.code, self.east, self.west);
+
+        Time {
+            zone: Some(*self),
+            offset_name,
+            ..time
+        }
+    }
+}
+
+/// This is an offset from the prime meridian in hours, written in `±HH:MM` notation, see [`ZoneInfo::offset_string`]
+pub(crate) struct Offset(pub(crate) f64);
+
+impl fmt::Display for Offset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let minutes = offset_minutes(self.0);
+        let sign = match minutes < 0 {
+            true => '-',
+            false => '+',
+        };
+
+        let minutes = minutes.abs();
+
+        write!(f, "{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+    }
+}
+
+/// The offset in hours, rounded to whole minutes
+fn offset_minutes(hours: f64) -> i64 {
+    (hours * 60.0).round() as i64
+}
+
+/// This function parses an offset in `±HH:MM` notation after a prefix (ex; `MTC+02:30`), into the prefix and hours
+///
+/// * The prefix is everything before the sign, and may be empty (ex; `-12:30`).
+///
+/// ```rust
+/// use rust_solar::clock::parse_offset;
+///
+/// assert_eq!(Some(("MTC", 2.5)), parse_offset("MTC+02:30"));
+/// assert_eq!(Some(("", -12.5)), parse_offset("-12:30"));
+/// assert_eq!(None, parse_offset("MTC+2.5"));
+/// ```
+pub fn parse_offset(text: &str) -> Option<(&str, f64)> {
+    let (prefix, offset) = text.split_at(text.find(['+', '-'])?);
+    let (sign, offset) = offset.split_at(1);
+    let (hours, minutes) = offset.split_once(':')?;
+    let digits = |part: &str, max: u32| {
+        let valid = (1..=2).contains(&part.len()) && part.bytes().all(|b| b.is_ascii_digit());
+
+        part.parse::<u32>()
+            .ok()
+            .filter(|value| valid && *value <= max)
+    };
+    let hours = f64::from(digits(hours, 99)?) + f64::from(digits(minutes, 59)?) / 60.0;
+
+    match sign {
+        "-" => Some((prefix, -hours)),
+        _ => Some((prefix, hours)),
+    }
+}
@@ -0,0 +1,132 @@
+use core::time::Duration;
+
+use crate::{
+    conversions::{KM_PER_AU, SPEED_OF_LIGHT_KM_S},
+    error::{Error, Result},
+    kepler::Body,
+    planets::earth::Earth,
+    position::{separation, Origin},
+};
+
+/// This is an iterator of the one way delays to a body over a range of julian dates, see [`delays`]
+///
+/// * It yields `(julian_date, delay)` pairs, from the start up to the end, and stops after the first error.
+#[derive(Debug, Clone)]
+pub struct Delays<'a, B: Body + ?Sized> {
+    body: &'a B,
+    start: f64,
+    end: f64,
+    step: f64,
+    index: u64,
+    done: bool,
+}
+
+impl<B: Body + ?Sized> Iterator for Delays<'_, B> {
+    type Item = Result<(f64, Duration)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let julian_date = self.start + self.step * self.index as f64;
+
+        if self.done || julian_date > self.end {
+            return None;
+        }
+
+        self.index += 1;
+
+        match one_way_delay(self.body, julian_date) {
+            Ok(delay) => Some(Ok((julian_date, delay))),
+            Err(error) => {
+                self.done = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+/// This function returns the time light takes from earth to a body at a julian date (UTC)
+///
+/// * The delay is the distance between the centers of the bodies at the julian date, over the speed of light,
+///   the motion of the bodies while the light travels is ignored (ex; about 0.1 seconds for mars).
+/// * See [`as_minutes`] to display it.
+/// * It fails with [`Error::NonFinite`] when the delay is too long for a [`Duration`] (ex; a body with a semimajor axis of 1e300 AU).
+///
+/// > $$t = \frac{d}{c}$$
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::{comms::one_way_delay, planets::mars::Mars};
+///
+/// let delay = one_way_delay(&Mars, 2451545.0).unwrap();
+///
+/// // mars is about 1.85 AU from earth, 15 minutes away
+/// assert_eq!(15, delay.as_secs() / 60);
+/// # }
+/// ```
+pub fn one_way_delay(body: &(impl Body + ?Sized), julian_date: f64) -> Result<Duration> {
+    let distance = separation(&Earth, body, julian_date, Origin::Heliocentric)?;
+
+    Duration::try_from_secs_f64(distance.0 * KM_PER_AU / SPEED_OF_LIGHT_KM_S)
+        .map_err(|_| Error::NonFinite)
+}
+
+/// This function returns the time of a message from earth to a body and back at a julian date (UTC), see [`one_way_delay`]
+///
+/// * It's twice the one way delay, the distance is the one at the julian date.
+pub fn round_trip_delay(body: &(impl Body + ?Sized), julian_date: f64) -> Result<Duration> {
+    Ok(one_way_delay(body, julian_date)? * 2)
+}
+
+/// This function walks a range of julian dates (UTC) in steps of earth days, and yields the one way delay at each of them
+///
+/// * The end is included when a step lands on it, a callback can go through [`Iterator::try_for_each`]
+/// * It fails with [`Error::NonFinite`] when the range or the step aren't finite,
+///   and with [`Error::InvalidTime`] when the step isn't positive or the range is reversed.
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::{comms::{as_minutes, delays}, planets::mars::Mars};
+///
+/// let mut plot = Vec::new();
+///
+/// delays(&Mars, 2451545.0, 2451575.0, 10.0)
+///     .unwrap()
+///     .try_for_each(|sample| sample.map(|(jd, delay)| plot.push((jd, as_minutes(delay)))))
+///     .unwrap();
+///
+/// assert_eq!(4, plot.len());
+/// # }
+/// ```
+pub fn delays<B: Body + ?Sized>(
+    body: &B,
+    start_jd: f64,
+    end_jd: f64,
+    step: f64,
+) -> Result<Delays<'_, B>> {
+    if !start_jd.is_finite() || !end_jd.is_finite() || !step.is_finite() {
+        return Err(Error::NonFinite);
+    }
+
+    if step <= 0.0 {
+        return Err(Error::InvalidTime("the step isn't positive"));
+    }
+
+    if end_jd < start_jd {
+        return Err(Error::InvalidTime("the range is reversed"));
+    }
+
+    Ok(Delays {
+        body,
+        start: start_jd,
+        end: end_jd,
+        step,
+        index: 0,
+        done: false,
+    })
+}
+
+/// This function returns a delay in minutes, for display (ex; `12.5`)
+pub fn as_minutes(delay: Duration) -> f64 {
+    delay.as_secs_f64() / 60.0
+}
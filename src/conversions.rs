@@ -1,19 +1,228 @@
-/// This function converts astronomical units to kilometers
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use crate::planets::EARTH_ROTATIONAL_PERIOD;
+
+/// This macro gives a unit the arithmetic that keeps it in the same unit
+///
+/// * A unit adds and subtracts with the same unit, and scales by a plain number.
+/// * Dividing a unit by the same unit is a plain ratio.
+macro_rules! unit {
+    ($unit:ident) => {
+        impl Add for $unit {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl AddAssign for $unit {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl Sub for $unit {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl SubAssign for $unit {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl Neg for $unit {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl Mul<f64> for $unit {
+            type Output = Self;
+
+            fn mul(self, rhs: f64) -> Self {
+                Self(self.0 * rhs)
+            }
+        }
+
+        impl Div<f64> for $unit {
+            type Output = Self;
+
+            fn div(self, rhs: f64) -> Self {
+                Self(self.0 / rhs)
+            }
+        }
+
+        impl Div for $unit {
+            type Output = f64;
+
+            fn div(self, rhs: Self) -> f64 {
+                self.0 / rhs.0
+            }
+        }
+    };
+}
+
+/// This is a length of time in earth days, like the difference of two julian dates
+///
+/// * Units only add up with the same unit, so days and seconds can't be mixed by accident.
+///
+/// ```compile_fail
+/// use rust_solar::conversions::{Days, Seconds};
+///
+/// let sum = Days(1.0) + Seconds(86400.0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Days(pub f64);
+
+/// This is a length of time in SI seconds, like the [`crate::kepler::Body::rotational_period`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Seconds(pub f64);
+
+/// This is a distance in astronomical units, like the [`crate::kepler::Body::semimajor`] axis
+///
+/// ```compile_fail
+/// use rust_solar::conversions::{Au, Days};
+///
+/// let wrong = Au(1.5) - Days(1.0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Au(pub f64);
+
+/// This is an angle in degrees, like a solar longitude
 ///
-/// > $$1AU = 1.495978707 * 10^{11}
+/// ```compile_fail
+/// use rust_solar::conversions::{Degrees, Radians};
+///
+/// let wrong = Degrees(90.0) + Radians(1.0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Degrees(pub f64);
+
+/// This is an angle in radians, like an anomaly
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Radians(pub f64);
+
+unit!(Days);
+unit!(Seconds);
+unit!(Au);
+unit!(Degrees);
+unit!(Radians);
+
+impl Days {
+    /// This method converts the days into seconds, an earth day is [`EARTH_ROTATIONAL_PERIOD`] seconds
+    pub fn to_seconds(self) -> Seconds {
+        Seconds(self.0 * EARTH_ROTATIONAL_PERIOD)
+    }
+
+    /// This method counts the sols of a given length in the days, with their fraction
+    ///
+    /// ```rust
+    /// use rust_solar::conversions::{Days, Seconds};
+    ///
+    /// assert_eq!(2.0, Days(1.0).sols(Seconds(43200.0)));
+    /// ```
+    ///
+    /// The length of the sol must be in seconds, like [`crate::kepler::Body::rotational_period_seconds`]
+    ///
+    /// ```compile_fail
+    /// use rust_solar::{conversions::Days, kepler::Body, planets::mars::Mars};
+    ///
+    /// let sols = Days(1.0).sols(Mars.orbital_period_days());
+    /// ```
+    pub fn sols(self, sol: Seconds) -> f64 {
+        self.0 * EARTH_ROTATIONAL_PERIOD / sol.0
+    }
+}
+
+impl Seconds {
+    /// This method converts the seconds into earth days
+    ///
+    /// ```rust
+    /// use rust_solar::conversions::{Days, Seconds};
+    ///
+    /// assert_eq!(Days(1.5), Seconds(129600.0).to_days());
+    /// ```
+    pub fn to_days(self) -> Days {
+        Days(self.0 / EARTH_ROTATIONAL_PERIOD)
+    }
+}
+
+impl Au {
+    /// This method converts the distance into kilometers, see [`au_to_km`]
+    pub fn to_km(self) -> f64 {
+        au_to_km(self.0)
+    }
+
+    /// This method creates a distance from kilometers, see [`km_to_au`]
+    pub fn from_km(km: f64) -> Self {
+        Self(km_to_au(km))
+    }
+}
+
+impl Degrees {
+    /// This method converts the angle into radians
+    pub fn to_radians(self) -> Radians {
+        Radians(deg_to_rad(self.0))
+    }
+}
+
+impl Radians {
+    /// This method converts the angle into degrees
+    pub fn to_degrees(self) -> Degrees {
+        Degrees(rad_to_deg(self.0))
+    }
+}
+
+impl From<Days> for Seconds {
+    fn from(days: Days) -> Self {
+        days.to_seconds()
+    }
+}
+
+impl From<Seconds> for Days {
+    fn from(seconds: Seconds) -> Self {
+        seconds.to_days()
+    }
+}
+
+impl From<Degrees> for Radians {
+    fn from(degrees: Degrees) -> Self {
+        degrees.to_radians()
+    }
+}
+
+impl From<Radians> for Degrees {
+    fn from(radians: Radians) -> Self {
+        radians.to_degrees()
+    }
+}
+
+/// This function converts astronomical units to meters, despite its name
+///
+/// > $$1AU = 1.495978707 * 10^{11}m$$
 ///
 /// Numbers are according to https://en.wikipedia.org/wiki/Astronomical_unit
 ///
 /// ### Example Of Venus orbiting average distance (0.723) AU
 ///
 /// ```rust
+///  # #![allow(deprecated)]
 ///  use rust_solar::conversions::au2km;
 ///
-///  // The AU of venus's' semi-major axis is 0.723
+///  // The AU of venus's' semi-major axis is 0.723, about 108 billion meters
 ///  assert_eq!(108159260516.09999, au2km(0.723));
 ///
 /// ```
 ///
+#[deprecated(note = "this returns meters, use `au_to_km` for kilometers")]
 pub fn au2km(value: f64) -> f64 {
     value * (1.495_978_707 * 100_000_000_000.0)
 }
@@ -21,5 +230,128 @@ pub fn au2km(value: f64) -> f64 {
 /// This function is a wrapper over calculating the radians in a circle
 ///
 pub fn radians_in_circle() -> f64 {
-    std::f64::consts::PI * 2.0
+    core::f64::consts::PI * 2.0
+}
+
+/// The kilometers in an astronomical unit, as defined by the IAU in 2012
+pub const KM_PER_AU: f64 = 149_597_870.7;
+
+/// The speed of light in a vacuum in km/s, exact since the meter is defined by it
+pub const SPEED_OF_LIGHT_KM_S: f64 = 299_792.458;
+
+/// The earth days in a julian year, see [`crate::kepler::Body::orbital_period_years`]
+pub const JULIAN_YEAR_DAYS: f64 = 365.25;
+
+/// This function converts astronomical units to kilometers
+///
+/// ```rust
+/// use rust_solar::conversions::au_to_km;
+///
+/// assert_eq!(149_597_870.7, au_to_km(1.0));
+/// ```
+pub fn au_to_km(au: f64) -> f64 {
+    au * KM_PER_AU
+}
+
+/// This function converts kilometers to astronomical units
+///
+/// ```rust
+/// use rust_solar::conversions::km_to_au;
+///
+/// assert_eq!(2.0, km_to_au(299_195_741.4));
+/// ```
+pub fn km_to_au(km: f64) -> f64 {
+    km / KM_PER_AU
+}
+
+/// This function converts degrees to radians, in any float precision
+///
+/// ```rust
+/// use rust_solar::conversions::deg_to_rad;
+///
+/// assert_eq!(core::f64::consts::PI, deg_to_rad(180.0));
+/// ```
+pub fn deg_to_rad<F: num_traits::Float>(degrees: F) -> F {
+    degrees.to_radians()
+}
+
+/// This function converts radians to degrees, in any float precision
+///
+/// ```rust
+/// use rust_solar::conversions::rad_to_deg;
+///
+/// assert_eq!(90.0, rad_to_deg(core::f64::consts::FRAC_PI_2));
+/// ```
+pub fn rad_to_deg<F: num_traits::Float>(radians: F) -> F {
+    radians.to_degrees()
+}
+
+/// This function converts an hour angle (or a timezone offset) to degrees, every hour is 15 degrees
+///
+/// ```rust
+/// use rust_solar::conversions::hours_to_deg;
+///
+/// assert_eq!(-187.5, hours_to_deg(-12.5));
+/// ```
+pub fn hours_to_deg(hours: f64) -> f64 {
+    hours * 15.0
+}
+
+/// This function converts degrees to an hour angle, every 15 degrees is an hour
+///
+/// ```rust
+/// use rust_solar::conversions::deg_to_hours;
+///
+/// assert_eq!(6.0, deg_to_hours(90.0));
+/// ```
+pub fn deg_to_hours(degrees: f64) -> f64 {
+    degrees / 15.0
+}
+
+/// This function wraps an angle into 0 to 360 degrees, 360 itself is 0
+///
+/// * The remainder is exact, so very large angles keep their precision.
+/// * An angle that isn't finite stays `NaN`
+///
+/// ```rust
+/// use rust_solar::conversions::normalize_degrees;
+///
+/// assert_eq!(270.0, normalize_degrees(-90.0));
+/// assert_eq!(0.0, normalize_degrees(720.0));
+/// assert_eq!(280.0, normalize_degrees(1e20));
+/// ```
+pub fn normalize_degrees<F: num_traits::Float>(degrees: F) -> F {
+    normalize(degrees, cast(360.0))
+}
+
+/// This function wraps an angle into 0 to 2π radians, 2π itself is 0, see [`normalize_degrees`]
+///
+/// ```rust
+/// use rust_solar::conversions::{normalize_radians, radians_in_circle};
+///
+/// assert_eq!(core::f64::consts::PI, normalize_radians(-core::f64::consts::PI));
+/// assert_eq!(0.0, normalize_radians(radians_in_circle() * 3.0));
+/// ```
+pub fn normalize_radians<F: num_traits::Float>(radians: F) -> F {
+    normalize(radians, cast(radians_in_circle()))
+}
+
+/// This function wraps a value into a circle
+fn normalize<F: num_traits::Float>(value: F, circle: F) -> F {
+    let remainder = value % circle;
+    let remainder = match remainder < F::zero() {
+        true => remainder + circle,
+        false => remainder,
+    };
+
+    // a tiny negative angle rounds up to the whole circle
+    match remainder >= circle {
+        true => F::zero(),
+        false => remainder,
+    }
+}
+
+/// This function converts a constant into any float precision (ex; `f32`), see [`crate::anomaly::Anomaly`]
+pub(crate) fn cast<F: num_traits::Float>(value: f64) -> F {
+    F::from(value).expect("A float to hold any f64, rounded")
 }
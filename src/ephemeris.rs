@@ -0,0 +1,230 @@
+use std::{borrow::Cow, io::Write};
+
+use strum::AsRefStr;
+
+use crate::{
+    error::{Error, Result},
+    kepler::{Body, Date, DateRange},
+    position::{self, Position},
+};
+
+/// This is a column of an ephemeris table, the header of the column is its name (ex; `ls`)
+#[derive(Debug, AsRefStr, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Column {
+    /// The julian date (UTC), to a microday
+    #[strum(serialize = "jd")]
+    JulianDate,
+    /// The year of the date
+    #[strum(serialize = "year")]
+    Year,
+    /// The month of the date
+    #[strum(serialize = "month")]
+    Month,
+    /// The day of the date
+    #[strum(serialize = "day")]
+    Day,
+    /// The solar longitude in degrees
+    #[strum(serialize = "ls")]
+    Ls,
+    /// The name of the season, quoted when it holds a comma, a quote or a line break
+    #[strum(serialize = "season")]
+    Season,
+    /// The distance from the sun in astronomical units
+    #[strum(serialize = "distance_au")]
+    Distance,
+    /// The heliocentric position towards the vernal equinox in astronomical units, see [`Position`]
+    #[strum(serialize = "x_au")]
+    X,
+    /// The heliocentric position towards the ecliptic longitude of 90 degrees in astronomical units
+    #[strum(serialize = "y_au")]
+    Y,
+    /// The heliocentric position above the ecliptic in astronomical units
+    #[strum(serialize = "z_au")]
+    Z,
+}
+
+impl Column {
+    /// Every column, in the order of a default table
+    pub const ALL: &'static [Self] = &[
+        Self::JulianDate,
+        Self::Year,
+        Self::Month,
+        Self::Day,
+        Self::Ls,
+        Self::Season,
+        Self::Distance,
+        Self::X,
+        Self::Y,
+        Self::Z,
+    ];
+
+    /// This method checks if the column needs the position of the body
+    fn is_positional(self) -> bool {
+        matches!(self, Self::Distance | Self::X | Self::Y | Self::Z)
+    }
+
+    /// This method formats the field of the column
+    fn field(self, julian_date: f64, date: &Date, position: &Position) -> Cow<'static, str> {
+        let field = match self {
+            Self::JulianDate => format!("{julian_date:.6}"),
+            Self::Year => format!("{}", date.year as i64),
+            Self::Month => format!("{}", date.month as i64),
+            Self::Day => format!("{}", date.day as i64),
+            Self::Ls => format!("{:.6}", date.ls),
+            Self::Season => return escape(date.season_str()),
+            Self::Distance => format!("{:.9}", position.distance().0),
+            Self::X => format!("{:.9}", position.x),
+            Self::Y => format!("{:.9}", position.y),
+            Self::Z => format!("{:.9}", position.z),
+        };
+
+        Cow::Owned(field)
+    }
+}
+
+/// This is the layout of an ephemeris table in CSV, see [`to_csv`]
+///
+/// * The default table has every column, [`CsvExport::builder`] picks the columns and their order.
+/// * The rows follow [`Body::dates_between`], so the body is checked and its orbit prepared once.
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::{ephemeris::{Column, CsvExport}, planets::mars::Mars};
+///
+/// let export = CsvExport::builder().column(Column::JulianDate).column(Column::Ls).build().unwrap();
+/// let mut out = Vec::new();
+///
+/// export.write(&Mars, 2451545.0, 2451547.0, 1.0, &mut out).unwrap();
+///
+/// assert!(String::from_utf8(out).unwrap().starts_with("jd,ls\n2451545.000000,"));
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvExport {
+    columns: Vec<Column>,
+}
+
+impl Default for CsvExport {
+    fn default() -> Self {
+        Self {
+            columns: Column::ALL.to_vec(),
+        }
+    }
+}
+
+impl CsvExport {
+    /// This method starts a builder, see [`CsvExportBuilder`]
+    pub fn builder() -> CsvExportBuilder {
+        CsvExportBuilder::default()
+    }
+
+    /// The columns of the table, in order
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// This method returns the header line of the table, without the line break
+    pub fn header(&self) -> String {
+        self.columns
+            .iter()
+            .map(|column| column.as_ref())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// This method writes the header and a row for every `step_sols` sols from `start_jd` to `end_jd` (UTC)
+    ///
+    /// * The step follows [`Body::dates_between`], 0 or not finite is [`crate::kepler::DateRange::DEFAULT_STEP`]
+    /// * Every line ends with `\n`
+    /// * It fails with [`Error::Io`] when the writer fails, the rows before it are already written.
+    pub fn write<W: Write>(
+        &self,
+        body: &(impl Body + ?Sized),
+        start_jd: f64,
+        end_jd: f64,
+        step_sols: f64,
+        mut writer: W,
+    ) -> Result<()> {
+        let dates = DateRange::try_new(body, start_jd, end_jd, step_sols)?;
+        let positional = self.columns.iter().any(|column| column.is_positional());
+
+        writeln!(writer, "{}", self.header()).map_err(io)?;
+
+        for (julian_date, date) in dates {
+            let position = match positional {
+                true => position::at_ls(body, date.ls),
+                false => Position::default(),
+            };
+            let row = self
+                .columns
+                .iter()
+                .map(|column| column.field(julian_date, &date, &position))
+                .collect::<Vec<_>>()
+                .join(",");
+
+            writeln!(writer, "{row}").map_err(io)?;
+        }
+
+        writer.flush().map_err(io)
+    }
+}
+
+/// This is the builder of a [`CsvExport`]
+///
+/// * The columns are written in the order they're added, at least one is required.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CsvExportBuilder {
+    columns: Vec<Column>,
+}
+
+impl CsvExportBuilder {
+    /// This method adds a column after the ones already added
+    pub fn column(mut self, column: Column) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// This method adds many columns after the ones already added
+    pub fn columns(mut self, columns: &[Column]) -> Self {
+        self.columns.extend_from_slice(columns);
+        self
+    }
+
+    /// This method checks the columns and creates the export
+    pub fn build(self) -> Result<CsvExport> {
+        if self.columns.is_empty() {
+            return Err(Error::InvalidFormat("the table has no columns"));
+        }
+
+        Ok(CsvExport {
+            columns: self.columns,
+        })
+    }
+}
+
+/// This function writes the ephemeris table of a body in CSV with every column, see [`CsvExport::write`]
+///
+/// * The columns are `jd,year,month,day,ls,season,distance_au,x_au,y_au,z_au`
+pub fn to_csv<W: Write>(
+    body: &(impl Body + ?Sized),
+    start_jd: f64,
+    end_jd: f64,
+    step_sols: f64,
+    writer: W,
+) -> Result<()> {
+    CsvExport::default().write(body, start_jd, end_jd, step_sols, writer)
+}
+
+/// This function quotes a field that holds a comma, a quote or a line break, doubling its quotes
+fn escape(field: &'static str) -> Cow<'static, str> {
+    match field.contains([',', '"', '\n', '\r']) {
+        true => Cow::Owned(format!("\"{}\"", field.replace('"', "\"\""))),
+        false => Cow::Borrowed(field),
+    }
+}
+
+/// This function keeps the kind of a failed write, see [`Error::Io`]
+fn io(error: std::io::Error) -> Error {
+    Error::Io(error.kind())
+}
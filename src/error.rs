@@ -0,0 +1,90 @@
+#[cfg(feature = "config")]
+use alloc::string::String;
+
+use thiserror::Error;
+
+/// This is the collection of errors that the library can return
+///
+/// * The messages are meant to be shown to users as they are, see `tests/error-ut.rs`
+/// * More variants may come, so match with a `_` arm.
+#[derive(Error, Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The value is NaN or infinite, you probably passed a failed parse.
+    #[error("expected a finite number")]
+    NonFinite,
+    /// The date cannot exist on the body.
+    #[error("invalid date: {0}")]
+    InvalidDate(&'static str),
+    /// The orbit or rotation of the body cannot be computed.
+    #[error("invalid orbit: {0}")]
+    InvalidOrbit(&'static str),
+    /// The eccentricity is negative.
+    #[error("invalid eccentricity: {0} isn't 0 or more")]
+    InvalidEccentricity(f64),
+    /// The anomaly solver didn't settle, see [`crate::anomaly::MAX_ITERATIONS`]
+    #[error("the anomaly didn't converge after {0} iterations")]
+    NoConvergence(u32),
+    /// The perihelion window is empty, reversed or outside of the orbit.
+    #[error("invalid perihelion: {0}")]
+    InvalidPerihelion(&'static str),
+    /// The season table doesn't cover every solar longitude once.
+    #[error("invalid season table: {0}")]
+    InvalidSeasonTable(&'static str),
+    /// The timezone isn't a code or a name of any timezone of the body.
+    #[error("unknown timezone")]
+    UnknownTimeZone,
+    /// The offset or the longitudes of a timezone cannot be used, see [`crate::clock::ZoneInfo`]
+    #[error("invalid timezone: {0}")]
+    InvalidTimeZone(&'static str),
+    /// The time cannot exist on the body.
+    #[error("invalid time: {0}")]
+    InvalidTime(&'static str),
+    /// The string isn't in the expected format, see [`crate::kepler::DateTime::from_canonical_str`]
+    #[error("invalid format: {0}")]
+    InvalidFormat(&'static str),
+    /// The function doesn't cross its target between the ends of the bracket, see [`crate::events::find_crossing`]
+    #[error("no crossing within the bracket")]
+    NoCrossing,
+    /// The output can't be written, see [`crate::ephemeris::to_csv`]
+    #[cfg(feature = "std")]
+    #[error("can't write: {0}")]
+    Io(std::io::ErrorKind),
+}
+
+/// This is the error of a body definition file, see [`crate::bodies::load_toml`]
+#[cfg(feature = "config")]
+#[derive(Error, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ConfigError {
+    /// The file can't be read.
+    #[error("can't read the file: {0}")]
+    Io(String),
+    /// The file isn't valid TOML or JSON, or it has no list of bodies.
+    #[error("can't parse the file: {0}")]
+    Parse(String),
+    /// A field of a body is missing or invalid.
+    #[error("{entry}: {field}: {reason}")]
+    Field {
+        /// The name of the body, or its position (ex; `#2`) when it has no name
+        entry: String,
+        /// The name of the field
+        field: String,
+        /// Why the field was rejected
+        reason: String,
+    },
+    /// A field of a body, or the body as a whole, was rejected by the library.
+    #[error("{entry}: {field}: {source}")]
+    Invalid {
+        /// The name of the body, or its position (ex; `#2`) when it has no name
+        entry: String,
+        /// The name of the field, empty when the body as a whole is rejected
+        field: String,
+        /// The error of the library
+        #[source]
+        source: Error,
+    },
+}
+
+/// This is a result that carries the library [`Error`]
+pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,464 @@
+use alloc::vec::Vec;
+
+use crate::{
+    anomaly::MAX_ITERATIONS,
+    error::{Error, Result},
+};
+
+/// The fraction of a bracket a golden section step takes, see [`find_extremum`]
+const GOLDEN_SECTION: f64 = 0.381_966_011_250_105_1;
+
+/// This is the way a function crosses its target, see [`scan`]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Crossing {
+    /// The function goes up or down through its target
+    #[default]
+    Either,
+    /// The function goes up through its target (ex; the solar longitude reaching 90)
+    Rising,
+    /// The function goes down through its target (ex; the phase angle of an outer planet closing in)
+    Falling,
+}
+
+impl Crossing {
+    /// This method checks if a step from one value to the next crosses 0 this way
+    ///
+    /// * A value of exactly 0 ends the step that reaches it, so a root on a sample is only found once.
+    fn between(self, low: f64, high: f64) -> bool {
+        let rising = low < 0.0 && high >= 0.0;
+        let falling = low > 0.0 && high <= 0.0;
+
+        match self {
+            Self::Either => rising || falling,
+            Self::Rising => rising,
+            Self::Falling => falling,
+        }
+    }
+}
+
+/// This is the kind of extremum to find, see [`find_extremum`]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Extremum {
+    /// The lowest value (ex; the distance at an opposition)
+    #[default]
+    Minimum,
+    /// The highest value (ex; the greatest elongation)
+    Maximum,
+}
+
+/// This is an iterator of the brackets where a function crosses 0, see [`try_scan`]
+///
+/// * It stops after the first error of the function.
+#[derive(Debug, Clone)]
+pub struct Scan<F> {
+    f: F,
+    end: f64,
+    step: f64,
+    crossing: Crossing,
+    /// The last sample and its value, none before the start is sampled
+    last: Option<(f64, f64)>,
+    start: f64,
+    /// The samples after the start so far, each one is counted from the start so the steps don't add up
+    index: u64,
+    done: bool,
+}
+
+impl<F: FnMut(f64) -> Result<f64>> Scan<F> {
+    /// This method samples the function until the next bracket, none when the range is walked
+    fn advance(&mut self) -> Result<Option<(f64, f64)>> {
+        let (mut low, mut low_value) = match self.last {
+            Some(last) => last,
+            None => (self.start, (self.f)(self.start)?),
+        };
+
+        while low < self.end {
+            self.index += 1;
+
+            let high = (self.start + self.index as f64 * self.step).min(self.end);
+            let high_value = (self.f)(high)?;
+
+            self.last = Some((high, high_value));
+
+            if self.crossing.between(low_value, high_value) {
+                return Ok(Some((low, high)));
+            }
+
+            (low, low_value) = (high, high_value);
+        }
+
+        Ok(None)
+    }
+}
+
+impl<F: FnMut(f64) -> Result<f64>> Iterator for Scan<F> {
+    type Item = Result<(f64, f64)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.advance() {
+            Ok(Some(bracket)) => Some(Ok(bracket)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(error) => {
+                self.done = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+/// This function walks a range in steps, and yields the brackets where a fallible function crosses 0, see [`scan`]
+///
+/// * The brackets are in order, each one goes from a sample to the next, so a step should be shorter
+///   than the time between two crossings.
+/// * It fails with [`Error::NonFinite`] when the range or the step aren't finite,
+///   and with [`Error::InvalidTime`] when the step isn't positive, the range is reversed
+///   or the step is too small to move an end of the range (ex; `1e-11` days at a julian date).
+pub fn try_scan<F: FnMut(f64) -> Result<f64>>(
+    f: F,
+    range: (f64, f64),
+    step: f64,
+    crossing: Crossing,
+) -> Result<Scan<F>> {
+    if !range.0.is_finite() || !range.1.is_finite() || !step.is_finite() {
+        return Err(Error::NonFinite);
+    }
+
+    if step <= 0.0 {
+        return Err(Error::InvalidTime("the step isn't positive"));
+    }
+
+    if range.1 < range.0 {
+        return Err(Error::InvalidTime("the range is reversed"));
+    }
+
+    if range.0 + step == range.0 || range.1 + step == range.1 {
+        return Err(Error::InvalidTime("the step is too small for the range"));
+    }
+
+    Ok(Scan {
+        f,
+        end: range.1,
+        step,
+        crossing,
+        last: None,
+        start: range.0,
+        index: 0,
+        done: false,
+    })
+}
+
+/// This function walks a range in steps, and yields the brackets where a function crosses 0
+///
+/// * A target other than 0 is one subtraction away (ex; `|jd| distance(jd) - 1.5`)
+/// * Each bracket can be narrowed down by [`find_crossing`], see [`find_crossings`] for both at once.
+///
+/// ```rust
+/// use rust_solar::events::{scan, Crossing};
+///
+/// let brackets: Vec<_> = scan(|x| x.sin(), (1.0, 10.0), 0.5, Crossing::Either).unwrap().collect();
+///
+/// // around π, 2π and 3π
+/// assert_eq!(vec![(3.0, 3.5), (6.0, 6.5), (9.0, 9.5)], brackets);
+/// ```
+pub fn scan(
+    f: impl Fn(f64) -> f64,
+    range: (f64, f64),
+    step: f64,
+    crossing: Crossing,
+) -> Result<impl Iterator<Item = (f64, f64)>> {
+    Ok(try_scan(move |x| Ok(f(x)), range, step, crossing)?.map_while(Result::ok))
+}
+
+/// This function finds where a fallible function crosses 0 within a bracket, see [`find_crossing`]
+///
+/// * An error of the function stops the search and is returned as it is.
+pub fn try_find_crossing(
+    f: impl Fn(f64) -> Result<f64>,
+    bracket: (f64, f64),
+    tolerance: f64,
+) -> Result<f64> {
+    if !bracket.0.is_finite() || !bracket.1.is_finite() || !tolerance.is_finite() {
+        return Err(Error::NonFinite);
+    }
+
+    if tolerance <= 0.0 {
+        return Err(Error::InvalidTime("the tolerance isn't positive"));
+    }
+
+    let (mut a, mut b) = bracket;
+    let (mut fa, mut fb) = (f(a)?, f(b)?);
+
+    if !fa.is_finite() || !fb.is_finite() {
+        return Err(Error::NonFinite);
+    }
+
+    if fa == 0.0 {
+        return Ok(a);
+    }
+
+    if fb == 0.0 {
+        return Ok(b);
+    }
+
+    if fa.signum() == fb.signum() {
+        return Err(Error::NoCrossing);
+    }
+
+    // `c` is the other side of the crossing, `d` the last step and `e` the one before it
+    let (mut c, mut fc) = (b, fb);
+    let (mut d, mut e) = (b - a, b - a);
+
+    for _ in 0..MAX_ITERATIONS {
+        if fb.signum() == fc.signum() {
+            (c, fc) = (a, fa);
+            d = b - a;
+            e = d;
+        }
+
+        // `b` is always the closest to 0
+        if fc.abs() < fb.abs() {
+            (a, fa) = (b, fb);
+            (b, fb) = (c, fc);
+            (c, fc) = (a, fa);
+        }
+
+        let precision = 2.0 * f64::EPSILON * b.abs() + tolerance / 2.0;
+        let middle = (c - b) / 2.0;
+
+        if middle.abs() <= precision || fb == 0.0 {
+            return Ok(b);
+        }
+
+        if e.abs() >= precision && fa.abs() > fb.abs() {
+            // an inverse quadratic step, or a secant step with only two points
+            let s = fb / fa;
+            let (mut p, mut q) = match a == c {
+                true => (2.0 * middle * s, 1.0 - s),
+                false => {
+                    let (q, r) = (fa / fc, fb / fc);
+
+                    (
+                        s * (2.0 * middle * q * (q - r) - (b - a) * (r - 1.0)),
+                        (q - 1.0) * (r - 1.0) * (s - 1.0),
+                    )
+                }
+            };
+
+            if p > 0.0 {
+                q = -q;
+            }
+
+            p = p.abs();
+
+            // the step has to stay within the bracket and shrink fast enough, otherwise it bisects
+            match 2.0 * p < (3.0 * middle * q - (precision * q).abs()).min((e * q).abs()) {
+                true => (e, d) = (d, p / q),
+                false => (e, d) = (middle, middle),
+            }
+        } else {
+            (e, d) = (middle, middle);
+        }
+
+        (a, fa) = (b, fb);
+        b += match d.abs() > precision {
+            true => d,
+            false => precision.copysign(middle),
+        };
+        fb = f(b)?;
+    }
+
+    Err(Error::NoConvergence(MAX_ITERATIONS))
+}
+
+/// This function finds where a function crosses 0 within a bracket, by Brent's method
+///
+/// * The ends of the bracket must be on opposite sides of 0, see [`scan`] to find them.
+/// * The crossing is found within the tolerance (ex; `1e-8` days is about a millisecond)
+/// * It fails with [`Error::NoCrossing`] when both ends are on the same side,
+///   and with [`Error::NoConvergence`] after [`MAX_ITERATIONS`] steps.
+///
+/// ```rust
+/// use rust_solar::events::find_crossing;
+///
+/// let root = find_crossing(|x| x * x - 2.0, (0.0, 2.0), 1e-12).unwrap();
+///
+/// assert!((root - 2f64.sqrt()).abs() < 1e-12);
+/// ```
+pub fn find_crossing(f: impl Fn(f64) -> f64, bracket: (f64, f64), tolerance: f64) -> Result<f64> {
+    try_find_crossing(|x| Ok(f(x)), bracket, tolerance)
+}
+
+/// This function finds every crossing of 0 of a function within a range, see [`scan`] and [`find_crossing`]
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::{events::{find_crossings, Crossing}, kepler::Body, planets::mars::Mars};
+///
+/// // when mars is 1.5 AU from the sun during 2001
+/// let crossings = find_crossings(
+///     |jd| Mars.heliocentric_position(jd).unwrap().distance().0 - 1.5,
+///     (2451910.5, 2452275.5),
+///     10.0,
+///     Crossing::Either,
+///     1e-8,
+/// )
+/// .unwrap();
+///
+/// for jd in crossings {
+///     assert!((Mars.heliocentric_position(jd).unwrap().distance().0 - 1.5).abs() < 1e-8);
+/// }
+/// # }
+/// ```
+pub fn find_crossings(
+    f: impl Fn(f64) -> f64,
+    range: (f64, f64),
+    step: f64,
+    crossing: Crossing,
+    tolerance: f64,
+) -> Result<Vec<f64>> {
+    scan(&f, range, step, crossing)?
+        .map(|bracket| find_crossing(&f, bracket, tolerance))
+        .collect()
+}
+
+/// This function finds an extremum of a fallible function within a bracket, see [`find_extremum`]
+///
+/// * An error of the function stops the search and is returned as it is.
+pub fn try_find_extremum(
+    f: impl Fn(f64) -> Result<f64>,
+    bracket: (f64, f64),
+    tolerance: f64,
+    extremum: Extremum,
+) -> Result<f64> {
+    if !bracket.0.is_finite() || !bracket.1.is_finite() || !tolerance.is_finite() {
+        return Err(Error::NonFinite);
+    }
+
+    if tolerance <= 0.0 {
+        return Err(Error::InvalidTime("the tolerance isn't positive"));
+    }
+
+    // a maximum is the minimum of the opposite function
+    let g = |x: f64| -> Result<f64> {
+        Ok(match extremum {
+            Extremum::Minimum => f(x)?,
+            Extremum::Maximum => -f(x)?,
+        })
+    };
+
+    let (mut a, mut b) = (bracket.0.min(bracket.1), bracket.0.max(bracket.1));
+    // `x` is the lowest so far, `w` the second lowest and `v` the one before it
+    let mut x = a + GOLDEN_SECTION * (b - a);
+    let (mut w, mut v) = (x, x);
+    let mut fx = g(x)?;
+    let (mut fw, mut fv) = (fx, fx);
+    let (mut d, mut e): (f64, f64) = (0.0, 0.0);
+
+    for _ in 0..MAX_ITERATIONS {
+        let middle = (a + b) / 2.0;
+        let precision = tolerance / 2.0 + f64::EPSILON * x.abs();
+
+        if (x - middle).abs() <= 2.0 * precision - (b - a) / 2.0 {
+            return Ok(x);
+        }
+
+        let golden = |x: f64| match x >= middle {
+            true => a - x,
+            false => b - x,
+        };
+
+        if e.abs() > precision {
+            // a parabola through the three lowest points
+            let r = (x - w) * (fx - fv);
+            let q = (x - v) * (fx - fw);
+            let mut p = (x - v) * q - (x - w) * r;
+            let mut q = 2.0 * (q - r);
+
+            if q > 0.0 {
+                p = -p;
+            }
+
+            q = q.abs();
+
+            let previous = e;
+
+            e = d;
+
+            if p.abs() >= (q * previous / 2.0).abs() || p <= q * (a - x) || p >= q * (b - x) {
+                e = golden(x);
+                d = GOLDEN_SECTION * e;
+            } else {
+                d = p / q;
+
+                if x + d - a < 2.0 * precision || b - (x + d) < 2.0 * precision {
+                    d = precision.copysign(middle - x);
+                }
+            }
+        } else {
+            e = golden(x);
+            d = GOLDEN_SECTION * e;
+        }
+
+        let u = match d.abs() >= precision {
+            true => x + d,
+            false => x + precision.copysign(d),
+        };
+        let fu = g(u)?;
+
+        if fu <= fx {
+            match u >= x {
+                true => a = x,
+                false => b = x,
+            }
+
+            (v, fv) = (w, fw);
+            (w, fw) = (x, fx);
+            (x, fx) = (u, fu);
+        } else {
+            match u < x {
+                true => a = u,
+                false => b = u,
+            }
+
+            if fu <= fw || w == x {
+                (v, fv) = (w, fw);
+                (w, fw) = (u, fu);
+            } else if fu <= fv || v == x || v == w {
+                (v, fv) = (u, fu);
+            }
+        }
+    }
+
+    Err(Error::NoConvergence(MAX_ITERATIONS))
+}
+
+/// This function finds a minimum or a maximum of a function within a bracket, by Brent's method
+///
+/// * The bracket should hold a single extremum, otherwise any of them may be found.
+/// * An extremum at an end of the bracket is found at that end.
+/// * The function is flat around an extremum, so it's only found as precisely as its values allow,
+///   about the square root of their precision.
+/// * It fails with [`Error::NoConvergence`] after [`MAX_ITERATIONS`] steps.
+///
+/// ```rust
+/// use rust_solar::events::{find_extremum, Extremum};
+///
+/// let x = find_extremum(|x| (x - 1.0).powi(2), (-3.0, 5.0), 1e-6, Extremum::Minimum).unwrap();
+///
+/// assert!((x - 1.0).abs() < 1e-6);
+/// ```
+pub fn find_extremum(
+    f: impl Fn(f64) -> f64,
+    bracket: (f64, f64),
+    tolerance: f64,
+    extremum: Extremum,
+) -> Result<f64> {
+    try_find_extremum(|x| Ok(f(x)), bracket, tolerance, extremum)
+}
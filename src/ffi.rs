@@ -0,0 +1,323 @@
+#![allow(unsafe_code)]
+
+use core::{ffi::c_char, str::FromStr};
+use std::{
+    ffi::CStr,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use crate::{
+    bodies::registry,
+    error::{Error, Result},
+    kepler::{Body, Date, Eras, Time, TimeZone},
+    moons::luna::Lunar,
+    planets::mars::{Mars, Martian},
+};
+
+/// The call succeeded
+pub const RS_SOLAR_OK: i32 = 0;
+/// A pointer that must not be null was null
+pub const RS_SOLAR_NULL_POINTER: i32 = -1;
+/// A string isn't valid UTF-8
+pub const RS_SOLAR_INVALID_STRING: i32 = -2;
+/// The call panicked, the output was left untouched
+pub const RS_SOLAR_PANIC: i32 = -3;
+/// See [`Error::NonFinite`]
+pub const RS_SOLAR_NON_FINITE: i32 = -10;
+/// See [`Error::InvalidDate`]
+pub const RS_SOLAR_INVALID_DATE: i32 = -11;
+/// See [`Error::InvalidOrbit`]
+pub const RS_SOLAR_INVALID_ORBIT: i32 = -12;
+/// See [`Error::InvalidPerihelion`]
+pub const RS_SOLAR_INVALID_PERIHELION: i32 = -13;
+/// See [`Error::InvalidSeasonTable`]
+pub const RS_SOLAR_INVALID_SEASON_TABLE: i32 = -14;
+/// See [`Error::UnknownTimeZone`]
+pub const RS_SOLAR_UNKNOWN_TIME_ZONE: i32 = -15;
+/// See [`Error::InvalidTime`]
+pub const RS_SOLAR_INVALID_TIME: i32 = -16;
+/// See [`Error::InvalidFormat`]
+pub const RS_SOLAR_INVALID_FORMAT: i32 = -17;
+/// See [`Error::InvalidEccentricity`]
+pub const RS_SOLAR_INVALID_ECCENTRICITY: i32 = -18;
+/// See [`Error::NoConvergence`]
+pub const RS_SOLAR_NO_CONVERGENCE: i32 = -19;
+/// See [`Error::InvalidTimeZone`]
+pub const RS_SOLAR_INVALID_TIME_ZONE: i32 = -20;
+/// See [`Error::Io`]
+pub const RS_SOLAR_IO: i32 = -21;
+/// See [`Error::NoCrossing`]
+pub const RS_SOLAR_NO_CROSSING: i32 = -22;
+
+/// The era is after discovery, see [`Eras::AD`]
+pub const RS_SOLAR_ERA_AD: i32 = 0;
+/// The era is before discovery, see [`Eras::BD`]
+pub const RS_SOLAR_ERA_BD: i32 = 1;
+/// The era is unknown, see [`Eras::Unknown`]
+pub const RS_SOLAR_ERA_UNKNOWN: i32 = 2;
+
+/// The capacity of the name fields, including the nul terminator
+pub const RS_SOLAR_NAME_LEN: usize = 32;
+/// The capacity of the short fields (codes, hour types), including the nul terminator
+pub const RS_SOLAR_CODE_LEN: usize = 8;
+
+/// This structure mirrors [`Date`] with fixed-size fields
+///
+/// * The season is its name as a nul terminated string, see [`Date::season_str`]
+///
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CDate {
+    /// This is the era of body, one of the `RS_SOLAR_ERA_*` constants
+    pub era: i32,
+    /// This is the year of body
+    pub year: f64,
+    /// This is the month of body
+    pub month: f64,
+    /// This is the day of body
+    pub day: f64,
+    /// This is the ls of the body
+    pub ls: f64,
+    /// This is the sol of the year with its fraction, counted from 0
+    pub sol: f64,
+    /// This is the season of the body
+    pub season: [c_char; RS_SOLAR_NAME_LEN],
+}
+
+impl Default for CDate {
+    fn default() -> Self {
+        Self {
+            era: RS_SOLAR_ERA_UNKNOWN,
+            year: 0.0,
+            month: 0.0,
+            day: 0.0,
+            ls: 0.0,
+            sol: 0.0,
+            season: [0; RS_SOLAR_NAME_LEN],
+        }
+    }
+}
+
+impl From<&Date> for CDate {
+    fn from(date: &Date) -> Self {
+        let era = match date.era {
+            Eras::AD => RS_SOLAR_ERA_AD,
+            Eras::BD => RS_SOLAR_ERA_BD,
+            Eras::Unknown => RS_SOLAR_ERA_UNKNOWN,
+        };
+
+        Self {
+            era,
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            ls: date.ls,
+            sol: date.sol,
+            season: fixed(date.season_str()),
+        }
+    }
+}
+
+/// This structure mirrors [`Time`] with fixed-size fields
+///
+/// * Every text is a nul terminated string, cut at its capacity
+///
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CTime {
+    /// This is the hour of the body
+    pub hour: i32,
+    /// This is the minute of the body
+    pub minute: u8,
+    /// This is the second of the body
+    pub second: u8,
+    /// This is the millisecond of the body
+    pub millisecond: u16,
+    /// This is the amount of hours in a day of the body, where the clock wraps
+    pub hours_per_day: u8,
+    /// This is the offset code of the body
+    pub code: [c_char; RS_SOLAR_CODE_LEN],
+    /// This is the name code of the body
+    pub name: [c_char; RS_SOLAR_NAME_LEN],
+    /// This is the offset name code of the body
+    pub offset_name: [c_char; RS_SOLAR_NAME_LEN],
+    /// This is the hour type of body (Millitary Time or 12Hour)
+    pub hour_type: [c_char; RS_SOLAR_CODE_LEN],
+}
+
+impl Default for CTime {
+    fn default() -> Self {
+        Self::from(&Time::default())
+    }
+}
+
+impl From<&Time> for CTime {
+    fn from(time: &Time) -> Self {
+        Self {
+            hour: time.hour,
+            minute: time.minute,
+            second: time.second,
+            millisecond: time.millisecond,
+            hours_per_day: time.hours_per_day,
+            code: fixed(time.code()),
+            name: fixed(time.name()),
+            offset_name: fixed(time.offset_name),
+            hour_type: fixed(time.hour_type.as_ref()),
+        }
+    }
+}
+
+/// This structure describes a built-in body, see [`crate::bodies::registry`]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CBody {
+    /// This is the name of the body
+    pub name: [c_char; RS_SOLAR_NAME_LEN],
+    /// This is the orbital period of the body, see [`Body::orbital_period`]
+    pub orbital_period: f64,
+    /// This is the rotational period of the body, see [`Body::rotational_period`]
+    pub rotational_period: f64,
+}
+
+impl From<&dyn Body> for CBody {
+    fn from(body: &dyn Body) -> Self {
+        Self {
+            name: fixed(body.name()),
+            orbital_period: body.orbital_period(),
+            rotational_period: body.rotational_period(),
+        }
+    }
+}
+
+/// This function computes the martian date of a julian date (UTC)
+///
+/// * Returns [`RS_SOLAR_OK`] and writes `out`, or a negative error code and leaves `out` untouched
+///
+/// # Safety
+///
+/// `out` must be null or point to a writable [`CDate`].
+#[no_mangle]
+pub unsafe extern "C" fn rs_solar_mars_to_date(jd: f64, out: *mut CDate) -> i32 {
+    if out.is_null() {
+        return RS_SOLAR_NULL_POINTER;
+    }
+
+    guard(|| {
+        let date = Mars.try_to_date(jd)?;
+
+        // SAFETY: out is not null and the caller promised it's writable.
+        unsafe { out.write(CDate::from(&date)) };
+
+        Ok(RS_SOLAR_OK)
+    })
+}
+
+/// This function computes the time of a julian date (UTC) in a martian or lunar timezone
+///
+/// * The timezone is either a code (ex; `"AMT"`) or a variant name (ex; `"MTCn5"`)
+/// * Returns [`RS_SOLAR_OK`] and writes `out`, or a negative error code and leaves `out` untouched
+///
+/// # Safety
+///
+/// `zone_code` must be null or a nul terminated string, and `out` must be null or point to a writable [`CTime`].
+#[no_mangle]
+pub unsafe extern "C" fn rs_solar_time_at(
+    jd: f64,
+    zone_code: *const c_char,
+    out: *mut CTime,
+) -> i32 {
+    if zone_code.is_null() || out.is_null() {
+        return RS_SOLAR_NULL_POINTER;
+    }
+
+    // SAFETY: zone_code is not null and the caller promised it's nul terminated.
+    let Ok(zone) = unsafe { CStr::from_ptr(zone_code) }.to_str() else {
+        return RS_SOLAR_INVALID_STRING;
+    };
+
+    guard(|| {
+        if !jd.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        let time = match Martian::from_str(zone) {
+            Ok(martian) => martian.at(jd),
+            Err(_) => Lunar::from_str(zone)?.at(jd),
+        };
+
+        // SAFETY: out is not null and the caller promised it's writable.
+        unsafe { out.write(CTime::from(&time)) };
+
+        Ok(RS_SOLAR_OK)
+    })
+}
+
+/// This function lists the built-in bodies
+///
+/// * Writes up to `capacity` bodies into `out` and returns how many bodies there are,
+///   so calling it with a null `out` and a `capacity` of 0 sizes the buffer
+///
+/// # Safety
+///
+/// `out` must be null with a `capacity` of 0, or point to `capacity` writable [`CBody`].
+#[no_mangle]
+pub unsafe extern "C" fn rs_solar_list_bodies(out: *mut CBody, capacity: usize) -> i32 {
+    if out.is_null() && capacity > 0 {
+        return RS_SOLAR_NULL_POINTER;
+    }
+
+    guard(|| {
+        let bodies = registry();
+
+        for (i, body) in bodies.iter().take(capacity).enumerate() {
+            // SAFETY: i is below capacity and the caller promised that many are writable.
+            unsafe { out.add(i).write(CBody::from(*body)) };
+        }
+
+        Ok(bodies.len() as i32)
+    })
+}
+
+/// This function is the error code of an error
+fn code(error: Error) -> i32 {
+    match error {
+        Error::NonFinite => RS_SOLAR_NON_FINITE,
+        Error::InvalidDate(_) => RS_SOLAR_INVALID_DATE,
+        Error::InvalidOrbit(_) => RS_SOLAR_INVALID_ORBIT,
+        Error::InvalidPerihelion(_) => RS_SOLAR_INVALID_PERIHELION,
+        Error::InvalidSeasonTable(_) => RS_SOLAR_INVALID_SEASON_TABLE,
+        Error::UnknownTimeZone => RS_SOLAR_UNKNOWN_TIME_ZONE,
+        Error::InvalidTime(_) => RS_SOLAR_INVALID_TIME,
+        Error::InvalidFormat(_) => RS_SOLAR_INVALID_FORMAT,
+        Error::InvalidEccentricity(_) => RS_SOLAR_INVALID_ECCENTRICITY,
+        Error::NoConvergence(_) => RS_SOLAR_NO_CONVERGENCE,
+        Error::InvalidTimeZone(_) => RS_SOLAR_INVALID_TIME_ZONE,
+        Error::Io(_) => RS_SOLAR_IO,
+        Error::NoCrossing => RS_SOLAR_NO_CROSSING,
+    }
+}
+
+/// This function keeps errors and panics from crossing the boundary, a panic can't unwind into C
+fn guard(f: impl FnOnce() -> Result<i32>) -> i32 {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(status)) => status,
+        Ok(Err(error)) => code(error),
+        Err(_) => RS_SOLAR_PANIC,
+    }
+}
+
+/// This function copies a string into a nul terminated buffer, cut at a character boundary
+fn fixed<const N: usize>(text: &str) -> [c_char; N] {
+    let mut buffer = [0; N];
+    let mut end = text.len().min(N - 1);
+
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    for (slot, byte) in buffer.iter_mut().zip(&text.as_bytes()[..end]) {
+        *slot = *byte as c_char;
+    }
+
+    buffer
+}
@@ -0,0 +1,400 @@
+/// How far the solar longitude of [`crate::kepler::Body::try_to_date`] of mars may drift from [`MARS`] in degrees,
+/// the kepler solver has no perturbations and a fixed perihelion
+///
+/// * The drift grows with the years from the epoch, it's within 0.2 degrees up to 2024 and about 0.202 in 2040.
+#[cfg(feature = "mars")]
+pub const MARS_KEPLER_DRIFT: f64 = 0.25;
+
+/// How far the solar longitude of [`crate::kepler::Body::try_to_date`] of earth may drift from [`EARTH`] in degrees
+#[cfg(feature = "earth")]
+pub const EARTH_KEPLER_DRIFT: f64 = 0.35;
+
+/// The landings of NASA, whose Ls is published to a tenth of a degree
+#[cfg(feature = "mars")]
+const LANDING: &str = "NASA mission Ls, checked against Mars24";
+
+/// The dates that had no Ls published, so it's the Ls of Mars24 to five decimals
+///
+/// * It's worked out apart from this library, from the published algorithm and the leap seconds
+///   (10 seconds of TAI - UTC before 1972).
+#[cfg(feature = "mars")]
+const MARS24: &str = "Mars24 algorithm (Allison & McEwen 2000, NASA GISS), with the leap seconds of the IERS";
+
+/// The worked example of Mars24, which starts from UTC and converts it to TT (JD 2451549.50074)
+#[cfg(feature = "mars")]
+const WORKED_EXAMPLE: &str = "Mars24 worked example A (NASA GISS), 2000 January 6 00:00:00 UTC";
+
+/// The equinoxes and solstices of the Astronomical Almanac, to the minute
+#[cfg(feature = "earth")]
+const ALMANAC: &str = "The Astronomical Almanac (USNO Earth's Seasons)";
+
+/// This is a reference conversion of a julian date on a body, with where its values come from
+///
+/// * The `ls` and the `msd` come from the source, so a test compares the library with them rather than with itself.
+/// * Every row is checked by the tests of the library, see `tests/fixtures-ut.rs`
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::{bodies, fixtures};
+///
+/// for fixture in fixtures::MARS {
+///     let body = bodies::find(fixture.body).unwrap();
+///     let date = body.to_date(fixture.julian_date);
+///
+///     assert!((date.ls - fixture.ls).abs() <= fixtures::MARS_KEPLER_DRIFT, "{}", fixture.label);
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fixture {
+    /// What happened at the date
+    pub label: &'static str,
+    /// Where the solar longitude, the mars sol date and the mars time come from
+    pub source: &'static str,
+    /// The name of the body, see [`crate::bodies::find`]
+    pub body: &'static str,
+    /// The date and time (UTC) as (year, month, day, hour, minute, second) of the gregorian calendar
+    pub utc: (i32, u32, u32, u32, u32, f64),
+    /// The julian date (UTC) of the `utc`, see [`crate::julian::greg2jd`]
+    pub julian_date: f64,
+    /// The published solar longitude (Ls) in degrees
+    pub ls: f64,
+    /// How closely the Ls was published in degrees
+    pub ls_tolerance: f64,
+    /// The mars sol date (MSD) of Mars24 to five decimals, only for mars
+    pub msd: Option<f64>,
+    /// The coordinated mars time (MTC) of Mars24 in hours to five decimals, only for mars
+    pub mtc: Option<f64>,
+}
+
+/// The reference conversions of mars, from 1965 to 2040, with the MSD and the MTC of Mars24 for each one
+#[cfg(feature = "mars")]
+pub const MARS: [Fixture; 20] = [
+    Fixture {
+        label: "Mariner 4 flyby",
+        source: MARS24,
+        body: "Mars",
+        utc: (1965, 7, 15, 1, 0, 57.0),
+        julian_date: 2438956.542326389,
+        ls: 143.15568,
+        ls_tolerance: 1e-5,
+        msd: Some(32539.97529),
+        mtc: Some(23.40701),
+    },
+    Fixture {
+        label: "Mariner 9 orbit insertion",
+        source: MARS24,
+        body: "Mars",
+        utc: (1971, 11, 14, 0, 18, 0.0),
+        julian_date: 2441269.5125,
+        ls: 292.36723,
+        ls_tolerance: 1e-5,
+        msd: Some(34791.06032),
+        mtc: Some(1.44768),
+    },
+    Fixture {
+        label: "Viking 1 landing",
+        source: LANDING,
+        body: "Mars",
+        utc: (1976, 7, 20, 11, 53, 6.0),
+        julian_date: 2442979.9952083332,
+        ls: 97.0,
+        ls_tolerance: 0.1,
+        msd: Some(36455.77792),
+        mtc: Some(18.66996),
+    },
+    Fixture {
+        label: "Viking 2 landing",
+        source: LANDING,
+        body: "Mars",
+        utc: (1976, 9, 3, 22, 37, 50.0),
+        julian_date: 2443025.4429398146,
+        ls: 117.6,
+        ls_tolerance: 0.1,
+        msd: Some(36500.00966),
+        mtc: Some(0.23185),
+    },
+    Fixture {
+        label: "1980 January 1",
+        source: MARS24,
+        body: "Mars",
+        utc: (1980, 1, 1, 0, 0, 0.0),
+        julian_date: 2444239.5,
+        ls: 46.70121,
+        ls_tolerance: 1e-5,
+        msd: Some(37681.58381),
+        mtc: Some(14.01154),
+    },
+    Fixture {
+        label: "1990 January 1",
+        source: MARS24,
+        body: "Mars",
+        utc: (1990, 1, 1, 0, 0, 0.0),
+        julian_date: 2447892.5,
+        ls: 145.82002,
+        ls_tolerance: 1e-5,
+        msd: Some(41236.84529),
+        mtc: Some(20.28707),
+    },
+    Fixture {
+        label: "Mars Pathfinder landing",
+        source: LANDING,
+        body: "Mars",
+        utc: (1997, 7, 4, 16, 57, 0.0),
+        julian_date: 2450634.20625,
+        ls: 142.7,
+        ls_tolerance: 0.1,
+        msd: Some(43905.19533),
+        mtc: Some(4.68793),
+    },
+    Fixture {
+        label: "Mars24 worked example",
+        source: WORKED_EXAMPLE,
+        body: "Mars",
+        utc: (2000, 1, 6, 0, 0, 0.0),
+        julian_date: 2451549.5,
+        ls: 277.18758,
+        ls_tolerance: 1e-5,
+        msd: Some(44795.99976),
+        mtc: Some(23.99431),
+    },
+    Fixture {
+        label: "Spirit landing",
+        source: LANDING,
+        body: "Mars",
+        utc: (2004, 1, 4, 4, 35, 0.0),
+        julian_date: 2453008.690972222,
+        ls: 327.7,
+        ls_tolerance: 0.1,
+        msd: Some(46216.14905),
+        mtc: Some(3.57728),
+    },
+    Fixture {
+        label: "Opportunity landing",
+        source: LANDING,
+        body: "Mars",
+        utc: (2004, 1, 25, 5, 5, 0.0),
+        julian_date: 2453029.7118055555,
+        ls: 339.1,
+        ls_tolerance: 0.1,
+        msd: Some(46236.60746),
+        mtc: Some(14.57902),
+    },
+    Fixture {
+        label: "Phoenix landing",
+        source: MARS24,
+        body: "Mars",
+        utc: (2008, 5, 25, 23, 38, 24.0),
+        julian_date: 2454612.485,
+        ls: 76.73086,
+        ls_tolerance: 1e-5,
+        msd: Some(47777.03245),
+        mtc: Some(0.77889),
+    },
+    Fixture {
+        label: "2010 January 1",
+        source: MARS24,
+        body: "Mars",
+        utc: (2010, 1, 1, 0, 0, 0.0),
+        julian_date: 2455197.5,
+        ls: 31.61182,
+        ls_tolerance: 1e-5,
+        msd: Some(48346.39498),
+        mtc: Some(9.47944),
+    },
+    Fixture {
+        label: "Curiosity landing",
+        source: LANDING,
+        body: "Mars",
+        utc: (2012, 8, 6, 5, 17, 57.0),
+        julian_date: 2456145.720798611,
+        ls: 150.7,
+        ls_tolerance: 0.1,
+        msd: Some(49269.24547),
+        mtc: Some(5.89131),
+    },
+    Fixture {
+        label: "2015 January 1",
+        source: MARS24,
+        body: "Mars",
+        utc: (2015, 1, 1, 0, 0, 0.0),
+        julian_date: 2457023.5,
+        ls: 263.49514,
+        ls_tolerance: 1e-5,
+        msd: Some(50123.53907),
+        mtc: Some(12.93773),
+    },
+    Fixture {
+        label: "InSight landing",
+        source: MARS24,
+        body: "Mars",
+        utc: (2018, 11, 26, 19, 52, 59.0),
+        julian_date: 2458449.328460648,
+        ls: 295.6564,
+        ls_tolerance: 1e-5,
+        msd: Some(51511.21851),
+        mtc: Some(5.24426),
+    },
+    Fixture {
+        label: "2020 January 1",
+        source: MARS24,
+        body: "Mars",
+        utc: (2020, 1, 1, 0, 0, 0.0),
+        julian_date: 2458849.5,
+        ls: 128.83358,
+        ls_tolerance: 1e-5,
+        msd: Some(51900.68318),
+        mtc: Some(16.39629),
+    },
+    Fixture {
+        label: "Perseverance landing",
+        source: MARS24,
+        body: "Mars",
+        utc: (2021, 2, 18, 20, 55, 0.0),
+        julian_date: 2459264.371527778,
+        ls: 5.64851,
+        ls_tolerance: 1e-5,
+        msd: Some(52304.45453),
+        mtc: Some(10.90864),
+    },
+    Fixture {
+        label: "2025 January 1",
+        source: MARS24,
+        body: "Mars",
+        utc: (2025, 1, 1, 0, 0, 0.0),
+        julian_date: 2460676.5,
+        ls: 23.88508,
+        ls_tolerance: 1e-5,
+        msd: Some(53678.80051),
+        mtc: Some(19.21217),
+    },
+    Fixture {
+        label: "2030 January 1",
+        source: MARS24,
+        body: "Mars",
+        utc: (2030, 1, 1, 0, 0, 0.0),
+        julian_date: 2462502.5,
+        ls: 252.81594,
+        ls_tolerance: 1e-5,
+        msd: Some(55455.94459),
+        mtc: Some(22.67019),
+    },
+    Fixture {
+        label: "2040 January 1",
+        source: MARS24,
+        body: "Mars",
+        utc: (2040, 1, 1, 0, 0, 0.0),
+        julian_date: 2466154.5,
+        ls: 15.53471,
+        ls_tolerance: 1e-5,
+        msd: Some(59010.23276),
+        mtc: Some(5.58623),
+    },
+];
+
+/// The reference conversions of earth, the equinoxes and solstices from 1960 to 2040
+///
+/// * The Ls of earth is the apparent longitude of the sun, so the March equinox is Ls 0.
+#[cfg(feature = "earth")]
+pub const EARTH: [Fixture; 9] = [
+    Fixture {
+        label: "1960 March equinox",
+        source: ALMANAC,
+        body: "Earth",
+        utc: (1960, 3, 20, 14, 43, 0.0),
+        julian_date: 2437014.113194444,
+        ls: 0.0,
+        ls_tolerance: 0.01,
+        msd: None,
+        mtc: None,
+    },
+    Fixture {
+        label: "1970 March equinox",
+        source: ALMANAC,
+        body: "Earth",
+        utc: (1970, 3, 21, 0, 56, 0.0),
+        julian_date: 2440666.538888889,
+        ls: 0.0,
+        ls_tolerance: 0.01,
+        msd: None,
+        mtc: None,
+    },
+    Fixture {
+        label: "1980 June solstice",
+        source: ALMANAC,
+        body: "Earth",
+        utc: (1980, 6, 21, 5, 47, 0.0),
+        julian_date: 2444411.7409722223,
+        ls: 90.0,
+        ls_tolerance: 0.01,
+        msd: None,
+        mtc: None,
+    },
+    Fixture {
+        label: "1990 September equinox",
+        source: ALMANAC,
+        body: "Earth",
+        utc: (1990, 9, 23, 6, 55, 0.0),
+        julian_date: 2448157.7881944445,
+        ls: 180.0,
+        ls_tolerance: 0.01,
+        msd: None,
+        mtc: None,
+    },
+    Fixture {
+        label: "2000 March equinox",
+        source: ALMANAC,
+        body: "Earth",
+        utc: (2000, 3, 20, 7, 35, 0.0),
+        julian_date: 2451623.815972222,
+        ls: 0.0,
+        ls_tolerance: 0.01,
+        msd: None,
+        mtc: None,
+    },
+    Fixture {
+        label: "2010 March equinox",
+        source: ALMANAC,
+        body: "Earth",
+        utc: (2010, 3, 20, 17, 32, 0.0),
+        julian_date: 2455276.230555556,
+        ls: 0.0,
+        ls_tolerance: 0.01,
+        msd: None,
+        mtc: None,
+    },
+    Fixture {
+        label: "2020 December solstice",
+        source: ALMANAC,
+        body: "Earth",
+        utc: (2020, 12, 21, 10, 2, 0.0),
+        julian_date: 2459204.918055556,
+        ls: 270.0,
+        ls_tolerance: 0.01,
+        msd: None,
+        mtc: None,
+    },
+    Fixture {
+        label: "2030 December solstice",
+        source: ALMANAC,
+        body: "Earth",
+        utc: (2030, 12, 21, 20, 9, 0.0),
+        julian_date: 2462857.339583333,
+        ls: 270.0,
+        ls_tolerance: 0.01,
+        msd: None,
+        mtc: None,
+    },
+    Fixture {
+        label: "2040 March equinox",
+        source: ALMANAC,
+        body: "Earth",
+        utc: (2040, 3, 20, 0, 11, 0.0),
+        julian_date: 2466233.507638889,
+        ls: 0.0,
+        ls_tolerance: 0.01,
+        msd: None,
+        mtc: None,
+    },
+];
@@ -0,0 +1,269 @@
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    conversions::{Au, Days, KM_PER_AU},
+    error::{Error, Result},
+    julian::{JulianDate, TimeScale},
+    kepler::Body,
+    planets::EARTH_ROTATIONAL_PERIOD,
+    position::Position,
+};
+
+pub use crate::orbit::OrbitalElements;
+
+/// This is a record of the VECTORS table of Horizons, see [`parse_vectors`]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct StateVector {
+    /// The position (`X`, `Y` and `Z`) in astronomical units
+    pub position: Position,
+    /// The velocity (`VX`, `VY` and `VZ`) in km/s
+    pub velocity: [f64; 3],
+}
+
+/// This is how far the positions of a body are from the state vectors of Horizons, see [`compare`]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Deviation {
+    /// The largest distance between a position and its state vector
+    pub max: Au,
+    /// The root mean square of the distances
+    pub rms: Au,
+    /// The amount of records compared
+    pub records: usize,
+}
+
+/// The units of the numbers of a table, from the `Output units` of its header
+#[derive(Debug, Clone, Copy)]
+struct Units {
+    /// The kilometers in a unit of distance
+    km: f64,
+    /// The seconds in a unit of time
+    seconds: f64,
+}
+
+impl Units {
+    /// This method reads the units from the header, kilometers and seconds when there are none (the default of Horizons)
+    fn from_header(header: &str) -> Self {
+        let units = header
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("Output units"))
+            .and_then(|line| line.split(':').nth(1))
+            .map_or("KM-S", str::trim);
+        let (distance, time) = units.split_once('-').unwrap_or(("KM", "S"));
+
+        Self {
+            km: match distance {
+                "AU" => KM_PER_AU,
+                _ => 1.0,
+            },
+            seconds: match time {
+                "D" => EARTH_ROTATIONAL_PERIOD,
+                _ => 1.0,
+            },
+        }
+    }
+
+    /// This method converts a distance into astronomical units
+    fn au(self, distance: f64) -> Au {
+        Au(distance * self.km / KM_PER_AU)
+    }
+
+    /// This method converts a speed into km/s
+    fn km_s(self, speed: f64) -> f64 {
+        speed * self.km / self.seconds
+    }
+
+    /// This method converts a length of time into earth days
+    fn days(self, time: f64) -> Days {
+        Days(time * self.seconds / EARTH_ROTATIONAL_PERIOD)
+    }
+}
+
+/// This is a record of a table, its julian date and its `KEY= value` fields
+struct Record<'a> {
+    julian_date: JulianDate,
+    fields: Vec<(&'a str, f64)>,
+}
+
+impl Record<'_> {
+    /// This method finds the value of a field
+    fn get(&self, key: &str) -> Result<f64> {
+        self.fields
+            .iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, value)| *value)
+            .ok_or(Error::InvalidFormat(
+                "a record of the ephemeris is missing a field",
+            ))
+    }
+}
+
+/// This function parses the ELEMENTS table of a Horizons ephemeris in plain text (not CSV)
+///
+/// * Everything outside of the `$$SOE` and `$$EOE` markers is ignored, except for the `Output units`
+/// * The julian dates keep their time scale, which is TDB unless the record says `UT` or `TT`
+/// * It fails with [`Error::InvalidFormat`] when there's no block, or a record is missing a field or a number
+///
+/// ```rust
+/// use rust_solar::horizons::parse_elements;
+///
+/// let text = "$$SOE
+/// 2451545.000000000 = A.D. 2000-Jan-01 12:00:00.0000 TDB
+///  EC= 9.3E-02 QR= 1.38E+00 IN= 1.85E+00
+///  OM= 4.96E+01 W = 2.87E+02 Tp=  2451428.77
+///  N = 5.24E-01 MA= 1.91E+01 TA= 2.27E+01
+///  A = 1.52E+00 AD= 1.67E+00 PR= 6.87E+02
+/// $$EOE";
+///
+/// let records = parse_elements(text).unwrap();
+///
+/// assert_eq!(0.093, records[0].1.eccentricity);
+/// ```
+pub fn parse_elements(text: &str) -> Result<Vec<(JulianDate, OrbitalElements)>> {
+    let (units, records) = records(text)?;
+
+    records
+        .iter()
+        .map(|record| {
+            let elements = OrbitalElements {
+                eccentricity: record.get("EC")?,
+                periapsis: units.au(record.get("QR")?),
+                inclination: record.get("IN")?,
+                ascending_node: record.get("OM")?,
+                argument_of_periapsis: record.get("W")?,
+                periapsis_jd: record.get("Tp")?,
+                mean_motion: record.get("N")? * EARTH_ROTATIONAL_PERIOD / units.seconds,
+                mean_anomaly: record.get("MA")?,
+                true_anomaly: record.get("TA")?,
+                semimajor: units.au(record.get("A")?),
+                apoapsis: units.au(record.get("AD")?),
+                period: units.days(record.get("PR")?),
+            };
+
+            Ok((record.julian_date, elements))
+        })
+        .collect()
+}
+
+/// This function parses the VECTORS table of a Horizons ephemeris in plain text (not CSV), see [`parse_elements`]
+///
+/// * The positions are turned into astronomical units and the velocities into km/s
+/// * The light time, range and range rate of the table are skipped
+pub fn parse_vectors(text: &str) -> Result<Vec<(JulianDate, StateVector)>> {
+    let (units, records) = records(text)?;
+
+    records
+        .iter()
+        .map(|record| {
+            let vector = StateVector {
+                position: Position::new(
+                    units.au(record.get("X")?).0,
+                    units.au(record.get("Y")?).0,
+                    units.au(record.get("Z")?).0,
+                ),
+                velocity: [
+                    units.km_s(record.get("VX")?),
+                    units.km_s(record.get("VY")?),
+                    units.km_s(record.get("VZ")?),
+                ],
+            };
+
+            Ok((record.julian_date, vector))
+        })
+        .collect()
+}
+
+/// This function compares the positions of a body with the state vectors of Horizons
+///
+/// * The vectors should be centered on the sun (`@10`) in the ecliptic of J2000, like [`Body::heliocentric_position`]
+/// * Each julian date is converted to UTC before the position is computed
+/// * It fails with [`Error::InvalidFormat`] when there are no vectors
+pub fn compare(
+    body: &(impl Body + ?Sized),
+    vectors: &[(JulianDate, StateVector)],
+) -> Result<Deviation> {
+    if vectors.is_empty() {
+        return Err(Error::InvalidFormat("there are no records to compare"));
+    }
+
+    let mut deviation = Deviation::default();
+    let mut squares = 0.0;
+
+    for (julian_date, vector) in vectors {
+        let position = body.heliocentric_position(julian_date.to_scale(TimeScale::UTC).value)?;
+        let distance = position.distance_to(&vector.position);
+
+        deviation.max = match distance > deviation.max {
+            true => distance,
+            false => deviation.max,
+        };
+        squares += distance.0 * distance.0;
+    }
+
+    deviation.records = vectors.len();
+    deviation.rms = Au((squares / vectors.len() as f64).sqrt());
+
+    Ok(deviation)
+}
+
+/// This function splits the block of a table into its records
+fn records(text: &str) -> Result<(Units, Vec<Record<'_>>)> {
+    let missing = Error::InvalidFormat("the ephemeris has no $$SOE and $$EOE block");
+    let (header, rest) = text.split_once("$$SOE").ok_or(missing)?;
+    let (block, _) = rest.split_once("$$EOE").ok_or(missing)?;
+    let mut records: Vec<Record> = Vec::new();
+
+    for line in block.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        match line.split_once('=') {
+            // a record starts with its julian date, then its calendar date and time scale
+            Some((julian_date, calendar)) if julian_date.trim().parse::<f64>().is_ok() => records
+                .push(Record {
+                    julian_date: JulianDate::new(number(julian_date)?, scale(calendar)),
+                    fields: Vec::new(),
+                }),
+            _ => records
+                .last_mut()
+                .ok_or(Error::InvalidFormat(
+                    "the ephemeris has a field before its first record",
+                ))?
+                .fields
+                .extend(fields(line)?),
+        }
+    }
+
+    Ok((Units::from_header(header), records))
+}
+
+/// This function reads the time scale after the calendar date of a record, TDB when there's none
+fn scale(calendar: &str) -> TimeScale {
+    match calendar.split_whitespace().last() {
+        Some("UT" | "UTC") => TimeScale::UTC,
+        Some("TT") => TimeScale::TT,
+        _ => TimeScale::TDB,
+    }
+}
+
+/// This function splits a line into its `KEY= value` fields, the space after a key (ex; `W =`) is optional
+fn fields(line: &str) -> Result<Vec<(&str, f64)>> {
+    let mut fields = Vec::new();
+    let mut rest = line;
+
+    while let Some((key, after)) = rest.split_once('=') {
+        let after = after.trim_start();
+        let end = after.find(char::is_whitespace).unwrap_or(after.len());
+
+        fields.push((key.trim(), number(&after[..end])?));
+        rest = &after[end..];
+    }
+
+    Ok(fields)
+}
+
+/// This function parses a number of a table
+fn number(text: &str) -> Result<f64> {
+    text.trim()
+        .parse()
+        .map_err(|_| Error::InvalidFormat("a field of the ephemeris isn't a number"))
+}
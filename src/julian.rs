@@ -1,33 +1,343 @@
-/// The first Julian Date epoch 
-pub const JD2NOON: f64 = 2451545.0;
+/// This module contains the conversions between time scales
+pub mod time_scales;
+/// This module contains the julian date split into a day and its fraction
+pub mod split;
+/// This module contains the julian dates of well known events, as `pub const`s
+pub mod landmarks;
+/// This module contains the conversions from and to `chrono`
+#[cfg(feature = "chrono")]
+mod with_chrono;
+/// This module contains the conversions from and to `time`
+#[cfg(feature = "time")]
+mod with_time;
 
-/// J2000 = JD - ['JD2NOON']
+#[cfg(feature = "chrono")]
+pub use with_chrono::{from_chrono, to_chrono};
+#[cfg(feature = "time")]
+pub use with_time::{from_odt, to_odt};
+pub use split::SplitJd;
+pub use time_scales::{
+    tai_to_tt, tai_to_utc, tdb_to_tt, tt_to_tai, tt_to_tdb, tt_to_utc, utc_to_tai, utc_to_tt,
+    JulianDate, TimeScale,
+};
+
+use core::fmt;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    error::{Error, Result},
+    planets::EARTH_ROTATIONAL_PERIOD,
+};
+
+/// The milliseconds in an earth day
+pub const MS_PER_DAY: f64 = EARTH_ROTATIONAL_PERIOD * 1000.0;
+
+/// The julian date (UTC) of the unix epoch, 1970-01-01 00:00:00
+pub const UNIX_EPOCH_JD: f64 = 2_440_587.5;
+
+/// The julian date (UTC) of seconds since the unix epoch
+///
+/// * Timestamps before 1970 are negative.
+///
+/// > $$JD = 2440587.5 + \frac{s}{86400}$$
+pub fn jd_from_unix_secs(secs: f64) -> f64 {
+    UNIX_EPOCH_JD + secs / EARTH_ROTATIONAL_PERIOD
+}
+
+/// The julian date (UTC) of milliseconds since the unix epoch
+///
+/// * Timestamps before 1970 are negative.
+///
+/// > $$JD = 2440587.5 + \frac{ms}{86400000}$$
+pub fn jd_from_unix_millis(millis: f64) -> f64 {
+    UNIX_EPOCH_JD + millis / MS_PER_DAY
+}
+
+/// The seconds since the unix epoch of a julian date (UTC)
+///
+/// * Timestamps before 1970 are negative.
+///
+/// > $$s = (JD - 2440587.5) \times 86400$$
+pub fn unix_secs_from_jd(jd: f64) -> f64 {
+    (jd - UNIX_EPOCH_JD) * EARTH_ROTATIONAL_PERIOD
+}
+
+/// The milliseconds since the unix epoch of a julian date (UTC)
+///
+/// > $$ms = (JD - 2440587.5) \times 86400000$$
+pub fn unix_millis_from_jd(jd: f64) -> f64 {
+    (jd - UNIX_EPOCH_JD) * MS_PER_DAY
+}
+
+/// The julian date (UTC) right now, according to the system clock
+///
+/// * On `wasm32` with the `wasm` feature the clock of the browser is read instead
+///
+#[cfg(feature = "std")]
+pub fn jd_now() -> f64 {
+    #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+    let millis = js_sys::Date::now();
+
+    #[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+    let millis = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("Unix Epoch to function")
+        .as_millis() as f64;
+
+    jd_from_unix_millis(millis)
+}
+
+/// This is a reference epoch, where the time of an element or a clock is zero
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum Epoch {
+    /// 2000-01-01 12:00:00 TT, the standard epoch of orbital elements
+    #[default]
+    J2000,
+    /// The beginning of the besselian year 1950
+    B1950,
+    /// 1970-01-01 00:00:00 UTC, see [`UNIX_EPOCH_JD`]
+    Unix,
+    /// Any other julian date
+    Custom(f64),
+}
+
+impl Epoch {
+    /// This method returns the julian date of the epoch
+    pub const fn jd(&self) -> f64 {
+        match self {
+            Self::J2000 => 2_451_545.0,
+            Self::B1950 => 2_433_282.423_5,
+            Self::Unix => UNIX_EPOCH_JD,
+            Self::Custom(jd) => *jd,
+        }
+    }
+
+    /// This method returns the days since the epoch of a julian date
+    pub fn days_since(&self, julian_date: f64) -> f64 {
+        julian_date - self.jd()
+    }
+}
+
+/// The first Julian Date epoch, see [`Epoch::J2000`]
+pub const JD2NOON: f64 = Epoch::J2000.jd();
+
+/// J2000 = JD - [`Epoch::J2000`]
 pub fn days_since_j2000(year: i32, month: i32, day: i32, offset: f64) -> f64 {
-    let j2 = get_jd(year, month, day, offset) - JD2NOON;
+    Epoch::J2000.days_since(get_jd(year, month, day, offset))
+}
+
+/// This is a date and time (UTC) of a calendar, usually the gregorian calendar
+///
+/// * Years are astronomical, 0 is 1 BC and -1 is 2 BC.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GregorianDate {
+    /// This is the year
+    pub year: i32,
+    /// This is the month, from 1 to 12
+    pub month: u8,
+    /// This is the day of the month, from 1
+    pub day: u8,
+    /// This is the hour, from 0 to 23
+    pub hour: u8,
+    /// This is the minute, from 0 to 59
+    pub minute: u8,
+    /// This is the second with its milliseconds, from 0 to less than 60
+    pub second: f64,
+}
+
+impl fmt::Display for GregorianDate {
+    /// 2000-01-01 12:00:00
+    ///
+    /// * With a precision, the fraction of the second is shown as well (ex; `{:.3}` is 2000-01-01 12:00:00.250)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:",
+            self.year, self.month, self.day, self.hour, self.minute
+        )?;
 
-    println!("{:?} Days since j2000", j2);
+        match f.precision() {
+            Some(precision) if precision > 0 => {
+                write!(f, "{:0width$.precision$}", self.second, width = precision + 3)
+            }
+            _ => write!(f, "{:02}", self.second.floor()),
+        }
+    }
+}
 
-    j2
+/// The julian date (UTC) of 1582-10-15 00:00:00, the first day of the gregorian calendar
+pub const GREGORIAN_REFORM_JD: f64 = 2_299_160.5;
+
+/// This is the calendar of a date, see [`jd2greg`] and [`greg2jd`]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CalendarSystem {
+    /// The proleptic gregorian calendar, also before the 1582 reform
+    #[default]
+    Gregorian,
+    /// The proleptic julian calendar, also after the 1582 reform
+    Julian,
+    /// The julian calendar until 1582-10-04 and the gregorian calendar from 1582-10-15 (see [`GREGORIAN_REFORM_JD`])
+    ///
+    /// * 1582-10-05 to 1582-10-14 were skipped by the reform, so they don't exist.
+    Auto,
 }
 
-/// converts julian date to gregorian date
-pub fn jd2greg(jd: f64) {
-    if let Ok(date_time) = julian_day_converter::julian_day_to_datetime(jd) {
-        println!("The date time is {}", date_time.format("%Y-%m-%d %H:%M:%S"));
+impl CalendarSystem {
+    /// This method returns the calendar of a julian day number, resolving [`CalendarSystem::Auto`]
+    fn at_day(self, jdn: i64) -> Self {
+        match self {
+            Self::Auto if (jdn as f64) < GREGORIAN_REFORM_JD => Self::Julian,
+            Self::Auto => Self::Gregorian,
+            calendar => calendar,
+        }
+    }
+
+    /// This method returns the calendar of a date, resolving [`CalendarSystem::Auto`]
+    fn at_date(self, year: i32, month: u32, day: u32) -> Result<Self> {
+        match self {
+            Self::Auto if (year, month, day) < (1582, 10, 5) => Ok(Self::Julian),
+            Self::Auto if (year, month, day) < (1582, 10, 15) => Err(Error::InvalidDate(
+                "the date was skipped by the gregorian reform",
+            )),
+            Self::Auto => Ok(Self::Gregorian),
+            calendar => Ok(calendar),
+        }
+    }
+
+    /// Checks if a year of the calendar has a leap day
+    fn is_leap_year(self, year: i32) -> bool {
+        match self {
+            Self::Julian => year.rem_euclid(4) == 0,
+            _ => (year % 4 == 0 && year % 100 != 0) || year % 400 == 0,
+        }
     }
+
+    /// The amount of days in a month of the calendar
+    fn days_in_month(self, year: i32, month: u32) -> u32 {
+        match month {
+            2 if self.is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+}
+
+/// converts julian date to a calendar date (UTC)
+///
+/// * The time is rounded to the nearest millisecond, so 23:59:59.9999 carries into the next day.
+/// * The calendar is proleptic unless it's [`CalendarSystem::Auto`], see [`greg2jd`]
+///
+/// ```rust
+/// use rust_solar::julian::{jd2greg, CalendarSystem};
+///
+/// assert_eq!(jd2greg(2451545.0, CalendarSystem::Gregorian).to_string(), "2000-01-01 12:00:00");
+/// assert_eq!(jd2greg(2451545.0, CalendarSystem::Julian).to_string(), "1999-12-19 12:00:00");
+/// ```
+pub fn jd2greg(jd: f64, calendar: CalendarSystem) -> GregorianDate {
+    let mut jdn = (jd + 0.5).floor() as i64;
+    let mut millis = ((jd + 0.5 - jdn as f64) * EARTH_ROTATIONAL_PERIOD * 1000.0).round() as i64;
+
+    if millis >= 86_400_000 {
+        millis -= 86_400_000;
+        jdn += 1;
+    }
+
+    // the centuries only matter to the gregorian calendar
+    let (b, c) = match calendar.at_day(jdn) {
+        CalendarSystem::Julian => (0, jdn + 32082),
+        _ => {
+            let a = jdn + 32044;
+            let b = (4 * a + 3).div_euclid(146_097);
+
+            (b, a - (146_097 * b).div_euclid(4))
+        }
+    };
+    let d = (4 * c + 3).div_euclid(1461);
+    let e = c - (1461 * d).div_euclid(4);
+    let m = (5 * e + 2).div_euclid(153);
+
+    GregorianDate {
+        year: (100 * b + d - 4800 + m / 10) as i32,
+        month: (m + 3 - 12 * (m / 10)) as u8,
+        day: (e - (153 * m + 2).div_euclid(5) + 1) as u8,
+        hour: (millis / 3_600_000) as u8,
+        minute: (millis % 3_600_000 / 60_000) as u8,
+        second: (millis % 60_000) as f64 / 1000.0,
+    }
+}
+
+/// converts a calendar date and time (UTC) to a julian date
+///
+/// * [`CalendarSystem::Gregorian`] is proleptic, so dates before the 1582 reform are gregorian too (ex; 1582-10-10 exists).
+/// * [`CalendarSystem::Julian`] is proleptic, so every fourth year is a leap year (ex; 1900-02-29 exists).
+/// * Years are astronomical, 0 is 1 BC and -1 is 2 BC.
+///
+/// > $$JDN_{gregorian} = d + \lfloor\frac{153m + 2}{5}\rfloor + 365y + \lfloor\frac{y}{4}\rfloor - \lfloor\frac{y}{100}\rfloor + \lfloor\frac{y}{400}\rfloor - 32045$$
+/// > $$JDN_{julian} = d + \lfloor\frac{153m + 2}{5}\rfloor + 365y + \lfloor\frac{y}{4}\rfloor - 32083$$
+///
+/// ```rust
+/// use rust_solar::julian::{greg2jd, CalendarSystem};
+///
+/// assert_eq!(greg2jd(2000, 1, 1, 12, 0, 0.0, CalendarSystem::Gregorian), Ok(2451545.0));
+/// assert_eq!(greg2jd(-4712, 1, 1, 12, 0, 0.0, CalendarSystem::Julian), Ok(0.0));
+/// ```
+pub fn greg2jd(
+    year: i32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: f64,
+    calendar: CalendarSystem,
+) -> Result<f64> {
+    if !(1..=12).contains(&month) {
+        return Err(Error::InvalidDate("the month is outside of 1 to 12"));
+    }
+
+    let calendar = calendar.at_date(year, month, day)?;
+
+    if day < 1 || day > calendar.days_in_month(year, month) {
+        return Err(Error::InvalidDate("the day is outside of the month"));
+    }
+
+    if !second.is_finite() {
+        return Err(Error::NonFinite);
+    }
+
+    if hour >= 24 || minute >= 60 || !(0.0..60.0).contains(&second) {
+        return Err(Error::InvalidTime("the time is outside of the day"));
+    }
+
+    // march is the first month, so the leap day is at the end of the year
+    let a = i64::from(month <= 2);
+    let y = i64::from(year) + 4800 - a;
+    let m = i64::from(month) + 12 * a - 3;
+    let days = i64::from(day) + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4);
+    let jdn = match calendar {
+        CalendarSystem::Julian => days - 32083,
+        _ => days - y.div_euclid(100) + y.div_euclid(400) - 32045,
+    };
+    let fraction = (f64::from(hour) * 3600.0 + f64::from(minute) * 60.0 + second)
+        / EARTH_ROTATIONAL_PERIOD;
+
+    Ok(jdn as f64 - 0.5 + fraction)
 }
 
 ///  your offset is decimal hours in military time: ex; 20.5 is 20:05pm is 8:05pm
 pub fn get_jd(year: i32, month: i32, day: i32, offset: f64) -> f64 {
-    let jd = (367 as f64 * year as f64
+    let jd = 367.0 * year as f64
         - (7 * (year + (month + 9) / 12) / 4) as f64
         - (((3 * (year + (month - 9) / 7) / 100) + 1) / 4) as f64
         + (275 * month / 9) as f64
         + day as f64
-        + 1721028.5 as f64
-        + offset as f64 / 24.0) as f64;
+        + 1721028.5
+        + offset / 24.0;
 
-    println!("Julian date: {:?}", jd);
+    #[cfg(feature = "log")]
+    log::trace!("Julian date: {jd}");
 
     jd
 }
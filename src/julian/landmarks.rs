@@ -0,0 +1,16 @@
+pub use super::{GREGORIAN_REFORM_JD as GREGORIAN_REFORM, UNIX_EPOCH_JD};
+
+/// 2000-01-01 12:00:00 TT, see [`super::Epoch::J2000`]
+pub const J2000: f64 = super::Epoch::J2000.jd();
+
+/// 1955-04-11 00:00:00 UTC, the start of Mars Year 1 in the Clancy numbering (Ls 0)
+pub const MARS_YEAR_1_START: f64 = 2_435_208.5;
+
+/// 1976-07-20 11:53:06 UTC, Viking 1 lands on Chryse Planitia (spacecraft event time)
+pub const VIKING_1_LANDING: f64 = 2_442_979.995_208_333;
+
+/// 2012-08-06 05:17:57 UTC, Curiosity lands in Gale crater (spacecraft event time)
+pub const CURIOSITY_LANDING: f64 = 2_456_145.720_798_611;
+
+/// 2021-02-18 20:55:00 UTC, Perseverance lands in Jezero crater (received on earth)
+pub const PERSEVERANCE_LANDING: f64 = 2_459_264.371_527_778;
@@ -0,0 +1,114 @@
+use core::ops::Sub;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    error::{Error, Result},
+    planets::EARTH_ROTATIONAL_PERIOD,
+};
+
+use super::{time_scales::tt_minus_utc, UNIX_EPOCH_JD};
+
+/// The nanoseconds in an earth day
+const NANOS_PER_DAY: i128 = 86_400_000_000_000;
+
+/// This is a julian date split into a whole day and a fraction of the day
+///
+/// * A single `f64` julian date only resolves about 40µs in the current era, the fraction of a split julian date resolves picoseconds.
+/// * Differences between split julian dates subtract the days and the fractions separately, so small differences aren't lost.
+///
+/// > $$JD = day + frac, \quad 0 \le frac < 1$$
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SplitJd {
+    /// This is the whole day of the julian date
+    pub day: f64,
+    /// This is the fraction of the day, from 0 to less than 1
+    pub frac: f64,
+}
+
+impl SplitJd {
+    /// This method creates a split julian date, moving any whole days of the fraction into the day
+    pub fn new(day: f64, frac: f64) -> Self {
+        let whole = day.floor();
+        let frac = (day - whole) + frac;
+        let carry = frac.floor();
+
+        Self {
+            day: whole + carry,
+            frac: frac - carry,
+        }
+    }
+
+    /// This method returns the julian date as a single `f64`, which loses the precision of the fraction
+    pub fn value(&self) -> f64 {
+        self.day + self.frac
+    }
+
+    /// This method adds days to the julian date, the whole days are added to the day exactly
+    pub fn add_days(&self, days: f64) -> Self {
+        let whole = days.trunc();
+
+        Self::new(self.day + whole, self.frac + (days - whole))
+    }
+
+    /// This method adds seconds (of 86,400 a day) to the julian date
+    pub fn add_seconds(&self, seconds: f64) -> Self {
+        self.add_days(seconds / EARTH_ROTATIONAL_PERIOD)
+    }
+
+    /// This method creates a split julian date (UTC) from seconds and nanoseconds since the unix epoch
+    ///
+    /// * Timestamps before 1970 have negative seconds, the nanoseconds always count forward.
+    pub fn from_unix_timestamp(secs: i64, nanos: u32) -> Self {
+        let days = secs.div_euclid(86_400);
+        let secs = secs.rem_euclid(86_400) as f64 + f64::from(nanos) / 1e9;
+
+        Self::new(UNIX_EPOCH_JD + days as f64, secs / EARTH_ROTATIONAL_PERIOD)
+    }
+
+    /// This method returns the seconds and nanoseconds since the unix epoch, rounded to the nanosecond
+    pub fn to_unix_timestamp(&self) -> Result<(i64, u32)> {
+        if !self.day.is_finite() || !self.frac.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        // the day of a julian date starts at noon, the unix epoch at midnight
+        let days = (self.day - (UNIX_EPOCH_JD - 0.5)) as i128;
+        let nanos = days * NANOS_PER_DAY + (self.frac * NANOS_PER_DAY as f64).round() as i128
+            - NANOS_PER_DAY / 2;
+        let secs = i64::try_from(nanos.div_euclid(1_000_000_000))
+            .map_err(|_| Error::InvalidDate("the date is outside of the timestamp range"))?;
+
+        Ok((secs, nanos.rem_euclid(1_000_000_000) as u32))
+    }
+
+    /// This method converts a julian date (UTC) into terrestrial time (TT), see [`super::utc_to_tt`]
+    ///
+    /// * The offset is added to the fraction, so it doesn't lose the precision of the fraction.
+    pub fn utc_to_tt(&self) -> Self {
+        self.add_seconds(tt_minus_utc(self.value()))
+    }
+}
+
+impl From<f64> for SplitJd {
+    fn from(julian_date: f64) -> Self {
+        Self::new(julian_date, 0.0)
+    }
+}
+
+impl From<SplitJd> for f64 {
+    fn from(julian_date: SplitJd) -> Self {
+        julian_date.value()
+    }
+}
+
+impl Sub for SplitJd {
+    type Output = f64;
+
+    /// The days between two julian dates
+    fn sub(self, rhs: Self) -> f64 {
+        (self.day - rhs.day) + (self.frac - rhs.frac)
+    }
+}
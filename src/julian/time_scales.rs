@@ -0,0 +1,281 @@
+#[cfg(feature = "std")]
+use std::sync::RwLock;
+
+#[cfg(feature = "std")]
+use crate::error::{Error, Result};
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    conversions::{deg_to_rad, normalize_degrees},
+    julian::Epoch,
+};
+
+/// The constant difference between Terrestrial Time and International Atomic Time in seconds
+pub const TT_MINUS_TAI: f64 = 32.184;
+
+/// The difference between International Atomic Time and UTC in seconds before leap seconds existed.
+///
+/// UTC used "rubber seconds" before 1972, so this library approximates that period
+/// with the offset UTC started with on 1972 January 1.
+pub const PRE_1972_TAI_MINUS_UTC: f64 = 10.0;
+
+/// The leap second table as (julian date the offset starts, TAI - UTC in seconds)
+///
+/// Numbers are according to https://hpiers.obspm.fr/iers/bul/bulc/Leap_Second.dat
+pub const LEAP_SECONDS: [(f64, f64); 28] = [
+    (2_441_317.5, 10.0), // 1972 Jan 1
+    (2_441_499.5, 11.0), // 1972 Jul 1
+    (2_441_683.5, 12.0), // 1973 Jan 1
+    (2_442_048.5, 13.0), // 1974 Jan 1
+    (2_442_413.5, 14.0), // 1975 Jan 1
+    (2_442_778.5, 15.0), // 1976 Jan 1
+    (2_443_144.5, 16.0), // 1977 Jan 1
+    (2_443_509.5, 17.0), // 1978 Jan 1
+    (2_443_874.5, 18.0), // 1979 Jan 1
+    (2_444_239.5, 19.0), // 1980 Jan 1
+    (2_444_786.5, 20.0), // 1981 Jul 1
+    (2_445_151.5, 21.0), // 1982 Jul 1
+    (2_445_516.5, 22.0), // 1983 Jul 1
+    (2_446_247.5, 23.0), // 1985 Jul 1
+    (2_447_161.5, 24.0), // 1988 Jan 1
+    (2_447_892.5, 25.0), // 1990 Jan 1
+    (2_448_257.5, 26.0), // 1991 Jan 1
+    (2_448_804.5, 27.0), // 1992 Jul 1
+    (2_449_169.5, 28.0), // 1993 Jul 1
+    (2_449_534.5, 29.0), // 1994 Jul 1
+    (2_450_083.5, 30.0), // 1996 Jan 1
+    (2_450_630.5, 31.0), // 1997 Jul 1
+    (2_451_179.5, 32.0), // 1999 Jan 1
+    (2_453_736.5, 33.0), // 2006 Jan 1
+    (2_454_832.5, 34.0), // 2009 Jan 1
+    (2_456_109.5, 35.0), // 2012 Jul 1
+    (2_457_204.5, 36.0), // 2015 Jul 1
+    (2_457_754.5, 37.0), // 2017 Jan 1
+];
+
+/// Leap seconds announced after this version of the library was released
+#[cfg(feature = "std")]
+static ADDED_LEAP_SECONDS: RwLock<Vec<(f64, f64)>> = RwLock::new(Vec::new());
+
+/// This function appends a leap second to the table at runtime
+///
+/// * `julian_date` is when the new offset starts (UTC)
+/// * `tai_minus_utc` is the total offset in seconds from then on, not the increment
+/// * This needs the `std` feature, without it the table is fixed
+///
+#[cfg(feature = "std")]
+pub fn add_leap_second(julian_date: f64, tai_minus_utc: f64) -> Result<()> {
+    if !julian_date.is_finite() || !tai_minus_utc.is_finite() {
+        return Err(Error::NonFinite);
+    }
+
+    ADDED_LEAP_SECONDS
+        .write()
+        .expect("Leap second table to be writable")
+        .push((julian_date, tai_minus_utc));
+
+    Ok(())
+}
+
+/// The difference between International Atomic Time and UTC in seconds at a julian date (UTC)
+///
+/// Dates before 1972 fall back to [`PRE_1972_TAI_MINUS_UTC`]
+pub fn tai_minus_utc(julian_date: f64) -> f64 {
+    #[cfg(feature = "std")]
+    let added = ADDED_LEAP_SECONDS
+        .read()
+        .expect("Leap second table to be readable");
+    #[cfg(not(feature = "std"))]
+    let added: [(f64, f64); 0] = [];
+
+    LEAP_SECONDS
+        .iter()
+        .chain(added.iter())
+        .filter(|(start, _)| *start <= julian_date)
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .map_or(PRE_1972_TAI_MINUS_UTC, |(_, offset)| *offset)
+}
+
+/// The difference between Terrestrial Time and UTC in seconds at a julian date (UTC)
+///
+/// > $$TT - UTC = (TAI - UTC) + 32.184$$
+pub fn tt_minus_utc(julian_date: f64) -> f64 {
+    tai_minus_utc(julian_date) + TT_MINUS_TAI
+}
+
+/// This function converts a julian date in UTC to TAI
+///
+/// ```rust
+/// use rust_solar::julian::utc_to_tai;
+///
+/// // 37 leap seconds since 2017
+/// assert!((utc_to_tai(2458849.5) - 2458849.5 - 37.0 / 86400.0).abs() < 1e-9);
+/// ```
+pub fn utc_to_tai(julian_date: f64) -> f64 {
+    julian_date + tai_minus_utc(julian_date) / SECONDS_PER_DAY
+}
+
+/// This function converts a julian date in TAI to UTC
+///
+/// * The offset is looked up twice so dates next to a leap second land on the right side
+///
+/// ```rust
+/// use rust_solar::julian::{tai_to_utc, utc_to_tai};
+///
+/// assert!((tai_to_utc(utc_to_tai(2458849.5)) - 2458849.5).abs() < 1e-9);
+/// ```
+pub fn tai_to_utc(julian_date: f64) -> f64 {
+    let guess = julian_date - tai_minus_utc(julian_date) / SECONDS_PER_DAY;
+
+    julian_date - tai_minus_utc(guess) / SECONDS_PER_DAY
+}
+
+/// This function converts a julian date in TAI to TT, see [`TT_MINUS_TAI`]
+///
+/// ```rust
+/// use rust_solar::julian::tai_to_tt;
+///
+/// assert!(((tai_to_tt(2451545.0) - 2451545.0) * 86400.0 - 32.184).abs() < 1e-4);
+/// ```
+pub fn tai_to_tt(julian_date: f64) -> f64 {
+    julian_date + TT_MINUS_TAI / SECONDS_PER_DAY
+}
+
+/// This function converts a julian date in TT to TAI, see [`TT_MINUS_TAI`]
+///
+/// ```rust
+/// use rust_solar::julian::{tai_to_tt, tt_to_tai};
+///
+/// assert!((tt_to_tai(tai_to_tt(2451545.0)) - 2451545.0).abs() < 1e-9);
+/// ```
+pub fn tt_to_tai(julian_date: f64) -> f64 {
+    julian_date - TT_MINUS_TAI / SECONDS_PER_DAY
+}
+
+/// This function converts a julian date in TT to TDB
+///
+/// > $$TDB - TT = 0.001657 \sin(g) + 0.000014 \sin(2g)$$
+///
+/// ```rust
+/// use rust_solar::julian::tt_to_tdb;
+///
+/// // the difference is never more than 1.7ms
+/// assert!((tt_to_tdb(2451545.0) - 2451545.0).abs() * 86400.0 <= 0.0017);
+/// ```
+pub fn tt_to_tdb(julian_date: f64) -> f64 {
+    julian_date + tdb_minus_tt(julian_date) / SECONDS_PER_DAY
+}
+
+/// This function converts a julian date in TDB to TT
+///
+/// ```rust
+/// use rust_solar::julian::{tdb_to_tt, tt_to_tdb};
+///
+/// assert!((tdb_to_tt(tt_to_tdb(2451545.0)) - 2451545.0).abs() < 1e-9);
+/// ```
+pub fn tdb_to_tt(julian_date: f64) -> f64 {
+    julian_date - tdb_minus_tt(julian_date) / SECONDS_PER_DAY
+}
+
+/// This function converts a julian date in UTC to TT, which is what the body clocks count in
+///
+/// > $$TT = UTC + (TAI - UTC) + 32.184$$
+///
+/// ```rust
+/// use rust_solar::julian::utc_to_tt;
+///
+/// // 2020-01-01 is 69.184 seconds ahead in TT
+/// assert!(((utc_to_tt(2458849.5) - 2458849.5) * 86400.0 - 69.184).abs() < 1e-4);
+/// ```
+pub fn utc_to_tt(julian_date: f64) -> f64 {
+    tai_to_tt(utc_to_tai(julian_date))
+}
+
+/// This function converts a julian date in TT to UTC
+///
+/// ```rust
+/// use rust_solar::julian::{tt_to_utc, utc_to_tt};
+///
+/// assert!((tt_to_utc(utc_to_tt(2458849.5)) - 2458849.5).abs() < 1e-9);
+/// ```
+pub fn tt_to_utc(julian_date: f64) -> f64 {
+    tai_to_utc(tt_to_tai(julian_date))
+}
+
+/// This is the collection of time scales a julian date can be expressed in
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeScale {
+    /// Coordinated Universal Time, the civil time scale with leap seconds.
+    #[default]
+    UTC,
+    /// International Atomic Time, UTC without the leap seconds.
+    TAI,
+    /// Terrestrial Time, TAI + 32.184 seconds.
+    TT,
+    /// Barycentric Dynamical Time, TT with a small periodic term (≤ 1.7ms).
+    TDB,
+}
+
+/// This is a julian date that remembers which time scale it's in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JulianDate {
+    /// The julian date in days
+    pub value: f64,
+    /// The time scale of the julian date
+    pub scale: TimeScale,
+}
+
+impl JulianDate {
+    /// This method creates a julian date in a time scale
+    pub fn new(value: f64, scale: TimeScale) -> Self {
+        Self { value, scale }
+    }
+
+    /// This method creates a julian date in UTC, which is what every `f64` julian date assumes
+    pub fn utc(value: f64) -> Self {
+        Self::new(value, TimeScale::UTC)
+    }
+
+    /// This method converts the julian date into another time scale
+    ///
+    /// * Every conversion goes through TAI
+    /// * A julian date that's already in the time scale is returned as it is, to the bit
+    ///
+    pub fn to_scale(self, scale: TimeScale) -> Self {
+        if self.scale == scale {
+            return self;
+        }
+
+        let tai = match self.scale {
+            TimeScale::UTC => utc_to_tai(self.value),
+            TimeScale::TAI => self.value,
+            TimeScale::TT => tt_to_tai(self.value),
+            TimeScale::TDB => tt_to_tai(tdb_to_tt(self.value)),
+        };
+
+        let value = match scale {
+            TimeScale::UTC => tai_to_utc(tai),
+            TimeScale::TAI => tai,
+            TimeScale::TT => tai_to_tt(tai),
+            TimeScale::TDB => tt_to_tdb(tai_to_tt(tai)),
+        };
+
+        Self { value, scale }
+    }
+}
+
+/// The seconds in a day of 86,400 SI seconds
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// The periodic difference between TDB and TT in seconds
+///
+/// > $$TDB - TT = 0.001657 \sin(g) + 0.000014 \sin(2g)$$
+/// > $$g = 357.53 + 0.98560028 (JD - 2451545.0)$$
+fn tdb_minus_tt(julian_date: f64) -> f64 {
+    let g = deg_to_rad(normalize_degrees(
+        357.53 + 0.985_600_28 * Epoch::J2000.days_since(julian_date),
+    ));
+
+    0.001_657 * g.sin() + 0.000_014 * (2.0 * g).sin()
+}
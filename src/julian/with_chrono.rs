@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+
+use crate::error::{Error, Result};
+
+use super::SplitJd;
+
+impl From<DateTime<Utc>> for SplitJd {
+    fn from(date_time: DateTime<Utc>) -> Self {
+        Self::from_unix_timestamp(date_time.timestamp(), date_time.timestamp_subsec_nanos())
+    }
+}
+
+/// The julian date (UTC) of a chrono date time
+///
+/// * Dates before 1970 work as well, and [`SplitJd::from`] keeps the nanoseconds.
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use rust_solar::julian::from_chrono;
+///
+/// assert_eq!(from_chrono(Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap()), 2451545.0);
+/// ```
+pub fn from_chrono(date_time: DateTime<Utc>) -> f64 {
+    SplitJd::from(date_time).value()
+}
+
+/// The chrono date time of a julian date (UTC), rounded to the nanosecond
+///
+/// * A `f64` julian date only resolves about 40µs in the current era, a [`SplitJd`] resolves nanoseconds.
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use rust_solar::julian::to_chrono;
+///
+/// assert_eq!(to_chrono(2451545.0), Ok(Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap()));
+/// ```
+pub fn to_chrono(julian_date: impl Into<SplitJd>) -> Result<DateTime<Utc>> {
+    let (secs, nanos) = julian_date.into().to_unix_timestamp()?;
+
+    DateTime::from_timestamp(secs, nanos)
+        .ok_or(Error::InvalidDate("the date is outside of the chrono range"))
+}
@@ -0,0 +1,43 @@
+use time::OffsetDateTime;
+
+use crate::error::{Error, Result};
+
+use super::SplitJd;
+
+impl From<OffsetDateTime> for SplitJd {
+    fn from(date_time: OffsetDateTime) -> Self {
+        Self::from_unix_timestamp(date_time.unix_timestamp(), date_time.nanosecond())
+    }
+}
+
+/// The julian date (UTC) of a `time` date time, in any offset
+///
+/// * Dates before 1970 work as well, and [`SplitJd::from`] keeps the nanoseconds.
+///
+/// ```rust
+/// use rust_solar::julian::from_odt;
+/// use time::macros::datetime;
+///
+/// assert_eq!(from_odt(datetime!(2000-01-01 12:00 UTC)), 2451545.0);
+/// assert_eq!(from_odt(datetime!(2000-01-01 14:00 +2)), 2451545.0);
+/// ```
+pub fn from_odt(date_time: OffsetDateTime) -> f64 {
+    SplitJd::from(date_time).value()
+}
+
+/// The `time` date time (UTC) of a julian date (UTC), rounded to the nanosecond
+///
+/// * A `f64` julian date only resolves about 40µs in the current era, a [`SplitJd`] resolves nanoseconds.
+///
+/// ```rust
+/// use rust_solar::julian::to_odt;
+/// use time::macros::datetime;
+///
+/// assert_eq!(to_odt(2451545.0), Ok(datetime!(2000-01-01 12:00 UTC)));
+/// ```
+pub fn to_odt(julian_date: impl Into<SplitJd>) -> Result<OffsetDateTime> {
+    let (secs, nanos) = julian_date.into().to_unix_timestamp()?;
+
+    OffsetDateTime::from_unix_timestamp_nanos(i128::from(secs) * 1_000_000_000 + i128::from(nanos))
+        .map_err(|_| Error::InvalidDate("the date is outside of the time range"))
+}
@@ -1,22 +1,244 @@
+/// This module contains what the dates of a body share
+mod cache;
+/// This module contains the checks of the constants of a body
+mod consistency;
+/// This module contains the bodies that are created at runtime
+mod custom;
+/// This module contains the bodies that orbit another body, like moons
+mod hosted;
+/// This module contains the canonical string format of date times
+mod canonical;
+/// This module contains the iterator over the dates between two julian dates
+mod range;
+/// This module contains the options of the formatting of dates
+mod format;
+/// This module contains the lengths of time in sols of a body
+mod duration;
+
+pub use cache::BodyCache;
+pub use consistency::{
+    validate, ConsistencyWarning, KEPLER_TOLERANCE, MAX_ROTATIONAL_PERIOD, MIN_ROTATIONAL_PERIOD,
+};
+pub use custom::{CustomBody, CustomBodyBuilder};
+pub use duration::SolDuration;
+pub use format::{EraPosition, FormatOptions, MONTH_NAMES};
+pub use hosted::HostedBody;
+pub use range::DateRange;
+
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{cmp::Ordering, fmt};
+
 use displaydoc::Display;
 use strum::AsRefStr;
 
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
 use crate::{
-    orbit::{MeanMotion, Perihelion, Season, SemiAxis, SolarLongitude, Type},
+    clock::{Offset, ZoneInfo},
+    conversions::{
+        deg_to_rad, normalize_degrees, rad_to_deg, Au, Days, Degrees, Seconds, JULIAN_YEAR_DAYS,
+        KM_PER_AU,
+    },
+    error::{Error, Result},
+    events,
+    julian::{jd2greg, CalendarSystem, Epoch, JulianDate, SplitJd, TimeScale},
+    orbit::{Hemisphere, MeanMotion, Orbit, Perihelion, Season, SeasonTable, SemiAxis},
     planets::EARTH_ROTATIONAL_PERIOD,
+    position::{self, Origin, Position},
 };
 
+/// The gravitational constant in km³/(kg·s²)
+pub const GRAVITATIONAL_CONSTANT: f64 = 6.674_30e-20;
+
+/// The standard gravitational parameter (GM) of the sun in km³/s²
+pub const SUN_GRAVITATIONAL_PARAMETER: f64 = 1.327_124_400_18e11;
+
+/// The nominal radius of the sun in kilometers (IAU 2015)
+pub const SUN_RADIUS_KM: f64 = 695_700.0;
+
+/// The nominal irradiance of the sun in W/m² at 1 astronomical unit (IAU 2015)
+pub const SOLAR_CONSTANT: f64 = 1_361.0;
+
+/// The Stefan–Boltzmann constant in W/(m²·K⁴)
+pub const STEFAN_BOLTZMANN: f64 = 5.670_374_419e-8;
+
+/// How far a year in sols may be from 1 and still be a single sol, where the sun stands still over the body
+///
+/// * See [`Date::compute`] and [`crate::clock::ClockCalibration::from_body`] for what happens then.
+pub const LOCKED_TOLERANCE: f64 = 1e-9;
+
+/// This function derives the sol of a body that turns once per orbit around its host, in the unit of the periods
+///
+/// * A moon locked to its planet still sees the sun go around, so its sol is the synodic period (ex; luna).
+/// * A planet locked to the sun (`host_orbit` is `None`) never sees the sun move, so its sol is infinite.
+///
+/// > $$\frac{1}{sol} = \frac{1}{orbit} - \frac{1}{host}$$
+///
+/// ```rust
+/// use rust_solar::kepler::locked_solar_day;
+///
+/// // luna goes around earth in 27.32 days, while earth goes around the sun
+/// assert!((locked_solar_day(27.3217, Some(365.256)) - 29.53).abs() < 0.01);
+/// assert_eq!(locked_solar_day(11.19, None), f64::INFINITY);
+/// ```
+pub fn locked_solar_day(orbit: f64, host_orbit: Option<f64>) -> f64 {
+    match host_orbit {
+        Some(host_orbit) => Rotation::Prograde.solar_day(orbit, host_orbit),
+        None => f64::INFINITY,
+    }
+}
+
+/// This function returns the apparent diameter in degrees of a sphere, from the distance of its center
+///
+/// * Both lengths are in the same unit, a distance within the radius is inside the sphere, so it fills the sky (180).
+///
+/// > $$\delta = 2\arcsin\frac{R}{d}$$
+///
+/// ```rust
+/// use rust_solar::kepler::{angular_diameter, SUN_RADIUS_KM};
+///
+/// // the sun is about half a degree wide from earth
+/// assert!((angular_diameter(SUN_RADIUS_KM, 149_597_870.7) - 0.533).abs() < 0.001);
+/// ```
+pub fn angular_diameter(radius: f64, distance: f64) -> f64 {
+    rad_to_deg(2.0 * (radius / distance).min(1.0).asin())
+}
+
+/// How close in sols the start of a month is found, see [`Date::start_of_month`]
+const MONTH_PRECISION: f64 = 1e-9;
+
+/// This function keeps a fraction of a whole below 1, a value that rounds up to 1 is the last one before it
+fn below_one(fraction: f64) -> f64 {
+    fraction.min(1.0 - f64::EPSILON / 2.0)
+}
+
+/// This function checks if a year of a body is a single sol, so the sun stands still over it, see [`LOCKED_TOLERANCE`]
+pub(crate) fn is_single_sol_year(orbital_period: f64) -> bool {
+    (orbital_period - 1.0).abs() <= LOCKED_TOLERANCE
+}
+
 /// This trait acts as a common field for all planets, asteroids, moons, exo-planets, and comets
 ///
+/// Bodies are read only, so they can be shared as `&dyn Body` between threads.
+///
 /// ## Limitations
 /// `Only Solar`: Sidereal days is not supported
 ///
-pub trait Body {
+pub trait Body: Send + Sync {
+    /// The name of the body, this is how the body is found in [`crate::bodies::find`]
+    fn name(&self) -> &str;
     /// Calculates the reference point which the body was discovered
+    ///
+    /// * The julian date is in the [`Body::epoch_scale`], see [`Body::epoch_jd`]
     fn epoch(&self) -> f64;
+    /// The time scale of the [`Body::epoch`], UTC by default
+    ///
+    /// * The epoch is converted to UTC before it's compared with a julian date, see [`Body::epoch_jd`]
+    fn epoch_scale(&self) -> TimeScale {
+        TimeScale::UTC
+    }
+    /// The [`Body::epoch`] with its time scale, so it can be converted to the scale of a julian date
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{julian::TimeScale, kepler::Body, planets::mars::Mars};
+    ///
+    /// let epoch = Mars.epoch_jd().to_scale(TimeScale::TT);
+    ///
+    /// // 46.184 seconds, the leap seconds of 1975 and the 32.184 seconds between TAI and TT
+    /// assert!(((epoch.value - Mars.epoch()) * 86400.0 - 46.184).abs() < 1e-3);
+    /// # }
+    /// ```
+    fn epoch_jd(&self) -> JulianDate {
+        JulianDate::new(self.epoch(), self.epoch_scale())
+    }
+    /// The year that begins at the [`Body::epoch`], so a body can follow a published year numbering
+    ///
+    /// * Years before the epoch year count down into the [`Eras::BD`] era.
+    fn epoch_year(&self) -> f64 {
+        1.0
+    }
+    /// The sol that starts the weeks, counted in sols of the calendar from the first sol of the [`Body::epoch_year`]
+    ///
+    /// * A body can override it to follow a published week (ex; `3.0` starts the weeks on the fourth sol).
+    /// * See [`Date::week_since_epoch`] for how the sols are counted.
+    fn week_anchor(&self) -> f64 {
+        0.0
+    }
+    /// When and by whom the body was discovered, if it wasn't known since antiquity
+    ///
+    /// * The eras count from the [`Body::epoch`], which may be a later scientific epoch than the discovery.
+    fn discovery(&self) -> Option<Discovery> {
+        None
+    }
+    /// Calculates the years of the body (with their fraction) since the [`Body::epoch`], negative before it
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::Body, planets::mars::Mars};
+    ///
+    /// assert_eq!(0.0, Mars.age_at(Mars.epoch()));
+    /// // the epoch is the start of Mars Year 12, so J2000 is in Mars Year 24
+    /// assert!((Mars.age_at(2451545.0) - 12.78).abs() < 0.01);
+    /// # }
+    /// ```
+    fn age_at(&self, julian_date: f64) -> f64 {
+        let epoch = self.epoch_jd().to_scale(TimeScale::UTC).value;
+
+        Days(julian_date - epoch).sols(self.rotational_period_seconds()) / self.orbital_period()
+    }
+    /// The reference epoch of the body's elements, where their time is zero
+    ///
+    /// * The [`Body::epoch`] is where the calendar starts, which is usually not the reference epoch.
+    fn reference_epoch(&self) -> Epoch {
+        Epoch::J2000
+    }
     /// Calculates the deviation of an orbit's path from a perfect circle.
     fn orbital_eccentricity(&self) -> f64;
+    /// The [`Body::orbital_period`] in earth days, since it's counted in sols of the body
+    fn orbital_period_days(&self) -> Days {
+        (self.rotational_period_seconds() * self.orbital_period()).to_days()
+    }
+    /// The [`Body::orbital_period`] in sols of the body (ex; 668.6 for mars)
+    ///
+    /// * Prefer this (or the earth days and years below) in new code, so the unit is in the name.
+    fn orbital_period_sols(&self) -> f64 {
+        self.orbital_period()
+    }
+    /// The [`Body::orbital_period`] in earth days of [`EARTH_ROTATIONAL_PERIOD`] seconds (ex; 686.98 for mars)
+    ///
+    /// * This is [`Body::orbital_period_days`] as a plain number.
+    fn orbital_period_earth_days(&self) -> f64 {
+        self.orbital_period_sols() * self.rotational_period() / EARTH_ROTATIONAL_PERIOD
+    }
+    /// The [`Body::orbital_period`] in julian years of [`JULIAN_YEAR_DAYS`] (ex; 1.88 for mars)
+    fn orbital_period_years(&self) -> f64 {
+        self.orbital_period_earth_days() / JULIAN_YEAR_DAYS
+    }
+    /// The [`Body::rotational_period`] in seconds, which is the length of a sol
+    fn rotational_period_seconds(&self) -> Seconds {
+        Seconds(self.rotational_period())
+    }
+    /// The [`Body::semimajor`] axis in astronomical units
+    fn semimajor_au(&self) -> Au {
+        Au(self.semimajor())
+    }
+    /// The [`Body::axial_tilt`] in degrees
+    fn axial_tilt_degrees(&self) -> Degrees {
+        Degrees(self.axial_tilt())
+    }
     /// Calculates the days in time it takes a body to orbit a host body that's the sun or a planet.
+    ///
+    /// * The days are sols of the body, not earth days, so new code should call [`Body::orbital_period_sols`],
+    ///   [`Body::orbital_period_earth_days`] or [`Body::orbital_period_years`] instead.
     fn orbital_period(&self) -> f64;
     /// Calculates the seconds in time it takes a body to rotate on its' axis.
     fn rotational_period(&self) -> f64;
@@ -24,37 +246,684 @@ pub trait Body {
     fn perihelion(&self) -> Perihelion;
     /// Calculates the average distance of this body from the sun.
     fn semimajor(&self) -> f64;
+    /// Calculates the mass of the body in kilograms, if it's known.
+    fn mass_kg(&self) -> Option<f64> {
+        None
+    }
+    /// Calculates the mean radius of the body in kilometers, if it's known.
+    fn mean_radius_km(&self) -> Option<f64> {
+        None
+    }
+    /// Calculates the gravitational parameter (GM) in km³/s² of what the body orbits.
+    ///
+    /// * This is the sun for planets, asteroids, and comets, moons should return their host planet's.
+    /// * A [`HostedBody`] returns its host planet's through [`HostedBody::host_gravitational_parameter`],
+    ///   since its [`Body`] orbit is the orbit of its host around the sun.
+    fn gravitational_parameter(&self) -> f64 {
+        SUN_GRAVITATIONAL_PARAMETER
+    }
+    /// Calculates the speed in km/s needed to escape the body from a distance of its center, if its mass is known.
+    ///
+    /// > $$v_e = \sqrt{\frac{2GM}{r}}$$
+    fn escape_velocity(&self, radius_km: f64) -> Option<f64> {
+        self.mass_kg()
+            .map(|mass| (2.0 * GRAVITATIONAL_CONSTANT * mass / radius_km).sqrt())
+    }
+    /// Calculates the tilt of the body's rotational axis against its orbital plane in degrees.
+    fn axial_tilt(&self) -> f64 {
+        0.0
+    }
+    /// Calculates the tilt of the orbit against the ecliptic of J2000 in degrees, 0 by default
+    fn inclination(&self) -> f64 {
+        0.0
+    }
+    /// Calculates the ecliptic longitude (J2000) in degrees where the orbit rises above the ecliptic, 0 by default
+    fn ascending_node(&self) -> f64 {
+        0.0
+    }
+    /// Calculates the ecliptic longitude (J2000) of the perihelion in degrees, seen from the sun
+    ///
+    /// * By default the vernal equinox of the body is the one of earth,
+    ///   so the perihelion is opposite of the sun at the solar longitude of the perihelion.
+    /// * It turns the orbit into the ecliptic, see [`Body::heliocentric_position`]
+    fn longitude_of_perihelion(&self) -> f64 {
+        normalize_degrees(self.perihelion().perihelion + 180.0)
+    }
+    /// Checks if the body turns once per orbit around what it's locked to, false by default
+    ///
+    /// * A moon locked to its planet has a sol of [`locked_solar_day`], like luna.
+    /// * A planet locked to the sun has no sol, so its year in sols is 1 and it has no dates, see [`Date::compute`]
+    fn is_tidally_locked(&self) -> bool {
+        false
+    }
+    /// The direction the body spins, prograde by default
+    ///
+    /// * The [`Body::rotational_period`] is still the solar day, see [`Rotation::solar_day`] to derive it.
+    /// * The sun of a retrograde body moves east, so the local times go backwards to the east.
+    fn rotation_direction(&self) -> Rotation {
+        Rotation::Prograde
+    }
+    /// Calculates the latitude of the sun over the body in degrees given a solar longitude
+    ///
+    /// > $$\delta = \arcsin(\sin\varepsilon \sin L_s)$$
+    fn solar_declination(&self, ls: f64) -> f64 {
+        rad_to_deg((deg_to_rad(self.axial_tilt()).sin() * deg_to_rad(ls).sin()).asin())
+    }
     /// Calculates the shortest distance between the center of the body to the edge of the body.
     fn semiminor(&self) -> f64 {
         SemiAxis(self.semimajor()).minor(self.orbital_eccentricity())
     }
     /// Calculates the mean motion which is the perihelian elapse.
-    fn mean_motion(&mut self, day: f64) -> f64 {
+    fn mean_motion(&self, day: f64) -> f64 {
         MeanMotion::by(
-            &mut MeanMotion,
+            &MeanMotion,
             day,
             self.perihelion(),
             self.orbital_period(),
         )
     }
+    /// Calculates how far the body is through its orbit at a julian date (UTC), from 0 at the perihelion up to 1
+    ///
+    /// * This is the mean anomaly over a whole turn, so it grows at the same pace all along the orbit.
+    /// * It's not the [`Date::year_progress`], the year starts at the solar longitude of 0 instead of the perihelion,
+    ///   and the solar longitude runs faster than the mean anomaly near the perihelion on an eccentric orbit
+    ///   (ex; mars is 0 through its orbit at its perihelion, and about 0.72 through its year).
+    ///
+    /// > $$\frac{M}{2\pi}$$
+    ///
+    /// # Panics
+    ///
+    /// When the julian date or the body can't be computed, see [`Body::try_to_date`]
+    fn orbit_progress(&self, julian_date: f64) -> f64 {
+        let mean_anomaly = self.mean_motion(self.to_date(julian_date).sol);
+
+        below_one((mean_anomaly / core::f64::consts::TAU).rem_euclid(1.0))
+    }
     /// Final Calculation into date
-    fn to_date(&mut self, julian_date: f64) -> Date {
-        Date::default().compute(
+    ///
+    /// * The julian date is assumed to be in UTC, see [`Body::to_date_jd`] for other time scales
+    /// * A julian date in TT passed as UTC is about 69 seconds late, which shows in the time of day
+    ///
+    /// # Panics
+    ///
+    /// When the julian date or the body can't be computed, see [`Body::try_to_date`]
+    fn to_date(&self, julian_date: f64) -> Date {
+        self.to_date_in(julian_date, Hemisphere::North)
+    }
+    /// Final Calculation into date, with the season of a hemisphere
+    ///
+    /// * A [`Body::season_table`] replaces the season of either hemisphere
+    ///
+    /// # Panics
+    ///
+    /// When the julian date or the body can't be computed, see [`Body::try_to_date_in`]
+    fn to_date_in(&self, julian_date: f64, hemisphere: Hemisphere) -> Date {
+        self.try_to_date_in(julian_date, hemisphere)
+            .expect("The julian date and the body to be valid")
+    }
+    /// Final Calculation into date from a chrono date time (UTC), see [`crate::julian::from_chrono`]
+    ///
+    /// # Panics
+    ///
+    /// When the body can't be computed, see [`Body::try_to_date`]
+    #[cfg(feature = "chrono")]
+    fn date_at(&self, date_time: chrono::DateTime<chrono::Utc>) -> Date {
+        self.to_date(crate::julian::from_chrono(date_time))
+    }
+    /// Final Calculation into date from a `time` date time, see [`crate::julian::from_odt`]
+    ///
+    /// # Panics
+    ///
+    /// When the body can't be computed, see [`Body::try_to_date`]
+    #[cfg(feature = "time")]
+    fn date_at_odt(&self, date_time: time::OffsetDateTime) -> Date {
+        self.to_date(crate::julian::from_odt(date_time))
+    }
+    /// Final Calculation into date, or an error when the julian date or the body can't be computed
+    fn try_to_date(&self, julian_date: f64) -> Result<Date> {
+        self.try_to_date_in(julian_date, Hemisphere::North)
+    }
+    /// Final Calculation into date with the season of a hemisphere, or an error when the julian date or the body can't be computed
+    fn try_to_date_in(&self, julian_date: f64, hemisphere: Hemisphere) -> Result<Date> {
+        let date = Date::default().compute(
             julian_date,
-            self.epoch(),
+            self.epoch_jd().to_scale(TimeScale::UTC).value,
+            self.epoch_year(),
             self.rotational_period(),
             self.perihelion(),
             self.semimajor(),
             self.orbital_eccentricity(),
             self.orbital_period(),
+            hemisphere,
+        )?;
+
+        Ok(match self.season_table() {
+            Some(table) => date.with_season_table(&table),
+            None => date,
+        })
+    }
+    /// The checked elements of the body and what its dates share, see [`BodyCache`]
+    ///
+    /// * Bodies that keep their cache (like [`CustomBody`]) should override this, otherwise it's derived on every call.
+    fn cache(&self) -> Result<BodyCache> {
+        BodyCache::new(self)
+    }
+    /// Final Calculation into the dates of many julian dates (UTC), in the same order
+    ///
+    /// * The body is checked and its orbit prepared once, so each date only solves the anomaly.
+    /// * Every date is the same as [`Body::to_date`] of its julian date.
+    ///
+    /// # Panics
+    ///
+    /// When a julian date or the body can't be computed, see [`Body::try_to_date`]
+    fn to_dates(&self, julian_dates: &[f64]) -> Vec<Date> {
+        let cache = self.cache().expect("The julian date and the body to be valid");
+        let table = self.season_table();
+
+        julian_dates
+            .iter()
+            .map(|julian_date| cache.expect_date(*julian_date, table.as_ref()))
+            .collect()
+    }
+    /// Final Calculation into the dates of many julian dates (UTC) on every core, see [`Body::to_dates`]
+    ///
+    /// * The dates are the same as [`Body::to_dates`] and in the same order, whatever the amount of threads.
+    ///
+    /// # Panics
+    ///
+    /// When a julian date or the body can't be computed, see [`Body::try_to_date`]
+    #[cfg(feature = "rayon")]
+    fn to_dates_par(&self, julian_dates: &[f64]) -> Vec<Date> {
+        use rayon::prelude::*;
+
+        let cache = self.cache().expect("The julian date and the body to be valid");
+        let table = self.season_table();
+
+        julian_dates
+            .par_iter()
+            .map(|julian_date| cache.expect_date(*julian_date, table.as_ref()))
+            .collect()
+    }
+    /// The solar longitudes of many julian dates (UTC) in degrees, see [`Body::to_dates`]
+    ///
+    /// # Panics
+    ///
+    /// When a julian date or the body can't be computed, see [`Body::try_to_date`]
+    fn to_ls_batch(&self, julian_dates: &[f64]) -> Vec<f64> {
+        let cache = self.cache().expect("The julian date and the body to be valid");
+
+        julian_dates
+            .iter()
+            .map(|julian_date| {
+                cache
+                    .ls(*julian_date)
+                    .expect("The julian date and the body to be valid")
+            })
+            .collect()
+    }
+    /// The dates between two julian dates (UTC), every `step_sols` sols, see [`DateRange`]
+    ///
+    /// * The step defaults to [`DateRange::DEFAULT_STEP`] when it's 0 or not finite, and its sign is ignored.
+    /// * When the start is after the end, the dates go backwards in time.
+    /// * Like [`Body::to_dates`], the body is checked and its orbit prepared once.
+    ///
+    /// # Panics
+    ///
+    /// When the julian dates or the body can't be computed, see [`Body::try_to_date`]
+    fn dates_between(&self, start_jd: f64, end_jd: f64, step_sols: f64) -> DateRange {
+        DateRange::new(self, start_jd, end_jd, step_sols)
+    }
+    /// The sols since the epoch at the prime meridian, with their fraction
+    ///
+    /// * Bodies with a standard sol count (like the mars sol date) should override this,
+    ///   otherwise midnight at the prime meridian is assumed to be at the epoch.
+    ///
+    /// * When the year is a single sol, the sun stands still and the sol date stays 0, see [`LOCKED_TOLERANCE`]
+    fn sol_date(&self, julian_date: f64) -> f64 {
+        if is_single_sol_year(self.orbital_period()) {
+            return 0.0;
+        }
+
+        let epoch = self.epoch_jd().to_scale(TimeScale::UTC).value;
+
+        Days(julian_date - epoch).sols(self.rotational_period_seconds())
+    }
+    /// The fraction of the sol that has passed at the prime meridian, from 0 up to 1, see [`Body::sol_date`]
+    ///
+    /// * It's continuous between sols, unlike the fields of a [`Time`] (ex; for animations).
+    fn sol_fraction(&self, julian_date: f64) -> f64 {
+        sol_fraction(self.sol_date(julian_date))
+    }
+    /// Calculates the local mean solar time at a longitude
+    ///
+    /// * The longitude is in degrees east, either from -180 to 180 or from 0 to 360.
+    /// * Every degree east is 1/360 of a sol ahead of the prime meridian, or behind it when the
+    ///   [`Body::rotation_direction`] is retrograde.
+    fn lmst(&self, lon_east_deg: f64, julian_date: f64) -> Time {
+        solar_time(
+            mean_solar_fraction(
+                self.sol_date(julian_date),
+                lon_east_deg,
+                self.rotation_direction(),
+            ),
+            mean_solar_offset(lon_east_deg, self.rotation_direction()),
+            lon_east_deg,
+            "LMST",
+            "Local Mean Solar Time",
+        )
+    }
+    /// Calculates the longitude in degrees east where the mean sun is overhead, from -180 up to 180
+    ///
+    /// * This is where the [`Body::lmst`] is noon, it moves west on a prograde body and east on a retrograde one.
+    fn subsolar_longitude(&self, julian_date: f64) -> f64 {
+        let noon = 0.5 - self.sol_date(julian_date).rem_euclid(1.0);
+
+        normalize_degrees(self.rotation_direction().signum() * noon * 360.0 + 180.0) - 180.0
+    }
+    /// Calculates the equation of time in degrees given a solar longitude
+    ///
+    /// * The reduction to the equator minus the equation of center, positive when the sun is ahead.
+    /// > $$EOT = (L_s - \alpha) - (\nu - M)$$
+    fn equation_of_time(&self, ls: f64) -> f64 {
+        let e = self.orbital_eccentricity();
+        let nu = deg_to_rad(ls - self.perihelion().perihelion);
+        let eccentric = 2.0 * (((1.0 - e) / (1.0 + e)).sqrt() * (nu / 2.0).tan()).atan();
+        let mean = eccentric - e * eccentric.sin();
+        let center = (nu - mean).sin().atan2((nu - mean).cos());
+
+        let ls = deg_to_rad(ls);
+        let alpha = (deg_to_rad(self.axial_tilt()).cos() * ls.sin()).atan2(ls.cos());
+        let reduction = (ls - alpha).sin().atan2((ls - alpha).cos());
+
+        rad_to_deg(reduction - center)
+    }
+    /// Calculates the local true solar time at a longitude
+    ///
+    /// * This is the [`Body::lmst`] corrected by the [`Body::equation_of_time`], so noon is when the sun crosses the meridian.
+    fn ltst(&self, lon_east_deg: f64, julian_date: f64) -> Time {
+        let eot = self.equation_of_time(self.to_date(julian_date).ls);
+
+        solar_time(
+            mean_solar_fraction(
+                self.sol_date(julian_date),
+                lon_east_deg,
+                self.rotation_direction(),
+            ) + eot / 360.0,
+            mean_solar_offset(lon_east_deg, self.rotation_direction()) + eot / 15.0,
+            lon_east_deg,
+            "LTST",
+            "Local True Solar Time",
         )
     }
+    /// The custom seasons of the body, otherwise the seasons follow [`Season::from_ls`]
+    fn season_table(&self) -> Option<SeasonTable> {
+        None
+    }
+    /// Final Calculation into date, from a julian date in any time scale
+    fn to_date_jd(&self, julian_date: JulianDate) -> Date {
+        self.to_date(julian_date.to_scale(TimeScale::UTC).value)
+    }
     /// Final Calculation into time
-    fn to_time(&mut self, date: Date) -> Time;
+    fn to_time(&self, date: Date) -> Time;
+    /// The timezone of the body with a code or a name, ignoring case
+    ///
+    /// * Bodies without timezones have none, see [`crate::define_timezones`]
+    fn timezone(&self, _code: &str) -> Option<Box<dyn TimeZone>> {
+        None
+    }
+    /// The timezones of the body from west to east, see [`crate::timezones::all`]
+    ///
+    /// * Bodies without timezones have none, see [`crate::define_timezones`]
+    fn timezones(&self) -> &[ZoneInfo] {
+        &[]
+    }
+    /// Final Calculation into date and time of a timezone on the body
+    fn to_datetime(&self, julian_date: f64, zone: &dyn TimeZone) -> DateTime {
+        let carry = zone.sol_carry(julian_date) as f64;
+
+        DateTime {
+            date: self.to_date(julian_date + (self.rotational_period_seconds() * carry).to_days().0),
+            time: zone.at(julian_date),
+            julian_date,
+            body: Cow::Owned(self.name().into()),
+            offset: zone.offset(),
+        }
+    }
+    /// The date and time of a timezone on the body right now
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::Body, planets::mars::{Mars, Martian}};
+    ///
+    /// println!("{}", Mars.now_in(&Martian::MTC));
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    fn now_in(&self, zone: &dyn TimeZone) -> DateTime {
+        self.to_datetime(crate::julian::jd_now(), zone)
+    }
+    /// The julian date where the sol of the date begins, the inverse of [`Body::to_date`]
+    fn to_julian(&self, date: &Date) -> Result<f64> {
+        date.julian(
+            self.epoch_jd().to_scale(TimeScale::UTC).value,
+            self.epoch_year(),
+            self.rotational_period(),
+            self.orbital_period(),
+        )
+    }
+    /// The julian date in the middle of the sol of the date
+    ///
+    /// * The last sol of a year is shorter, so its middle is earlier.
+    fn to_julian_midsol(&self, date: &Date) -> Result<f64> {
+        let start = self.to_julian(date)?;
+        let length = (self.orbital_period() - (date.day - 1.0)).min(1.0);
+
+        Ok(start + (self.rotational_period_seconds() * (length / 2.0)).to_days().0)
+    }
+    /// The first julian date (UTC) at or after `after` where the body reaches a solar longitude
+    ///
+    /// * The solar longitude is in degrees, from 0 up to 360.
+    /// * The solar longitude only grows within an orbit, so the date is found within one, see [`events::find_crossing`]
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::Body, planets::mars::Mars};
+    ///
+    /// let jd = Mars.jd_at_ls(90.0, 2451545.0).unwrap();
+    ///
+    /// assert!((Mars.to_date(jd).ls - 90.0).abs() < 1e-4);
+    /// # }
+    /// ```
+    fn jd_at_ls(&self, target: f64, after: f64) -> Result<f64> {
+        if !target.is_finite() || !after.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        if !(0.0..360.0).contains(&target) {
+            return Err(Error::InvalidDate("the solar longitude is outside of 0 to 360"));
+        }
+
+        let start = self.try_to_date(after)?.ls;
+        let goal = normalize_degrees(target - start);
+
+        if goal == 0.0 {
+            return Ok(after);
+        }
+
+        // a whole orbit sweeps every solar longitude, so the crossing is within it
+        let end = after + self.orbital_period_days().0;
+        let swept = |jd: f64| -> Result<f64> {
+            match jd < end {
+                true => Ok(normalize_degrees(self.try_to_date(jd)?.ls - start) - goal),
+                false => Ok(360.0 - goal),
+            }
+        };
+
+        // about a millisecond
+        events::try_find_crossing(swept, (after, end), 1e-8)
+    }
+    /// Calculates the length in sols of each quarter of the orbit, from the solar longitude of 0, 90, 180 and 270
+    ///
+    /// * The quarters are the seasons of the northern hemisphere, from the spring (ex; about 194, 178, 142 and 154 sols for mars).
+    /// * The body is faster near its perihelion, so the seasons around it are shorter, and they add up to the [`Body::orbital_period_sols`]
+    /// * The seasons are of the first orbit after the epoch, see [`Body::jd_at_ls`]
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::Body, planets::mars::Mars};
+    ///
+    /// let [spring, _, autumn, _] = Mars.season_lengths();
+    ///
+    /// assert!(spring > autumn);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// When the body can't be computed, see [`Body::try_to_date`]
+    fn season_lengths(&self) -> [f64; 4] {
+        let sol = self.rotational_period_seconds();
+        let mut start = self
+            .jd_at_ls(0.0, self.epoch_jd().to_scale(TimeScale::UTC).value)
+            .expect("The body to be valid");
+
+        [90.0, 180.0, 270.0, 0.0].map(|ls| {
+            let end = self.jd_at_ls(ls, start).expect("The body to be valid");
+            let length = Days(end - start).sols(sol);
+
+            start = end;
+            length
+        })
+    }
+    /// The next season of the northern hemisphere after a julian date (UTC), and the julian date where it starts
+    ///
+    /// * The seasons are the [`Body::season_table`], or the ones of [`Season::from_ls`] when there's none,
+    ///   see [`SeasonTable::next_change`]
+    /// * Late in the winter, the season that comes next is the spring of the next orbit.
+    /// * It fails with [`Error::InvalidSeasonTable`] when the season never changes.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::Body, orbit::Season, planets::mars::Mars};
+    ///
+    /// let (season, julian_date) = Mars.next_season_change(2451545.0).unwrap();
+    ///
+    /// // mars was in its northern winter in 2000
+    /// assert_eq!(Season::VernalEquinox, season);
+    /// assert!(julian_date > 2451545.0);
+    /// # }
+    /// ```
+    fn next_season_change(&self, julian_date: f64) -> Result<(Season, f64)> {
+        let ls = self.try_to_date(julian_date)?.ls;
+        let (start, season) = self
+            .season_table()
+            .unwrap_or_default()
+            .next_change(ls)
+            .ok_or(Error::InvalidSeasonTable("the season never changes"))?;
+
+        Ok((season, self.jd_at_ls(start, julian_date)?))
+    }
+    /// The sols from a julian date (UTC) until the next season starts, see [`Body::next_season_change`]
+    fn sols_until_next_season(&self, julian_date: f64) -> Result<SolDuration> {
+        let (_, change) = self.next_season_change(julian_date)?;
+
+        Ok(SolDuration::from_sols(
+            Days(change - julian_date).sols(self.rotational_period_seconds()),
+        ))
+    }
+    /// The time from a date of the body to another one, negative when the other one is earlier
+    ///
+    /// * The dates are counted in sols from the [`Body::epoch`], with the fraction of their sol, see [`Date::sol`]
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::{Body, Date}, planets::mars::Mars};
+    ///
+    /// let landing = Mars.to_date(2459264.371527778);
+    /// let later = Mars.to_date(2459264.371527778 + 10.0);
+    ///
+    /// assert_eq!(9, Mars.sols_between(&landing, &later).sols);
+    /// # }
+    /// ```
+    fn sols_between(&self, from: &Date, to: &Date) -> SolDuration {
+        let period = self.orbital_period();
+
+        SolDuration::from_sols((to.year - from.year) * period + (to.sol - from.sol))
+    }
+    /// The date of the body a duration after a date, or before it when the duration is negative
+    ///
+    /// * The inverse is subtracting the duration, see [`Body::sols_between`]
+    /// * It fails with [`Error::NonFinite`] when the date isn't finite.
+    fn add_sols(&self, date: &Date, duration: SolDuration) -> Result<Date> {
+        if !date.year.is_finite() || !date.sol.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        let sols = (date.year - self.epoch_year()) * self.orbital_period()
+            + date.sol
+            + duration.as_sols();
+        let epoch = self.epoch_jd().to_scale(TimeScale::UTC).value;
+
+        self.try_to_date(epoch + (self.rotational_period_seconds() * sols).to_days().0)
+    }
+    /// Calculates the apparent diameter of the sun in degrees from the body at a julian date (UTC), see [`angular_diameter`]
+    ///
+    /// * The distance is the one of [`Body::heliocentric_position`], so a hosted body sees the sun from its host.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::Body, planets::mars::Mars};
+    ///
+    /// // the sun looks about 2/3 as wide from mars as from earth
+    /// assert!((Mars.solar_angular_diameter(2451545.0) - 0.35).abs() < 0.05);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// When the julian date or the body can't be computed, see [`Body::try_to_date`]
+    fn solar_angular_diameter(&self, julian_date: f64) -> f64 {
+        let distance = position::at_ls(self, self.to_date(julian_date).ls).distance();
+
+        angular_diameter(SUN_RADIUS_KM, distance.0 * KM_PER_AU)
+    }
+    /// Calculates the power of sunlight in W/m² on a surface facing the sun, above the atmosphere of the body, at a julian date (UTC)
+    ///
+    /// * The [`SOLAR_CONSTANT`] falls with the square of the distance, see [`Body::solar_angular_diameter`] for the distance.
+    ///
+    /// > $$S = \frac{S_0}{r^2}$$
+    ///
+    /// # Panics
+    ///
+    /// When the julian date or the body can't be computed, see [`Body::try_to_date`]
+    fn solar_irradiance(&self, julian_date: f64) -> f64 {
+        let distance = position::at_ls(self, self.to_date(julian_date).ls).distance();
+
+        SOLAR_CONSTANT / (distance.0 * distance.0)
+    }
+    /// Calculates the temperature in kelvins of a black body at the distance of the body, at a julian date (UTC)
+    ///
+    /// * The albedo is the fraction of sunlight reflected away, from 0 up to 1 (ex; 0.25 for mars).
+    /// * The body turns fast, so its whole surface radiates the heat and there's no greenhouse (ex; about 255 K for earth).
+    ///
+    /// > $$T = \left(\frac{S(1 - A)}{4\sigma}\right)^{1/4}$$
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::Body, planets::mars::Mars};
+    ///
+    /// assert!((Mars.equilibrium_temperature(2451545.0, 0.25) - 210.0).abs() < 10.0);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// When the julian date or the body can't be computed, see [`Body::try_to_date`]
+    fn equilibrium_temperature(&self, julian_date: f64, albedo: f64) -> f64 {
+        (self.solar_irradiance(julian_date) * (1.0 - albedo) / (4.0 * STEFAN_BOLTZMANN))
+            .sqrt()
+            .sqrt()
+    }
+    /// Calculates the position of the body from the sun at a julian date (UTC), see [`Body::position`]
+    ///
+    /// * The true anomaly is the solar longitude of the date from the solar longitude of the perihelion.
+    /// * A hosted body (ex; luna) is at the position of its host, since its dates follow the orbit of the host.
+    ///
+    /// > $$r = \frac{a(1 - e^2)}{1 + e\cos\nu}$$
+    fn heliocentric_position(&self, julian_date: f64) -> Result<Position> {
+        Ok(position::at_ls(self, self.try_to_date(julian_date)?.ls))
+    }
+    /// Calculates the position of the body from an origin at a julian date (UTC), in the ecliptic of J2000
+    ///
+    /// * [`Origin::Heliocentric`] is the [`Body::heliocentric_position`]
+    /// * [`Origin::Barycentric`] adds the offset of the sun from the barycenter, see [`position::sun_offset`]
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::Body, planets::mars::Mars, position::Origin};
+    ///
+    /// let sun = Mars.position(2451545.0, Origin::Heliocentric).unwrap();
+    /// let barycenter = Mars.position(2451545.0, Origin::Barycentric).unwrap();
+    ///
+    /// assert!(sun.distance_to(&barycenter).0 < 0.01);
+    /// # }
+    /// ```
+    fn position(&self, julian_date: f64, origin: Origin) -> Result<Position> {
+        let heliocentric = self.heliocentric_position(julian_date)?;
+
+        Ok(match origin {
+            Origin::Heliocentric => heliocentric,
+            Origin::Barycentric => heliocentric + position::sun_offset(julian_date)?,
+        })
+    }
+}
+
+/// The fraction of the sol that has passed at a longitude east of the prime meridian
+fn mean_solar_fraction(sol_date: f64, lon_east_deg: f64, rotation: Rotation) -> f64 {
+    (sol_date + rotation.signum() * normalize_degrees(lon_east_deg) / 360.0).rem_euclid(1.0)
+}
+
+/// The fraction of a sol date, from 0 up to 1
+///
+/// * A sol date just below a whole sol can wrap to exactly 1, which is the start of the next sol.
+pub(crate) fn sol_fraction(sol_date: f64) -> f64 {
+    sol_date.rem_euclid(1.0) % 1.0
+}
+
+/// The offset in hours of the mean solar time at a longitude east of the prime meridian, from -12 up to 12
+fn mean_solar_offset(lon_east_deg: f64, rotation: Rotation) -> f64 {
+    rotation.signum() * (normalize_degrees(lon_east_deg + 180.0) - 180.0) / 15.0
 }
 
-#[derive(Debug, Default, AsRefStr, Clone, Copy)]
+/// The time of a solar clock from the fraction of the sol, wrapped into the same sol
+///
+/// * The timezone of a solar clock is only its longitude, so it starts and ends there.
+fn solar_time(
+    fraction: f64,
+    offset: f64,
+    lon_east_deg: f64,
+    code: &'static str,
+    name: &'static str,
+) -> Time {
+    let lon = normalize_degrees(lon_east_deg + 180.0) - 180.0;
+
+    Time {
+        zone: Some(ZoneInfo {
+            code,
+            name,
+            offset,
+            east: lon,
+            west: lon,
+        }),
+        ..Time::from_sol_fraction(fraction.rem_euclid(1.0))
+    }
+}
+
+/// This function converts the date of a body into the date of another body
+///
+/// * The middle of the sol is used, so converting back and forth between bodies with similar sols is stable.
+///
+pub fn convert_date(
+    from: &(impl Body + ?Sized),
+    date: &Date,
+    to: &(impl Body + ?Sized),
+) -> Result<Date> {
+    to.try_to_date(from.to_julian_midsol(date)?)
+}
+
+#[derive(Debug, Default, AsRefStr, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 /// This represents eras that the date is in
+///
+/// * The eras are anchored to the [`Body::epoch`], which isn't always the [`Body::discovery`]
 pub enum Eras {
     #[strum(serialize = "AD")]
     /// After Discovery, this must be a present or latter date.
@@ -67,7 +936,61 @@ pub enum Eras {
     Unknown,
 }
 
-#[derive(Display, Debug, Default, Clone)]
+/// This is the discovery of a body, see [`Body::discovery`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Discovery {
+    /// The julian date (UTC) of the discovery
+    pub jd: f64,
+    /// The gregorian year of the discovery
+    pub year: i32,
+    /// Who discovered the body
+    pub discoverer: &'static str,
+}
+
+/// This is the direction a body spins, seen from above its north pole, see [`Body::rotation_direction`]
+#[derive(Debug, Default, AsRefStr, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Rotation {
+    /// The body spins the same way as it orbits, so the sun rises in the east (ex; earth and mars)
+    #[default]
+    #[strum(serialize = "Prograde")]
+    Prograde,
+    /// The body spins against its orbit, so the sun rises in the west (ex; venus and uranus)
+    #[strum(serialize = "Retrograde")]
+    Retrograde,
+}
+
+impl Rotation {
+    /// This method returns 1 for a prograde rotation and -1 for a retrograde one
+    pub fn signum(self) -> f64 {
+        match self {
+            Self::Prograde => 1.0,
+            Self::Retrograde => -1.0,
+        }
+    }
+
+    /// This method derives the solar day (the sol) from the rotation against the stars, in the unit of both periods
+    ///
+    /// * A prograde body has to turn a little more than once for the sun to come back, a retrograde body a little less.
+    ///
+    /// > $$\frac{1}{sol} = \left|\frac{1}{sidereal} \mp \frac{1}{orbit}\right|$$
+    ///
+    /// ```rust
+    /// use rust_solar::kepler::Rotation;
+    ///
+    /// // venus turns once in 243 days against its 224.7 day orbit
+    /// let sol = Rotation::Retrograde.solar_day(243.0226, 224.701);
+    ///
+    /// assert!((sol - 116.75).abs() < 0.01);
+    /// ```
+    pub fn solar_day(self, sidereal: f64, orbit: f64) -> f64 {
+        1.0 / (1.0 / sidereal - self.signum() / orbit).abs()
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 /// This is a collection of what a date should consist of
 pub struct Date {
     /// This is the era of body
@@ -81,7 +1004,9 @@ pub struct Date {
     /// This is the ls of the body
     pub ls: f64,
     /// This is the season of the body (Optional)
-    pub season: String,
+    pub season: Season,
+    /// This is the sol of the year with its fraction, counted from 0
+    pub sol: f64,
 }
 
 impl Date {
@@ -90,63 +1015,405 @@ impl Date {
     /// The `1.0` is added to make sure that year, month, or day is not 0.
     ///
     /// Some planets may have different dates for seasons,
-    /// the seasons are baesd on the 360 orbital path.
+    /// the seasons are baesd on the 360 orbital path of the hemisphere.
+    ///
+    /// The year that begins at the epoch is the `epoch_year`, see [`Body::epoch_year`].
     ///
+    /// The julian date and the epoch are both in UTC, so they're differenced in the same time scale,
+    /// see [`Body::epoch_jd`] and [`Body::to_date_jd`].
+    ///
+    /// A year of a single sol (within [`LOCKED_TOLERANCE`]) is a body locked to the sun, which has no days to count,
+    /// so it's an [`Error::InvalidOrbit`] instead of a date where the month and the day never change.
+    ///
+    #[allow(clippy::too_many_arguments)]
     pub fn compute(
         &self,
         julian_date: f64,
         epoch: f64,
+        epoch_year: f64,
         rotational_period: f64,
-        mut peri: Perihelion,
+        peri: Perihelion,
         semimajor: f64,
         orbital_eccentricity: f64,
         orbital_period: f64,
-    ) -> Self {
-        let mut tmp_year = 12.0;
-        let mut tmp_day = (julian_date - epoch) * EARTH_ROTATIONAL_PERIOD / rotational_period;
-
-        let shape = Type::default().shape(orbital_eccentricity);
-
-        while tmp_day >= orbital_period {
-            tmp_day -= orbital_period;
-            tmp_year += 1.0;
+        hemisphere: Hemisphere,
+    ) -> Result<Self> {
+        if !julian_date.is_finite() {
+            return Err(Error::NonFinite);
         }
 
-        while tmp_day < 0.0 {
-            tmp_day += orbital_period;
-            tmp_year -= 1.0;
-        }
-
-        let ls = SolarLongitude.compute(
-            shape,
-            tmp_day,
-            orbital_eccentricity,
+        let cache = BodyCache::from_elements(
+            epoch,
+            epoch_year,
+            rotational_period,
             peri,
-            orbital_period,
             semimajor,
-        );
-        let year = tmp_year;
-        let month = 1.0 + (ls / peri.avg_ls()).floor();
-        let day = 1.0 + tmp_day.floor();
-        let season = Season::default().from(ls as u32);
+            orbital_eccentricity,
+            orbital_period,
+        )?;
+
+        Self::compute_cached(julian_date, &cache, hemisphere)
+    }
+
+    /// This method is [`Date::compute`] on a body that's already checked, see [`BodyCache`]
+    pub(crate) fn compute_cached(
+        julian_date: f64,
+        cache: &BodyCache,
+        hemisphere: Hemisphere,
+    ) -> Result<Self> {
+        let (year, sol) = cache.year_and_sol(julian_date)?;
+
+        Self::on_orbit(year, sol, cache.orbit(), hemisphere)
+    }
+
+    /// This method computes the date given the year and the sol of the year (counted from 0)
+    fn at_sol(
+        year: f64,
+        sol: f64,
+        peri: Perihelion,
+        semimajor: f64,
+        orbital_eccentricity: f64,
+        orbital_period: f64,
+        hemisphere: Hemisphere,
+    ) -> Result<Self> {
+        peri.validate()?;
+
+        let orbit = Orbit::new(orbital_eccentricity, peri, orbital_period, semimajor)?;
+
+        Self::on_orbit(year, sol, &orbit, hemisphere)
+    }
+
+    /// This method computes the date given the year and the sol of the year on a checked orbit
+    fn on_orbit(year: f64, sol: f64, orbit: &Orbit, hemisphere: Hemisphere) -> Result<Self> {
+        let ls = orbit.ls(sol)?;
+        let month = orbit.month(ls);
+        let day = 1.0 + sol.floor();
+        let season = Season::from_ls(ls, hemisphere);
         let era = match year as i32 > 0 {
             true => Eras::AD,
             false => Eras::BD,
         };
 
-        Self {
+        Ok(Self {
             era,
             year,
             month,
             day,
             ls,
             season,
+            sol,
+        })
+    }
+
+    /// This method returns the sol of the year with its fraction, counted from 1
+    ///
+    /// * The floor of the ordinal is the [`Date::day`]
+    pub fn ordinal(&self) -> f64 {
+        self.sol + 1.0
+    }
+
+    /// This method returns how far the date is through its year, from 0 at the start up to 1
+    ///
+    /// * This is the sol of the year over the [`Body::orbital_period`] of the body of the date,
+    ///   the last fraction of a sol stays below 1 (ex; 0.5 is the middle sol of the year).
+    /// * See [`Body::orbit_progress`] for how far the body is from its perihelion.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::{Body, Date}, planets::mars::Mars};
+    ///
+    /// let start = Date::from_ordinal(36.0, 1.0, &Mars).unwrap();
+    ///
+    /// assert_eq!(0.0, start.year_progress(&Mars));
+    /// # }
+    /// ```
+    pub fn year_progress(&self, body: &(impl Body + ?Sized)) -> f64 {
+        below_one(self.sol / body.orbital_period())
+    }
+
+    /// This method computes the date of a body given the year and the [`Date::ordinal`] of the sol
+    ///
+    /// * The season is of the northern hemisphere, like [`Body::to_date`], see [`Date::from_ordinal_in`]
+    pub fn from_ordinal(year: f64, ordinal: f64, body: &(impl Body + ?Sized)) -> Result<Self> {
+        Self::from_ordinal_in(year, ordinal, body, Hemisphere::North)
+    }
+
+    /// This method computes the date of a body given the year and the [`Date::ordinal`] of the sol, with the season of a hemisphere
+    ///
+    /// * A [`Body::season_table`] replaces the season of either hemisphere
+    pub fn from_ordinal_in(
+        year: f64,
+        ordinal: f64,
+        body: &(impl Body + ?Sized),
+        hemisphere: Hemisphere,
+    ) -> Result<Self> {
+        if !year.is_finite() {
+            return Err(Error::NonFinite);
         }
+
+        let date = Self::at_sol(
+            year,
+            ordinal - 1.0,
+            body.perihelion(),
+            body.semimajor(),
+            body.orbital_eccentricity(),
+            body.orbital_period(),
+            hemisphere,
+        )?;
+
+        Ok(match body.season_table() {
+            Some(table) => date.with_season_table(&table),
+            None => date,
+        })
+    }
+
+    /// This method returns the date at the start of the year of the date, the first sol at 0
+    pub fn start_of_year(&self, body: &(impl Body + ?Sized)) -> Result<Self> {
+        Self::from_ordinal(self.year, 1.0, body)
+    }
+
+    /// This method returns the date where the month of the date starts, within its year
+    ///
+    /// * The months are slices of solar longitude, so a month starts in the middle of a sol, where the body crosses into it.
+    /// * A year that starts just before the solar longitude of 0 begins in the last month,
+    ///   so the start of that month is the start of the year.
+    /// * A date at the start of its month is its own start.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::Body, planets::mars::Mars};
+    ///
+    /// let date = Mars.to_date(2451545.0);
+    /// let start = date.start_of_month(&Mars).unwrap();
+    ///
+    /// assert_eq!(date.month, start.month);
+    /// assert!(start.sol <= date.sol);
+    /// assert_eq!(start, start.start_of_month(&Mars).unwrap());
+    /// # }
+    /// ```
+    pub fn start_of_month(&self, body: &(impl Body + ?Sized)) -> Result<Self> {
+        let orbit = Orbit::new(
+            body.orbital_eccentricity(),
+            body.perihelion(),
+            body.orbital_period(),
+            body.semimajor(),
+        )?;
+        let within = |sol: f64| -> Result<bool> { Ok(orbit.month(orbit.ls(sol)?) == self.month) };
+
+        if !within(self.sol - MONTH_PRECISION)? {
+            return Ok(*self);
+        }
+
+        // a sol at a time back to the previous month, then halves between the two
+        let mut start = self.sol;
+        let mut before = (start - 1.0).max(0.0);
+
+        while within(before)? {
+            if before == 0.0 {
+                return self.start_of_year(body);
+            }
+
+            start = before;
+            before = (before - 1.0).max(0.0);
+        }
+
+        while start - before > MONTH_PRECISION {
+            let middle = (start + before) / 2.0;
+
+            match within(middle)? {
+                true => start = middle,
+                false => before = middle,
+            }
+        }
+
+        Self::from_ordinal(self.year, start + 1.0, body)
+    }
+
+    /// This method returns the date at the start of the nearest sol, the start of the next year when it's nearer
+    ///
+    /// * The last sol of a year is only a fraction of a sol, so its end is the start of the next year.
+    pub fn round_to_sol(&self, body: &(impl Body + ?Sized)) -> Result<Self> {
+        let period = body.orbital_period();
+        let floor = self.sol.floor();
+        let next = (floor + 1.0).min(period);
+
+        match self.sol - floor < next - self.sol {
+            true => Self::from_ordinal(self.year, floor + 1.0, body),
+            false if next < period => Self::from_ordinal(self.year, next + 1.0, body),
+            false => Self::from_ordinal(self.year + 1.0, 1.0, body),
+        }
+    }
+
+    /// This method returns the week of the date since the [`Body::week_anchor`], counted from 0 and negative before it
+    ///
+    /// * The weeks run on through the years, every sol of the calendar is a sol of the week, the short last sol
+    ///   of a year too, so a year of [`Body::orbital_period`] sols has its ceiling of them (ex; 669 for mars).
+    /// * It fails with [`Error::InvalidDate`] when the week is empty, and with [`Error::NonFinite`] when the date isn't finite.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::{Body, Date}, planets::mars::Mars};
+    ///
+    /// let last = Date::from_ordinal(36.0, 669.0, &Mars).unwrap();
+    /// let first = Date::from_ordinal(37.0, 1.0, &Mars).unwrap();
+    ///
+    /// // the 669 sols of a mars year aren't whole weeks, the next year goes on from the last sol
+    /// assert_eq!(last.sol_of_week(&Mars, 7).unwrap() % 7 + 1, first.sol_of_week(&Mars, 7).unwrap());
+    /// assert_eq!(1, first.week_of_year(&Mars, 7).unwrap());
+    /// # }
+    /// ```
+    pub fn week_since_epoch(&self, body: &(impl Body + ?Sized), week_len: u8) -> Result<f64> {
+        Ok((self.calendar_sols(body, week_len)? / f64::from(week_len)).floor())
+    }
+
+    /// This method returns the sol of the week of the date, counted from 1, see [`Date::week_since_epoch`]
+    pub fn sol_of_week(&self, body: &(impl Body + ?Sized), week_len: u8) -> Result<u8> {
+        let sols = self.calendar_sols(body, week_len)?;
+
+        Ok(1 + sols.rem_euclid(f64::from(week_len)) as u8)
+    }
+
+    /// This method returns the week of the year of the date, counted from 1
+    ///
+    /// * The first week is the one with the first sol of the year, which may have started in the last year,
+    ///   see [`Date::week_since_epoch`].
+    pub fn week_of_year(&self, body: &(impl Body + ?Sized), week_len: u8) -> Result<u32> {
+        let first = Self { day: 1.0, ..*self };
+        let weeks =
+            self.week_since_epoch(body, week_len)? - first.week_since_epoch(body, week_len)?;
+
+        Ok(1 + weeks as u32)
+    }
+
+    /// This method counts the sols of the calendar from the [`Body::week_anchor`] up to the sol of the date
+    fn calendar_sols(&self, body: &(impl Body + ?Sized), week_len: u8) -> Result<f64> {
+        if !self.year.is_finite() || !self.day.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        if week_len == 0 {
+            return Err(Error::InvalidDate("the week has no sols"));
+        }
+
+        let year_sols = body.orbital_period().ceil();
+
+        Ok((self.year - body.epoch_year()) * year_sols + (self.day - 1.0) - body.week_anchor())
+    }
+
+    /// This method returns the solar longitude in degrees with its full precision, from 0 up to 360
+    ///
+    /// * [`Date`] shows it to a tenth of a degree, where 359.96 shows as 0.0
+    pub fn ls_precise(&self) -> f64 {
+        self.ls
+    }
+
+    /// This method returns the name of the season
+    pub fn season_str(&self) -> &'static str {
+        match self.season {
+            Season::Custom(label) => label,
+            season => season.into(),
+        }
+    }
+
+    /// This method shows the date with the epoch of the body its era is anchored to, see [`AnchoredDate`]
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::Body, planets::mars::Mars};
+    ///
+    /// let date = Mars.to_date(2451545.0);
+    ///
+    /// assert_eq!(
+    ///     format!("{date} (anchored to 1975-12-19 epoch)"),
+    ///     date.anchored(&Mars).to_string()
+    /// );
+    /// # }
+    /// ```
+    pub fn anchored(&self, body: &(impl Body + ?Sized)) -> AnchoredDate {
+        AnchoredDate {
+            date: *self,
+            epoch: body.epoch_jd().to_scale(TimeScale::UTC).value,
+        }
+    }
+
+    /// This method replaces the season with the season of a table
+    pub fn with_season_table(mut self, table: &SeasonTable) -> Self {
+        self.season = table.season(self.ls);
+        self
+    }
+
+    /// This method is the inverse of [`Date::compute`],
+    /// it gives the julian date where the sol of the date begins.
+    ///
+    /// The last sol of a year may be shorter than a full sol,
+    /// because the orbital period is rarely a whole amount of sols.
+    ///
+    pub fn julian(
+        &self,
+        epoch: f64,
+        epoch_year: f64,
+        rotational_period: f64,
+        orbital_period: f64,
+    ) -> Result<f64> {
+        if !self.year.is_finite() || !self.day.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        if self.day < 1.0 || self.day > orbital_period.ceil() {
+            return Err(Error::InvalidDate("the day is outside of the year"));
+        }
+
+        match (self.era, self.year as i32 > 0) {
+            (Eras::AD, false) | (Eras::BD, true) => {
+                return Err(Error::InvalidDate("the era doesn't match the year"))
+            }
+            _ => (),
+        }
+
+        let sols = (self.year - epoch_year) * orbital_period + (self.day - 1.0);
+
+        Ok(epoch + (Seconds(rotational_period) * sols).to_days().0)
     }
 }
 
-#[derive(Display, Debug, Default, Clone)]
+impl fmt::Display for Date {
+    /// AD 0036-06-360 Ls 172.8 (Summer Solstice)
+    ///
+    /// * The tenth of a degree before the wrap rounds up to 360, so it's shown as 0, see [`FormatOptions`]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_with(f, &FormatOptions::default())
+    }
+}
+
+/// This is a date shown with the epoch its era is anchored to, see [`Date::anchored`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnchoredDate {
+    date: Date,
+    epoch: f64,
+}
+
+impl fmt::Display for AnchoredDate {
+    /// AD 0036-06-360 Ls 172.8 (Summer Solstice) (anchored to 1975-12-19 epoch)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let epoch = jd2greg(self.epoch, CalendarSystem::Gregorian);
+
+        write!(
+            f,
+            "{} (anchored to {:04}-{:02}-{:02} epoch)",
+            self.date, epoch.year, epoch.month, epoch.day
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 /// This is a collection of what a time should consist of
+///
+/// * A time doesn't own any of its text, so it's [`Copy`] and making one doesn't allocate.
 pub struct Time {
     /// This is the hour of the body
     pub hour: i32,
@@ -154,14 +1421,32 @@ pub struct Time {
     pub minute: u8,
     /// This is the second of the body
     pub second: u8,
-    /// This is the offset code of the body
-    pub code: String,   
-    /// This is the name code of the body
-    pub name: String,
-    /// This is the offset name code of the body
-    pub offset_name: String,
+    /// This is the millisecond of the body
+    pub millisecond: u16,
+    /// This is the timezone of the time, none for a clock without one (ex; after [`Time::to_mtc`])
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub zone: Option<ZoneInfo>,
+    /// This is the variant name of the timezone (ex; MTCp1), empty without one
+    pub offset_name: &'static str,
     /// This is the hour type of body (Millitary Time or 12Hour)
-    pub hour_type: String,
+    pub hour_type: HourType,
+    /// This is the amount of hours in a day of the body, where the clock wraps
+    pub hours_per_day: u8,
+}
+
+impl Default for Time {
+    fn default() -> Self {
+        Self {
+            hour: 0,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+            zone: None,
+            offset_name: "",
+            hour_type: HourType::Unknown,
+            hours_per_day: 24,
+        }
+    }
 }
 
 impl Time {
@@ -169,6 +1454,259 @@ impl Time {
     pub fn compute(&mut self) -> Self {
         Self::default()
     }
+
+    /// This method returns the code of the timezone (ex; AMT), empty without one
+    pub fn code(&self) -> &'static str {
+        self.zone.map_or("", |zone| zone.code)
+    }
+
+    /// This method returns the name of the timezone (ex; Amazonis Time), empty without one
+    pub fn name(&self) -> &'static str {
+        self.zone.map_or("", |zone| zone.name)
+    }
+
+    /// This method returns the offset of the timezone from the prime meridian in hours, 0 without one
+    pub fn offset(&self) -> f64 {
+        self.zone.map_or(0.0, |zone| zone.offset)
+    }
+
+    /// This method creates a 24 hour time from the fraction of the sol that has passed
+    ///
+    /// * The time is rounded to the nearest millisecond, so 59.9995 seconds carries into the next minute.
+    pub fn from_sol_fraction(fraction: f64) -> Self {
+        let day = 24 * 3_600_000;
+        let total = (fraction.rem_euclid(1.0) * day as f64).round() as i64;
+
+        Self::default().add_milliseconds(total)
+    }
+
+    /// This method adds milliseconds to the time, carrying into seconds, minutes and hours.
+    ///
+    /// * The hour wraps at the [`Time::hours_per_day`] of the body.
+    /// * Negative milliseconds go backwards and wrap into the previous day.
+    ///
+    pub fn add_milliseconds(&self, ms: i64) -> Time {
+        let day = i64::from(self.hours_per_day.max(1)) * 3_600_000;
+        let total = (self.milliseconds() + ms).rem_euclid(day);
+        let hour = total / 3_600_000;
+
+        Time {
+            hour: hour as i32,
+            minute: (total % 3_600_000 / 60_000) as u8,
+            second: (total % 60_000 / 1000) as u8,
+            millisecond: (total % 1000) as u16,
+            hour_type: HourType::from_hour(hour as u8),
+            ..*self
+        }
+    }
+
+    /// The milliseconds of the clock since the start of the sol, as they are (ex; minute = 72 is not wrapped)
+    fn milliseconds(&self) -> i64 {
+        i64::from(self.hour) * 3_600_000
+            + i64::from(self.minute) * 60_000
+            + i64::from(self.second) * 1000
+            + i64::from(self.millisecond)
+    }
+
+    /// This method adds seconds to the time, see [`Time::add_milliseconds`]
+    pub fn add_seconds(&self, s: i64) -> Time {
+        self.add_milliseconds(s * 1000)
+    }
+
+    /// This method adds minutes to the time, see [`Time::add_seconds`]
+    pub fn add_minutes(&self, m: i64) -> Time {
+        self.add_seconds(m * 60)
+    }
+
+    /// This method adds hours to the time, see [`Time::add_seconds`]
+    pub fn add_hours(&self, h: i64) -> Time {
+        self.add_seconds(h * 3600)
+    }
+
+    /// This method fixes a denormalized time (ex; minute = 72) in place.
+    pub fn normalize(&mut self) {
+        *self = self.add_seconds(0);
+    }
+
+    /// This method returns the time at the prime meridian, the coordinated time of the body (ex; MTC on mars)
+    ///
+    /// * The [`Time::offset`] is taken back to the millisecond, and the timezone is cleared.
+    /// * The time wraps into the sol of the prime meridian, which may be the sol before or after.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::{Body, TimeZone}, planets::mars::{Mars, Martian}};
+    ///
+    /// let amt = Martian::MTCn5.at(2451545.0);
+    /// let mtc = Martian::MTC.at(2451545.0);
+    ///
+    /// assert_eq!(mtc.to_string(), format!("{} NT (+00:00)", amt.to_mtc()));
+    /// # }
+    /// ```
+    pub fn to_mtc(&self) -> Time {
+        Time {
+            zone: None,
+            offset_name: "",
+            ..self.add_milliseconds(-(self.offset() * 3_600_000.0).round() as i64)
+        }
+    }
+
+    /// This method returns the time from an earlier time to this one, both taken to the prime meridian first
+    ///
+    /// * Without dates, the earlier time is within the sol before this one (ex; 23:00 AMT to 01:30 ET is 4 hours).
+    /// * With the date of this time, the date of the earlier time and their body, the whole sols between them are
+    ///   counted as well, see [`Body::sols_between`], the dates only need to be within half a sol of the times.
+    /// * The sol is the [`Time::hours_per_day`] of this time.
+    pub fn elapsed_since(
+        &self,
+        earlier: &Time,
+        dates: Option<(&Date, &Date, &dyn Body)>,
+    ) -> SolDuration {
+        let day = i64::from(self.hours_per_day.max(1)) * 3_600_000;
+        let fraction = (self.to_mtc().milliseconds() - earlier.to_mtc().milliseconds())
+            .rem_euclid(day) as f64
+            / day as f64;
+
+        match dates {
+            Some((date, earlier_date, body)) => {
+                let sols = body.sols_between(earlier_date, date).as_sols();
+
+                SolDuration::from_sols((sols - fraction).round() + fraction)
+            }
+            None => SolDuration::from_sols(fraction),
+        }
+    }
+}
+
+impl fmt::Display for Time {
+    /// 13:07:42 ABT (+02:30)
+    ///
+    /// * With a precision, the milliseconds are shown as well (ex; `{:.3}` is 13:07:42.123 ABT (+02:30))
+    /// * A time without a timezone is only the clock (ex; 13:07:42)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+
+        if let Some(precision) = f.precision() {
+            let digits = precision.min(3);
+            let fraction = u32::from(self.millisecond) / 10_u32.pow(3 - digits as u32);
+
+            if digits > 0 {
+                write!(f, ".{:0digits$}", fraction)?;
+            }
+        }
+
+        match self.zone {
+            Some(zone) => write!(f, " {} ({})", zone.code, Offset(zone.offset)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Times are compared at the prime meridian, so the same moment in two zones is equal (ex; 23:00 AMT is 11:30 NT)
+///
+/// * Only the clock is compared, within the sol, so 23:00 AMT (11:30 NT) is before 01:30 ET (15:30 NT),
+///   even when the ET is the sol before. Compare a [`DateTime`] to order moments across sols.
+/// * A time doesn't know its body, so comparing the times of two bodies is meaningless,
+///   the clocks are ordered by their [`Time::hours_per_day`] first so those of different lengths never mix.
+/// * The order is total (there's no `None` of [`PartialOrd`]), so times can be sorted and kept in ordered
+///   collections (ex; a log of the times of a sol), and the clocks of different lengths only need to stay apart.
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::{kepler::TimeZone, planets::mars::Martian};
+///
+/// let amt = Martian::MTCn5.at(2451545.0);
+/// let nt = Martian::MTC.at(2451545.0);
+///
+/// assert_eq!(amt, nt);
+/// assert!(amt < nt.add_minutes(1));
+/// # }
+/// ```
+impl PartialEq for Time {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Time {}
+
+impl PartialOrd for Time {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Time {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.hours_per_day, self.to_mtc().milliseconds())
+            .cmp(&(other.hours_per_day, other.to_mtc().milliseconds()))
+    }
+}
+
+#[derive(Debug, Clone)]
+/// This is a collection of a date and a time at the same moment
+///
+/// * Date times are compared by the julian date they were computed from
+/// * The date and the time are read only, so they can't drift from that julian date
+pub struct DateTime {
+    /// This is the date of the body
+    date: Date,
+    /// This is the time of the body
+    time: Time,
+    /// This is the julian date (UTC) the date and time came from
+    julian_date: f64,
+    /// This is the name of the body
+    body: Cow<'static, str>,
+    /// This is the offset of the timezone from the prime meridian in hours
+    offset: f64,
+}
+
+impl DateTime {
+    /// This method returns the date of the body
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// This method returns the time of the body
+    pub fn time(&self) -> Time {
+        self.time
+    }
+
+    /// This method returns the julian date (UTC) of the date time, the inverse of [`Body::to_datetime`]
+    pub fn to_julian(&self) -> f64 {
+        self.julian_date
+    }
+
+    /// This method returns the name of the body of the date time
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// This method returns the offset of the timezone from the prime meridian in hours
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+}
+
+impl fmt::Display for DateTime {
+    /// AD 0036-06-360 Ls 172.8 (Summer Solstice) 13:07:42 AMT
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.date, self.time)
+    }
+}
+
+impl PartialEq for DateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.julian_date == other.julian_date
+    }
+}
+
+impl PartialOrd for DateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.julian_date.partial_cmp(&other.julian_date)
+    }
 }
 
 /// This trait acts as a common field for all  all planets, asteroids, moons, exo-planets, and comets.
@@ -178,15 +1716,74 @@ impl Time {
 /// 
 /// 
 pub trait TimeZone {
+    #[allow(clippy::new_ret_no_self, clippy::wrong_self_convention)]
     /// This method generates a new timezone and returns the time for it
     /// 
     /// * You just need to specifiy the offset and it'll calibrate it for you.
+    /// * The time is right now according to the system clock, so this needs the `std` feature.
     /// 
-    fn new(&self) -> Time;
+    #[cfg(feature = "std")]
+    fn new(&self) -> Time {
+        self.at(crate::julian::jd_now())
+    }
+
+    /// This method returns the time of the timezone at a julian date (UTC)
+    fn at(&self, julian_date: f64) -> Time;
+
+    /// This method returns the time of the timezone at a split julian date (UTC)
+    ///
+    /// * Timezones on a [`crate::clock::BodyClock`] keep the precision of the fraction, others use [`TimeZone::at`].
+    fn at_split(&self, julian_date: SplitJd) -> Time {
+        self.at(julian_date.value())
+    }
+
+    /// This method returns the time of the timezone at a chrono date time (UTC), keeping its precision
+    #[cfg(feature = "chrono")]
+    fn at_chrono(&self, date_time: chrono::DateTime<chrono::Utc>) -> Time {
+        self.at_split(date_time.into())
+    }
+
+    /// This method returns the time of the timezone at a `time` date time, keeping its precision
+    #[cfg(feature = "time")]
+    fn at_odt(&self, date_time: time::OffsetDateTime) -> Time {
+        self.at_split(date_time.into())
+    }
+
+    /// This method returns the time of the timezone at a julian date in any time scale
+    fn at_jd(&self, julian_date: JulianDate) -> Time {
+        self.at(julian_date.to_scale(TimeScale::UTC).value)
+    }
+
+    /// This method returns the offset of the timezone from the prime meridian in hours
+    fn offset(&self) -> f64 {
+        0.0
+    }
+
+    /// This method returns the time of the timezone at a julian date (UTC) in hours, from 0 up to 24
+    ///
+    /// * Timezones on a [`crate::clock::BodyClock`] are continuous, unlike the fields of [`TimeZone::at`],
+    ///   others are only as fine as the milliseconds of [`TimeZone::at`].
+    /// * Flooring the hours gives the hour of [`TimeZone::at`], and so on for the minutes and the seconds.
+    fn fractional_hours(&self, julian_date: f64) -> f64 {
+        let time = self.at(julian_date);
+
+        f64::from(time.hour)
+            + f64::from(time.minute) / 60.0
+            + f64::from(time.second) / 3600.0
+            + f64::from(time.millisecond) / 3_600_000.0
+    }
+
+    /// This method returns the sols the timezone is ahead (1) or behind (-1) of the prime meridian at a julian date (UTC)
+    ///
+    /// * This is carried into the date when the offset pushes the time across midnight.
+    fn sol_carry(&self, _julian_date: f64) -> i64 {
+        0
+    }
 }
 
 
-#[derive(Display, Debug, Clone, Copy, Default, AsRefStr)]
+#[derive(Display, Debug, Clone, Copy, Default, PartialEq, Eq, AsRefStr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 /// The hour type of the timezone
 pub enum HourType {
     /// Ante Meridiem
@@ -202,14 +1799,18 @@ pub enum HourType {
 }
 
 impl HourType {
-    /// This method computes the hour type of the time given an hour.
+    /// This method computes the hour type of the time given an hour, as text, see [`HourType::from_hour`]
+    #[allow(clippy::new_ret_no_self)]
     pub fn new(&self, hour: u8) -> String {
+        Self::from_hour(hour).as_ref().to_string()
+    }
+
+    /// This method computes the hour type of the time given an hour.
+    pub fn from_hour(hour: u8) -> Self {
         match hour {
             0..=11 => Self::AM,
             12..=24 => Self::PM,
             _ => Self::Unknown,
         }
-        .as_ref()
-        .to_string()
     }
 }
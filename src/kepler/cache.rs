@@ -0,0 +1,159 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    conversions::{Days, Seconds},
+    error::{Error, Result},
+    julian::TimeScale,
+    orbit::{Hemisphere, Orbit, Perihelion, SeasonTable},
+};
+
+use super::{is_single_sol_year, Body, Date};
+
+/// This structure holds what stays the same between the dates of a body,
+/// so converting many julian dates only solves the anomaly of each one, see [`Body::cache`]
+///
+/// * The body is checked once, like [`Date::compute`]
+/// * The shape, the month length (avg ls), the semi-latus rectum, the factor of the true anomaly
+///   and the date of the perihelion are derived once, see [`Orbit`]
+/// * Every date is the same as [`Body::try_to_date`] of its julian date, to the bit.
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::{kepler::{Body, BodyCache}, planets::mars::Mars};
+///
+/// let cache = BodyCache::new(&Mars).unwrap();
+///
+/// assert_eq!(Mars.to_date(2451545.0), cache.date(2451545.0).unwrap());
+/// assert!(cache.date(f64::NAN).is_err());
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BodyCache {
+    epoch: f64,
+    epoch_year: f64,
+    rotational_period: Seconds,
+    orbital_period: f64,
+    orbit: Orbit,
+}
+
+impl BodyCache {
+    /// This method checks a body once and derives what its dates share
+    pub fn new(body: &(impl Body + ?Sized)) -> Result<Self> {
+        Self::from_elements(
+            body.epoch_jd().to_scale(TimeScale::UTC).value,
+            body.epoch_year(),
+            body.rotational_period(),
+            body.perihelion(),
+            body.semimajor(),
+            body.orbital_eccentricity(),
+            body.orbital_period(),
+        )
+    }
+
+    /// This method checks the elements of a body once, like [`Date::compute`]
+    ///
+    /// * The epoch is in UTC, like the julian dates
+    ///
+    /// * It's inlined (with [`Orbit::new`]) so a single date keeps the constants of its body, see `benches/batch.rs`
+    #[inline]
+    pub(crate) fn from_elements(
+        epoch: f64,
+        epoch_year: f64,
+        rotational_period: f64,
+        peri: Perihelion,
+        semimajor: f64,
+        orbital_eccentricity: f64,
+        orbital_period: f64,
+    ) -> Result<Self> {
+        if !epoch.is_finite() || !epoch_year.is_finite() || !rotational_period.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        if rotational_period <= 0.0 {
+            return Err(Error::InvalidOrbit("the rotational period isn't positive"));
+        }
+
+        let orbit = Orbit::new(orbital_eccentricity, peri, orbital_period, semimajor)?;
+
+        if is_single_sol_year(orbital_period) {
+            return Err(Error::InvalidOrbit(
+                "the year is a single sol, the sun stands still over a body locked to it",
+            ));
+        }
+
+        peri.validate()?;
+
+        Ok(Self {
+            epoch,
+            epoch_year,
+            rotational_period: Seconds(rotational_period),
+            orbital_period,
+            orbit,
+        })
+    }
+
+    /// The orbit of the body, see [`Orbit`]
+    pub fn orbit(&self) -> &Orbit {
+        &self.orbit
+    }
+
+    /// This method computes the date of a julian date (UTC), see [`Body::try_to_date`]
+    ///
+    /// * The seasons follow [`crate::orbit::Season::from_ls`], the [`Body::season_table`] isn't kept
+    pub fn date(&self, julian_date: f64) -> Result<Date> {
+        self.date_in(julian_date, Hemisphere::North)
+    }
+
+    /// This method computes the date of a julian date (UTC) with the season of a hemisphere, see [`Body::try_to_date_in`]
+    pub fn date_in(&self, julian_date: f64, hemisphere: Hemisphere) -> Result<Date> {
+        Date::compute_cached(julian_date, self, hemisphere)
+    }
+
+    /// This method computes the solar longitude of a julian date (UTC), without the rest of the date
+    pub fn ls(&self, julian_date: f64) -> Result<f64> {
+        let (_, sol) = self.year_and_sol(julian_date)?;
+
+        self.orbit.ls(sol)
+    }
+
+    /// This method splits a julian date into the year and the sol of the year (counted from 0)
+    pub(crate) fn year_and_sol(&self, julian_date: f64) -> Result<(f64, f64)> {
+        if !julian_date.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        let sols = Days(julian_date - self.epoch).sols(self.rotational_period);
+        let years = (sols / self.orbital_period).floor();
+        let mut year = self.epoch_year + years;
+        let mut sol = sols - years * self.orbital_period;
+
+        // the division can round across a year boundary
+        if sol < 0.0 {
+            sol += self.orbital_period;
+            year -= 1.0;
+        } else if sol >= self.orbital_period {
+            sol -= self.orbital_period;
+            year += 1.0;
+        }
+
+        Ok((year, sol))
+    }
+
+    /// This method computes the date of a julian date with the seasons of a table, see [`Body::season_table`]
+    ///
+    /// # Panics
+    ///
+    /// When the julian date can't be computed
+    pub(crate) fn expect_date(&self, julian_date: f64, table: Option<&SeasonTable>) -> Date {
+        let date = self
+            .date(julian_date)
+            .expect("The julian date and the body to be valid");
+
+        match table {
+            Some(table) => date.with_season_table(table),
+            None => date,
+        }
+    }
+}
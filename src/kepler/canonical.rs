@@ -0,0 +1,310 @@
+use alloc::{format, string::String};
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    bodies,
+    error::{Error, Result},
+};
+
+use super::{Body, Date, DateTime, Time, TimeZone};
+
+/// The milliseconds of the 24 hour clock of a sol, see [`Time::from_sol_fraction`]
+const MS_PER_SOL: i64 = 24 * 3_600_000;
+
+impl DateTime {
+    /// This method renders the date time in the canonical format, the stable interchange format of the library
+    ///
+    /// ```text
+    /// canonical = body ":" year "-" month "-" day "T" hour ":" minute ":" second [ "." millisecond ] offset "[" zone "]"
+    /// body      = the name of the body in upper case, see `bodies::find`
+    /// year      = [ "-" ] 4*DIGIT    ; zero padded, the year 0 and below are before discovery (BD)
+    /// month     = 2DIGIT             ; the month of the solar longitude, see `Date::month`
+    /// day       = 3DIGIT             ; the day of the year, see `Date::day`
+    /// hour      = 2DIGIT             ; 00 to 23
+    /// minute    = 2DIGIT             ; 00 to 59
+    /// second    = 2DIGIT             ; 00 to 59
+    /// millisecond = 3DIGIT           ; left out when it's 000
+    /// offset    = ( "+" / "-" ) 2DIGIT ":" 2DIGIT   ; the offset of the timezone in hours and minutes
+    /// zone      = the code of the timezone
+    /// ```
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::{Body, DateTime}, planets::mars::{Mars, Martian}};
+    ///
+    /// let date_time = Mars.to_datetime(2451545.0, &Martian::MTCn5);
+    ///
+    /// assert_eq!("MARS:0024-10-522T02:23:02.141-12:30[AMT]", date_time.to_canonical_string());
+    /// # }
+    /// ```
+    pub fn to_canonical_string(&self) -> String {
+        let year = self.date.year as i64;
+        let offset = (self.offset * 60.0).round() as i64;
+        let mut text = format!(
+            "{}:{}{:04}-{:02}-{:03}T{:02}:{:02}:{:02}",
+            self.body.to_ascii_uppercase(),
+            if year < 0 { "-" } else { "" },
+            year.abs(),
+            self.date.month as i64,
+            self.date.day as i64,
+            self.time.hour,
+            self.time.minute,
+            self.time.second,
+        );
+
+        if self.time.millisecond != 0 {
+            text += &format!(".{:03}", self.time.millisecond);
+        }
+
+        text += &format!(
+            "{}{:02}:{:02}[{}]",
+            if offset < 0 { "-" } else { "+" },
+            offset.abs() / 60,
+            offset.abs() % 60,
+            self.time.code()
+        );
+
+        text
+    }
+
+    /// This method parses a date time in the canonical format, see [`DateTime::to_canonical_string`]
+    ///
+    /// * The body and the timezone are found by name, ignoring case.
+    /// * The month and the offset must match the day and the timezone, so a string only has one meaning.
+    /// * The julian date is found again from the date and the time, to the millisecond of the clock.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::kepler::DateTime;
+    ///
+    /// let date_time = DateTime::from_canonical_str("MARS:0024-10-522T02:23:02.141-12:30[AMT]").unwrap();
+    ///
+    /// assert!((date_time.to_julian() - 2451545.0).abs() < 1e-7);
+    /// assert!(DateTime::from_canonical_str("0024-10-522T02:23:02-12:30[AMT]").is_err());
+    /// # }
+    /// ```
+    pub fn from_canonical_str(text: &str) -> Result<Self> {
+        let (body, rest) = text
+            .split_once(':')
+            .filter(|(body, _)| !body.starts_with(|c: char| c.is_ascii_digit() || c == '-'))
+            .filter(|(body, _)| !body.is_empty())
+            .ok_or(Error::InvalidFormat("the body prefix is missing"))?;
+        let body = bodies::find(body).ok_or(Error::InvalidFormat("the body is unknown"))?;
+
+        let (date, rest) = rest
+            .split_once('T')
+            .ok_or(Error::InvalidFormat("the time is missing"))?;
+        let (rest, zone) = rest
+            .strip_suffix(']')
+            .and_then(|rest| rest.split_once('['))
+            .ok_or(Error::InvalidFormat("the timezone is missing"))?;
+        let split = rest
+            .len()
+            .checked_sub(6)
+            .filter(|split| rest.is_char_boundary(*split))
+            .ok_or(Error::InvalidFormat("the offset is malformed"))?;
+        let (clock, offset) = rest.split_at(split);
+
+        let (year, month, day) = parse_date(date)?;
+        let (hour, minute, second, millisecond) = parse_clock(clock)?;
+        let offset = parse_offset(offset)?;
+
+        if hour >= 24 {
+            return Err(Error::InvalidTime("the hour is outside of the sol"));
+        }
+
+        if minute >= 60 {
+            return Err(Error::InvalidTime("the minute is outside of the hour"));
+        }
+
+        if second >= 60 {
+            return Err(Error::InvalidTime("the second is outside of the minute"));
+        }
+
+        let zone = body.timezone(zone).ok_or(Error::UnknownTimeZone)?;
+
+        if (zone.offset() * 60.0).round() as i64 != offset {
+            return Err(Error::InvalidFormat(
+                "the offset doesn't match the timezone",
+            ));
+        }
+
+        if day < 1 || (day - 1) as f64 >= body.orbital_period() {
+            return Err(Error::InvalidDate("the day is outside of the year"));
+        }
+
+        let date = Date::from_ordinal(year as f64, day as f64, body)?;
+        let time = Time {
+            hour: hour as i32,
+            minute: minute as u8,
+            second: second as u8,
+            millisecond: millisecond as u16,
+            ..Time::default()
+        };
+
+        // the clock of the timezone may start its sols apart from the calendar,
+        // and the start of the sol is only found to about a sol, so the sols around it are tried as well
+        let start = body.to_julian(&date)?;
+        let sol = body.rotational_period_seconds().to_days().0;
+        let elapsed = (ms_of_sol(&time) - ms_of_sol(&zone.at(start))).rem_euclid(MS_PER_SOL);
+        let guess = start + (elapsed as f64 + 0.5) / MS_PER_SOL as f64 * sol;
+
+        let date_time = [0.0, -1.0, 1.0, -2.0, 2.0]
+            .into_iter()
+            .map(|sols| settle(body, zone.as_ref(), guess + sols * sol, &time))
+            .find(|candidate| {
+                candidate.date.year == date.year
+                    && candidate.date.day == date.day
+                    && ms_of_sol(&candidate.time) == ms_of_sol(&time)
+            })
+            .ok_or(Error::InvalidDate(
+                "the date and time don't exist in the timezone",
+            ))?;
+
+        if date_time.date.month != month as f64 {
+            return Err(Error::InvalidDate("the month doesn't match the day"));
+        }
+
+        Ok(date_time)
+    }
+}
+
+/// This function moves a julian date onto the millisecond of a time,
+/// since the clock rounds down and the sols of the clock aren't exactly the rotational period
+fn settle(body: &dyn Body, zone: &dyn TimeZone, julian_date: f64, time: &Time) -> DateTime {
+    let sol = body.rotational_period_seconds().to_days().0;
+    let mut julian_date = julian_date;
+    let mut date_time = body.to_datetime(julian_date, zone);
+
+    for _ in 0..3 {
+        let difference = (ms_of_sol(time) - ms_of_sol(&date_time.time) + MS_PER_SOL / 2)
+            .rem_euclid(MS_PER_SOL)
+            - MS_PER_SOL / 2;
+
+        if difference == 0 {
+            break;
+        }
+
+        julian_date += difference as f64 / MS_PER_SOL as f64 * sol;
+        date_time = body.to_datetime(julian_date, zone);
+    }
+
+    if ms_of_sol(&date_time.time) == ms_of_sol(time) {
+        return date_time;
+    }
+
+    // a step can land on either edge of the millisecond, so the last one is found in quarters
+    (-8..=8)
+        .map(|quarter| {
+            let step = f64::from(quarter) / 4.0 / MS_PER_SOL as f64 * sol;
+
+            body.to_datetime(julian_date + step, zone)
+        })
+        .find(|candidate| ms_of_sol(&candidate.time) == ms_of_sol(time))
+        .unwrap_or(date_time)
+}
+
+/// The milliseconds since the start of the sol
+fn ms_of_sol(time: &Time) -> i64 {
+    ((i64::from(time.hour) * 60 + i64::from(time.minute)) * 60 + i64::from(time.second)) * 1000
+        + i64::from(time.millisecond)
+}
+
+/// This function parses a number of digits, at least `width` of them when `exact` is false
+fn digits(text: &str, width: usize, exact: bool, error: &'static str) -> Result<i64> {
+    let valid = text.bytes().all(|byte| byte.is_ascii_digit())
+        && (text.len() == width || (!exact && text.len() > width && text.len() <= 18));
+
+    match valid {
+        true => text.parse().map_err(|_| Error::InvalidFormat(error)),
+        false => Err(Error::InvalidFormat(error)),
+    }
+}
+
+/// This function parses `[-]YYYY-MM-DDD`
+fn parse_date(text: &str) -> Result<(i64, i64, i64)> {
+    let (sign, text) = match text.strip_prefix('-') {
+        Some(text) => (-1, text),
+        None => (1, text),
+    };
+    let mut fields = text.split('-');
+    let year = digits(
+        fields.next().unwrap_or(""),
+        4,
+        false,
+        "the year is malformed",
+    )?;
+    let month = digits(
+        fields.next().unwrap_or(""),
+        2,
+        true,
+        "the month is malformed",
+    )?;
+    let day = digits(fields.next().unwrap_or(""), 3, true, "the day is malformed")?;
+
+    if fields.next().is_some() {
+        return Err(Error::InvalidFormat("the date has too many fields"));
+    }
+
+    Ok((sign * year, month, day))
+}
+
+/// This function parses `HH:MM:SS[.mmm]`
+fn parse_clock(text: &str) -> Result<(i64, i64, i64, i64)> {
+    let (text, millisecond) = match text.split_once('.') {
+        Some((text, millisecond)) => (
+            text,
+            digits(millisecond, 3, true, "the millisecond is malformed")?,
+        ),
+        None => (text, 0),
+    };
+    let mut fields = text.split(':');
+    let hour = digits(
+        fields.next().unwrap_or(""),
+        2,
+        true,
+        "the hour is malformed",
+    )?;
+    let minute = digits(
+        fields.next().unwrap_or(""),
+        2,
+        true,
+        "the minute is malformed",
+    )?;
+    let second = digits(
+        fields.next().unwrap_or(""),
+        2,
+        true,
+        "the second is malformed",
+    )?;
+
+    if fields.next().is_some() {
+        return Err(Error::InvalidFormat("the time has too many fields"));
+    }
+
+    Ok((hour, minute, second, millisecond))
+}
+
+/// This function parses `(+|-)HH:MM` into minutes
+fn parse_offset(text: &str) -> Result<i64> {
+    let (sign, text) = match (text.strip_prefix('+'), text.strip_prefix('-')) {
+        (Some(text), _) => (1, text),
+        (_, Some(text)) => (-1, text),
+        _ => return Err(Error::InvalidFormat("the offset is malformed")),
+    };
+    let (hours, minutes) = text
+        .split_once(':')
+        .ok_or(Error::InvalidFormat("the offset is malformed"))?;
+    let hours = digits(hours, 2, true, "the offset is malformed")?;
+    let minutes = digits(minutes, 2, true, "the offset is malformed")?;
+
+    if minutes >= 60 {
+        return Err(Error::InvalidFormat("the offset is malformed"));
+    }
+
+    Ok(sign * (hours * 60 + minutes))
+}
@@ -0,0 +1,108 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use alloc::vec::Vec;
+use core::{cmp::Ordering, f64::consts::TAU};
+
+use displaydoc::Display;
+
+use crate::{conversions::Seconds, error::Error};
+
+use super::Body;
+
+/// The relative difference allowed between the orbital period and the one of kepler's third law
+///
+/// * The catalog rounds its constants (ex; mars has a semimajor of 1.52), which is off by less than 1%
+/// * A constant in the wrong unit (ex; hours instead of seconds, or km instead of au) is off by far more
+pub const KEPLER_TOLERANCE: f64 = 0.01;
+
+/// The shortest sol that's sane, a minute in seconds
+pub const MIN_ROTATIONAL_PERIOD: f64 = 60.0;
+
+/// The longest sol that's sane, a julian year in seconds
+pub const MAX_ROTATIONAL_PERIOD: f64 = 31_557_600.0;
+
+/// This is a constant of a body that disagrees with the rest of the body, see [`validate`]
+///
+/// * The body may still compute dates, these are warnings of constants that are likely wrong.
+/// * More variants may come, so match with a `_` arm.
+#[derive(Display, Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum ConsistencyWarning {
+    /// the orbital period is {days} days, but kepler's third law expects {expected} days
+    KeplerThirdLaw {
+        /// The orbital period of the body in earth days
+        days: f64,
+        /// The orbital period of the semimajor axis and the gravitational parameter in earth days
+        expected: f64,
+    },
+    /// the eccentricity {0} isn't a closed orbit (from 0 up to 1)
+    Eccentricity(f64),
+    /// the rotational period of {0} seconds isn't between a minute and a year
+    RotationalPeriod(f64),
+    /// the perihelion can't be used: {0}
+    Perihelion(Error),
+    /// the semiminor axis {semiminor} is longer than the semimajor axis {semimajor}
+    Semiminor {
+        /// The [`Body::semiminor`] axis in astronomical units
+        semiminor: f64,
+        /// The [`Body::semimajor`] axis in astronomical units
+        semimajor: f64,
+    },
+}
+
+/// This function checks that the constants of a body agree with each other
+///
+/// * The orbital period follows kepler's third law within [`KEPLER_TOLERANCE`]
+/// * The eccentricity is a closed orbit, from 0 up to 1
+/// * The rotational period is between [`MIN_ROTATIONAL_PERIOD`] and [`MAX_ROTATIONAL_PERIOD`]
+/// * The perihelion is valid, see [`crate::orbit::Perihelion::validate`]
+/// * The [`Body::semiminor`] axis isn't longer than the [`Body::semimajor`] axis
+///
+/// A NaN or infinite constant fails every check it's part of.
+///
+/// > $$T = 2\pi\sqrt{\frac{a^3}{GM}}$$
+///
+/// ```rust
+/// use rust_solar::{bodies, kepler::validate};
+///
+/// for body in bodies::registry() {
+///     assert!(validate(*body).is_empty(), "{}", body.name());
+/// }
+/// ```
+pub fn validate(body: &(impl Body + ?Sized)) -> Vec<ConsistencyWarning> {
+    let mut warnings = Vec::new();
+    let days = body.orbital_period_days().0;
+    let semimajor_km = body.semimajor_au().to_km();
+    let expected = Seconds(TAU * (semimajor_km * semimajor_km * semimajor_km / body.gravitational_parameter()).sqrt())
+        .to_days()
+        .0;
+
+    if !(0.0..=expected * KEPLER_TOLERANCE).contains(&(days - expected).abs()) {
+        warnings.push(ConsistencyWarning::KeplerThirdLaw { days, expected });
+    }
+
+    let orbital_eccentricity = body.orbital_eccentricity();
+
+    if !(0.0..1.0).contains(&orbital_eccentricity) {
+        warnings.push(ConsistencyWarning::Eccentricity(orbital_eccentricity));
+    }
+
+    let rotational_period = body.rotational_period();
+
+    if !(MIN_ROTATIONAL_PERIOD..=MAX_ROTATIONAL_PERIOD).contains(&rotational_period) {
+        warnings.push(ConsistencyWarning::RotationalPeriod(rotational_period));
+    }
+
+    if let Err(error) = body.perihelion().validate() {
+        warnings.push(ConsistencyWarning::Perihelion(error));
+    }
+
+    let (semiminor, semimajor) = (body.semiminor(), body.semimajor());
+
+    if matches!(semiminor.partial_cmp(&semimajor), Some(Ordering::Greater) | None) {
+        warnings.push(ConsistencyWarning::Semiminor { semiminor, semimajor });
+    }
+
+    warnings
+}
@@ -0,0 +1,429 @@
+use alloc::{borrow::Cow, boxed::Box};
+
+use crate::{
+    clock::{parse_offset, BodyClock, ZoneInfo},
+    error::{Error, Result},
+    julian::{Epoch, JulianDate, SplitJd, TimeScale},
+    orbit::{self, Perihelion, SeasonTable},
+};
+
+use super::{Body, BodyCache, Date, Discovery, Rotation, Time, TimeZone};
+
+/// This is a body whose elements are only known at runtime, like a newly discovered asteroid
+///
+/// * It's created through [`CustomBody::builder`], so its elements are always valid.
+/// * It can be registered next to the built-in bodies, see [`crate::bodies::register`]
+///
+/// ```rust
+/// use rust_solar::{kepler::{Body, CustomBody}, orbit::Perihelion};
+///
+/// let vulcan = CustomBody::builder()
+///     .name("Vulcan")
+///     .epoch(2_451_545.0)
+///     .orbital_eccentricity(0.02)
+///     .orbital_period(120.0)
+///     .rotational_period(100_000.0)
+///     .semimajor(0.3)
+///     .perihelion(Perihelion::new((40.0, 50.0), (90.0, 120.0), 100.0).unwrap())
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(vulcan.name(), "Vulcan");
+/// assert_eq!(vulcan.to_date(2_451_545.0).year, 1.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomBody {
+    name: Cow<'static, str>,
+    epoch: f64,
+    epoch_scale: TimeScale,
+    epoch_year: f64,
+    week_anchor: f64,
+    orbital_eccentricity: f64,
+    orbital_period: f64,
+    rotational_period: f64,
+    rotation_direction: Rotation,
+    semimajor: f64,
+    perihelion: Perihelion,
+    axial_tilt: f64,
+    inclination: f64,
+    ascending_node: f64,
+    discovery: Option<Discovery>,
+    season_table: Option<SeasonTable>,
+    timezones: Cow<'static, [ZoneInfo]>,
+    cache: BodyCache,
+}
+
+impl CustomBody {
+    /// This method starts a builder, see [`CustomBodyBuilder`]
+    pub fn builder() -> CustomBodyBuilder {
+        CustomBodyBuilder::default()
+    }
+
+    /// This method returns the time of a julian date (UTC) in a timezone of the body
+    ///
+    /// * The timezone is found by its code, ignoring case
+    /// * The sols are counted from the epoch of the body, see [`BodyClock::from_body`]
+    pub fn time_at(&self, julian_date: f64, code: &str) -> Result<Time> {
+        let zone = self.zone(code).ok_or(Error::UnknownTimeZone)?;
+
+        if !julian_date.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        Ok(zone.at(julian_date))
+    }
+
+    /// This method finds a timezone of the body by its code, ignoring case, or by its offset (ex; `+02:30`)
+    fn zone(&self, code: &str) -> Option<CustomZone> {
+        let offset = parse_offset(code).filter(|(prefix, _)| prefix.is_empty());
+
+        self.timezones
+            .iter()
+            .find(|info| {
+                info.code.eq_ignore_ascii_case(code)
+                    || offset.is_some_and(|(_, hours)| info.has_offset(hours))
+            })
+            .map(|info| CustomZone {
+                clock: BodyClock::from_body(self.clone()),
+                info: *info,
+            })
+    }
+}
+
+/// This is a timezone of a [`CustomBody`], on a clock that counts sols from the epoch of the body
+#[derive(Debug, Clone)]
+struct CustomZone {
+    clock: BodyClock<CustomBody>,
+    info: ZoneInfo,
+}
+
+impl TimeZone for CustomZone {
+    fn at(&self, julian_date: f64) -> Time {
+        self.at_split(julian_date.into())
+    }
+
+    fn at_split(&self, julian_date: SplitJd) -> Time {
+        self.info
+            .stamp(self.clock.at(julian_date, self.info.offset), self.info.code)
+    }
+
+    fn offset(&self) -> f64 {
+        self.info.offset
+    }
+
+    fn fractional_hours(&self, julian_date: f64) -> f64 {
+        self.clock.fractional_hours(julian_date, self.info.offset)
+    }
+
+    fn sol_carry(&self, julian_date: f64) -> i64 {
+        self.clock.sol_carry(julian_date, self.info.offset)
+    }
+}
+
+impl Body for CustomBody {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn epoch(&self) -> f64 {
+        self.epoch
+    }
+
+    fn epoch_scale(&self) -> TimeScale {
+        self.epoch_scale
+    }
+
+    fn epoch_year(&self) -> f64 {
+        self.epoch_year
+    }
+
+    fn week_anchor(&self) -> f64 {
+        self.week_anchor
+    }
+
+    fn discovery(&self) -> Option<Discovery> {
+        self.discovery
+    }
+
+    fn orbital_eccentricity(&self) -> f64 {
+        self.orbital_eccentricity
+    }
+
+    fn orbital_period(&self) -> f64 {
+        self.orbital_period
+    }
+
+    fn rotational_period(&self) -> f64 {
+        self.rotational_period
+    }
+
+    fn perihelion(&self) -> Perihelion {
+        self.perihelion
+    }
+
+    fn semimajor(&self) -> f64 {
+        self.semimajor
+    }
+
+    fn axial_tilt(&self) -> f64 {
+        self.axial_tilt
+    }
+
+    fn rotation_direction(&self) -> Rotation {
+        self.rotation_direction
+    }
+
+    fn inclination(&self) -> f64 {
+        self.inclination
+    }
+
+    fn ascending_node(&self) -> f64 {
+        self.ascending_node
+    }
+
+    fn cache(&self) -> Result<BodyCache> {
+        Ok(self.cache)
+    }
+
+    fn season_table(&self) -> Option<SeasonTable> {
+        self.season_table.clone()
+    }
+
+    fn timezone(&self, code: &str) -> Option<Box<dyn TimeZone>> {
+        self.zone(code)
+            .map(|zone| Box::new(zone) as Box<dyn TimeZone>)
+    }
+
+    fn timezones(&self) -> &[ZoneInfo] {
+        &self.timezones
+    }
+
+    fn to_time(&self, _date: Date) -> Time {
+        Time::default()
+    }
+}
+
+/// This is the builder of a [`CustomBody`]
+///
+/// * The orbit, the rotation and the perihelion are required, everything else has a default.
+///
+#[derive(Debug, Clone, Default)]
+pub struct CustomBodyBuilder {
+    name: Option<Cow<'static, str>>,
+    epoch: Option<f64>,
+    epoch_scale: Option<TimeScale>,
+    epoch_year: Option<f64>,
+    week_anchor: Option<f64>,
+    orbital_eccentricity: Option<f64>,
+    orbital_period: Option<f64>,
+    rotational_period: Option<f64>,
+    rotation_direction: Rotation,
+    semimajor: Option<f64>,
+    perihelion: Option<Perihelion>,
+    axial_tilt: Option<f64>,
+    inclination: Option<f64>,
+    ascending_node: Option<f64>,
+    discovery: Option<Discovery>,
+    season_table: Option<SeasonTable>,
+    timezones: Cow<'static, [ZoneInfo]>,
+}
+
+impl CustomBodyBuilder {
+    /// The name of the body, `"Custom"` by default
+    ///
+    /// * It's a `&'static str` or an owned `String`, so names read at runtime don't have to live forever.
+    pub fn name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The julian date where the calendar of the body starts, J2000 by default
+    pub fn epoch(mut self, epoch: f64) -> Self {
+        self.epoch = Some(epoch);
+        self
+    }
+
+    /// The time scale of the epoch, UTC by default, see [`Body::epoch_scale`]
+    pub fn epoch_scale(mut self, epoch_scale: TimeScale) -> Self {
+        self.epoch_scale = Some(epoch_scale);
+        self
+    }
+
+    /// The year that begins at the epoch, 1 by default, see [`Body::epoch_year`]
+    pub fn epoch_year(mut self, epoch_year: f64) -> Self {
+        self.epoch_year = Some(epoch_year);
+        self
+    }
+
+    /// The sol that starts the weeks, 0 by default, see [`Body::week_anchor`]
+    pub fn week_anchor(mut self, week_anchor: f64) -> Self {
+        self.week_anchor = Some(week_anchor);
+        self
+    }
+
+    /// The eccentricity of the orbit, from 0 up
+    pub fn orbital_eccentricity(mut self, orbital_eccentricity: f64) -> Self {
+        self.orbital_eccentricity = Some(orbital_eccentricity);
+        self
+    }
+
+    /// The sols it takes the body to orbit, see [`Body::orbital_period`]
+    pub fn orbital_period(mut self, orbital_period: f64) -> Self {
+        self.orbital_period = Some(orbital_period);
+        self
+    }
+
+    /// The seconds it takes the body to rotate, see [`Body::rotational_period`]
+    pub fn rotational_period(mut self, rotational_period: f64) -> Self {
+        self.rotational_period = Some(rotational_period);
+        self
+    }
+
+    /// The direction the body spins, prograde by default, see [`Body::rotation_direction`]
+    pub fn rotation_direction(mut self, rotation_direction: Rotation) -> Self {
+        self.rotation_direction = rotation_direction;
+        self
+    }
+
+    /// The semimajor axis of the orbit in AU
+    pub fn semimajor(mut self, semimajor: f64) -> Self {
+        self.semimajor = Some(semimajor);
+        self
+    }
+
+    /// The perihelion data of the orbit
+    pub fn perihelion(mut self, perihelion: Perihelion) -> Self {
+        self.perihelion = Some(perihelion);
+        self
+    }
+
+    /// The axial tilt in degrees, 0 by default
+    pub fn axial_tilt(mut self, axial_tilt: f64) -> Self {
+        self.axial_tilt = Some(axial_tilt);
+        self
+    }
+
+    /// The inclination of the orbit to the ecliptic in degrees, 0 by default, see [`Body::inclination`]
+    pub fn inclination(mut self, inclination: f64) -> Self {
+        self.inclination = Some(inclination);
+        self
+    }
+
+    /// The longitude of the ascending node in degrees, 0 by default, see [`Body::ascending_node`]
+    ///
+    /// * The longitude of the perihelion follows the solar longitude of the perihelion, see [`Body::longitude_of_perihelion`]
+    pub fn ascending_node(mut self, ascending_node: f64) -> Self {
+        self.ascending_node = Some(ascending_node);
+        self
+    }
+
+    /// The discovery of the body, none by default, see [`Body::discovery`]
+    pub fn discovery(mut self, discovery: Discovery) -> Self {
+        self.discovery = Some(discovery);
+        self
+    }
+
+    /// The custom seasons of the body, the ones of [`orbit::Season::from_ls`] by default, see [`Body::season_table`]
+    pub fn season_table(mut self, season_table: SeasonTable) -> Self {
+        self.season_table = Some(season_table);
+        self
+    }
+
+    /// The timezones of the body from west to east, none by default
+    ///
+    /// * They're a `&'static` slice or an owned `Vec`.
+    pub fn timezones(mut self, timezones: impl Into<Cow<'static, [ZoneInfo]>>) -> Self {
+        self.timezones = timezones.into();
+        self
+    }
+
+    /// This method checks the elements and creates the body
+    ///
+    /// * The eccentricity must be 0 or more, and the periods and the semimajor axis must be positive.
+    pub fn build(self) -> Result<CustomBody> {
+        let orbital_eccentricity = self
+            .orbital_eccentricity
+            .ok_or(Error::InvalidOrbit("the eccentricity is missing"))?;
+        let orbital_period = self
+            .orbital_period
+            .ok_or(Error::InvalidOrbit("the orbital period is missing"))?;
+        let rotational_period = self
+            .rotational_period
+            .ok_or(Error::InvalidOrbit("the rotational period is missing"))?;
+        let semimajor = self
+            .semimajor
+            .ok_or(Error::InvalidOrbit("the semimajor axis is missing"))?;
+        let perihelion = self
+            .perihelion
+            .ok_or(Error::InvalidPerihelion("the perihelion is missing"))?;
+
+        let epoch = self.epoch.unwrap_or(Epoch::J2000.jd());
+        let epoch_scale = self.epoch_scale.unwrap_or_default();
+        let epoch_year = self.epoch_year.unwrap_or(1.0);
+        let week_anchor = self.week_anchor.unwrap_or(0.0);
+        let axial_tilt = self.axial_tilt.unwrap_or(0.0);
+        let inclination = self.inclination.unwrap_or(0.0);
+        let ascending_node = self.ascending_node.unwrap_or(0.0);
+
+        if !epoch.is_finite()
+            || !epoch_year.is_finite()
+            || !week_anchor.is_finite()
+            || !rotational_period.is_finite()
+            || !axial_tilt.is_finite()
+            || !inclination.is_finite()
+            || !ascending_node.is_finite()
+            || self.discovery.is_some_and(|discovery| !discovery.jd.is_finite())
+        {
+            return Err(Error::NonFinite);
+        }
+
+        orbit::validate(orbital_eccentricity, orbital_period, semimajor)?;
+
+        if rotational_period <= 0.0 {
+            return Err(Error::InvalidOrbit("the rotational period isn't positive"));
+        }
+
+        if semimajor <= 0.0 {
+            return Err(Error::InvalidOrbit("the semimajor axis isn't positive"));
+        }
+
+        perihelion.validate()?;
+
+        if self.timezones.iter().any(|info| {
+            !info.offset.is_finite() || !info.east.is_finite() || !info.west.is_finite()
+        }) {
+            return Err(Error::InvalidTimeZone(
+                "the offset or the longitudes aren't finite",
+            ));
+        }
+
+        Ok(CustomBody {
+            name: self.name.unwrap_or(Cow::Borrowed("Custom")),
+            epoch,
+            epoch_scale,
+            epoch_year,
+            week_anchor,
+            orbital_eccentricity,
+            orbital_period,
+            rotational_period,
+            rotation_direction: self.rotation_direction,
+            semimajor,
+            perihelion,
+            axial_tilt,
+            inclination,
+            ascending_node,
+            discovery: self.discovery,
+            season_table: self.season_table,
+            timezones: self.timezones,
+            cache: BodyCache::from_elements(
+                JulianDate::new(epoch, epoch_scale).to_scale(TimeScale::UTC).value,
+                epoch_year,
+                rotational_period,
+                perihelion,
+                semimajor,
+                orbital_eccentricity,
+                orbital_period,
+            )?,
+        })
+    }
+}
@@ -0,0 +1,170 @@
+use core::{
+    fmt,
+    ops::{Add, Neg, Sub},
+    time::Duration,
+};
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    conversions::Seconds,
+    error::{Error, Result},
+};
+
+use super::Body;
+
+/// The seconds of the 24 hour clock of a sol, see [`super::Time::from_sol_fraction`]
+const SECONDS_PER_SOL: f64 = 86_400.0;
+
+/// This is a length of time on a body, in sols and the seconds of the 24 hour clock of a sol
+///
+/// * An hour is a 24th of the sol of the body, not an earth hour (ex; about 61.6 minutes on mars),
+///   so a duration only becomes earth time with a body, see [`SolDuration::to_duration`].
+/// * The seconds are always within the sol, from 0 up to 86400, so a negative duration has negative sols
+///   (ex; half a sol back is -1 sols and 43200 seconds).
+///
+/// ```rust
+/// use rust_solar::kepler::SolDuration;
+///
+/// let duration = SolDuration::from_sols(3.0) + SolDuration::from_hours(4.0) + SolDuration::from_minutes(12.5);
+///
+/// assert_eq!("3 sols 04:12:30", duration.to_string());
+/// assert_eq!(SolDuration::new(3, 15_150.0), duration);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SolDuration {
+    /// The whole sols
+    pub sols: i64,
+    /// The seconds of the clock after the whole sols, from 0 up to 86400
+    pub seconds: f64,
+}
+
+impl SolDuration {
+    /// This method creates a duration, the seconds past a sol are carried into the sols
+    pub fn new(sols: i64, seconds: f64) -> Self {
+        let carry = (seconds / SECONDS_PER_SOL).floor();
+        let mut seconds = seconds - carry * SECONDS_PER_SOL;
+        let mut sols = sols + carry as i64;
+
+        // the subtraction can round up to a whole sol
+        if seconds >= SECONDS_PER_SOL {
+            seconds -= SECONDS_PER_SOL;
+            sols += 1;
+        }
+
+        Self { sols, seconds }
+    }
+
+    /// This method creates a duration from sols with their fraction
+    pub fn from_sols(sols: f64) -> Self {
+        let whole = sols.floor();
+
+        Self::new(whole as i64, (sols - whole) * SECONDS_PER_SOL)
+    }
+
+    /// This method creates a duration from the hours of the clock, a 24th of a sol each
+    pub fn from_hours(hours: f64) -> Self {
+        Self::new(0, hours * 3_600.0)
+    }
+
+    /// This method creates a duration from the minutes of the clock
+    pub fn from_minutes(minutes: f64) -> Self {
+        Self::new(0, minutes * 60.0)
+    }
+
+    /// This method creates a duration from an earth duration on a body, the inverse of [`SolDuration::to_duration`]
+    pub fn from_duration(duration: Duration, body: &(impl Body + ?Sized)) -> Self {
+        Self::from_sols(duration.as_secs_f64() / body.rotational_period())
+    }
+
+    /// This method returns the sols with their fraction
+    pub fn as_sols(&self) -> f64 {
+        self.sols as f64 + self.seconds / SECONDS_PER_SOL
+    }
+
+    /// This method returns the earth time of the duration on a body, the sols are [`Body::rotational_period`] long
+    ///
+    /// * It fails with [`Error::InvalidTime`] when the duration is negative, since an earth duration can't be.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::SolDuration, planets::mars::Mars};
+    ///
+    /// let sol = SolDuration::from_sols(1.0).to_duration(&Mars).unwrap();
+    ///
+    /// // 24 hours, 39 minutes and 35 seconds
+    /// assert_eq!(88_775, sol.as_secs());
+    /// # }
+    /// ```
+    pub fn to_duration(&self, body: &(impl Body + ?Sized)) -> Result<Duration> {
+        let seconds = (body.rotational_period_seconds() * self.as_sols()).0;
+
+        if !seconds.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        if seconds < 0.0 {
+            return Err(Error::InvalidTime("the duration is negative"));
+        }
+
+        Ok(Duration::from_secs_f64(seconds))
+    }
+
+    /// This method returns the duration in SI seconds on a body, negative when the duration is
+    pub fn to_seconds(&self, body: &(impl Body + ?Sized)) -> Seconds {
+        body.rotational_period_seconds() * self.as_sols()
+    }
+}
+
+impl Add for SolDuration {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.sols + other.sols, self.seconds + other.seconds)
+    }
+}
+
+impl Sub for SolDuration {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Neg for SolDuration {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.sols, -self.seconds)
+    }
+}
+
+impl fmt::Display for SolDuration {
+    /// 3 sols 04:12:09
+    ///
+    /// * A negative duration is written as the negative of its length (ex; -0 sols 12:00:00 for half a sol back)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (sign, length) = match self.sols < 0 {
+            true => ("-", -*self),
+            false => ("", *self),
+        };
+        let seconds = length.seconds.floor() as i64;
+
+        write!(
+            f,
+            "{sign}{} {} {:02}:{:02}:{:02}",
+            length.sols,
+            match length.sols == 1 {
+                true => "sol",
+                false => "sols",
+            },
+            seconds / 3_600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    }
+}
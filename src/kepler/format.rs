@@ -0,0 +1,179 @@
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+
+use super::{Date, DateTime};
+
+/// The names of the months of the solar longitude, the signs of the zodiac the sun is in from the vernal equinox
+///
+/// * Each month is 30 degrees of solar longitude, see [`Date::month`]
+pub const MONTH_NAMES: [&str; 12] = [
+    "Mesha", "Rishabha", "Mithuna", "Karka", "Simha", "Kanya", "Tula", "Vrishika", "Dhanus",
+    "Makara", "Kumbha", "Mina",
+];
+
+/// This is where the era is written, see [`FormatOptions`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum EraPosition {
+    /// Before the year (ex; AD 0036-06-360)
+    #[default]
+    Before,
+    /// After the year (ex; 0036-06-360 AD)
+    After,
+}
+
+/// These are the options of [`Date::format_with`] and [`DateTime::format_with`]
+///
+/// * The default options write the same as [`Date`] does with `{}`, so `format!("{date}")` doesn't change.
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::{kepler::{Body, FormatOptions}, planets::mars::Mars};
+///
+/// let date = Mars.to_date(2451545.0);
+///
+/// assert_eq!(date.to_string(), date.format_with(&FormatOptions::default()));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FormatOptions {
+    /// The digits the year is padded to with zeros, 4 by default
+    pub pad_year: usize,
+    /// The decimals of the solar longitude, 1 by default
+    pub decimal_ls: usize,
+    /// Whether the era is in upper case (ex; `AD` or `ad`), true by default
+    pub uppercase_era: bool,
+    /// Whether the month is written by its name in words, see [`MONTH_NAMES`], false by default
+    ///
+    /// * The date is written as prose (ex; `AD Year 24, Month of Makara, Sol 522`),
+    ///   a month outside of the names is written by its number (ex; `Month 13`).
+    pub month_names: bool,
+    /// Where the era is written, before the year by default
+    pub era: EraPosition,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            pad_year: 4,
+            decimal_ls: 1,
+            uppercase_era: true,
+            month_names: false,
+            era: EraPosition::Before,
+        }
+    }
+}
+
+impl Date {
+    /// This method writes the date with options, for tables and for prose, see [`FormatOptions`]
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::{Body, EraPosition, FormatOptions}, planets::mars::Mars};
+    ///
+    /// let date = Mars.to_date(2451545.0);
+    /// let table = FormatOptions { pad_year: 5, decimal_ls: 3, ..FormatOptions::default() };
+    /// let prose = FormatOptions { pad_year: 0, decimal_ls: 0, month_names: true, era: EraPosition::After, ..FormatOptions::default() };
+    ///
+    /// assert_eq!("AD 00024-10-522 Ls 274.262 (Winter Solstice)", date.format_with(&table));
+    /// assert_eq!("Year 24 AD, Month of Makara, Sol 522 Ls 274 (Winter Solstice)", date.format_with(&prose));
+    /// # }
+    /// ```
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        let mut text = String::new();
+
+        self.write_with(&mut text, options)
+            .expect("A string to be writable");
+        text
+    }
+
+    /// This method writes the date with options, the [`fmt::Display`] of a date is the default options
+    pub(super) fn write_with(&self, out: &mut impl Write, options: &FormatOptions) -> fmt::Result {
+        let era: &str = self.era.as_ref();
+        let year = Year(self.year as i64, options.pad_year);
+
+        if options.era == EraPosition::Before {
+            write_era(out, era, options.uppercase_era)?;
+            out.write_char(' ')?;
+        }
+
+        match options.month_names {
+            true => write!(out, "Year {year}")?,
+            false => write!(
+                out,
+                "{year}-{:02}-{:03}",
+                self.month as i64, self.day as i64
+            )?,
+        }
+
+        if options.era == EraPosition::After {
+            out.write_char(' ')?;
+            write_era(out, era, options.uppercase_era)?;
+        }
+
+        if options.month_names {
+            match MONTH_NAMES.get((self.month as usize).wrapping_sub(1)) {
+                Some(name) => write!(out, ", Month of {name}")?,
+                None => write!(out, ", Month {}", self.month as i64)?,
+            }
+
+            write!(out, ", Sol {}", self.day as i64)?;
+        }
+
+        write!(
+            out,
+            " Ls {:.decimals$} ({})",
+            shown_ls(self.ls, options.decimal_ls),
+            self.season_str(),
+            decimals = options.decimal_ls
+        )
+    }
+}
+
+impl DateTime {
+    /// This method writes the date time with options, the time is written like [`crate::kepler::Time`] does with `{}`
+    ///
+    /// * See [`Date::format_with`]
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        let mut text = self.date.format_with(options);
+
+        write!(text, " {}", self.time).expect("A string to be writable");
+        text
+    }
+}
+
+/// This is a year padded with zeros to a width, the sign is part of the width like `{:04}`
+struct Year(i64, usize);
+
+impl fmt::Display for Year {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:0width$}", self.0, width = self.1)
+    }
+}
+
+/// This function writes an era in upper or lower case
+fn write_era(out: &mut impl Write, era: &str, uppercase: bool) -> fmt::Result {
+    match uppercase {
+        true => out.write_str(era),
+        false => era
+            .chars()
+            .try_for_each(|c| out.write_char(c.to_ascii_lowercase())),
+    }
+}
+
+/// This function returns the ls as it's shown with its decimals, where a rounding up to 360 is shown as 0
+fn shown_ls(ls: f64, decimals: usize) -> f64 {
+    let scale = 10_u64.pow(decimals.min(15) as u32) as f64;
+
+    match (ls * scale).round() >= 360.0 * scale {
+        true => 0.0,
+        false => ls,
+    }
+}
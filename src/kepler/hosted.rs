@@ -0,0 +1,166 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use core::f64::consts::TAU;
+
+use crate::{
+    anomaly::elliptical_newton,
+    conversions::{deg_to_rad, normalize_degrees, rad_to_deg, Days, Seconds},
+    error::{Error, Result},
+};
+
+use super::{angular_diameter, locked_solar_day, Body, GRAVITATIONAL_CONSTANT};
+
+/// This trait is a body that orbits another body instead of the sun, like a moon around its planet
+///
+/// ## Two orbits
+/// The [`Body`] of a hosted body is the orbit of its host around the sun, so the year, the seasons
+/// and the solar longitude (Ls) of its dates are the ones of the host (ex; luna shares the year of earth).
+/// Its [`Body::gravitational_parameter`] stays the sun's, which is what that orbit follows.
+///
+/// The orbit around the host is this trait, and it uses the gravitational parameter of the host:
+///
+/// * [`HostedBody::host_semimajor_km`] and [`HostedBody::host_orbital_eccentricity`] are its shape
+/// * [`HostedBody::sidereal_period`] and [`HostedBody::orbital_speed`] follow the mass of the host
+/// * [`HostedBody::host_longitude`] is where the body is on it
+/// * [`HostedBody::host_mean_anomaly`], [`HostedBody::host_eccentric_anomaly`], [`HostedBody::host_true_anomaly`]
+///   and [`HostedBody::host_distance_km`] are the kepler orbit around the host, override the mean anomaly
+///   when the body has a better series than the mean motion of [`HostedBody::host_periapsis_jd`]
+///
+/// ## The host longitude
+/// The solar longitude of a hosted body is measured from the vernal equinox of the host, it doesn't move as the
+/// body goes around its host. The position around the host is the host longitude instead, the angle
+/// from the host–sun line to the body, seen from the host, in the direction of the orbit:
+///
+/// * 0 is a conjunction, the body is between its host and the sun (ex; a new moon)
+/// * 180 is an opposition, the host is between the body and the sun (ex; a full moon)
+///
+/// ```rust
+/// # #[cfg(feature = "luna")]
+/// # {
+/// use rust_solar::{kepler::HostedBody, moons::luna::Luna};
+///
+/// assert_eq!(Luna.host().name(), "Earth");
+/// // the moon goes around earth at about 1 km/s
+/// assert!((Luna.mean_orbital_speed() - 1.02).abs() < 0.01);
+/// # }
+/// ```
+pub trait HostedBody: Body {
+    /// The body that this body orbits
+    fn host(&self) -> &'static dyn Body;
+    /// Calculates the average distance of this body from the center of its host in kilometers.
+    fn host_semimajor_km(&self) -> f64;
+    /// Calculates the eccentricity of the orbit around the host.
+    fn host_orbital_eccentricity(&self) -> f64 {
+        0.0
+    }
+    /// Calculates the gravitational parameter (GM) in km³/s² of the orbit around the host.
+    ///
+    /// * This is the mass of the host and of this body, the mass of a small moon is left out when it isn't known.
+    /// * This is NaN when the mass of the host isn't known.
+    ///
+    /// > $$\mu = G(M + m)$$
+    fn host_gravitational_parameter(&self) -> f64 {
+        self.host().mass_kg().map_or(f64::NAN, |host| {
+            GRAVITATIONAL_CONSTANT * (host + self.mass_kg().unwrap_or(0.0))
+        })
+    }
+    /// Calculates the time to go once around the host against the stars, in earth days.
+    ///
+    /// > $$T = 2\pi\sqrt{\frac{a^3}{\mu}}$$
+    fn sidereal_period(&self) -> Days {
+        let semimajor = self.host_semimajor_km();
+
+        Seconds(
+            TAU * (semimajor * semimajor * semimajor / self.host_gravitational_parameter()).sqrt(),
+        )
+        .to_days()
+    }
+    /// Calculates the sol in earth days when the body is locked to its host, see [`super::locked_solar_day`]
+    ///
+    /// * It's the [`HostedBody::sidereal_period`] against the year of the host (ex; about 29.5 days for luna).
+    fn locked_solar_day(&self) -> Days {
+        Days(locked_solar_day(
+            self.sidereal_period().0,
+            Some(self.host().orbital_period_earth_days()),
+        ))
+    }
+    /// Calculates the speed in km/s around the host at a distance from the center of the host.
+    ///
+    /// > $$v = \sqrt{\mu\left(\frac{2}{r} - \frac{1}{a}\right)}$$
+    fn orbital_speed(&self, distance_km: f64) -> f64 {
+        (self.host_gravitational_parameter() * (2.0 / distance_km - 1.0 / self.host_semimajor_km()))
+            .sqrt()
+    }
+    /// Calculates the speed in km/s around the host at the semimajor axis.
+    ///
+    /// > $$v = \sqrt{\frac{\mu}{a}}$$
+    fn mean_orbital_speed(&self) -> f64 {
+        self.orbital_speed(self.host_semimajor_km())
+    }
+    /// Calculates the apparent diameter of the host in degrees at the semimajor axis, if the radius of the host is known.
+    ///
+    /// * It's seen from the center of the body, see [`super::angular_diameter`] (ex; about 2 degrees of earth from luna).
+    fn host_angular_diameter(&self) -> Option<f64> {
+        self.host()
+            .mean_radius_km()
+            .map(|radius| angular_diameter(radius, self.host_semimajor_km()))
+    }
+    /// Calculates the host longitude in degrees at a julian date (UTC), from 0 up to 360
+    ///
+    /// * See the definition of [`HostedBody`]
+    fn host_longitude(&self, julian_date: f64) -> Result<f64>;
+    /// The julian date (UTC) of a pass through the periapsis, the point of the orbit closest to the host
+    ///
+    /// * It anchors the default [`HostedBody::host_mean_anomaly`], it's the epoch of the body unless it's overridden.
+    fn host_periapsis_jd(&self) -> f64 {
+        self.epoch()
+    }
+    /// Calculates the mean motion around the host in degrees per earth day.
+    ///
+    /// > $$n = \frac{360}{T}$$
+    fn host_mean_motion(&self) -> f64 {
+        360.0 / self.sidereal_period().0
+    }
+    /// Calculates the mean anomaly around the host in degrees at a julian date (UTC), from 0 up to 360
+    ///
+    /// > $$M = n(t - t_p)$$
+    fn host_mean_anomaly(&self, julian_date: f64) -> Result<f64> {
+        if !julian_date.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        Ok(normalize_degrees(
+            self.host_mean_motion() * (julian_date - self.host_periapsis_jd()),
+        ))
+    }
+    /// Calculates the eccentric anomaly around the host in degrees at a julian date (UTC), from 0 up to 360
+    ///
+    /// * It solves the kepler equation of [`HostedBody::host_orbital_eccentricity`], see [`elliptical_newton`]
+    fn host_eccentric_anomaly(&self, julian_date: f64) -> Result<f64> {
+        let mean_anomaly = deg_to_rad(self.host_mean_anomaly(julian_date)?);
+
+        elliptical_newton(mean_anomaly, self.host_orbital_eccentricity())
+            .map(|anomaly| normalize_degrees(rad_to_deg(anomaly)))
+    }
+    /// Calculates the true anomaly around the host in degrees at a julian date (UTC), from 0 up to 360
+    ///
+    /// > $$\nu = 2\arctan\left(\sqrt{\frac{1+e}{1-e}}\tan\frac{E}{2}\right)$$
+    fn host_true_anomaly(&self, julian_date: f64) -> Result<f64> {
+        let eccentricity = self.host_orbital_eccentricity();
+        let eccentric = deg_to_rad(self.host_eccentric_anomaly(julian_date)?);
+
+        Ok(normalize_degrees(rad_to_deg(
+            2.0 * (((1.0 + eccentricity) / (1.0 - eccentricity)).sqrt() * (eccentric / 2.0).tan())
+                .atan(),
+        )))
+    }
+    /// Calculates the distance in kilometers from the center of the host at a julian date (UTC)
+    ///
+    /// > $$r = a(1 - e\cos E)$$
+    fn host_distance_km(&self, julian_date: f64) -> Result<f64> {
+        let eccentric = deg_to_rad(self.host_eccentric_anomaly(julian_date)?);
+
+        Ok(self.host_semimajor_km() * (1.0 - self.host_orbital_eccentricity() * eccentric.cos()))
+    }
+}
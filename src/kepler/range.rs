@@ -0,0 +1,140 @@
+use core::iter::FusedIterator;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    error::{Error, Result},
+    orbit::SeasonTable,
+};
+
+use super::{Body, BodyCache, Date};
+
+/// This is an iterator over the dates of a body between two julian dates, see [`Body::dates_between`]
+///
+/// * It yields `(julian_date, date)` pairs, from the start towards the end.
+/// * The last julian date is at or before the end, it never steps past it.
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::{kepler::Body, planets::mars::Mars};
+///
+/// let sols: Vec<_> = Mars.dates_between(2451545.0, 2451555.0, 1.0).collect();
+///
+/// assert_eq!(10, sols.len());
+/// assert_eq!(sols[0].1.day + 1.0, sols[1].1.day);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DateRange {
+    cache: BodyCache,
+    season_table: Option<SeasonTable>,
+    start: f64,
+    step: f64,
+    front: u64,
+    back: u64,
+}
+
+impl DateRange {
+    /// The step in sols when none is given, see [`Body::dates_between`]
+    pub const DEFAULT_STEP: f64 = 1.0;
+
+    /// This method prepares the body once and counts the steps between the julian dates
+    ///
+    /// # Panics
+    ///
+    /// When the julian dates or the body can't be computed, see [`Body::try_to_date`]
+    pub(super) fn new(
+        body: &(impl Body + ?Sized),
+        start_jd: f64,
+        end_jd: f64,
+        step_sols: f64,
+    ) -> Self {
+        Self::try_new(body, start_jd, end_jd, step_sols)
+            .expect("The julian date and the body to be valid")
+    }
+
+    /// This method is [`DateRange::new`], or an error when the julian dates or the body can't be computed
+    pub(crate) fn try_new(
+        body: &(impl Body + ?Sized),
+        start_jd: f64,
+        end_jd: f64,
+        step_sols: f64,
+    ) -> Result<Self> {
+        let cache = body.cache()?;
+
+        if !start_jd.is_finite() || !end_jd.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        let step_sols = match step_sols.is_finite() && step_sols != 0.0 {
+            true => step_sols.abs(),
+            false => Self::DEFAULT_STEP,
+        };
+        let span = (end_jd - start_jd).abs();
+        let step = (body.rotational_period_seconds() * step_sols).to_days().0;
+        let mut steps = (span / step).floor() as u64;
+
+        // the division can round past the end
+        if steps as f64 * step > span {
+            steps -= 1;
+        }
+
+        Ok(Self {
+            cache,
+            season_table: body.season_table(),
+            start: start_jd,
+            step: if end_jd < start_jd { -step } else { step },
+            front: 0,
+            back: steps + 1,
+        })
+    }
+
+    /// This method computes the date of a step
+    fn at(&self, index: u64) -> (f64, Date) {
+        let julian_date = self.start + index as f64 * self.step;
+
+        (
+            julian_date,
+            self.cache
+                .expect_date(julian_date, self.season_table.as_ref()),
+        )
+    }
+}
+
+impl Iterator for DateRange {
+    type Item = (f64, Date);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.front += 1;
+
+        Some(self.at(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.back - self.front) as usize;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for DateRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        Some(self.at(self.back))
+    }
+}
+
+impl ExactSizeIterator for DateRange {}
+
+impl FusedIterator for DateRange {}
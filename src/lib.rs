@@ -6,10 +6,10 @@
     // trivial_numeric_casts,
     unsafe_code,
     unstable_features,
-    missing_docs,
     unused_import_braces,
     unused_qualifications
 )]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! # Rust Solar Information
 //!
@@ -28,7 +28,9 @@
 //! (Geometry of Orbits)[https://www.bogan.ca/orbits/geometry.html]
 //! (Julian Date Converter)[https://aa.usno.navy.mil/data/JulianDate]
 //! - (Semi Latus Rectum of Parabola or Hyperbola)[https://www.orbiter-forum.com/threads/how-calculate-semi-latus-rectum-of-parabola-or-hyperbola.40315/]
-//!     > Semi-Latus Rectum equation by Kolodez 
+//!
+//!     > Semi-Latus Rectum equation by Kolodez
+//!
 //! (Orbital Periods)[https://upload.wikimedia.org/wikipedia/commons/thumb/b/be/Solar_system_orbital_period_vs_semimajor_axis.svg/800px-Solar_system_orbital_period_vs_semimajor_axis.svg.png]
 //! (Astronomy Calculations)[https://docs.google.com/spreadsheets/d/1rwc2mVxyHuUEou_hxnG6kzl24XdqqmIAS5_1nJDpJ6o/edit#gid=1479831395]
 //! 
@@ -40,11 +42,39 @@
 //! 
 //! # Features
 //!
-//!  - `asteroids` : Brings asteroid support
-//!  - `planets`: Brings planet support
+//!  - `full`: Brings every body below (on by default)
+//!
+//!    Each body is a feature of its own (`mars`, `earth`, `jupiter`, `saturn`, `luna` and `ceres`), so `--no-default-features --features mars`
+//!    only builds mars, and [`bodies::registry`] only holds the bodies whose features are on.
+//!  - `asteroids` : Brings asteroid support (`ceres`)
+//!  - `planets`: Brings planet support (`earth`, `mars`, `jupiter` and `saturn`)
 //!  - `exo-planets`: Brings exo-planet support
 //!  - `comets`: Brings comet support
-//!  - `moons`: Brings moon support
+//!  - `moons`: Brings moon support (`luna`, which needs `earth`)
+//!  - `std`: Reads the system clock, allows adding leap seconds at runtime and writes [`ephemeris`] tables (on by default)
+//!
+//!    Without it the library is `no_std` (it still needs `alloc`), and the float math goes through `libm`.
+//!  - `log`: Emits solver steps and timezone details through the `log` crate (off by default)
+//!  - `chrono`: Converts julian dates from and to `chrono::DateTime<Utc>` (off by default)
+//!  - `time`: Converts julian dates from and to `time::OffsetDateTime` (off by default)
+//!  - `serde`: Serializes dates, times and timezone information (off by default)
+//!  - `wasm`: Exports [`wasm`] to javascript through `wasm-bindgen` (off by default)
+//!  - `ffi`: Exports [`ffi`] to C and generates `rust_solar.h` through `cbindgen` (off by default)
+//!
+//!    The header is written to `OUT_DIR`, or to `RUST_SOLAR_HEADER` when it's set.
+//!    Build the library with `cargo rustc --release --features ffi --crate-type cdylib` (or `staticlib`).
+//!  - `python`: Exports [`python`] as the `rust_solar` python module through `pyo3` (off by default)
+//!
+//!    Build the module with `maturin develop`, see `pyproject.toml`.
+//!  - `config`: Loads custom bodies from TOML or JSON files, see `examples/bodies.toml` (off by default)
+//!  - `horizons`: Parses JPL Horizons ephemerides and compares the positions of a body with them, see [`horizons`] (off by default)
+//!  - `mpc`: Turns the MPCORB lines of the Minor Planet Center into custom bodies, see [`mpc`] (off by default)
+//!  - `rayon`: Converts many julian dates on every core through `rayon` (off by default)
+//!
+//!    See [`kepler::Body::to_dates_par`] and [`bodies::ephemeris_table`].
+//!  - `tokio`: Turns the ticks of a timezone into an async stream that waits between ticks (off by default)
+//!
+//!    See [`clock::Ticks::into_stream`].
 //!
 //!    To use features maybe?
 //!    (https://stackoverflow.com/questions/27454761/what-is-a-crate-attribute-and-where-do-i-add-it)
@@ -53,9 +83,27 @@
 //!
 //!
 
+extern crate alloc;
+
+/// This module contains the macros for declaring bodies
+mod macros;
+
+/// This module contains the float math of `std`, through `libm` when there's no `std`
+#[cfg(not(feature = "std"))]
+mod math;
+
 /// This module contains calculations for supported planets
 pub mod planets;
 
+/// This module contains calculations for supported asteroids
+pub mod asteroids;
+
+/// This module contains calculations for supported moons
+pub mod moons;
+
+/// This module contains the registry of supported bodies
+pub mod bodies;
+
 /// This module contains common kepler data
 pub mod kepler;
 
@@ -65,11 +113,71 @@ pub mod conversions;
 /// This module contains common orbital data
 pub mod orbit;
 
+/// This module contains the positions of bodies, from the sun or the barycenter
+pub mod position;
+
+/// This module contains the transfers between the orbits of bodies
+pub mod transfer;
+
+/// This module contains the light time between earth and the other bodies
+#[cfg(feature = "earth")]
+pub mod comms;
+
+/// This module contains the export of ephemeris tables
+#[cfg(feature = "std")]
+pub mod ephemeris;
+
+/// This module contains the parsers of JPL Horizons ephemerides
+#[cfg(feature = "horizons")]
+pub mod horizons;
+
+/// This module contains the parsers of the orbits of the Minor Planet Center
+#[cfg(feature = "mpc")]
+pub mod mpc;
+
 /// This module contains anomalic equations
 pub mod anomaly;
 
+/// This module contains the search for events, when a function of time crosses a target or peaks
+pub mod events;
+
 /// This module contains julian operations
 pub mod julian;
 
 /// why
 pub mod why;
+
+/// This module contains the layout of calendars, the sols of a month in weeks, and calendars of whole sols with leap years
+pub mod calendar;
+
+/// This module contains the rules of events that repeat on a body
+pub mod recurrence;
+
+/// This module contains mission sol counting
+pub mod mission;
+
+/// This module contains the clock engine shared by the timezones of every body
+pub mod clock;
+
+/// This module contains the timezones of every body, found by their codes
+pub mod timezones;
+
+/// This module contains the errors of the library
+pub mod error;
+
+/// This module contains reference conversions of mars and earth, to test an integration against
+pub mod fixtures;
+
+/// This module contains the javascript bindings
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+/// This module contains the C bindings
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+/// This module contains the python bindings
+#[cfg(feature = "python")]
+pub mod python;
+
+pub use error::{Error, Result};
@@ -0,0 +1,245 @@
+/// This macro declares a body and implements [`crate::kepler::Body`] for it from its constants
+///
+/// * Any method of the body can be overridden inside of the `impl` block, for special cases like tidally locked moons.
+///
+/// ```rust
+/// use rust_solar::{define_body, kepler::Body};
+///
+/// define_body! {
+///     /// This structure represents a planet that only exists in this example
+///     pub struct Vulcan {
+///         name: "Vulcan",
+///         epoch: 2_451_545.0,
+///         orbital_eccentricity: 0.02,
+///         orbital_period: 120.0,
+///         rotational_period: 100_000.0,
+///         semimajor: 0.3,
+///         perihelion: ((40.0, 50.0), (90.0, 120.0), 100.0),
+///     }
+///     impl {
+///         fn axial_tilt(&self) -> f64 {
+///             3.0
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Vulcan.name(), "Vulcan");
+/// assert_eq!(Vulcan.axial_tilt(), 3.0);
+/// assert_eq!(Vulcan.to_date(2_451_545.0).year, 1.0);
+/// ```
+#[macro_export]
+macro_rules! define_body {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $body:ident {
+            name: $name:expr,
+            epoch: $epoch:expr,
+            orbital_eccentricity: $eccentricity:expr,
+            orbital_period: $orbital_period:expr,
+            rotational_period: $rotational_period:expr,
+            semimajor: $semimajor:expr,
+            perihelion: ($month:expr, $ls:expr, $perihelion:expr $(,)?),
+        }
+        $(impl { $($overrides:tt)* })?
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone)]
+        $vis struct $body;
+
+        impl $crate::kepler::Body for $body {
+            fn name(&self) -> &str {
+                $name
+            }
+
+            fn epoch(&self) -> f64 {
+                $epoch
+            }
+
+            fn orbital_eccentricity(&self) -> f64 {
+                $eccentricity
+            }
+
+            fn orbital_period(&self) -> f64 {
+                $orbital_period
+            }
+
+            fn rotational_period(&self) -> f64 {
+                $rotational_period
+            }
+
+            fn perihelion(&self) -> $crate::orbit::Perihelion {
+                $crate::orbit::Perihelion {
+                    month: $month,
+                    ls: $ls,
+                    perihelion: $perihelion,
+                }
+            }
+
+            fn semimajor(&self) -> f64 {
+                $semimajor
+            }
+
+            fn to_time(&self, _date: $crate::kepler::Date) -> $crate::kepler::Time {
+                $crate::kepler::Time::default()
+            }
+
+            $($($overrides)*)?
+        }
+    };
+}
+
+/// This macro declares the timezones of a body and implements [`crate::kepler::TimeZone`] for them
+///
+/// * Each timezone is `Variant => (code, name, offset in hours, east longitude, west longitude)`.
+/// * The time comes from a [`crate::clock::BodyClock`], so only the offsets differ between timezones.
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::{
+///     clock::{BodyClock, ClockCalibration},
+///     define_timezones,
+///     kepler::TimeZone,
+///     planets::mars::Mars,
+/// };
+///
+/// const CLOCK: BodyClock<Mars> = BodyClock::new(
+///     Mars,
+///     ClockCalibration { epoch_tt: 2_451_545.0, sol_length: 1.0, sol_offset: 0.5, alignment: 0.0 },
+/// );
+///
+/// define_timezones! {
+///     /// The timezones of a hemisphere split in two
+///     pub enum Halves for CLOCK {
+///         /// The western half
+///         West => ("W", "West Time", -6.0, -180.0, 0.0),
+///         /// The eastern half
+///         #[default]
+///         East => ("E", "East Time", 6.0, 0.0, 180.0),
+///     }
+/// }
+///
+/// assert_eq!(Halves::from_longitude(90.0), Some(Halves::East));
+/// assert_eq!("w".parse(), Ok(Halves::West));
+/// assert_eq!(Halves::East.at(2_451_545.0).hour, 18);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! define_timezones {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $zone:ident for $clock:path {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => ($code:expr, $name:expr, $offset:expr, $east:expr, $west:expr $(,)?)
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        $vis enum $zone {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )+
+        }
+
+        impl $zone {
+            /// Every timezone, from west to east
+            pub const ALL: &'static [Self] = &[$(Self::$variant),+];
+
+            /// The variant name of every timezone, in the same order as [`Self::ALL`]
+            pub const NAMES: &'static [&'static str] = &[$(stringify!($variant)),+];
+
+            /// The information of every timezone, in the same order as [`Self::ALL`]
+            pub const INFO: &'static [$crate::clock::ZoneInfo] = &[$(
+                $crate::clock::ZoneInfo {
+                    code: $code,
+                    name: $name,
+                    offset: $offset,
+                    east: $east,
+                    west: $west,
+                },
+            )+];
+
+            /// This method iterates over every timezone, from west to east
+            pub fn iter() -> impl Iterator<Item = Self> {
+                Self::ALL.iter().copied()
+            }
+
+            /// This method returns the code, name, offset and longitudes of the timezone
+            pub fn info(&self) -> $crate::clock::ZoneInfo {
+                Self::INFO[*self as usize]
+            }
+
+            /// This method returns the times of the timezone, every interval in seconds of the clock from a julian date (UTC)
+            ///
+            /// * See [`crate::clock::Ticks`]
+            pub fn ticks(&self, start_jd: f64, interval_seconds: f64) -> $crate::clock::Ticks<Self> {
+                $clock.ticks(*self, start_jd, interval_seconds)
+            }
+
+            /// This method finds the timezone of a longitude in degrees east
+            pub fn from_longitude(lon_east_deg: f64) -> Option<Self> {
+                Self::iter().find(|zone| zone.info().contains(lon_east_deg))
+            }
+        }
+
+        impl AsRef<str> for $zone {
+            fn as_ref(&self) -> &str {
+                Self::NAMES[*self as usize]
+            }
+        }
+
+        impl ::core::str::FromStr for $zone {
+            type Err = $crate::Error;
+
+            /// The timezone of a code or a variant name, ignoring case, or of an offset (ex; `MTC+02:30` or `+02:30`)
+            ///
+            /// * The prefix of an offset is the variant name of the timezone on the prime meridian, or none.
+            fn from_str(s: &str) -> $crate::Result<Self> {
+                let by_offset = || {
+                    let (prefix, offset) = $crate::clock::parse_offset(s)?;
+                    let prime = Self::iter().find(|zone| zone.info().has_offset(0.0))?;
+
+                    match prefix.is_empty() || prime.as_ref().eq_ignore_ascii_case(prefix) {
+                        true => Self::iter().find(|zone| zone.info().has_offset(offset)),
+                        false => None,
+                    }
+                };
+
+                Self::iter()
+                    .find(|zone| {
+                        zone.as_ref().eq_ignore_ascii_case(s)
+                            || zone.info().code.eq_ignore_ascii_case(s)
+                    })
+                    .or_else(by_offset)
+                    .ok_or($crate::Error::UnknownTimeZone)
+            }
+        }
+
+        impl $crate::kepler::TimeZone for $zone {
+            fn sol_carry(&self, julian_date: f64) -> i64 {
+                $clock.sol_carry(julian_date, self.info().offset)
+            }
+
+            fn offset(&self) -> f64 {
+                self.info().offset
+            }
+
+            fn fractional_hours(&self, julian_date: f64) -> f64 {
+                $clock.fractional_hours(julian_date, self.info().offset)
+            }
+
+            fn at(&self, julian_date: f64) -> $crate::kepler::Time {
+                self.at_split(julian_date.into())
+            }
+
+            fn at_split(&self, julian_date: $crate::julian::SplitJd) -> $crate::kepler::Time {
+                let info = self.info();
+
+                info.stamp($clock.at(julian_date, info.offset), Self::NAMES[*self as usize])
+            }
+        }
+    };
+}
@@ -0,0 +1,94 @@
+use alloc::string::{String, ToString};
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    conversions::Days,
+    kepler::{Body, Date, Eras, Time, TimeZone},
+};
+
+/// This is a sol counter that starts at a mission's landing, instead of the body's epoch
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::{mission::MissionClock, planets::mars::Mars};
+///
+/// // Midnight at Gale crater before the landing
+/// let curiosity = MissionClock::new(Mars, 2_456_145.08, "Curiosity");
+///
+/// // 2015 May 31
+/// assert_eq!(1000, curiosity.sol(2_457_173.5));
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MissionClock<B: Body> {
+    /// This is the body the mission is on
+    pub body: B,
+    /// This is the julian date (UTC) where sol 0 starts, usually local midnight before the landing
+    pub epoch_jd: f64,
+    /// This is the name of the mission
+    pub name: String,
+}
+
+impl<B: Body> MissionClock<B> {
+    /// This method creates a mission clock
+    pub fn new(body: B, epoch_jd: f64, name: &str) -> Self {
+        Self {
+            body,
+            epoch_jd,
+            name: name.to_string(),
+        }
+    }
+
+    /// The sols since the epoch with their fraction, negative before the epoch
+    fn elapsed(&self, julian_date: f64) -> f64 {
+        Days(julian_date - self.epoch_jd).sols(self.body.rotational_period_seconds())
+    }
+
+    /// This method counts the mission sol of a julian date (UTC)
+    ///
+    /// * The epoch is sol 0, the sol before the epoch is -1 rather than another sol 0.
+    pub fn sol(&self, julian_date: f64) -> i64 {
+        self.elapsed(julian_date).floor() as i64
+    }
+
+    /// This method computes the date of the body where year 1 starts at the epoch
+    ///
+    /// * The solar longitude, month, and season are still the body's.
+    /// * Years before the epoch are in the BD era, counting down from year 0.
+    pub fn date(&self, julian_date: f64) -> Date {
+        let orbital_period = self.body.orbital_period();
+        let elapsed = self.elapsed(julian_date);
+        let year = 1.0 + (elapsed / orbital_period).floor();
+        let sol = elapsed.rem_euclid(orbital_period);
+
+        Date {
+            era: match year > 0.0 {
+                true => Eras::AD,
+                false => Eras::BD,
+            },
+            year,
+            day: 1.0 + sol.floor(),
+            sol,
+            ..self.body.to_date(julian_date)
+        }
+    }
+
+    /// This method computes the time of the mission sol in a timezone of the body
+    ///
+    /// * The clock reads midnight at the epoch and at the start of every [`MissionClock::sol`] after it.
+    /// * The offset of the timezone is added to the fraction of the mission sol, so a timezone without an offset
+    ///   reads the local mean time of the landing site.
+    /// * An epoch at midnight on the prime meridian reads the same time as [`TimeZone::at`].
+    pub fn time(&self, julian_date: f64, zone: &impl TimeZone) -> Time {
+        let stamped = zone.at(julian_date);
+
+        Time {
+            zone: stamped.zone,
+            offset_name: stamped.offset_name,
+            ..Time::from_sol_fraction(self.elapsed(julian_date) + zone.offset() / 24.0)
+        }
+    }
+}
@@ -0,0 +1,3 @@
+/// This module contains luna calculations
+#[cfg(feature = "luna")]
+pub mod luna;
@@ -0,0 +1,144 @@
+/// This module contains the phases of luna, see [`phase`]
+pub mod phases;
+
+pub use phases::{next_phase, phase, LunarPhase, Phase};
+
+use alloc::boxed::Box;
+use core::str::FromStr;
+
+use crate::{
+    clock::{BodyClock, ClockCalibration},
+    error::Result,
+    julian::TimeScale,
+    kepler::{Body, HostedBody, TimeZone},
+    planets::earth::{Earth, EARTH_EPOCH_JD},
+};
+
+/// This is the mean radius of luna in kilometers
+pub const LUNA_RADIUS_KM: f64 = 1_737.4;
+
+/// The year of luna starts with the year of earth, see [`EARTH_EPOCH_JD`]
+pub const LUNA_EPOCH_JD: f64 = EARTH_EPOCH_JD;
+
+/// This is the mean distance of luna from the center of earth in kilometers
+pub const LUNA_SEMIMAJOR_KM: f64 = 384_399.0;
+
+/// The length of a lunar sol (synodic month) in earth days
+const SYNODIC_MONTH: f64 = 29.530_588_853;
+
+/// The calibration of the lunar sol count, where every new moon is midnight on the near side
+pub const LUNA_CALIBRATION: ClockCalibration = ClockCalibration {
+    // A.D 2000 January 6, 18:14 (New Moon)
+    epoch_tt: 2_451_550.260_4,
+    sol_length: SYNODIC_MONTH,
+    sol_offset: 0.0,
+    alignment: 0.0,
+};
+
+/// The clock of the lunar timezones
+const LUNA_CLOCK: BodyClock<Luna> = BodyClock::new(Luna, LUNA_CALIBRATION);
+
+crate::define_body! {
+    /// This structure represents the moon of earth
+    ///
+    /// * A sol is a synodic month, from one new moon to the next.
+    /// * The year follows the orbit of earth around the sun.
+    pub struct Luna {
+        name: "Luna",
+        epoch: LUNA_EPOCH_JD,
+        orbital_eccentricity: 0.0167,
+        orbital_period: 365.25 / SYNODIC_MONTH,
+        rotational_period: SYNODIC_MONTH * 86_400.0,
+        semimajor: 1.0,
+        perihelion: (
+            (276.0 / SYNODIC_MONTH, 306.0 / SYNODIC_MONTH),
+            (270.0, 300.0),
+            283.0,
+        ),
+    }
+    impl {
+        /// The [`LUNA_EPOCH_JD`] is the epoch of earth, in UTC
+        fn epoch_scale(&self) -> TimeScale {
+            TimeScale::UTC
+        }
+
+        fn axial_tilt(&self) -> f64 {
+            1.54
+        }
+
+        /// Luna shows earth the same face, so its sol is the synodic month, see [`HostedBody::locked_solar_day`]
+        fn is_tidally_locked(&self) -> bool {
+            true
+        }
+
+        fn mass_kg(&self) -> Option<f64> {
+            Some(7.342e22)
+        }
+
+        /// See [`LUNA_RADIUS_KM`]
+        fn mean_radius_km(&self) -> Option<f64> {
+            Some(LUNA_RADIUS_KM)
+        }
+
+        /// The lunar sols since the new moon of the epoch
+        fn sol_date(&self, julian_date: f64) -> f64 {
+            LUNA_CALIBRATION.sol_date(julian_date)
+        }
+
+        fn timezone(&self, code: &str) -> Option<Box<dyn TimeZone>> {
+            Lunar::from_str(code)
+                .ok()
+                .map(|zone| Box::new(zone) as Box<dyn TimeZone>)
+        }
+
+        fn timezones(&self) -> &[crate::clock::ZoneInfo] {
+            Lunar::INFO
+        }
+    }
+}
+
+/// The orbit of luna around earth
+impl HostedBody for Luna {
+    fn host(&self) -> &'static dyn Body {
+        &Earth
+    }
+
+    fn host_semimajor_km(&self) -> f64 {
+        LUNA_SEMIMAJOR_KM
+    }
+
+    fn host_orbital_eccentricity(&self) -> f64 {
+        0.0549
+    }
+
+    /// The host longitude of luna is its [`phases::elongation`], 0 at a new moon
+    fn host_longitude(&self, julian_date: f64) -> Result<f64> {
+        phases::elongation(julian_date)
+    }
+
+    /// The mean anomaly of the series of the moon, the anomalistic month is longer than the sidereal one
+    fn host_mean_anomaly(&self, julian_date: f64) -> Result<f64> {
+        phases::mean_anomaly(julian_date)
+    }
+}
+
+crate::define_timezones! {
+    /// This structure represents the lunar timezone
+    ///
+    /// Each timezone is a fifth of the moon, 72 degrees or 4.8 lunar hours wide.
+    ///
+    /// There is no DST on luna
+    pub enum Lunar for LUNA_CLOCK {
+        /// Lunar Coordinated Time - 2, on the far side
+        LTCn2 => ("HZT", "Hertzsprung Time", -9.6, -180.0, -108.0),
+        /// Lunar Coordinated Time - 1
+        LTCn1 => ("PRT", "Procellarum Time", -4.8, -108.0, -36.0),
+        /// Lunar Coordinated Time, facing earth
+        #[default]
+        LTC => ("MDT", "Medii Time", 0.0, -36.0, 36.0),
+        /// Lunar Coordinated Time + 1
+        LTCp1 => ("CRT", "Crisium Time", 4.8, 36.0, 108.0),
+        /// Lunar Coordinated Time + 2, on the far side
+        LTCp2 => ("MOT", "Moscoviense Time", 9.6, 108.0, 180.0),
+    }
+}
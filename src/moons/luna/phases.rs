@@ -0,0 +1,267 @@
+//! The phases of luna, from the elongation of the moon from the sun as seen from earth
+//!
+//! * The longitudes are the truncated series of Meeus (Astronomical Algorithms, chapters 25 and 47),
+//!   which find the new moons of 2024 within a few minutes.
+//! * The nutation is left out, it moves both longitudes alike so it cancels out of the elongation.
+
+use strum::{AsRefStr, IntoStaticStr};
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    conversions::{deg_to_rad, normalize_degrees},
+    error::{Error, Result},
+    events::{self, Crossing},
+    julian::{utc_to_tt, Epoch},
+};
+
+/// The longest synodic month in earth days, so the next phase is always found within it
+const LONGEST_SYNODIC_MONTH: f64 = 30.0;
+
+/// The degrees of elongation that are still at a phase, a few milliseconds of the moon
+const PHASE_TOLERANCE: f64 = 1e-6;
+
+/// The multiples of the mean elongation, the sun's and the moon's mean anomaly and the argument of latitude,
+/// with the amplitude in millionths of a degree of the longitude of the moon (Meeus, table 47.A)
+const MOON_TERMS: [(f64, f64, f64, f64, f64); 32] = [
+    (0.0, 0.0, 1.0, 0.0, 6_288_774.0),
+    (2.0, 0.0, -1.0, 0.0, 1_274_027.0),
+    (2.0, 0.0, 0.0, 0.0, 658_314.0),
+    (0.0, 0.0, 2.0, 0.0, 213_618.0),
+    (0.0, 1.0, 0.0, 0.0, -185_116.0),
+    (0.0, 0.0, 0.0, 2.0, -114_332.0),
+    (2.0, 0.0, -2.0, 0.0, 58_793.0),
+    (2.0, -1.0, -1.0, 0.0, 57_066.0),
+    (2.0, 0.0, 1.0, 0.0, 53_322.0),
+    (2.0, -1.0, 0.0, 0.0, 45_758.0),
+    (0.0, 1.0, -1.0, 0.0, -40_923.0),
+    (1.0, 0.0, 0.0, 0.0, -34_720.0),
+    (0.0, 1.0, 1.0, 0.0, -30_383.0),
+    (2.0, 0.0, 0.0, -2.0, 15_327.0),
+    (0.0, 0.0, 1.0, 2.0, -12_528.0),
+    (0.0, 0.0, 1.0, -2.0, 10_980.0),
+    (4.0, 0.0, -1.0, 0.0, 10_675.0),
+    (0.0, 0.0, 3.0, 0.0, 10_034.0),
+    (4.0, 0.0, -2.0, 0.0, 8_548.0),
+    (2.0, 1.0, -1.0, 0.0, -7_888.0),
+    (2.0, 1.0, 0.0, 0.0, -6_766.0),
+    (1.0, 0.0, -1.0, 0.0, -5_163.0),
+    (1.0, 1.0, 0.0, 0.0, 4_987.0),
+    (2.0, -1.0, 1.0, 0.0, 4_036.0),
+    (2.0, 0.0, 2.0, 0.0, 3_994.0),
+    (4.0, 0.0, 0.0, 0.0, 3_861.0),
+    (2.0, 0.0, -3.0, 0.0, 3_665.0),
+    (0.0, 1.0, -2.0, 0.0, -2_689.0),
+    (2.0, 0.0, -1.0, 2.0, -2_602.0),
+    (2.0, -1.0, -2.0, 0.0, 2_390.0),
+    (1.0, 0.0, 1.0, 0.0, -2_348.0),
+    (2.0, -2.0, 0.0, 0.0, 2_236.0),
+];
+
+/// The named phases of luna, each one is 45 degrees of elongation around its [`Phase::elongation`]
+#[derive(AsRefStr, IntoStaticStr, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Phase {
+    /// The moon is between earth and the sun, so its near side is dark
+    #[strum(serialize = "New Moon")]
+    New,
+    /// Less than half of the near side is lit, and growing
+    #[strum(serialize = "Waxing Crescent")]
+    WaxingCrescent,
+    /// Half of the near side is lit, and growing
+    #[strum(serialize = "First Quarter")]
+    FirstQuarter,
+    /// More than half of the near side is lit, and growing
+    #[strum(serialize = "Waxing Gibbous")]
+    WaxingGibbous,
+    /// Earth is between the moon and the sun, so its near side is lit
+    #[strum(serialize = "Full Moon")]
+    Full,
+    /// More than half of the near side is lit, and shrinking
+    #[strum(serialize = "Waning Gibbous")]
+    WaningGibbous,
+    /// Half of the near side is lit, and shrinking
+    #[strum(serialize = "Last Quarter")]
+    LastQuarter,
+    /// Less than half of the near side is lit, and shrinking
+    #[strum(serialize = "Waning Crescent")]
+    WaningCrescent,
+}
+
+impl Phase {
+    /// Every phase, from the new moon on
+    pub const ALL: [Phase; 8] = [
+        Phase::New,
+        Phase::WaxingCrescent,
+        Phase::FirstQuarter,
+        Phase::WaxingGibbous,
+        Phase::Full,
+        Phase::WaningGibbous,
+        Phase::LastQuarter,
+        Phase::WaningCrescent,
+    ];
+
+    /// This method returns the elongation in degrees at the middle of the phase (ex; 90 for the first quarter)
+    pub fn elongation(self) -> f64 {
+        45.0 * f64::from(self as u8)
+    }
+
+    /// This method returns the phase of an elongation in degrees, from 0 up to 360
+    pub fn from_elongation(elongation: f64) -> Self {
+        Self::ALL[((normalize_degrees(elongation) + 22.5) / 45.0) as usize % 8]
+    }
+}
+
+/// This is the phase of luna at a julian date, see [`phase`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LunarPhase {
+    /// The longitude of the moon minus the longitude of the sun in degrees, from 0 up to 360
+    pub elongation: f64,
+    /// The lit fraction of the near side, from 0 (new) to 1 (full)
+    ///
+    /// > $$k = \frac{1 - \cos\psi}{2}$$
+    ///
+    /// * The latitude of the moon is left out, so a new moon is never more than 0.2% lit.
+    pub illuminated: f64,
+    /// The named phase of the elongation
+    pub phase: Phase,
+}
+
+/// This function returns the phase of luna at a julian date (UTC)
+///
+/// ```rust
+/// use rust_solar::moons::luna::{phase, Phase};
+///
+/// // the new moon of 2024 January 11, 11:57 UTC
+/// let new = phase(2460321.0).unwrap();
+///
+/// assert_eq!(Phase::New, new.phase);
+/// assert!(new.illuminated < 0.001);
+/// ```
+pub fn phase(julian_date: f64) -> Result<LunarPhase> {
+    let elongation = elongation(julian_date)?;
+
+    Ok(LunarPhase {
+        elongation,
+        illuminated: (1.0 - deg_to_rad(elongation).cos()) / 2.0,
+        phase: Phase::from_elongation(elongation),
+    })
+}
+
+/// This function finds the first julian date (UTC) at or after `after` where luna reaches the middle of a phase
+///
+/// * The elongation only grows, so the phase is bracketed a day at a time, see [`events::scan`]
+///
+/// ```rust
+/// use rust_solar::moons::luna::{next_phase, Phase};
+///
+/// // the full moon of 2024 January 25, 17:54 UTC
+/// let full = next_phase(2460310.5, Phase::Full).unwrap();
+///
+/// assert!((full - 2460335.246).abs() < 0.01);
+/// ```
+pub fn next_phase(after: f64, phase: Phase) -> Result<f64> {
+    let start = elongation(after)?;
+    let goal = normalize_degrees(phase.elongation() - start);
+
+    // a julian date found by this function is at its phase, within a few milliseconds
+    if goal == 0.0 || goal > 360.0 - PHASE_TOLERANCE {
+        return Ok(after);
+    }
+
+    // how far the moon is past the phase, it wraps around at the opposite phase
+    let miss = |jd: f64| -> Result<f64> {
+        Ok(normalize_degrees(elongation(jd)? - phase.elongation() + 180.0) - 180.0)
+    };
+
+    // the moon gains 10 to 15 degrees a day, so a day can't reach the opposite phase
+    let bracket = events::try_scan(
+        miss,
+        (after, after + LONGEST_SYNODIC_MONTH),
+        1.0,
+        Crossing::Rising,
+    )?
+    .next()
+    .transpose()?
+    .ok_or(Error::NoConvergence(LONGEST_SYNODIC_MONTH as u32))?;
+
+    // about a millisecond
+    events::try_find_crossing(miss, bracket, 1e-8)
+}
+
+/// This function returns the elongation of the moon from the sun in longitude at a julian date (UTC), in degrees
+///
+/// * 0 is a new moon, 180 is a full moon.
+pub fn elongation(julian_date: f64) -> Result<f64> {
+    if !julian_date.is_finite() {
+        return Err(Error::NonFinite);
+    }
+
+    let centuries = Epoch::J2000.days_since(utc_to_tt(julian_date)) / 36_525.0;
+
+    Ok(normalize_degrees(
+        moon_longitude(centuries) - sun_longitude(centuries),
+    ))
+}
+
+/// The apparent longitude of the sun in degrees, without the nutation (Meeus, chapter 25)
+fn sun_longitude(t: f64) -> f64 {
+    let mean_longitude = 280.466_46 + 36_000.769_83 * t + 0.000_303_2 * t * t;
+    let anomaly = deg_to_rad(357.529_11 + 35_999.050_29 * t - 0.000_153_7 * t * t);
+    let center = (1.914_602 - 0.004_817 * t - 0.000_014 * t * t) * anomaly.sin()
+        + (0.019_993 - 0.000_101 * t) * (2.0 * anomaly).sin()
+        + 0.000_289 * (3.0 * anomaly).sin();
+    // the aberration
+    mean_longitude + center - 0.005_69
+}
+
+/// The mean anomaly of the moon in degrees at a julian date (UTC), from 0 up to 360
+///
+/// * It's measured from the perigee, which the sun moves back and forth by a few degrees (Meeus, chapter 47)
+pub(crate) fn mean_anomaly(julian_date: f64) -> Result<f64> {
+    if !julian_date.is_finite() {
+        return Err(Error::NonFinite);
+    }
+
+    let centuries = Epoch::J2000.days_since(utc_to_tt(julian_date)) / 36_525.0;
+
+    Ok(normalize_degrees(moon_anomaly(centuries)))
+}
+
+/// The mean anomaly of the moon in degrees (Meeus, chapter 47)
+fn moon_anomaly(t: f64) -> f64 {
+    134.963_396_4 + 477_198.867_505_5 * t + 0.008_741_4 * t * t
+}
+
+/// The longitude of the moon in degrees, without the nutation (Meeus, chapter 47)
+fn moon_longitude(t: f64) -> f64 {
+    let mean_longitude = 218.316_447_7 + 481_267.881_234_21 * t - 0.001_578_6 * t * t;
+    let elongation = 297.850_192_1 + 445_267.111_403_4 * t - 0.001_881_9 * t * t;
+    let sun_anomaly = 357.529_109_2 + 35_999.050_290_9 * t - 0.000_153_6 * t * t;
+    let moon_anomaly = moon_anomaly(t);
+    let latitude = 93.272_095 + 483_202.017_523_3 * t - 0.003_653_9 * t * t;
+    // the eccentricity of earth's orbit shrinks, which weakens the terms of the sun's anomaly
+    let eccentricity = 1.0 - 0.002_516 * t - 0.000_007_4 * t * t;
+
+    let terms: f64 = MOON_TERMS
+        .iter()
+        .map(|(d, m, mp, f, amplitude)| {
+            let angle = d * elongation + m * sun_anomaly + mp * moon_anomaly + f * latitude;
+
+            let weight = match m.abs() as u8 {
+                0 => 1.0,
+                1 => eccentricity,
+                _ => eccentricity * eccentricity,
+            };
+
+            amplitude * weight * deg_to_rad(angle).sin()
+        })
+        .sum();
+    // venus, and the flattening of earth
+    let additive = 3_958.0 * deg_to_rad(119.75 + 131.849 * t).sin()
+        + 1_962.0 * deg_to_rad(mean_longitude - latitude).sin();
+
+    mean_longitude + (terms + additive) / 1_000_000.0
+}
@@ -0,0 +1,206 @@
+use alloc::{string::String, vec::Vec};
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    anomaly::elliptical_newton,
+    conversions::{deg_to_rad, normalize_degrees, rad_to_deg, Au, Days},
+    error::{Error, Result},
+    julian::{greg2jd, CalendarSystem, JulianDate, TimeScale},
+    kepler::CustomBody,
+    orbit::{OrbitalElements, Perihelion},
+    planets::EARTH_ROTATIONAL_PERIOD,
+};
+
+/// The degrees of solar longitude of a month of a minor planet, so its years have 12 months
+const MONTH_LS: f64 = 30.0;
+
+/// This function parses a line of the MPCORB format of the Minor Planet Center into a body and its elements
+///
+/// * The name of the body is the readable designation (ex; `(1) Ceres`), or the packed one when the line has none.
+/// * The rotation of a minor planet isn't in the line, so a sol of the body is an earth day.
+/// * The calendar of the body starts at the solar longitude of 0 before the epoch of the elements (TT),
+///   the solar longitudes follow the longitude of the perihelion, see [`Body::longitude_of_perihelion`](crate::kepler::Body::longitude_of_perihelion)
+/// * The periapsis, the apoapsis, the period, the time of the periapsis and the true anomaly are derived from the line.
+/// * It fails with [`Error::InvalidFormat`] when the line is too short or a column isn't a number,
+///   and with [`Error::InvalidOrbit`] when the orbit isn't elliptical.
+///
+/// ```rust
+/// use rust_solar::{kepler::Body, mpc::parse_line};
+///
+/// let line = "00001    3.34  0.12 K205V 162.68631   73.73161   80.28698   10.58862  0.0775571  0.21406009   2.7676569  0 MPO492748  6751 115 1801-2019 0.60 M-v 30h Williams   0000      (1) Ceres                   20190915";
+/// let (ceres, elements) = parse_line(line).unwrap();
+///
+/// assert_eq!("(1) Ceres", ceres.name());
+/// assert_eq!(10.58862, elements.inclination);
+/// ```
+pub fn parse_line(line: &str) -> Result<(CustomBody, OrbitalElements)> {
+    let column = |start: usize, end: usize| -> Result<&str> {
+        line.get(start..end)
+            .map(str::trim)
+            .ok_or(Error::InvalidFormat(
+                "the line is too short for the MPCORB format",
+            ))
+    };
+
+    let epoch = unpack_epoch(column(20, 25)?)?;
+    let mean_anomaly = number(column(26, 35)?)?;
+    let argument_of_periapsis = number(column(37, 46)?)?;
+    let ascending_node = number(column(48, 57)?)?;
+    let inclination = number(column(59, 68)?)?;
+    let eccentricity = number(column(70, 79)?)?;
+    let mean_motion = number(column(80, 91)?)?;
+    let semimajor = number(column(92, 103)?)?;
+
+    let designation = match line.get(166..194).map(str::trim) {
+        Some(readable) if !readable.is_empty() => readable,
+        _ => column(0, 7)?,
+    };
+
+    if !(0.0..1.0).contains(&eccentricity) || mean_motion <= 0.0 {
+        return Err(Error::InvalidOrbit(
+            "the orbit of the minor planet isn't elliptical",
+        ));
+    }
+
+    let period = 360.0 / mean_motion;
+    let periapsis_jd = epoch.value - mean_anomaly / mean_motion;
+    let eccentric = elliptical_newton(deg_to_rad(mean_anomaly), eccentricity)?;
+    let true_anomaly = normalize_degrees(rad_to_deg(
+        2.0 * ((1.0 + eccentricity).sqrt() * (eccentric / 2.0).sin())
+            .atan2((1.0 - eccentricity).sqrt() * (eccentric / 2.0).cos()),
+    ));
+
+    let elements = OrbitalElements {
+        eccentricity,
+        periapsis: Au(semimajor * (1.0 - eccentricity)),
+        inclination,
+        ascending_node,
+        argument_of_periapsis,
+        periapsis_jd,
+        mean_motion,
+        mean_anomaly,
+        true_anomaly,
+        semimajor: Au(semimajor),
+        apoapsis: Au(semimajor * (1.0 + eccentricity)),
+        period: Days(period),
+    };
+
+    // the sun is behind the body, so the solar longitude of the perihelion is half a turn from its longitude
+    let perihelion_ls = normalize_degrees(ascending_node + argument_of_periapsis + 180.0);
+    // the days from the mean anomaly of a solar longitude to the perihelion
+    let days_from = |ls: f64| mean_from_true(ls - perihelion_ls, eccentricity) / mean_motion;
+    let until_perihelion = (-days_from(0.0)).rem_euclid(period);
+    let start = periapsis_jd - until_perihelion;
+    let epoch_jd = start + period * ((epoch.value - start) / period).floor();
+
+    // the month of the perihelion, at the pace of its own solar longitudes
+    let ls_start = (perihelion_ls / MONTH_LS).floor() * MONTH_LS;
+    let pace = (days_from(ls_start + MONTH_LS) - days_from(ls_start)) / MONTH_LS;
+    let month_start = until_perihelion - pace * (perihelion_ls - ls_start);
+
+    let body = CustomBody::builder()
+        .name(String::from(designation))
+        .epoch(epoch_jd)
+        .epoch_scale(TimeScale::TT)
+        .orbital_eccentricity(eccentricity)
+        .orbital_period(period)
+        .rotational_period(EARTH_ROTATIONAL_PERIOD)
+        .semimajor(semimajor)
+        .inclination(inclination)
+        .ascending_node(ascending_node)
+        .perihelion(Perihelion::new(
+            (month_start, month_start + pace * MONTH_LS),
+            (ls_start, ls_start + MONTH_LS),
+            perihelion_ls,
+        )?)
+        .build()?;
+
+    Ok((body, elements))
+}
+
+/// This function parses every line of an MPCORB file, see [`parse_line`]
+///
+/// * The header of `MPCORB.DAT` is skipped up to its line of dashes, and so are the blank lines.
+/// * It fails on the first line that doesn't parse.
+pub fn parse_file(text: &str) -> Result<Vec<(CustomBody, OrbitalElements)>> {
+    let body = match text.split_once("\n-----") {
+        Some((_, rest)) => rest.split_once('\n').map_or("", |(_, lines)| lines),
+        None => text,
+    };
+
+    body.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(parse_line)
+        .collect()
+}
+
+/// This function decodes a packed epoch of the Minor Planet Center (ex; `K24AP`) into its julian date at 0h TT
+///
+/// * The century is a letter (`I` for 1800, `J` for 1900 and `K` for 2000) before the two digits of the year.
+/// * The month and the day are `1` to `9`, then `A` for 10 up to `V` for 31.
+/// * It fails with [`Error::InvalidFormat`] when a character is out of place, and with [`Error::InvalidDate`] when the month or the day doesn't exist.
+///
+/// ```rust
+/// use rust_solar::{julian::TimeScale, mpc::unpack_epoch};
+///
+/// let epoch = unpack_epoch("K24AP").unwrap();
+///
+/// // 2024 October 25
+/// assert_eq!(2460608.5, epoch.value);
+/// assert_eq!(TimeScale::TT, epoch.scale);
+/// ```
+pub fn unpack_epoch(packed: &str) -> Result<JulianDate> {
+    let invalid = Error::InvalidFormat("the packed epoch isn't valid");
+    let &[century, tens, ones, month, day] = packed.as_bytes() else {
+        return Err(invalid);
+    };
+
+    let century = match century {
+        b'I'..=b'L' => i32::from(century - b'I') + 18,
+        _ => return Err(invalid),
+    };
+    let year = match (tens, ones) {
+        (b'0'..=b'9', b'0'..=b'9') => i32::from(tens - b'0') * 10 + i32::from(ones - b'0'),
+        _ => return Err(invalid),
+    };
+
+    let julian_date = greg2jd(
+        century * 100 + year,
+        unpack_digit(month).ok_or(invalid)?,
+        unpack_digit(day).ok_or(invalid)?,
+        0,
+        0,
+        0.0,
+        CalendarSystem::Gregorian,
+    )?;
+
+    Ok(JulianDate::new(julian_date, TimeScale::TT))
+}
+
+/// This function decodes a packed month or day, `1` to `9` then `A` for 10 up to `V` for 31
+fn unpack_digit(digit: u8) -> Option<u32> {
+    match digit {
+        b'1'..=b'9' => Some(u32::from(digit - b'0')),
+        b'A'..=b'V' => Some(u32::from(digit - b'A') + 10),
+        _ => None,
+    }
+}
+
+/// This function turns a true anomaly into a mean anomaly in degrees, from -180 up to 180
+///
+/// > $$E = \operatorname{atan2}(\sqrt{1 - e^2}\sin\nu, e + \cos\nu), \quad M = E - e\sin E$$
+fn mean_from_true(true_anomaly: f64, eccentricity: f64) -> f64 {
+    let nu = deg_to_rad(true_anomaly);
+    let eccentric =
+        ((1.0 - eccentricity * eccentricity).sqrt() * nu.sin()).atan2(eccentricity + nu.cos());
+
+    rad_to_deg(eccentric - eccentricity * eccentric.sin())
+}
+
+/// This function parses a column of a line
+fn number(text: &str) -> Result<f64> {
+    text.parse()
+        .map_err(|_| Error::InvalidFormat("a column of the line isn't a number"))
+}
@@ -1,7 +1,16 @@
-use crate::{anomaly::Anomaly, conversions::radians_in_circle};
-use strum::AsRefStr;
+use alloc::{vec, vec::Vec};
 
-#[derive(Debug, Default, Clone, Copy)]
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    anomaly::{Anomaly, Elements},
+    conversions::{cast, normalize_degrees, rad_to_deg, radians_in_circle, Au, Days},
+    error::{Error, Result},
+};
+use strum::{AsRefStr, IntoStaticStr};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 /// This is the collection of orbital types a body would follow
 pub enum Type {
     /// The orbit path is round, like a donut.
@@ -21,39 +30,129 @@ pub enum Type {
 
 impl Type {
     /// Gives the shape of the keplerian body based of orbital shpae deviation
-    pub fn shape(&self, obe: f64) -> Self {
+    pub fn shape<F: num_traits::Float>(&self, obe: F) -> Self {
         match obe {
-            e if e == 0.0 => Self::Circular,
-            e if e > 0.0 && e < 1.0 => Self::Elliptical,
-            e if e == 1.0 => Self::Parabolic,
-            e if e > 1.0 => Self::Hyperbolic,
-            e if e == f64::INFINITY => Self::Straight,
+            e if e == F::zero() => Self::Circular,
+            e if e > F::zero() && e < F::one() => Self::Elliptical,
+            e if e == F::one() => Self::Parabolic,
+            e if e > F::one() => Self::Hyperbolic,
+            e if e == F::infinity() => Self::Straight,
             _ => Self::Unknown,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 /// This data structure contains perihelion data.
-pub struct Perihelion {
+///
+/// * It's `f64` by default, the anomalies also take it in `f32`, see [`Anomaly`]
+/// * It's easier to write through [`Perihelion::builder`], which names each field and checks their ranges.
+///
+/// The windows are one stretch of the orbit the perihelion is in, like the month of the perihelion.
+/// The sol of the perihelion is found by walking the ls window over the sol window at an even pace,
+/// so they should start and end at the same points of the orbit.
+pub struct Perihelion<F = f64> {
     /// ### (Start, End)
-    pub month: (f64, f64),
+    /// The sols of the year (counted from 0) where the [`Perihelion::ls`] window starts and ends
+    ///
+    /// * It's only used to place the perihelion in the year, see [`Perihelion::date`]
+    pub month: (F, F),
     /// ### (Start, End)
-    pub ls: (f64, f64),
+    /// The solar longitudes in degrees where the [`Perihelion::month`] window starts and ends
+    ///
+    /// * It places the perihelion in the year with the sol window, see [`Perihelion::date`]
+    /// * Its width is also the length of every month, see [`Perihelion::avg_ls`]
+    pub ls: (F, F),
     /// ### The solar longitude of the perihelion
-    pub perihelion: f64,
+    /// In degrees, from 0 up to 360
+    ///
+    /// * It places the perihelion in the year, see [`Perihelion::date`]
+    /// * It turns the true anomaly (measured from the perihelion) into the solar longitude, see [`Perihelion::time`]
+    pub perihelion: F,
 }
 
-impl Perihelion {
+impl<F: num_traits::Float> Perihelion<F> {
+    /// This method creates a perihelion, see [`Perihelion::validate`]
+    pub fn new(month: (F, F), ls: (F, F), perihelion: F) -> Result<Self> {
+        let peri = Self {
+            month,
+            ls,
+            perihelion,
+        };
+
+        peri.validate()?;
+
+        Ok(peri)
+    }
+
+    /// This method checks that the windows aren't empty or reversed, and the perihelion is within the orbit
+    pub fn validate(&self) -> Result<()> {
+        let values = [
+            self.month.0,
+            self.month.1,
+            self.ls.0,
+            self.ls.1,
+            self.perihelion,
+        ];
+
+        if values.iter().any(|value| !value.is_finite()) {
+            return Err(Error::NonFinite);
+        }
+
+        if self.ls.1 <= self.ls.0 {
+            return Err(Error::InvalidPerihelion(
+                "the ls window is empty or reversed",
+            ));
+        }
+
+        if self.month.1 <= self.month.0 {
+            return Err(Error::InvalidPerihelion(
+                "the month window is empty or reversed",
+            ));
+        }
+
+        if self.perihelion < F::zero() || self.perihelion >= cast(360.0) {
+            return Err(Error::InvalidPerihelion(
+                "the perihelion is outside of 0 to 360",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// This method starts a builder, see [`PerihelionBuilder`]
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
+    /// use rust_solar::{kepler::Body, orbit::Perihelion, planets::mars::Mars};
+    ///
+    /// let perihelion = Perihelion::builder()
+    ///     .perihelion_ls(251.0)
+    ///     .perihelion_sol_window(468.5, 514.6)
+    ///     .ls_window(240.0, 270.0)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Mars.perihelion(), perihelion);
+    /// # }
+    /// ```
+    pub fn builder() -> PerihelionBuilder<F> {
+        PerihelionBuilder::default()
+    }
+
     /// The days since the the perihelion by the orbital_period and day in planet
     /// orbital_period is the body's orbital period, not the earth.
-    /// 
-    pub fn elapse(&mut self, day: f64, orbital_period: f64) -> f64 {
+    ///
+    pub fn elapse(&self, day: F, orbital_period: F) -> F {
         (day - self.date()) / orbital_period
     }
 
-    /// The date of the perihelion by the orbital period
-    pub fn date(&mut self) -> f64 {
+    /// The sol of the year (counted from 0) of the perihelion
+    ///
+    /// * The [`Perihelion::perihelion`] is found in the ls window, and the sol window is walked as far in.
+    /// * The mean anomaly is counted from this sol, see [`MeanMotion::by`]
+    pub fn date(&self) -> F {
         let avg_days = self.month.1 - self.month.0;
         let avg_ls = self.ls.1 - self.ls.0;
         let until_peri = self.perihelion - self.ls.0;
@@ -62,17 +161,99 @@ impl Perihelion {
         (peri_day * until_peri) + self.month.0
     }
 
-    /// The time of the perihelion within the orbit
-    pub fn time(&mut self) -> f64 {
-        radians_in_circle() * (1.0 - self.perihelion / 360.0)
+    /// The time of the perihelion within the orbit, as the angle in radians left from the perihelion to ls 0
+    ///
+    /// * The solar longitude is the true anomaly minus this angle, see [`SolarLongitude::compute`]
+    pub fn time(&self) -> F {
+        cast::<F>(radians_in_circle()) * (F::one() - self.perihelion / cast(360.0))
     }
 
-    /// The average solar longitude between the start and end of the perihelion
-    pub fn avg_ls(&mut self) -> f64 {
+    /// The degrees of solar longitude a month covers on average, the width of the [`Perihelion::ls`] window
+    ///
+    /// * Every month is this long, so the months of a year are 360 over it (ex; 30 for 12 months like mars)
+    /// * It's an average of the ls per month, not of the ls in the window, see [`Orbit::month`]
+    pub fn avg_ls(&self) -> F {
         self.ls.1 - self.ls.0
     }
 }
 
+/// This is the builder of a [`Perihelion`]
+///
+/// * Every field is required, see [`Perihelion`] for how each one is used.
+/// * On top of [`Perihelion::validate`], the ls window must be within 0 to 360 and hold the perihelion,
+///   and the sol window can't start before the year.
+#[derive(Debug, Clone, Copy)]
+pub struct PerihelionBuilder<F = f64> {
+    perihelion_ls: Option<F>,
+    sol_window: Option<(F, F)>,
+    ls_window: Option<(F, F)>,
+}
+
+impl<F> Default for PerihelionBuilder<F> {
+    fn default() -> Self {
+        Self {
+            perihelion_ls: None,
+            sol_window: None,
+            ls_window: None,
+        }
+    }
+}
+
+impl<F: num_traits::Float> PerihelionBuilder<F> {
+    /// The solar longitude of the perihelion in degrees, see [`Perihelion::perihelion`]
+    pub fn perihelion_ls(mut self, ls: F) -> Self {
+        self.perihelion_ls = Some(ls);
+        self
+    }
+
+    /// The sols of the year (counted from 0) where the ls window starts and ends, see [`Perihelion::month`]
+    pub fn perihelion_sol_window(mut self, start: F, end: F) -> Self {
+        self.sol_window = Some((start, end));
+        self
+    }
+
+    /// The solar longitudes in degrees of the window the perihelion is in, see [`Perihelion::ls`]
+    pub fn ls_window(mut self, start: F, end: F) -> Self {
+        self.ls_window = Some((start, end));
+        self
+    }
+
+    /// This method checks the fields and creates the perihelion
+    pub fn build(self) -> Result<Perihelion<F>> {
+        let perihelion = self
+            .perihelion_ls
+            .ok_or(Error::InvalidPerihelion("the perihelion is missing"))?;
+        let month = self
+            .sol_window
+            .ok_or(Error::InvalidPerihelion("the sol window is missing"))?;
+        let ls = self
+            .ls_window
+            .ok_or(Error::InvalidPerihelion("the ls window is missing"))?;
+
+        let peri = Perihelion::new(month, ls, perihelion)?;
+
+        if ls.0 < F::zero() || ls.1 > cast(360.0) {
+            return Err(Error::InvalidPerihelion(
+                "the ls window is outside of 0 to 360",
+            ));
+        }
+
+        if perihelion < ls.0 || perihelion > ls.1 {
+            return Err(Error::InvalidPerihelion(
+                "the perihelion is outside of the ls window",
+            ));
+        }
+
+        if month.0 < F::zero() {
+            return Err(Error::InvalidPerihelion(
+                "the sol window starts before the year",
+            ));
+        }
+
+        Ok(peri)
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 /// This is the data for calculating solar longitude among orbiting bodies.
 pub struct SolarLongitude;
@@ -80,69 +261,227 @@ pub struct SolarLongitude;
 impl SolarLongitude {
     /// This method computes the ls which should be given by [`kepler::Body`].
     /// * The final computation is in *degrees*
+    /// * It works in any float precision (ex; `f32` or `f64`), see [`Anomaly`]
     ///
-    pub fn compute(&self, 
+    pub fn compute<F: num_traits::Float>(
+        &self,
         shape: Type,
-        day: f64,
+        day: F,
+        orbital_eccentricity: F,
+        peri: Perihelion<F>,
+        orbital_period: F,
+        major_axis: F,
+    ) -> Result<F> {
+        if !day.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        validate(orbital_eccentricity, orbital_period, major_axis)?;
+
+        solve(
+            shape,
+            day,
+            orbital_eccentricity,
+            peri,
+            orbital_period,
+            major_axis,
+            peri.time(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// This is the part of the solar longitude that stays the same for a body,
+/// so converting many dates only solves the anomaly of each one, see [`crate::kepler::BodyCache`]
+///
+/// * The shape, the semi-latus rectum and the factor of the true anomaly are derived once, see [`Anomaly::try_truly`]
+/// * The date of the perihelion is found once, see [`MeanMotion::since`]
+pub struct Orbit {
+    elements: Elements<f64>,
+    shape: Type,
+    orbital_period: f64,
+    peri_date: f64,
+    peri_time: f64,
+    avg_ls: f64,
+}
+
+impl Orbit {
+    /// This method checks the orbit once and classifies its shape, see [`Type::shape`]
+    #[inline]
+    pub fn new(
         orbital_eccentricity: f64,
-        mut peri: Perihelion,
+        peri: Perihelion,
         orbital_period: f64,
-        major_axis: f64,
-    ) -> f64 {
-        let theta = Anomaly.truly(shape, day, orbital_eccentricity, peri, orbital_period, major_axis);
-        let mut ls = theta - peri.time();
+        semimajor: f64,
+    ) -> Result<Self> {
+        validate(orbital_eccentricity, orbital_period, semimajor)?;
 
-        if ls < 0.0 {
-            ls += radians_in_circle();
-        }
+        let shape = Type::default().shape(orbital_eccentricity);
 
-        if ls > radians_in_circle() {
-            ls -= radians_in_circle();
+        Ok(Self {
+            elements: Elements::new(shape, orbital_eccentricity, semimajor),
+            shape,
+            orbital_period,
+            peri_date: peri.date(),
+            peri_time: peri.time(),
+            avg_ls: peri.avg_ls(),
+        })
+    }
+
+    /// The shape of the orbit
+    pub fn shape(&self) -> Type {
+        self.shape
+    }
+
+    /// The length of a month in degrees of ls, see [`Perihelion::avg_ls`]
+    pub fn avg_ls(&self) -> f64 {
+        self.avg_ls
+    }
+
+    /// This method computes the ls of a sol in *degrees*, the same as [`SolarLongitude::compute`]
+    pub fn ls(&self, day: f64) -> Result<f64> {
+        if !day.is_finite() {
+            return Err(Error::NonFinite);
         }
 
-        ls.to_degrees()
+        let theta =
+            self.elements
+                .truly(MeanMotion.since(day, self.peri_date, self.orbital_period))?;
+
+        Ok(ls_degrees(theta, self.peri_time))
+    }
+
+    /// The month of a solar longitude, each month is [`Perihelion::avg_ls`] degrees long
+    pub fn month(&self, ls: f64) -> f64 {
+        1.0 + (ls / self.avg_ls).floor()
+    }
+}
+
+/// This function solves the anomaly of a finite sol into the ls in *degrees*, `peri_time` is [`Perihelion::time`]
+fn solve<F: num_traits::Float>(
+    shape: Type,
+    day: F,
+    orbital_eccentricity: F,
+    peri: Perihelion<F>,
+    orbital_period: F,
+    major_axis: F,
+    peri_time: F,
+) -> Result<F> {
+    let theta = Anomaly.try_truly(
+        shape,
+        day,
+        orbital_eccentricity,
+        peri,
+        orbital_period,
+        major_axis,
+    )?;
+
+    Ok(ls_degrees(theta, peri_time))
+}
+
+/// This function turns a true anomaly into the ls in *degrees*, from 0 up to 360, `peri_time` is [`Perihelion::time`]
+///
+/// * Every ls goes through here, it's wrapped after the conversion, since an angle a hair below 2π converts to 360.
+fn ls_degrees<F: num_traits::Float>(theta: F, peri_time: F) -> F {
+    normalize_degrees(rad_to_deg(theta - peri_time))
+}
+
+/// Checks that an orbit can be computed from its eccentricity, period and semi-major axis
+pub(crate) fn validate<F: num_traits::Float>(
+    orbital_eccentricity: F,
+    orbital_period: F,
+    major_axis: F,
+) -> Result<()> {
+    if !orbital_eccentricity.is_finite() || !orbital_period.is_finite() || !major_axis.is_finite() {
+        return Err(Error::NonFinite);
+    }
+
+    if orbital_eccentricity < F::zero() {
+        return Err(Error::InvalidEccentricity(
+            orbital_eccentricity.to_f64().unwrap_or(f64::NAN),
+        ));
+    }
+
+    if orbital_period <= F::zero() {
+        return Err(Error::InvalidOrbit("the orbital period isn't positive"));
     }
+
+    Ok(())
 }
 
 #[derive(Debug, Copy, Clone)]
-/// This structure is for the semi axises of an ellipse
-pub struct SemiAxis(pub f64);
+/// This structure is for the semi axises of an ellipse, in `f64` by default
+pub struct SemiAxis<F = f64>(pub F);
 
-impl SemiAxis {
+impl<F: num_traits::Float> SemiAxis<F> {
     /// This is just a wrapper to return the major axis.
     ///
-    pub fn major(self) -> f64 {
+    pub fn major(self) -> F {
         self.0
     }
 
     /// Calculates the shortest distance between the center of the body to the edge of the body.
     ///
     /// ```rust
+    /// # #[cfg(feature = "mars")]
+    /// # {
     ///
     /// use crate::rust_solar::planets::mars::Mars;
     /// use crate::rust_solar::kepler::Body;
     /// use crate::rust_solar::orbit::SemiAxis;
-    /// 
+    ///
     /// let martian_semi_minor_axis = SemiAxis(Mars.semimajor()).minor(Mars.orbital_eccentricity());
     ///
     /// assert_eq!(1.5067401888, martian_semi_minor_axis)
     ///
+    /// # }
     /// ```
-    pub fn minor(self, orbital_eccentricity: f64) -> f64 {
-        self.major() * (1.0 - orbital_eccentricity.powf(2.0))
+    pub fn minor(self, orbital_eccentricity: F) -> F {
+        self.major() * (F::one() - orbital_eccentricity.powf(cast(2.0)))
     }
 }
 
-
+/// These are the osculating elements of an orbit at an instant, as ephemeris services publish them
+///
+/// * The angles are in degrees and the distances in astronomical units, whatever the units of the source.
+/// * They're read from JPL Horizons (`horizons` feature) and the Minor Planet Center (`mpc` feature).
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct OrbitalElements {
+    /// The eccentricity (`EC` of Horizons, `e` of the MPC)
+    pub eccentricity: f64,
+    /// The periapsis distance (`QR` of Horizons)
+    pub periapsis: Au,
+    /// The inclination to the ecliptic of J2000 (`IN` of Horizons, `Incl.` of the MPC)
+    pub inclination: f64,
+    /// The longitude of the ascending node (`OM` of Horizons, `Node` of the MPC)
+    pub ascending_node: f64,
+    /// The argument of periapsis (`W` of Horizons, `Peri.` of the MPC)
+    pub argument_of_periapsis: f64,
+    /// The julian date of the periapsis (`Tp` of Horizons), in the time scale of the source
+    pub periapsis_jd: f64,
+    /// The mean motion in degrees per earth day (`N` of Horizons, `n` of the MPC)
+    pub mean_motion: f64,
+    /// The mean anomaly (`MA` of Horizons, `M` of the MPC)
+    pub mean_anomaly: f64,
+    /// The true anomaly (`TA` of Horizons)
+    pub true_anomaly: f64,
+    /// The semimajor axis (`A` of Horizons, `a` of the MPC)
+    pub semimajor: Au,
+    /// The apoapsis distance (`AD` of Horizons)
+    pub apoapsis: Au,
+    /// The orbital period (`PR` of Horizons)
+    pub period: Days,
+}
 
 /// The collection of seasons in which all keplerian bodies follow
-#[derive(AsRefStr, Debug, Default, Copy, Clone)]
+#[derive(AsRefStr, IntoStaticStr, Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Season {
     /// March 19th
     #[strum(serialize = "Vernal Equinox")]
     VernalEquinox,
 
-    /// July 6 
+    /// July 6
     #[strum(serialize = "Aphelion")]
     Aphelion,
 
@@ -162,6 +501,9 @@ pub enum Season {
     #[strum(serialize = "Winter Solstice")]
     WinterSolstice,
 
+    /// A season from a custom [`SeasonTable`], like the martian dust storm season
+    Custom(&'static str),
+
     /// This structure is not the problem, it must be the solar longitude,
     /// or maybe the planet you chose doesn't have seasons?
     #[strum(serialize = "N/A")]
@@ -170,22 +512,145 @@ pub enum Season {
 }
 
 impl Season {
-    /// This method creates a season given a solar longitude.
-    pub fn from(&self, ls: u32) -> String {
-        match ls  {
-            71 => Self::Aphelion,
-            251 => Self::Perihelion,
-            0..=90 => Self::VernalEquinox,
-            91..=180 => Self::SummerSolstice,
-            181..=270 => Self::AutumnEquinox,
-            271..=360 => Self::WinterSolstice,
-            _ => Self::Unknown
+    /// This method creates a season given a solar longitude in degrees.
+    ///
+    /// * Each season starts at its boundary, so Ls 90.0 is the summer solstice and Ls 89.9 is not.
+    /// * The southern hemisphere is half a year apart, so Ls 0 is its autumn equinox.
+    /// * Aphelion and perihelion belong to the orbit, so they're the same in both hemispheres.
+    pub fn from_ls(ls: f64, hemisphere: Hemisphere) -> Self {
+        let seasonal = match hemisphere {
+            Hemisphere::North => ls,
+            Hemisphere::South if ls.is_finite() => normalize_degrees(ls + 180.0),
+            Hemisphere::South => ls,
+        };
+
+        match ls {
+            ls if (71.0..72.0).contains(&ls) => Self::Aphelion,
+            ls if (251.0..252.0).contains(&ls) => Self::Perihelion,
+            ls if !(0.0..=360.0).contains(&ls) => Self::Unknown,
+            _ if seasonal < 90.0 => Self::VernalEquinox,
+            _ if seasonal < 180.0 => Self::SummerSolstice,
+            _ if seasonal < 270.0 => Self::AutumnEquinox,
+            _ => Self::WinterSolstice,
+        }
+    }
+}
+
+/// This is a table of solar longitude ranges to seasons
+///
+/// The ranges must cover every solar longitude in [0, 360) exactly once.
+///
+/// ```rust
+/// use rust_solar::orbit::{Season, SeasonTable};
+///
+/// let dust = SeasonTable::new(vec![
+///     (0.0, 180.0, Season::Custom("Clear")),
+///     (180.0, 330.0, Season::Custom("Dusty")),
+///     (330.0, 360.0, Season::Custom("Clear")),
+/// ])
+/// .unwrap();
+///
+/// assert_eq!(Season::Custom("Dusty"), dust.season(250.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeasonTable {
+    /// (start, end, season) sorted by the start, the end is not in the range
+    ranges: Vec<(f64, f64, Season)>,
+}
+
+impl SeasonTable {
+    /// This method creates a season table, making sure the ranges have no gaps or overlaps
+    pub fn new(mut ranges: Vec<(f64, f64, Season)>) -> Result<Self> {
+        if ranges
+            .iter()
+            .any(|(start, end, _)| !start.is_finite() || !end.is_finite())
+        {
+            return Err(Error::NonFinite);
+        }
+
+        ranges.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        if ranges.iter().any(|(start, end, _)| start >= end) {
+            return Err(Error::InvalidSeasonTable("a range ends before it starts"));
+        }
+
+        if ranges.first().map(|range| range.0) != Some(0.0)
+            || ranges.last().map(|range| range.1) != Some(360.0)
+        {
+            return Err(Error::InvalidSeasonTable("the ranges don't cover 0 to 360"));
+        }
+
+        if ranges.windows(2).any(|pair| pair[0].1 != pair[1].0) {
+            return Err(Error::InvalidSeasonTable(
+                "the ranges have a gap or an overlap",
+            ));
+        }
+
+        Ok(Self { ranges })
+    }
+
+    /// This method finds the season of a solar longitude in degrees
+    pub fn season(&self, ls: f64) -> Season {
+        self.ranges
+            .iter()
+            .find(|(start, end, _)| (*start..*end).contains(&ls))
+            .map_or(Season::Unknown, |(_, _, season)| *season)
+    }
+
+    /// This method finds the next solar longitude after `ls` where the season changes, and the season that starts there
+    ///
+    /// * The ranges wrap from 360 to 0, so the change after the last range is in the next orbit.
+    /// * Ranges in a row with the same season are one season (ex; `Clear` from 330 through 0 to 180).
+    /// * There's no change when the table has a single season.
+    ///
+    /// ```rust
+    /// use rust_solar::orbit::{Season, SeasonTable};
+    ///
+    /// let seasons = SeasonTable::default();
+    ///
+    /// assert_eq!(Some((90.0, Season::SummerSolstice)), seasons.next_change(80.0));
+    /// assert_eq!(Some((0.0, Season::VernalEquinox)), seasons.next_change(300.0));
+    /// ```
+    pub fn next_change(&self, ls: f64) -> Option<(f64, Season)> {
+        let ls = normalize_degrees(ls);
+        let current = self.season(ls);
+        let after = self.ranges.iter().filter(|(start, _, _)| *start > ls);
+        let before = self.ranges.iter().filter(|(start, _, _)| *start <= ls);
+
+        after
+            .chain(before)
+            .find(|(_, _, season)| *season != current)
+            .map(|(start, _, season)| (*start, *season))
+    }
+}
+
+impl Default for SeasonTable {
+    /// The seasons of [`Season::from_ls`] in the northern hemisphere
+    fn default() -> Self {
+        Self {
+            ranges: vec![
+                (0.0, 71.0, Season::VernalEquinox),
+                (71.0, 72.0, Season::Aphelion),
+                (72.0, 90.0, Season::VernalEquinox),
+                (90.0, 180.0, Season::SummerSolstice),
+                (180.0, 251.0, Season::AutumnEquinox),
+                (251.0, 252.0, Season::Perihelion),
+                (252.0, 270.0, Season::AutumnEquinox),
+                (270.0, 360.0, Season::WinterSolstice),
+            ],
         }
-        .as_ref()
-        .to_string()
     }
 }
 
+/// The hemisphere of a body, which decides the season
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Hemisphere {
+    /// The half of the body above the equator
+    #[default]
+    North,
+    /// The half of the body below the equator
+    South,
+}
 
 /// The mean motion where all bodies share
 #[derive(Debug, Default, Copy, Clone)]
@@ -193,15 +658,20 @@ pub struct MeanMotion;
 
 impl MeanMotion {
     /// This method abstracts the ability to calculate the mean motion
-    /// 
+    ///
     /// * Mean Motion Equation
     /// > $$n={\frac {2\pi }{P}}$$
-    /// 
+    ///
     /// - `n` is the mean motion
     /// - `P` is the orbital period
-    pub fn by(&mut self, day: f64, mut peri: Perihelion, orbital_period: f64) -> f64 {
-        let elapse = Perihelion::elapse(&mut peri, day, orbital_period);
+    pub fn by<F: num_traits::Float>(&self, day: F, peri: Perihelion<F>, orbital_period: F) -> F {
+        self.since(day, peri.date(), orbital_period)
+    }
 
-        radians_in_circle() * (elapse - elapse.round())
+    /// This method is [`MeanMotion::by`] when the date of the perihelion is already known, see [`Perihelion::date`]
+    pub fn since<F: num_traits::Float>(&self, day: F, peri_date: F, orbital_period: F) -> F {
+        let elapse = (day - peri_date) / orbital_period;
+
+        cast::<F>(radians_in_circle()) * (elapse - elapse.round())
     }
-}
\ No newline at end of file
+}
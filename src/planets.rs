@@ -1,6 +1,15 @@
+/// This module contains earth calculations
+#[cfg(feature = "earth")]
+pub mod earth;
 /// This module contains jupiter calculations
+#[cfg(feature = "jupiter")]
 pub mod jupiter;
+/// The orbit of jupiter is always built in, for [`crate::position::sun_offset`]
+#[cfg(not(feature = "jupiter"))]
+#[allow(dead_code)]
+pub(crate) mod jupiter;
 /// This module contains mars calculations
+#[cfg(feature = "mars")]
 pub mod mars;
 /// This module contains mercury calculations
 pub mod mercury;
@@ -9,7 +18,12 @@ pub mod neptune;
 /// This module contains pluto calculations
 pub mod pluto;
 /// This module contains saturn calculations
+#[cfg(feature = "saturn")]
 pub mod saturn;
+/// The orbit of saturn is always built in, for [`crate::position::sun_offset`]
+#[cfg(not(feature = "saturn"))]
+#[allow(dead_code)]
+pub(crate) mod saturn;
 /// This module contains uranus calculations
 pub mod uranus;
 /// This module contains venus calculations
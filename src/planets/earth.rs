@@ -0,0 +1,80 @@
+use crate::{
+    julian::TimeScale,
+    kepler::{Body, Date, Time},
+    orbit::Perihelion,
+};
+
+use super::{EARTH_ORBITAL_PERIOD, EARTH_ROTATIONAL_PERIOD};
+
+/// This is the mean radius of earth in kilometers
+pub const EARTH_RADIUS_KM: f64 = 6_371.0;
+
+/// A.D 2000 March 20, 07:35:00 UTC (Vernal Equinox)
+pub const EARTH_EPOCH_JD: f64 = 2_451_623.816;
+
+#[derive(Debug, Copy, Clone)]
+/// This structure represents the third planet from the sun
+pub struct Earth;
+
+impl Body for Earth {
+    fn name(&self) -> &str {
+        "Earth"
+    }
+
+    /// See [`EARTH_EPOCH_JD`]
+    fn epoch(&self) -> f64 {
+        EARTH_EPOCH_JD
+    }
+
+    /// The [`EARTH_EPOCH_JD`] is in UTC, like the equinoxes of the Astronomical Almanac
+    fn epoch_scale(&self) -> TimeScale {
+        TimeScale::UTC
+    }
+
+    fn orbital_eccentricity(&self) -> f64 {
+        0.0167
+    }
+
+    fn orbital_period(&self) -> f64 {
+        EARTH_ORBITAL_PERIOD
+    }
+
+    fn rotational_period(&self) -> f64 {
+        EARTH_ROTATIONAL_PERIOD
+    }
+
+    /// The perihelion is around January 3rd, 289 days after the vernal equinox
+    fn perihelion(&self) -> Perihelion {
+        Perihelion {
+            month: (276.0, 306.0),
+            ls: (270.0, 300.0),
+            perihelion: 283.0,
+        }
+    }
+
+    fn semimajor(&self) -> f64 {
+        1.0
+    }
+
+    fn axial_tilt(&self) -> f64 {
+        23.44
+    }
+
+    fn mass_kg(&self) -> Option<f64> {
+        Some(5.9722e24)
+    }
+
+    /// See [`EARTH_RADIUS_KM`]
+    fn mean_radius_km(&self) -> Option<f64> {
+        Some(EARTH_RADIUS_KM)
+    }
+
+    /// The orbit of earth is the ecliptic, so it has no inclination or ascending node
+    fn longitude_of_perihelion(&self) -> f64 {
+        102.937
+    }
+
+    fn to_time(&self, _date: Date) -> Time {
+        Time::default().compute()
+    }
+}
@@ -1 +1,48 @@
+/// This is the mean radius of jupiter in kilometers
+pub const JUPITER_RADIUS_KM: f64 = 69_911.0;
 
+/// A.D 1997 August 9, 06:13:49 UTC, the start of a jovian year (Ls 0)
+///
+/// * It's derived from the perihelion of 2023 January 21 and the orbit below.
+pub const JUPITER_EPOCH_JD: f64 = 2_450_669.759_6;
+
+crate::define_body! {
+    /// This structure represents the fifth planet from the sun
+    ///
+    /// * A sol is the solar day of system III, 9 hours 55 minutes and 33 seconds.
+    pub struct Jupiter {
+        name: "Jupiter",
+        epoch: JUPITER_EPOCH_JD,
+        orbital_eccentricity: 0.0489,
+        orbital_period: 10_475.9,
+        rotational_period: 35_733.0,
+        semimajor: 5.2026,
+        perihelion: ((810.6, 1_603.7), (30.0, 60.0), 57.0),
+    }
+    impl {
+        fn axial_tilt(&self) -> f64 {
+            3.13
+        }
+
+        fn mass_kg(&self) -> Option<f64> {
+            Some(1.898_19e27)
+        }
+
+        /// See [`JUPITER_RADIUS_KM`]
+        fn mean_radius_km(&self) -> Option<f64> {
+            Some(JUPITER_RADIUS_KM)
+        }
+
+        fn inclination(&self) -> f64 {
+            1.303
+        }
+
+        fn ascending_node(&self) -> f64 {
+            100.464
+        }
+
+        fn longitude_of_perihelion(&self) -> f64 {
+            14.728
+        }
+    }
+}
@@ -1,251 +1,214 @@
-use std::time::UNIX_EPOCH;
+/// This module contains the Mars24 algorithm, see [`Mars::to_date_mars24`]
+pub mod mars24;
+/// This module contains phobos and deimos, and their transits across the sun, see [`moon_events`]
+pub mod moons;
 
+use alloc::boxed::Box;
+use core::str::FromStr;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
 use crate::{
-    julian::JD2NOON,
-    kepler::{Body, Date, HourType, Time, TimeZone},
-    orbit::{MeanMotion, Perihelion, SemiAxis},
+    clock::{BodyClock, ClockCalibration},
+    error::{Error, Result},
+    julian::{unix_millis_from_jd, SplitJd, TimeScale},
+    kepler::{Body, Date, Time, TimeZone},
 };
-use displaydoc::Display;
-use strum::{AsRefStr, EnumProperty};
 
 use super::EARTH_ROTATIONAL_PERIOD;
 
-#[derive(Debug, Copy, Clone)]
-/// This structure represents the fourth planet from the sun
-pub struct Mars;
+pub use moons::{
+    moon_events, Deimos, MarsMoon, MoonEvent, Phobos, DEIMOS_RADIUS_KM, DEIMOS_SEMIMAJOR_KM,
+    PHOBOS_RADIUS_KM, PHOBOS_SEMIMAJOR_KM,
+};
 
-#[derive(Default, Debug, Copy, Clone, AsRefStr, EnumProperty)]
-/// This structure represents the martian timezone
-///
-/// Offset is in 1 decisol, (-2.5 west, +2.5 east)
-///
-/// There is no DST on mars
-///
-/// 1 sol = 25 hours
-/// 1 decisol = 2.5 hours
-///
-/// 12.5 + 12.5 = 25
-/// MTC-5 to MTC+5 is 25 hours
-pub enum Martian {
-    #[strum(props(
-        Code = "AMT",
-        Name = "Amazonis Time",
-        Offset = "-12.5",
-        East = "-180",
-        West = "-162"
-    ))]
-    /// Mars Coordinated Time - 5
-    MTCn5,
-    #[strum(props(
-        Code = "OT",
-        Name = "Olympus Time",
-        Offset = "-10.0",
-        East = "-162",
-        West = "-126"
-    ))]
-    /// Mars Coordinated Time - 4
-    MTCn4,
-    #[strum(props(
-        Code = "TT",
-        Name = "Tharsis Time",
-        Offset = "-7.5",
-        East = "-126",
-        West = "-90"
-    ))]
-    /// Mars Coordinated Time - 3
-    MTCn3,
-    #[strum(props(
-        Code = "MT",
-        Name = "Marineris Time",
-        Offset = "-5.0",
-        East = "-90",
-        West = "-54"
-    ))]
-    /// Mars Coordinated Time - 2
-    MTCn2,
-    #[strum(props(
-        Code = "AGT",
-        Name = "Argyre Time",
-        Offset = "-2.5",
-        East = "-54",
-        West = "-18"
-    ))]
-    /// Mars Coordinated Time - 1
-    MTCn1,
-    #[default]
-    #[strum(props(
-        Code = "NT",
-        Name = "Noachis Time",
-        Offset = "0.0",
-        East = "-18",
-        West = "18"
-    ))]
-    /// Mars Coordinated Time
-    MTC,
-    #[strum(props(
-        Code = "ABT",
-        Name = "Arabia Time",
-        Offset = "2.5",
-        East = "18",
-        West = "54"
-    ))]
-    /// Mars Coordinated Time + 1
-    MTCp1,
-    #[strum(props(
-        Code = "HT",
-        Name = "Hellas Time",
-        Offset = "5.0",
-        East = "54",
-        West = "90"
-    ))]
-    /// Mars Coordinated Time + 2
-    MTCp2,
-    #[strum(props(
-        Code = "UT",
-        Name = "Utopia Time",
-        Offset = "7.5",
-        East = "90",
-        West = "126"
-    ))]
-    /// Mars Coordinated Time + 3
-    MTCp3,
-    #[strum(props(
-        Code = "ET",
-        Name = "Elysium Time",
-        Offset = "10.0",
-        East = "126",
-        West = "162"
-    ))]
-    /// Mars Coordinated Time + 4
-    MTCp4,
-    #[strum(props(
-        Code = "ACT",
-        Name = "Arcadia Time",
-        Offset = "12.5",
-        East = "162",
-        West = "180"
-    ))]
-    /// Mars Coordinated Time + 5
-    MTCp5,
-}
+/// This is the mean radius of mars in kilometers
+pub const MARS_RADIUS_KM: f64 = 3_389.5;
 
-impl Body for Mars {
-    /// A.D 1975 December 19, 04:00:00.3
-    fn epoch(&self) -> f64 {
-        2.442765667e6
-    }
+/// A.D 1975 December 19, 04:00:00.3 (UTC), the start of Mars Year 12 (Ls 0)
+pub const MARS_EPOCH_JD: f64 = 2_442_765.667;
 
-    fn orbital_eccentricity(&self) -> f64 {
-        0.0934
+crate::define_timezones! {
+    /// This structure represents the martian timezone
+    ///
+    /// Offset is in 1 decisol, (-2.5 west, +2.5 east)
+    ///
+    /// There is no DST on mars
+    ///
+    /// 1 sol = 25 hours
+    /// 1 decisol = 2.5 hours
+    ///
+    /// 12.5 + 12.5 = 25
+    /// MTC-5 to MTC+5 is 25 hours
+    pub enum Martian for MARS_CLOCK {
+        /// Mars Coordinated Time - 5
+        MTCn5 => ("AMT", "Amazonis Time", -12.5, -180.0, -162.0),
+        /// Mars Coordinated Time - 4
+        MTCn4 => ("OT", "Olympus Time", -10.0, -162.0, -126.0),
+        /// Mars Coordinated Time - 3
+        MTCn3 => ("TT", "Tharsis Time", -7.5, -126.0, -90.0),
+        /// Mars Coordinated Time - 2
+        MTCn2 => ("MT", "Marineris Time", -5.0, -90.0, -54.0),
+        /// Mars Coordinated Time - 1
+        MTCn1 => ("AGT", "Argyre Time", -2.5, -54.0, -18.0),
+        /// Mars Coordinated Time
+        #[default]
+        MTC => ("NT", "Noachis Time", 0.0, -18.0, 18.0),
+        /// Mars Coordinated Time + 1
+        MTCp1 => ("ABT", "Arabia Time", 2.5, 18.0, 54.0),
+        /// Mars Coordinated Time + 2
+        MTCp2 => ("HT", "Hellas Time", 5.0, 54.0, 90.0),
+        /// Mars Coordinated Time + 3
+        MTCp3 => ("UT", "Utopia Time", 7.5, 90.0, 126.0),
+        /// Mars Coordinated Time + 4
+        MTCp4 => ("ET", "Elysium Time", 10.0, 126.0, 162.0),
+        /// Mars Coordinated Time + 5
+        MTCp5 => ("ACT", "Arcadia Time", 12.5, 162.0, 180.0),
     }
+}
 
-    fn orbital_period(&self) -> f64 {
-        668.6
+crate::define_body! {
+    /// This structure represents the fourth planet from the sun
+    pub struct Mars {
+        name: "Mars",
+        epoch: MARS_EPOCH_JD,
+        orbital_eccentricity: 0.0934,
+        orbital_period: 668.6,
+        rotational_period: 88_775.245,
+        semimajor: 1.52,
+        perihelion: ((468.5, 514.6), (240.0, 270.0), 251.0),
     }
+    impl {
+        /// The epoch begins Mars Year 12 of the Clancy numbering, where Mars Year 1 began on 1955 April 11
+        fn epoch_year(&self) -> f64 {
+            12.0
+        }
 
-    fn rotational_period(&self) -> f64 {
-        88_775.245
-    }
+        /// The [`MARS_EPOCH_JD`] is in UTC, like the year starts of the Clancy numbering
+        fn epoch_scale(&self) -> TimeScale {
+            TimeScale::UTC
+        }
 
-    fn perihelion(&self) -> Perihelion {
-        Perihelion {
-            month: (468.5, 514.6),
-            ls: (240.0, 270.0),
-            perihelion: 251.0,
+        fn axial_tilt(&self) -> f64 {
+            25.19
         }
-    }
 
-    fn semimajor(&self) -> f64 {
-        1.52
-    }
+        fn mass_kg(&self) -> Option<f64> {
+            Some(6.4171e23)
+        }
 
-    fn semiminor(&self) -> f64 {
-        SemiAxis(self.semimajor()).minor(self.orbital_eccentricity())
-    }
+        /// See [`MARS_RADIUS_KM`]
+        fn mean_radius_km(&self) -> Option<f64> {
+            Some(MARS_RADIUS_KM)
+        }
 
-    fn mean_motion(&mut self, day: f64) -> f64 {
-        MeanMotion::by(
-            &mut MeanMotion,
-            day,
-            self.perihelion(),
-            self.orbital_period(),
-        )
-    }
+        fn inclination(&self) -> f64 {
+            1.850
+        }
 
-    fn to_date(&mut self, julian_date: f64) -> Date {
-        Date::default().compute(
-            julian_date,
-            self.epoch(),
-            self.rotational_period(),
-            self.perihelion(),
-            self.semimajor(),
-            self.orbital_eccentricity(),
-            self.orbital_period(),
-        )
-    }
+        fn ascending_node(&self) -> f64 {
+            49.558
+        }
 
-    fn to_time(&mut self, date: Date) -> Time {
-        Time::default().compute()
+        fn longitude_of_perihelion(&self) -> f64 {
+            336.060
+        }
+
+        /// The mars sol date (MSD)
+        fn sol_date(&self, julian_date: f64) -> f64 {
+            mars_sol_date(julian_date)
+        }
+
+        fn timezone(&self, code: &str) -> Option<Box<dyn TimeZone>> {
+            Martian::from_str(code)
+                .ok()
+                .map(|zone| Box::new(zone) as Box<dyn TimeZone>)
+        }
+
+        fn timezones(&self) -> &[crate::clock::ZoneInfo] {
+            Martian::INFO
+        }
     }
 }
 
-impl TimeZone for Martian {
-    /// Body Earth Ratio
+impl Martian {
+    /// This method returns the mars sol date (MSD) shifted by the offset of the timezone
     ///
-    /// * body_rotational_period / earth_rotational_period
+    /// * The whole part is the local sol and the fraction is the local time of the sol.
+    pub fn local_sol_date(&self, julian_date: f64) -> f64 {
+        MARS_CLOCK.sol_date(julian_date, self.info().offset)
+    }
+
+    /// This method converts a date and a time in this timezone back into a julian date (UTC).
     ///
-    /// Body Moon Ratio
+    /// The date is required because the time alone repeats every sol.
     ///
-    /// * moon_rotational_period / body_rotational_period (host planet of the exact moon)
+    /// * The date is the one of this timezone, like [`Body::to_datetime`], so it's a sol ahead or behind of the MTC
+    ///   when the offset carries the time across midnight, see [`TimeZone::sol_carry`].
     ///
-    fn new(&self) -> Time {
-        let millis = std::time::SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Unix Epoch to function")
-            .as_millis() as f64;
-
-        let jd_ut = 2_440_587.5 + (millis / EARTH_ROTATIONAL_PERIOD * 1000.0);
-        let jd_tt = jd_ut + (37.0 + 32.184) / EARTH_ROTATIONAL_PERIOD;
-        let jd2000_t = jd_tt - JD2NOON;
-        let mars_earth_ratio = 1.027491252_f64;
-        let midday = 44_796.0_f64;
-        let alignment = 0.00096_f64;
-        let msx0 = jd2000_t - 4.5;
-        let msd = (msx0 / mars_earth_ratio) + midday - alignment;
-        // let mtc = (24.0 * msd) % 24.0;
-        let fh = msd.fract(); // Fractional Hour
-        let mut hour = (24.0 * fh).floor();
-        let fm = (24.0 * fh).fract();
-        let minute = (60.0 * fm).floor();
-        let second = 60.0 * (60.0 * fm).fract();
-        let hour_type = HourType::default().new(
-            hour as u8
-                + self
-                    .get_str("Offset")
-                    .unwrap()
-                    .parse::<f64>()
-                    .expect("Offset to be established") as u8,
-        );
-
-        match hour as u8 > 24 {
-            true => hour = 0.0,
-            false => (),
+    pub fn to_utc(&self, date: &Date, time: &Time) -> Result<f64> {
+        if !(0..24).contains(&time.hour)
+            || time.minute >= 60
+            || time.second >= 60
+            || time.millisecond >= 1000
+        {
+            return Err(Error::InvalidTime("the time is outside of the sol"));
         }
 
-        println!(
-            "East: {:?}, West: {:?}",
-            self.get_str("East").unwrap(),
-            self.get_str("West").unwrap()
-        );
-        
-        Time {
-            hour: hour as i32,
-            minute: minute as u8,
-            second: second as u8,
-            code: self.get_str("Code").unwrap().to_string(),
-            name: self.get_str("Name").unwrap().to_string(),
-            offset_name: self.as_ref().to_string(),
-            hour_type: hour_type,
-        }
+        let start = Mars.to_julian(date)?;
+        let local = (f64::from(time.hour) * 3600.0
+            + f64::from(time.minute) * 60.0
+            + f64::from(time.second)
+            + f64::from(time.millisecond) / 1000.0)
+            / EARTH_ROTATIONAL_PERIOD;
+        let mtc = local - self.info().offset / 24.0;
+        let elapsed = (mtc - mars_sol_date(start)).rem_euclid(1.0);
+        let prime = start + elapsed * MARS_CALIBRATION.sol_length;
+        // the carry of the local date only depends on the time, so it's the same on the sol of the MTC
+        let carry = MARS_CLOCK.sol_carry(prime, self.info().offset) as f64;
+
+        Ok(prime - (Mars.rotational_period_seconds() * carry).to_days().0)
+    }
+
+    /// This method is [`Martian::to_utc`] given in unix milliseconds.
+    pub fn to_unix_millis(&self, date: &Date, time: &Time) -> Result<f64> {
+        Ok(unix_millis_from_jd(self.to_utc(date, time)?))
     }
 }
+
+/// The calibration of the mars sol date (MSD), as used by Mars24
+pub const MARS_CALIBRATION: ClockCalibration = ClockCalibration {
+    epoch_tt: 2_451_549.5,
+    sol_length: 1.027491252,
+    sol_offset: 44_796.0,
+    alignment: 0.00096,
+};
+
+/// The clock of the martian timezones
+const MARS_CLOCK: BodyClock<Mars> = BodyClock::new(Mars, MARS_CALIBRATION);
+
+impl Mars {
+    /// This method returns the mars sol date (MSD) of a julian date (UTC), see [`mars_sol_date`]
+    pub fn msd(&self, julian_date: impl Into<SplitJd>) -> f64 {
+        mars_sol_date(julian_date)
+    }
+}
+
+/// This function returns the mars sol date (MSD) of a julian date (UTC)
+///
+/// The MSD is the count of sols since December 29, 1873, as used by Mars24.
+///
+/// * A [`SplitJd`] keeps the precision of the fraction, see [`SplitJd`]
+///
+/// > $$MSD = \frac{JD_{TT} - 2451549.5}{1.027491252} + 44796.0 - 0.00096$$
+pub fn mars_sol_date(julian_date: impl Into<SplitJd>) -> f64 {
+    MARS_CALIBRATION.sol_date(julian_date)
+}
+
+/// This function returns the coordinated mars time (MTC) of a julian date (UTC) in fractional hours
+///
+/// > $$MTC = 24 \times (MSD \bmod 1)$$
+pub fn coordinated_mars_time(julian_date: impl Into<SplitJd>) -> f64 {
+    24.0 * mars_sol_date(julian_date).rem_euclid(1.0)
+}
@@ -0,0 +1,207 @@
+//! The Mars24 algorithm of NASA GISS (Allison & McEwen 2000), with its perturbation terms
+//!
+//! * It only works for mars, but it's the reference most tools compare against, see [`Mars::to_date_mars24`]
+//! * The equations are numbered like the Mars24 worked examples (ex; `B-4` is the equation of center)
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    conversions::{deg_to_rad, normalize_degrees, rad_to_deg},
+    error::{Error, Result},
+    julian::{utc_to_tt, Epoch},
+    kepler::{Body, Date, Eras},
+    orbit::{Hemisphere, Orbit, Season},
+};
+
+use super::{coordinated_mars_time, mars_sol_date, Mars};
+
+/// The length of the martian tropical year in earth days
+pub const MARS_YEAR_DAYS: f64 = 686.9726;
+
+/// 1955 April 11, the day Mars Year 1 of the Clancy numbering begins (Ls 0)
+pub const MARS_YEAR_ONE_JD: f64 = 2_435_208.5;
+
+/// The amplitude in degrees, the period in julian years and the phase in degrees
+/// of the perturbations of jupiter, earth and venus (B-3)
+const PERTURBERS: [(f64, f64, f64); 7] = [
+    (0.0071, 2.2353, 49.409),
+    (0.0057, 2.7543, 168.173),
+    (0.0039, 1.1177, 191.837),
+    (0.0037, 15.7866, 21.736),
+    (0.0021, 2.1354, 15.704),
+    (0.0020, 2.4694, 95.528),
+    (0.0018, 32.8493, 49.095),
+];
+
+/// The tries to find the start of a mars year before giving up
+const MAX_ITERATIONS: u32 = 50;
+
+/// This is every step of the Mars24 algorithm at one julian date (UTC)
+///
+/// ```rust
+/// use rust_solar::planets::mars::mars24::Mars24;
+///
+/// // the worked example of Mars24, 2000 January 6 00:00:00 UTC
+/// let mars24 = Mars24::at(2451549.5).unwrap();
+///
+/// assert!((mars24.ls - 277.18758).abs() < 1e-4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mars24 {
+    /// (A-5) The days since J2000 in terrestrial time (TT)
+    pub j2000_tt: f64,
+    /// (B-1) The mean anomaly in degrees
+    pub mean_anomaly: f64,
+    /// (B-2) The angle of the fictitious mean sun in degrees
+    pub fictitious_mean_sun: f64,
+    /// (B-3) The perturbations of the other planets in degrees
+    pub perturbers: f64,
+    /// (B-4) The equation of center in degrees, the true anomaly minus the mean anomaly
+    pub equation_of_center: f64,
+    /// (B-5) The solar longitude (Ls) in degrees
+    pub ls: f64,
+    /// (C-1) The equation of time in degrees, the true solar time minus the mean solar time
+    pub equation_of_time: f64,
+    /// (C-2) The mars sol date (MSD), see [`mars_sol_date`]
+    pub msd: f64,
+    /// (C-3) The coordinated mars time (MTC) in hours, see [`coordinated_mars_time`]
+    pub mtc: f64,
+    /// (D-1) The declination of the sun in degrees
+    pub solar_declination: f64,
+    /// (D-2) The distance from mars to the sun in AU
+    pub heliocentric_distance: f64,
+}
+
+impl Mars24 {
+    /// This method runs the algorithm at a julian date (UTC)
+    pub fn at(julian_date: f64) -> Result<Self> {
+        if !julian_date.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        let j2000_tt = Epoch::J2000.days_since(utc_to_tt(julian_date));
+        let mean_anomaly = 19.3871 + 0.52402073 * j2000_tt;
+        let fictitious_mean_sun = 270.3871 + 0.524038496 * j2000_tt;
+        let perturbers = PERTURBERS
+            .iter()
+            .map(|(amplitude, period, phase)| {
+                amplitude * deg_to_rad(0.985626 * j2000_tt / period + phase).cos()
+            })
+            .sum::<f64>();
+
+        let m = deg_to_rad(mean_anomaly);
+        let equation_of_center = (10.691 + 3.0e-7 * j2000_tt) * m.sin()
+            + 0.623 * (2.0 * m).sin()
+            + 0.050 * (3.0 * m).sin()
+            + 0.005 * (4.0 * m).sin()
+            + 0.0005 * (5.0 * m).sin()
+            + perturbers;
+        let ls = normalize_degrees(fictitious_mean_sun + equation_of_center);
+
+        let l = deg_to_rad(ls);
+        let equation_of_time = 2.861 * (2.0 * l).sin() - 0.071 * (4.0 * l).sin()
+            + 0.002 * (6.0 * l).sin()
+            - equation_of_center;
+        let solar_declination = rad_to_deg((0.42565 * l.sin()).asin()) + 0.25 * l.sin();
+        let heliocentric_distance = 1.52367934
+            * (1.00436
+                - 0.09309 * m.cos()
+                - 0.004336 * (2.0 * m).cos()
+                - 0.00031 * (3.0 * m).cos()
+                - 0.00003 * (4.0 * m).cos());
+
+        Ok(Self {
+            j2000_tt,
+            mean_anomaly,
+            fictitious_mean_sun,
+            perturbers,
+            equation_of_center,
+            ls,
+            equation_of_time,
+            msd: mars_sol_date(julian_date),
+            mtc: coordinated_mars_time(julian_date),
+            solar_declination,
+            heliocentric_distance,
+        })
+    }
+}
+
+/// This function returns the solar longitude (Ls) of a julian date (UTC) in degrees, see [`Mars24`]
+pub fn ls(julian_date: f64) -> Result<f64> {
+    Ok(Mars24::at(julian_date)?.ls)
+}
+
+/// This function finds the julian date (UTC) where the mars year of a julian date begins (Ls 0)
+fn year_start(julian_date: f64) -> Result<f64> {
+    let mut start = julian_date - ls(julian_date)? / 360.0 * MARS_YEAR_DAYS;
+
+    for _ in 0..MAX_ITERATIONS {
+        // the Ls that's left to go back, from -180 to 180
+        let behind = normalize_degrees(ls(start)? + 180.0) - 180.0;
+        let rate = (ls(start + 0.5)? - ls(start - 0.5)? + 540.0) % 360.0 - 180.0;
+
+        if behind.abs() < 1e-9 {
+            return match start > julian_date {
+                true => year_start(start - 1.0),
+                false => Ok(start),
+            };
+        }
+
+        start -= behind / rate;
+    }
+
+    Err(Error::NoConvergence(MAX_ITERATIONS))
+}
+
+impl Mars {
+    /// This method computes the date of a julian date (UTC) with the Mars24 algorithm, see [`Mars::try_to_date_mars24`]
+    ///
+    /// # Panics
+    ///
+    /// When the julian date can't be computed, see [`Body::try_to_date`]
+    pub fn to_date_mars24(&self, julian_date: f64) -> Date {
+        self.try_to_date_mars24(julian_date)
+            .expect("The julian date and the body to be valid")
+    }
+
+    /// This method computes the date of a julian date (UTC) with the Mars24 algorithm instead of the kepler solver
+    ///
+    /// * The year is the mars year of the Clancy numbering, and it begins where the Ls crosses 0.
+    /// * The sol of the year is counted in mars sol dates (MSD) from the start of the year.
+    /// * It's closer to Mars24 than [`Body::try_to_date`], but only mars has it.
+    ///
+    /// ```rust
+    /// use rust_solar::{kepler::Eras, planets::mars::Mars};
+    ///
+    /// let date = Mars.try_to_date_mars24(2459264.371527778).unwrap();
+    ///
+    /// // perseverance landed on the twelfth sol of mars year 36
+    /// assert_eq!((Eras::AD, 36.0, 12.0), (date.era, date.year, date.day));
+    /// ```
+    pub fn try_to_date_mars24(&self, julian_date: f64) -> Result<Date> {
+        let ls = ls(julian_date)?;
+        let start = year_start(julian_date)?;
+        let year = 1.0 + ((start - MARS_YEAR_ONE_JD) / MARS_YEAR_DAYS).round();
+        let sol = mars_sol_date(julian_date) - mars_sol_date(start);
+        let orbit = Orbit::new(
+            self.orbital_eccentricity(),
+            self.perihelion(),
+            self.orbital_period(),
+            self.semimajor(),
+        )?;
+
+        Ok(Date {
+            era: match year > 0.0 {
+                true => Eras::AD,
+                false => Eras::BD,
+            },
+            year,
+            month: orbit.month(ls),
+            day: 1.0 + sol.floor(),
+            ls,
+            season: Season::from_ls(ls, Hemisphere::North),
+            sol,
+        })
+    }
+}
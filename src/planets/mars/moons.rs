@@ -0,0 +1,431 @@
+//! The moons of mars, phobos and deimos, and their transits across the sun as seen from the surface of mars
+//!
+//! * [`Phobos`] and [`Deimos`] are [`HostedBody`](crate::kepler::HostedBody)s, their year is the year of mars.
+//! * The moons and mars turn like the rotation models of the IAU (Archinal et al. 2018). The prime meridian
+//!   of a moon faces mars, so the moon is opposite its prime meridian,
+//!   and the sun follows [`Mars24`](super::mars24::Mars24).
+//! * The moons are on circles of their semimajor axes, which is within 1.5% of their distance,
+//!   and the site is on a sphere of the mean radius of mars.
+//! * The times are within a few minutes, the eccentricity of phobos (0.015) puts it up to 1.7 degrees ahead or behind
+//!   on its circle, so a grazing transit can be missed or found a sol apart.
+
+use alloc::vec::Vec;
+
+use strum::{AsRefStr, IntoStaticStr};
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    conversions::{deg_to_rad, normalize_degrees, rad_to_deg, KM_PER_AU},
+    error::{Error, Result},
+    events::{self, Crossing, Extremum},
+    julian::{utc_to_tt, Epoch, TimeScale},
+    kepler::{Body, HostedBody, SUN_RADIUS_KM},
+};
+
+use super::{mars24::Mars24, Mars, MARS_EPOCH_JD, MARS_RADIUS_KM};
+
+/// This is the mean radius of phobos in kilometers
+pub const PHOBOS_RADIUS_KM: f64 = 11.08;
+
+/// This is the mean radius of deimos in kilometers
+pub const DEIMOS_RADIUS_KM: f64 = 6.2;
+
+/// This is the semimajor axis of the orbit of phobos in kilometers
+pub const PHOBOS_SEMIMAJOR_KM: f64 = 9_376.0;
+
+/// This is the semimajor axis of the orbit of deimos in kilometers
+pub const DEIMOS_SEMIMAJOR_KM: f64 = 23_458.0;
+
+/// The sol of phobos, from one conjunction with the sun to the next, in earth days
+const PHOBOS_SOL: f64 = 0.319_06;
+
+/// The sol of deimos, from one conjunction with the sun to the next, in earth days
+const DEIMOS_SOL: f64 = 1.264_77;
+
+/// The sol of mars in earth days, the perihelion of a moon is the one of mars
+const MARS_SOL: f64 = 88_775.245 / 86_400.0;
+
+crate::define_body! {
+    /// This structure represents the inner moon of mars
+    ///
+    /// * A sol is from one conjunction with the sun to the next, it's locked to mars.
+    /// * The year follows the orbit of mars around the sun.
+    pub struct Phobos {
+        name: "Phobos",
+        epoch: MARS_EPOCH_JD,
+        orbital_eccentricity: 0.0934,
+        orbital_period: 686.98 / PHOBOS_SOL,
+        rotational_period: PHOBOS_SOL * 86_400.0,
+        semimajor: 1.52,
+        perihelion: (
+            (468.5 * MARS_SOL / PHOBOS_SOL, 514.6 * MARS_SOL / PHOBOS_SOL),
+            (240.0, 270.0),
+            251.0,
+        ),
+    }
+    impl {
+        /// The year of phobos is the year of mars, see [`Mars::epoch_year`]
+        fn epoch_year(&self) -> f64 {
+            Mars.epoch_year()
+        }
+
+        /// The [`MARS_EPOCH_JD`] is in UTC
+        fn epoch_scale(&self) -> TimeScale {
+            TimeScale::UTC
+        }
+
+        fn is_tidally_locked(&self) -> bool {
+            true
+        }
+
+        fn mass_kg(&self) -> Option<f64> {
+            Some(1.0659e16)
+        }
+
+        /// See [`PHOBOS_RADIUS_KM`]
+        fn mean_radius_km(&self) -> Option<f64> {
+            Some(PHOBOS_RADIUS_KM)
+        }
+    }
+}
+
+crate::define_body! {
+    /// This structure represents the outer moon of mars
+    ///
+    /// * A sol is from one conjunction with the sun to the next, it's locked to mars.
+    /// * The year follows the orbit of mars around the sun.
+    pub struct Deimos {
+        name: "Deimos",
+        epoch: MARS_EPOCH_JD,
+        orbital_eccentricity: 0.0934,
+        orbital_period: 686.98 / DEIMOS_SOL,
+        rotational_period: DEIMOS_SOL * 86_400.0,
+        semimajor: 1.52,
+        perihelion: (
+            (468.5 * MARS_SOL / DEIMOS_SOL, 514.6 * MARS_SOL / DEIMOS_SOL),
+            (240.0, 270.0),
+            251.0,
+        ),
+    }
+    impl {
+        /// The year of deimos is the year of mars, see [`Mars::epoch_year`]
+        fn epoch_year(&self) -> f64 {
+            Mars.epoch_year()
+        }
+
+        /// The [`MARS_EPOCH_JD`] is in UTC
+        fn epoch_scale(&self) -> TimeScale {
+            TimeScale::UTC
+        }
+
+        fn is_tidally_locked(&self) -> bool {
+            true
+        }
+
+        fn mass_kg(&self) -> Option<f64> {
+            Some(1.4762e15)
+        }
+
+        /// See [`DEIMOS_RADIUS_KM`]
+        fn mean_radius_km(&self) -> Option<f64> {
+            Some(DEIMOS_RADIUS_KM)
+        }
+    }
+}
+
+/// The orbit of phobos around mars
+impl HostedBody for Phobos {
+    fn host(&self) -> &'static dyn Body {
+        &Mars
+    }
+
+    /// See [`PHOBOS_SEMIMAJOR_KM`]
+    fn host_semimajor_km(&self) -> f64 {
+        PHOBOS_SEMIMAJOR_KM
+    }
+
+    fn host_orbital_eccentricity(&self) -> f64 {
+        0.0151
+    }
+
+    /// The host longitude from the rotation model of the moon, see [`MarsMoon::position`]
+    fn host_longitude(&self, julian_date: f64) -> Result<f64> {
+        host_longitude(MarsMoon::Phobos, julian_date)
+    }
+}
+
+/// The orbit of deimos around mars
+impl HostedBody for Deimos {
+    fn host(&self) -> &'static dyn Body {
+        &Mars
+    }
+
+    /// See [`DEIMOS_SEMIMAJOR_KM`]
+    fn host_semimajor_km(&self) -> f64 {
+        DEIMOS_SEMIMAJOR_KM
+    }
+
+    fn host_orbital_eccentricity(&self) -> f64 {
+        0.0002
+    }
+
+    /// The host longitude from the rotation model of the moon, see [`MarsMoon::position`]
+    fn host_longitude(&self, julian_date: f64) -> Result<f64> {
+        host_longitude(MarsMoon::Deimos, julian_date)
+    }
+}
+
+/// The step of the search in earth days, phobos crosses the sky in about 4 hours and deimos in about 2.5 days
+const SEARCH_STEP: f64 = 10.0 / 1_440.0;
+
+/// The step of the rate of the separation in earth days, about a second
+const RATE_STEP: f64 = 1.0 / 86_400.0;
+
+/// This is a moon of mars
+#[derive(Debug, AsRefStr, IntoStaticStr, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MarsMoon {
+    /// The inner moon, it rises in the west twice a sol
+    Phobos,
+    /// The outer moon, it takes about 2.5 sols to cross the sky
+    Deimos,
+}
+
+impl MarsMoon {
+    /// Both moons, from the inner one
+    pub const ALL: [Self; 2] = [Self::Phobos, Self::Deimos];
+
+    /// The body of the moon, with its orbit around mars
+    pub fn body(self) -> &'static dyn HostedBody {
+        match self {
+            Self::Phobos => &Phobos,
+            Self::Deimos => &Deimos,
+        }
+    }
+
+    /// The mean radius of the moon in kilometers
+    pub fn radius_km(self) -> f64 {
+        self.body().mean_radius_km().unwrap_or(0.0)
+    }
+
+    /// The semimajor axis of the orbit of the moon in kilometers, see [`HostedBody::host_semimajor_km`]
+    pub fn semimajor_km(self) -> f64 {
+        self.body().host_semimajor_km()
+    }
+
+    /// This method returns the position of the moon from the center of mars at a julian date (UTC), in kilometers
+    ///
+    /// * `x` points to the prime meridian of mars, `y` to the longitude of 90 degrees east and `z` to the north pole.
+    pub fn position(self, julian_date: f64) -> Result<[f64; 3]> {
+        if !julian_date.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        let days = Epoch::J2000.days_since(utc_to_tt(julian_date));
+        let centuries = days / 36_525.0;
+        let m1 = deg_to_rad(169.51 - 0.435_764_0 * days);
+        let m2 = deg_to_rad(192.93 + 1_128.409_670_0 * days + 8.864 * centuries * centuries);
+        let m3 = deg_to_rad(53.47 - 0.018_118_4 * days);
+
+        let (ra, dec, meridian) = match self {
+            Self::Phobos => (
+                317.68 - 0.108 * centuries + 1.79 * m1.sin() - 0.10 * m2.sin(),
+                52.90 - 0.061 * centuries - 1.08 * m1.cos() + 0.06 * m2.cos(),
+                35.06 + 1_128.844_585_0 * days + 8.864 * centuries * centuries
+                    - 1.42 * m1.sin()
+                    - 0.78 * m2.sin(),
+            ),
+            Self::Deimos => (
+                316.65 - 0.108 * centuries + 2.98 * m3.sin(),
+                53.52 - 0.061 * centuries - 1.78 * m3.cos(),
+                79.41 + 285.161_897_0 * days - 0.520 * centuries * centuries - 2.58 * m3.sin()
+                    + 0.19 * m3.cos(),
+            ),
+        };
+
+        let (mars_meridian, mars_east, mars_pole) = frame(
+            317.681_43 - 0.1061 * centuries,
+            52.886_50 - 0.0609 * centuries,
+            176.630 + 350.891_982_26 * days,
+        );
+        // the prime meridian of the moon points to mars
+        let (moon_meridian, _, _) = frame(ra, dec, meridian);
+        let position = moon_meridian.map(|axis| -axis * self.semimajor_km());
+
+        Ok([
+            dot(position, mars_meridian),
+            dot(position, mars_east),
+            dot(position, mars_pole),
+        ])
+    }
+}
+
+/// This is a transit of a moon of mars across the sun, as seen from a site on mars, see [`moon_events`]
+///
+/// * Phobos covers at most about half of the width of the sun, and deimos a dot of it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoonEvent {
+    /// The moon that crosses the sun
+    pub moon: MarsMoon,
+    /// The julian date (UTC) where the moon touches the sun
+    pub start_jd: f64,
+    /// The julian date (UTC) where the moon is the closest to the center of the sun
+    pub peak_jd: f64,
+    /// The julian date (UTC) where the moon leaves the sun
+    pub end_jd: f64,
+    /// The angle in degrees between the centers of the moon and the sun at the peak
+    pub separation: f64,
+    /// The angular radius in degrees of the sun at the peak
+    pub sun_radius: f64,
+    /// The angular radius in degrees of the moon at the peak
+    pub moon_radius: f64,
+}
+
+/// This function finds the transits of phobos and deimos across the sun from `start_jd` to `end_jd` (UTC)
+///
+/// * The site is at a planetocentric latitude and an east longitude in degrees (ex; Gale crater is at -4.59, 137.44)
+/// * A transit only counts when the sun is above the horizon at its peak, the events are sorted by their peak.
+/// * The times are within a few minutes, see the [module](self) for why.
+/// * It fails with [`Error::NonFinite`] when an argument isn't finite.
+///
+/// ```rust
+/// use rust_solar::planets::mars::{moon_events, MarsMoon};
+///
+/// // curiosity saw phobos cross the sun on sol 2359, 2019 March 26 in California
+/// let events = moon_events(-4.59, 137.44, 2458569.5, 2458570.0).unwrap();
+///
+/// assert!(events.iter().any(|event| event.moon == MarsMoon::Phobos));
+/// ```
+pub fn moon_events(lat: f64, lon: f64, start_jd: f64, end_jd: f64) -> Result<Vec<MoonEvent>> {
+    if !lat.is_finite() || !lon.is_finite() {
+        return Err(Error::NonFinite);
+    }
+
+    let (lat, lon) = (deg_to_rad(lat), deg_to_rad(lon));
+    let up = [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()];
+    let site = up.map(|axis| axis * MARS_RADIUS_KM);
+    let mut found = Vec::new();
+
+    for moon in MarsMoon::ALL {
+        let separation = |jd: f64| -> Result<f64> { Ok(sky(moon, site, jd)?.separation) };
+        // the separation stops shrinking at a closest approach
+        let rate = |jd: f64| -> Result<f64> {
+            Ok(separation(jd + RATE_STEP)? - separation(jd - RATE_STEP)?)
+        };
+
+        for bracket in events::try_scan(rate, (start_jd, end_jd), SEARCH_STEP, Crossing::Rising)? {
+            let peak_jd = events::try_find_extremum(separation, bracket?, 1e-7, Extremum::Minimum)?;
+            let peak = sky(moon, site, peak_jd)?;
+            let contact = peak.sun_radius + peak.moon_radius;
+
+            if peak.separation >= contact || dot(peak.sun, up) <= 0.0 {
+                continue;
+            }
+
+            let touching = |jd: f64| -> Result<f64> { Ok(separation(jd)? - contact) };
+
+            found.push(MoonEvent {
+                moon,
+                start_jd: events::try_find_crossing(
+                    touching,
+                    (peak_jd - SEARCH_STEP, peak_jd),
+                    1e-8,
+                )?,
+                peak_jd,
+                end_jd: events::try_find_crossing(
+                    touching,
+                    (peak_jd, peak_jd + SEARCH_STEP),
+                    1e-8,
+                )?,
+                separation: peak.separation,
+                sun_radius: peak.sun_radius,
+                moon_radius: peak.moon_radius,
+            });
+        }
+    }
+
+    found.sort_by(|a, b| a.peak_jd.total_cmp(&b.peak_jd));
+
+    Ok(found)
+}
+
+/// This is the sun and a moon in the sky of a site
+struct Sky {
+    /// The direction of the sun in the frame of mars
+    sun: [f64; 3],
+    /// The angle in degrees between the centers of the moon and the sun
+    separation: f64,
+    /// The angular radius in degrees of the sun
+    sun_radius: f64,
+    /// The angular radius in degrees of the moon
+    moon_radius: f64,
+}
+
+/// This function returns the direction of the sun in the frame of mars, the subsolar point of Mars24
+fn subsolar(mars24: &Mars24) -> [f64; 3] {
+    // from the west longitude of Mars24
+    let lat = deg_to_rad(mars24.solar_declination);
+    let lon = -deg_to_rad(mars24.mtc * 15.0 + mars24.equation_of_time + 180.0);
+
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+/// This function returns the host longitude of a moon in degrees, the angle east from the subsolar point to the moon
+/// along the equator of mars, see [`HostedBody::host_longitude`]
+///
+/// * The moons go east around mars, the direction of their orbits.
+fn host_longitude(moon: MarsMoon, julian_date: f64) -> Result<f64> {
+    let sun = subsolar(&Mars24::at(julian_date)?);
+    let [x, y, _] = moon.position(julian_date)?;
+
+    Ok(normalize_degrees(rad_to_deg(
+        y.atan2(x) - sun[1].atan2(sun[0]),
+    )))
+}
+
+/// This function places the sun and a moon in the sky of a site, given in kilometers in the frame of mars
+fn sky(moon: MarsMoon, site: [f64; 3], julian_date: f64) -> Result<Sky> {
+    let mars24 = Mars24::at(julian_date)?;
+    let sun = subsolar(&mars24);
+
+    let moon_position = moon.position(julian_date)?;
+    let toward_moon = [0, 1, 2].map(|i| moon_position[i] - site[i]);
+    let distance = dot(toward_moon, toward_moon).sqrt();
+    let cross = [
+        toward_moon[1] * sun[2] - toward_moon[2] * sun[1],
+        toward_moon[2] * sun[0] - toward_moon[0] * sun[2],
+        toward_moon[0] * sun[1] - toward_moon[1] * sun[0],
+    ];
+
+    Ok(Sky {
+        sun,
+        separation: rad_to_deg(dot(cross, cross).sqrt().atan2(dot(toward_moon, sun))),
+        sun_radius: rad_to_deg((SUN_RADIUS_KM / (mars24.heliocentric_distance * KM_PER_AU)).asin()),
+        moon_radius: rad_to_deg((moon.radius_km() / distance).asin()),
+    })
+}
+
+/// This function returns the prime meridian, the meridian 90 degrees east and the pole of a body in the ICRF,
+/// from the right ascension and the declination of its pole and the angle of its prime meridian in degrees
+///
+/// * The prime meridian is measured east along the equator of the body, from where it crosses the equator of the ICRF.
+fn frame(ra: f64, dec: f64, meridian: f64) -> ([f64; 3], [f64; 3], [f64; 3]) {
+    let (ra, dec, meridian) = (deg_to_rad(ra), deg_to_rad(dec), deg_to_rad(meridian));
+    let pole = [dec.cos() * ra.cos(), dec.cos() * ra.sin(), dec.sin()];
+    // the ascending node of the equator of the body, and the point 90 degrees east of it
+    let node = [-ra.sin(), ra.cos(), 0.0];
+    let beyond = [-dec.sin() * ra.cos(), -dec.sin() * ra.sin(), dec.cos()];
+    let along = |a: f64, b: f64| [0, 1, 2].map(|i| node[i] * a + beyond[i] * b);
+
+    (
+        along(meridian.cos(), meridian.sin()),
+        along(-meridian.sin(), meridian.cos()),
+        pole,
+    )
+}
+
+/// This function returns the dot product of two vectors
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
@@ -1 +1,48 @@
+/// This is the mean radius of saturn in kilometers
+pub const SATURN_RADIUS_KM: f64 = 58_232.0;
 
+/// A.D 1980 March 19, 20:17:14 UTC, the start of a saturnian year (Ls 0)
+///
+/// * It's derived from the perihelion of 2003 July 26 and the orbit below.
+pub const SATURN_EPOCH_JD: f64 = 2_444_318.345_3;
+
+crate::define_body! {
+    /// This structure represents the sixth planet from the sun
+    ///
+    /// * A sol is the solar day of the magnetic field, 10 hours 33 minutes and 39 seconds.
+    pub struct Saturn {
+        name: "Saturn",
+        epoch: SATURN_EPOCH_JD,
+        orbital_eccentricity: 0.0565,
+        orbital_period: 24_450.8,
+        rotational_period: 38_019.0,
+        semimajor: 9.537,
+        perihelion: ((18_835.4, 20_655.4), (270.0, 300.0), 279.0),
+    }
+    impl {
+        fn axial_tilt(&self) -> f64 {
+            26.73
+        }
+
+        fn mass_kg(&self) -> Option<f64> {
+            Some(5.6834e26)
+        }
+
+        /// See [`SATURN_RADIUS_KM`]
+        fn mean_radius_km(&self) -> Option<f64> {
+            Some(SATURN_RADIUS_KM)
+        }
+
+        fn inclination(&self) -> f64 {
+            2.485
+        }
+
+        fn ascending_node(&self) -> f64 {
+            113.665
+        }
+
+        fn longitude_of_perihelion(&self) -> f64 {
+            92.599
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use core::ops::{Add, Mul, Neg, Sub};
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    conversions::{deg_to_rad, normalize_degrees, rad_to_deg, Au},
+    error::Result,
+    kepler::{Body, GRAVITATIONAL_CONSTANT, SUN_GRAVITATIONAL_PARAMETER},
+};
+
+/// This is the point that positions are measured from, see [`Body::position`]
+///
+/// * The sun and the planets go around the barycenter of the solar system, so the sun wobbles
+///   around it by less than 0.01 AU, mostly because of jupiter and saturn.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Origin {
+    /// The center of the sun
+    #[default]
+    Heliocentric,
+    /// The barycenter of the sun and the giant planets, see [`sun_offset`]
+    Barycentric,
+}
+
+/// This is a position in astronomical units, in the ecliptic and the equinox of J2000
+///
+/// * `x` points to the vernal equinox, `z` to the north pole of the ecliptic.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Position {
+    /// The distance towards the vernal equinox
+    pub x: f64,
+    /// The distance towards the ecliptic longitude of 90 degrees
+    pub y: f64,
+    /// The distance above the ecliptic
+    pub z: f64,
+}
+
+impl Position {
+    /// This method creates a position from its coordinates in astronomical units
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// This method returns the distance from the origin
+    pub fn distance(&self) -> Au {
+        Au((self.x * self.x + self.y * self.y + self.z * self.z).sqrt())
+    }
+
+    /// This method returns the distance to another position, measured from the same origin
+    pub fn distance_to(&self, other: &Self) -> Au {
+        (*other - *self).distance()
+    }
+
+    /// This method returns the ecliptic longitude in degrees, from 0 up to 360
+    pub fn longitude(&self) -> f64 {
+        normalize_degrees(rad_to_deg(self.y.atan2(self.x)))
+    }
+}
+
+impl Add for Position {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Position {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Neg for Position {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<f64> for Position {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+/// This function places a body on its orbit at a solar longitude in degrees, see [`Body::heliocentric_position`]
+pub(crate) fn at_ls(body: &(impl Body + ?Sized), ls: f64) -> Position {
+    let true_anomaly = ls - body.perihelion().perihelion;
+    let e = body.orbital_eccentricity();
+    let distance = body.semimajor() * (1.0 - e * e) / (1.0 + e * deg_to_rad(true_anomaly).cos());
+
+    on_orbit(body, distance, true_anomaly)
+}
+
+/// This function places a body on its orbit, given its distance from the sun and its true anomaly in degrees
+///
+/// * The orbit is turned into the ecliptic by the [`Body::inclination`], the [`Body::ascending_node`]
+///   and the [`Body::longitude_of_perihelion`].
+///
+/// > $$x = r(\cos\Omega\cos u - \sin\Omega\sin u\cos i)$$
+/// > $$y = r(\sin\Omega\cos u + \cos\Omega\sin u\cos i)$$
+/// > $$z = r\sin u\sin i$$
+fn on_orbit(body: &(impl Body + ?Sized), distance: f64, true_anomaly: f64) -> Position {
+    let node = deg_to_rad(body.ascending_node());
+    let inclination = deg_to_rad(body.inclination());
+    // the argument of latitude, the angle from the ascending node
+    let u = deg_to_rad(body.longitude_of_perihelion() - body.ascending_node() + true_anomaly);
+    let (sin_u, cos_u) = (u.sin(), u.cos());
+    let (sin_node, cos_node) = (node.sin(), node.cos());
+
+    Position::new(
+        distance * (cos_node * cos_u - sin_node * sin_u * inclination.cos()),
+        distance * (sin_node * cos_u + cos_node * sin_u * inclination.cos()),
+        distance * sin_u * inclination.sin(),
+    )
+}
+
+/// This function returns the position of the sun from the barycenter of the solar system at a julian date (UTC)
+///
+/// * The barycenter holds the sun, jupiter and saturn, whose orbits are built in without the `jupiter` and `saturn`
+///   features, so the offset is the same whatever the features.
+///
+/// > $$\vec{r}_\odot = -\frac{\sum m_i \vec{r}_i}{M_\odot + \sum m_i}$$
+///
+/// ```rust
+/// use rust_solar::position::sun_offset;
+///
+/// // at J2000 jupiter and saturn are on the same side, so the sun is about 0.0073 AU away on the other
+/// let offset = sun_offset(2451545.0).unwrap();
+///
+/// assert!((offset.distance().0 - 0.0073).abs() < 0.0003, "{offset:?}");
+/// assert!((offset.longitude() - 219.0).abs() < 2.0, "{offset:?}");
+/// ```
+pub fn sun_offset(julian_date: f64) -> Result<Position> {
+    let giants: [&dyn Body; 2] = [
+        &crate::planets::jupiter::Jupiter,
+        &crate::planets::saturn::Saturn,
+    ];
+
+    let mut total = SUN_GRAVITATIONAL_PARAMETER / GRAVITATIONAL_CONSTANT;
+    let mut moment = Position::default();
+
+    for giant in giants {
+        let mass = giant.mass_kg().unwrap_or(0.0);
+
+        total += mass;
+        moment = moment + giant.heliocentric_position(julian_date)? * mass;
+    }
+
+    Ok(-moment * (1.0 / total))
+}
+
+/// This function returns the distance between two bodies at a julian date (UTC)
+///
+/// * The distance is the same from either origin, up to the rounding of the positions.
+pub fn separation(
+    from: &(impl Body + ?Sized),
+    to: &(impl Body + ?Sized),
+    julian_date: f64,
+    origin: Origin,
+) -> Result<Au> {
+    Ok(from
+        .position(julian_date, origin)?
+        .distance_to(&to.position(julian_date, origin)?))
+}
@@ -0,0 +1,192 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::str::FromStr;
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+use crate::{
+    error::Error,
+    kepler::{Body, Date, Eras, Time},
+    planets::mars::{Mars, Martian},
+};
+
+impl From<Error> for PyErr {
+    fn from(error: Error) -> Self {
+        PyValueError::new_err(error.to_string())
+    }
+}
+
+/// This structure wraps [`Mars`] for python
+#[pyclass(name = "Mars", frozen)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PyMars;
+
+// python has no `self` by value, so the names follow the body instead
+#[allow(clippy::wrong_self_convention)]
+#[pymethods]
+impl PyMars {
+    #[new]
+    fn new() -> Self {
+        Self
+    }
+
+    /// The date of a julian date (UTC), see [`Body::try_to_date`]
+    fn to_date(&self, jd: f64) -> PyResult<PyDate> {
+        Ok(PyDate(Mars.try_to_date(jd)?))
+    }
+
+    /// The date and time of a julian date (UTC) in a timezone, see [`Body::to_datetime`]
+    ///
+    /// * The timezone is either a code (ex; `"AMT"`) or a variant name (ex; `"MTCn5"`)
+    fn to_datetime(&self, jd: f64, zone: &str) -> PyResult<(PyDate, PyTime)> {
+        let zone = Martian::from_str(zone)?;
+
+        Mars.try_to_date(jd)?;
+
+        let date_time = Mars.to_datetime(jd, &zone);
+
+        Ok((PyDate(date_time.date()), PyTime(date_time.time())))
+    }
+
+    /// The first julian date (UTC) at or after `after` where mars reaches a solar longitude, see [`Body::jd_at_ls`]
+    fn jd_at_ls(&self, target: f64, after: f64) -> PyResult<f64> {
+        Ok(Mars.jd_at_ls(target, after)?)
+    }
+
+    fn __repr__(&self) -> &'static str {
+        "Mars()"
+    }
+
+    fn __str__(&self) -> &'static str {
+        Mars.name()
+    }
+}
+
+/// This structure wraps [`Date`] for python
+#[pyclass(name = "Date", frozen)]
+#[derive(Debug, Clone, Copy)]
+pub struct PyDate(pub Date);
+
+#[pymethods]
+impl PyDate {
+    #[getter]
+    fn era(&self) -> &'static str {
+        match self.0.era {
+            Eras::AD => "AD",
+            Eras::BD => "BD",
+            Eras::Unknown => "Unknown",
+        }
+    }
+
+    #[getter]
+    fn year(&self) -> f64 {
+        self.0.year
+    }
+
+    #[getter]
+    fn month(&self) -> f64 {
+        self.0.month
+    }
+
+    #[getter]
+    fn day(&self) -> f64 {
+        self.0.day
+    }
+
+    #[getter]
+    fn ls(&self) -> f64 {
+        self.0.ls
+    }
+
+    #[getter]
+    fn season(&self) -> &'static str {
+        self.0.season_str()
+    }
+
+    #[getter]
+    fn sol(&self) -> f64 {
+        self.0.sol
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Date(era='{}', year={}, month={}, day={}, ls={}, season='{}', sol={})",
+            self.era(),
+            self.0.year,
+            self.0.month,
+            self.0.day,
+            self.0.ls,
+            self.0.season_str(),
+            self.0.sol
+        )
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// This structure wraps [`Time`] for python
+#[pyclass(name = "Time", frozen)]
+#[derive(Debug, Clone, Copy)]
+pub struct PyTime(pub Time);
+
+#[pymethods]
+impl PyTime {
+    #[getter]
+    fn hour(&self) -> i32 {
+        self.0.hour
+    }
+
+    #[getter]
+    fn minute(&self) -> u8 {
+        self.0.minute
+    }
+
+    #[getter]
+    fn second(&self) -> u8 {
+        self.0.second
+    }
+
+    #[getter]
+    fn millisecond(&self) -> u16 {
+        self.0.millisecond
+    }
+
+    #[getter]
+    fn code(&self) -> &str {
+        self.0.code()
+    }
+
+    #[getter]
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Time(hour={}, minute={}, second={}, millisecond={}, code='{}')",
+            self.0.hour,
+            self.0.minute,
+            self.0.second,
+            self.0.millisecond,
+            self.0.code()
+        )
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// The `rust_solar` python module
+#[pymodule]
+fn rust_solar(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyMars>()?;
+    module.add_class::<PyDate>()?;
+    module.add_class::<PyTime>()?;
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use alloc::vec::Vec;
+
+use crate::{
+    calendar::months_of_year,
+    error::{Error, Result},
+    kepler::{Body, Date},
+};
+
+/// This is a rule for an event that repeats on a body, see [`Recurrence::occurrences`]
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::{planets::mars::Mars, recurrence::Recurrence};
+///
+/// // a deep clean every 30 sols, and an anniversary at the perihelion of each mars year
+/// let cleaning = Recurrence::EverySols(30.0).occurrences(&Mars, 2451545.0, 3).unwrap();
+/// let anniversary = Recurrence::AnnualAtLs(251.0).occurrences(&Mars, 2451545.0, 2).unwrap();
+///
+/// assert_eq!(3, cleaning.len());
+/// assert_eq!(2, anniversary.len());
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Recurrence {
+    /// An event every amount of sols of the body, the first one is at the start
+    EverySols(f64),
+    /// An event every year, where the body reaches a solar longitude in degrees, see [`Body::jd_at_ls`]
+    AnnualAtLs(f64),
+    /// An event at the start of a sol of every month, counted from 1, see [`crate::calendar::month_grid`]
+    ///
+    /// * A month shorter than the sol has its event on its last sol instead.
+    Monthly {
+        /// The sol of the month
+        sol: u32,
+    },
+}
+
+impl Recurrence {
+    /// This method returns the julian dates (UTC) of the next `count` events at or after `start_jd`, in order
+    ///
+    /// * It fails with [`Error::NonFinite`] when a number isn't finite, with [`Error::InvalidTime`] when the sols
+    ///   between events aren't positive, and with [`Error::InvalidDate`] when the solar longitude is outside of 0 to 360
+    ///   or the sol of the month is 0.
+    pub fn occurrences(
+        &self,
+        body: &(impl Body + ?Sized),
+        start_jd: f64,
+        count: usize,
+    ) -> Result<Vec<f64>> {
+        if !start_jd.is_finite() {
+            return Err(Error::NonFinite);
+        }
+
+        match *self {
+            Self::EverySols(sols) => every_sols(body, start_jd, count, sols),
+            Self::AnnualAtLs(ls) => annual_at_ls(body, start_jd, count, ls),
+            Self::Monthly { sol } => monthly(body, start_jd, count, sol),
+        }
+    }
+}
+
+/// This function steps from the start by a whole amount of sols, each event from the start so the steps don't drift
+fn every_sols(
+    body: &(impl Body + ?Sized),
+    start_jd: f64,
+    count: usize,
+    sols: f64,
+) -> Result<Vec<f64>> {
+    if !sols.is_finite() {
+        return Err(Error::NonFinite);
+    }
+
+    if sols <= 0.0 {
+        return Err(Error::InvalidTime(
+            "the sols between events aren't positive",
+        ));
+    }
+
+    let step = (body.rotational_period_seconds() * sols).to_days().0;
+
+    Ok((0..count)
+        .map(|index| start_jd + step * index as f64)
+        .collect())
+}
+
+/// This function finds the solar longitude once an orbit, half an orbit after the last event
+fn annual_at_ls(
+    body: &(impl Body + ?Sized),
+    start_jd: f64,
+    count: usize,
+    ls: f64,
+) -> Result<Vec<f64>> {
+    let half_orbit = body.orbital_period_days().0 / 2.0;
+    let mut found = Vec::with_capacity(count);
+    let mut after = start_jd;
+
+    for _ in 0..count {
+        let julian_date = body.jd_at_ls(ls, after)?;
+
+        found.push(julian_date);
+        after = julian_date + half_orbit;
+    }
+
+    Ok(found)
+}
+
+/// This function walks the months from the year of the start, and keeps the sols at or after it
+fn monthly(body: &(impl Body + ?Sized), start_jd: f64, count: usize, sol: u32) -> Result<Vec<f64>> {
+    if sol == 0 {
+        return Err(Error::InvalidDate("the day is outside of the month"));
+    }
+
+    let mut found = Vec::with_capacity(count);
+    let mut year = body.try_to_date(start_jd)?.year;
+
+    while found.len() < count {
+        for month in months_of_year(body, year)? {
+            let Some(day) = month.get(sol as usize - 1).or(month.last()) else {
+                continue;
+            };
+            let julian_date =
+                body.to_julian(&Date::from_ordinal(year, f64::from(day.day), body)?)?;
+
+            if julian_date >= start_jd && found.len() < count {
+                found.push(julian_date);
+            }
+        }
+
+        year += 1.0;
+    }
+
+    Ok(found)
+}
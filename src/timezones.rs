@@ -0,0 +1,86 @@
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{bodies, kepler::TimeZone};
+
+/// This is a timezone of a body, from every timezone of the registered bodies, see [`all`]
+///
+/// * The code of a timezone is only unique within its body (ex; two bodies may both have an `OT`),
+///   so the body and the code together name it, see [`find_qualified`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ZoneEntry {
+    /// This is the name of the body, see [`crate::kepler::Body::name`]
+    pub body: &'static str,
+    /// This is the abbreviation of the timezone
+    pub code: &'static str,
+    /// This is the name of the timezone
+    pub name: &'static str,
+    /// This is the offset from the prime meridian in hours
+    pub offset: f64,
+}
+
+impl ZoneEntry {
+    /// This method returns the timezone of the entry, to find the time in it
+    ///
+    /// * It's none when the body is no longer found by its name, see [`crate::bodies::find`]
+    pub fn timezone(&self) -> Option<Box<dyn TimeZone>> {
+        bodies::find(self.body)?.timezone(self.code)
+    }
+}
+
+/// This function returns every timezone of the built-in and the registered bodies, each body from west to east
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::timezones::all;
+///
+/// assert!(all().iter().any(|zone| zone.body == "Mars" && zone.code == "AMT"));
+/// # }
+/// ```
+pub fn all() -> Vec<ZoneEntry> {
+    bodies::all()
+        .into_iter()
+        .flat_map(|body| {
+            body.timezones().iter().map(move |info| ZoneEntry {
+                body: body.name(),
+                code: info.code,
+                name: info.name,
+                offset: info.offset,
+            })
+        })
+        .collect()
+}
+
+/// This function finds every timezone with a code, ignoring case, of any body
+///
+/// * A code may be used by more than one body, so all of them are returned in the order of [`all`].
+pub fn find(code: &str) -> Vec<ZoneEntry> {
+    all()
+        .into_iter()
+        .filter(|zone| zone.code.eq_ignore_ascii_case(code))
+        .collect()
+}
+
+/// This function finds a timezone by the name of its body and its code (ex; `mars/AMT`), ignoring case
+///
+/// * It's none without a `/`, or when the body doesn't have the timezone.
+///
+/// ```rust
+/// # #[cfg(feature = "mars")]
+/// # {
+/// use rust_solar::timezones::find_qualified;
+///
+/// let amt = find_qualified("mars/amt").unwrap();
+///
+/// assert_eq!(("Mars", "AMT", -12.5), (amt.body, amt.code, amt.offset));
+/// assert_eq!(None, find_qualified("luna/AMT"));
+/// # }
+/// ```
+pub fn find_qualified(qualified: &str) -> Option<ZoneEntry> {
+    let (body, code) = qualified.split_once('/')?;
+
+    all()
+        .into_iter()
+        .find(|zone| zone.body.eq_ignore_ascii_case(body) && zone.code.eq_ignore_ascii_case(code))
+}
@@ -0,0 +1,211 @@
+use core::f64::consts::PI;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
+use crate::{
+    conversions::{normalize_degrees, Au, Days, Seconds},
+    error::{Error, Result},
+    events::{self, Crossing},
+    kepler::{Body, GRAVITATIONAL_CONSTANT},
+};
+
+/// This is a transfer between the orbits of two bodies around the sun, half of an ellipse touching both, see [`hohmann`]
+///
+/// * The orbits are taken as circles of their semimajor axes, in the same plane.
+/// * The excess speeds are what a spacecraft keeps after it escapes a body, see [`HohmannTransfer::delta_v`] for the burns.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct HohmannTransfer {
+    /// The semimajor axis of the transfer ellipse, halfway between both orbits
+    pub semimajor: Au,
+    /// The time from the departure to the arrival, half of the period of the transfer ellipse
+    pub transfer_time: Days,
+    /// The angle in degrees the target has to lead the departure body by at departure, negative when it trails
+    pub phase_angle: f64,
+    /// The speed around the sun in km/s on the transfer ellipse when it leaves the first orbit
+    pub departure_speed: f64,
+    /// The speed around the sun in km/s on the transfer ellipse when it reaches the second orbit
+    pub arrival_speed: f64,
+    /// The speed in km/s the transfer leaves the first body with, against the orbital speed of the body (v∞)
+    pub departure_excess: f64,
+    /// The speed in km/s the transfer reaches the second body with, against the orbital speed of the body (v∞)
+    pub arrival_excess: f64,
+    /// The gravitational parameter (GM) in km³/s² and the mean radius in km of the first body, if they're known
+    departure_body: Option<(f64, f64)>,
+    /// The gravitational parameter (GM) in km³/s² and the mean radius in km of the second body, if they're known
+    arrival_body: Option<(f64, f64)>,
+}
+
+impl HohmannTransfer {
+    /// This method computes the burns in km/s to leave a circular parking orbit around the first body
+    /// and to enter one around the second body, from the altitudes of the parking orbits in km
+    ///
+    /// * Each burn turns the speed of the parking orbit into the excess speed of the transfer, by the vis-viva equation.
+    /// * It fails with [`Error::InvalidOrbit`] when the mass or the radius of a body isn't known (ex; a comet),
+    ///   the [`HohmannTransfer::departure_excess`] and [`HohmannTransfer::arrival_excess`] are still there.
+    ///
+    /// > $$\Delta v = \sqrt{v_\infty^2 + \frac{2\mu}{r}} - \sqrt{\frac{\mu}{r}}$$
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "earth", feature = "mars"))]
+    /// # {
+    /// use rust_solar::{planets::{earth::Earth, mars::Mars}, transfer::hohmann};
+    ///
+    /// // from and to a parking orbit 200 km high
+    /// let (departure, arrival) = hohmann(&Earth, &Mars).delta_v(200.0, 200.0).unwrap();
+    ///
+    /// assert!((departure - 3.6).abs() < 0.1);
+    /// assert!((arrival - 2.1).abs() < 0.1);
+    /// # }
+    /// ```
+    pub fn delta_v(&self, park_alt_from_km: f64, park_alt_to_km: f64) -> Result<(f64, f64)> {
+        Ok((
+            burn(self.departure_body, park_alt_from_km, self.departure_excess)?,
+            burn(self.arrival_body, park_alt_to_km, self.arrival_excess)?,
+        ))
+    }
+}
+
+/// This function computes the burn between a parking orbit around a body and an excess speed, see [`HohmannTransfer::delta_v`]
+fn burn(body: Option<(f64, f64)>, altitude_km: f64, excess: f64) -> Result<f64> {
+    if !altitude_km.is_finite() {
+        return Err(Error::NonFinite);
+    }
+
+    let (mu, radius) = body.ok_or(Error::InvalidOrbit(
+        "the mass or the radius of the body isn't known",
+    ))?;
+
+    if altitude_km < 0.0 {
+        return Err(Error::InvalidOrbit(
+            "the parking orbit is below the surface",
+        ));
+    }
+
+    let distance = radius + altitude_km;
+
+    Ok((excess * excess + 2.0 * mu / distance).sqrt() - (mu / distance).sqrt())
+}
+
+/// This function returns the gravitational parameter (GM) in km³/s² and the mean radius in km of a body, if they're known
+fn parking(body: &(impl Body + ?Sized)) -> Option<(f64, f64)> {
+    Some((
+        GRAVITATIONAL_CONSTANT * body.mass_kg()?,
+        body.mean_radius_km()?,
+    ))
+}
+
+/// This function computes the hohmann transfer from the orbit of a body to the orbit of another body
+///
+/// * The orbits follow the [`Body::gravitational_parameter`] of the first body, which is the sun.
+/// * The transfer goes outwards or inwards, an inward transfer has the target trailing behind.
+///
+/// > $$t = \pi\sqrt{\frac{a_t^3}{\mu}}, \quad \theta = 180° - 360°\frac{t}{T_{to}}$$
+///
+/// ```rust
+/// # #[cfg(all(feature = "earth", feature = "mars"))]
+/// # {
+/// use rust_solar::{planets::{earth::Earth, mars::Mars}, transfer::hohmann};
+///
+/// let transfer = hohmann(&Earth, &Mars);
+///
+/// assert!((transfer.transfer_time.0 - 259.0).abs() < 1.0);
+/// assert!((transfer.phase_angle - 44.0).abs() < 1.0);
+/// # }
+/// ```
+pub fn hohmann(from: &(impl Body + ?Sized), to: &(impl Body + ?Sized)) -> HohmannTransfer {
+    let mu = from.gravitational_parameter();
+    let (departure, arrival) = (from.semimajor_au().to_km(), to.semimajor_au().to_km());
+    let semimajor = (departure + arrival) / 2.0;
+    let transfer_time = Seconds(PI * (semimajor * semimajor * semimajor / mu).sqrt()).to_days();
+    let speed = |distance: f64| (mu * (2.0 / distance - 1.0 / semimajor)).sqrt();
+    let (departure_speed, arrival_speed) = (speed(departure), speed(arrival));
+
+    HohmannTransfer {
+        semimajor: Au::from_km(semimajor),
+        transfer_time,
+        phase_angle: 180.0 - 360.0 * (transfer_time / to.orbital_period_days()),
+        departure_speed,
+        arrival_speed,
+        departure_excess: (departure_speed - (mu / departure).sqrt()).abs(),
+        arrival_excess: (arrival_speed - (mu / arrival).sqrt()).abs(),
+        departure_body: parking(from),
+        arrival_body: parking(to),
+    }
+}
+
+/// This function returns the angle in degrees the second body leads the first by, seen from the sun, from -180 up to 180
+///
+/// * It's measured in the ecliptic, see [`Body::heliocentric_position`]
+pub fn phase_angle(
+    from: &(impl Body + ?Sized),
+    to: &(impl Body + ?Sized),
+    julian_date: f64,
+) -> Result<f64> {
+    let angle = to.heliocentric_position(julian_date)?.longitude()
+        - from.heliocentric_position(julian_date)?.longitude();
+
+    Ok(normalize_degrees(angle + 180.0) - 180.0)
+}
+
+/// This function finds the first julian date (UTC) at or after `after` where the [`phase_angle`] is the one of the [`hohmann`] transfer
+///
+/// * The phase angle comes back once every synodic period, so the window is searched within one of them.
+/// * It fails with [`Error::InvalidOrbit`] when both bodies have the same orbital period, the phase angle never changes.
+///
+/// ```rust
+/// # #[cfg(all(feature = "earth", feature = "mars"))]
+/// # {
+/// use rust_solar::{planets::{earth::Earth, mars::Mars}, transfer::next_departure_window};
+///
+/// let first = next_departure_window(&Earth, &Mars, 2451545.0).unwrap();
+/// let second = next_departure_window(&Earth, &Mars, first + 1.0).unwrap();
+///
+/// // about 26 months apart
+/// assert!((second - first - 780.0).abs() < 60.0);
+/// # }
+/// ```
+pub fn next_departure_window(
+    from: &(impl Body + ?Sized),
+    to: &(impl Body + ?Sized),
+    after: f64,
+) -> Result<f64> {
+    if !after.is_finite() {
+        return Err(Error::NonFinite);
+    }
+
+    let synodic =
+        1.0 / (1.0 / from.orbital_period_days().0 - 1.0 / to.orbital_period_days().0).abs();
+
+    if !synodic.is_finite() {
+        return Err(Error::InvalidOrbit(
+            "the bodies have the same orbital period",
+        ));
+    }
+
+    let target = hohmann(from, to).phase_angle;
+    let miss = |jd: f64| -> Result<f64> {
+        Ok(normalize_degrees(phase_angle(from, to, jd)? - target + 180.0) - 180.0)
+    };
+
+    if miss(after)? == 0.0 {
+        return Ok(after);
+    }
+
+    // the faster body pulls ahead, so the phase angle of an outer target goes down
+    let crossing = match from.orbital_period_days() < to.orbital_period_days() {
+        true => Crossing::Falling,
+        false => Crossing::Rising,
+    };
+
+    // a degree of the synodic period, the eccentric orbits may stretch it a little
+    let bracket = events::try_scan(miss, (after, after + synodic * 1.25), synodic / 360.0, crossing)?
+        .next()
+        .transpose()?
+        .ok_or(Error::InvalidOrbit(
+            "the phase angle never reaches the one of the transfer",
+        ))?;
+
+    // about a millisecond
+    events::try_find_crossing(miss, bracket, 1e-8)
+}
@@ -0,0 +1,48 @@
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    kepler::{Body, TimeZone},
+    planets::mars::{Mars, Martian},
+};
+
+/// This function computes the martian date of a julian date (UTC)
+///
+/// * The date is returned as an object, see [`crate::kepler::Date`]
+///
+/// ```js
+/// const date = mars_date(2451545.0); // { era: "AD", year: 24, ... }
+/// ```
+#[wasm_bindgen]
+pub fn mars_date(jd: f64) -> Result<JsValue, JsValue> {
+    let date = Mars.try_to_date(jd).map_err(JsError::from)?;
+
+    Ok(serde_wasm_bindgen::to_value(&date)?)
+}
+
+/// This function computes the martian time of a julian date (UTC) in a timezone
+///
+/// * The timezone is either a code (ex; `"AMT"`) or a variant name (ex; `"MTCn5"`), see [`Martian`]
+///
+/// ```js
+/// const time = mars_time(2451545.0, "AMT"); // { hour, minute, second, code: "AMT", ... }
+/// ```
+#[wasm_bindgen]
+pub fn mars_time(jd: f64, zone: &str) -> Result<JsValue, JsValue> {
+    let time = Martian::from_str(zone).map_err(JsError::from)?.at(jd);
+
+    Ok(serde_wasm_bindgen::to_value(&time)?)
+}
+
+/// This function lists the information of every martian timezone, from west to east
+///
+/// * Every `code` can be passed to [`mars_time`]
+///
+#[wasm_bindgen]
+pub fn list_timezones() -> Result<JsValue, JsValue> {
+    let zones: Vec<_> = Martian::iter().map(|zone| zone.info()).collect();
+
+    Ok(serde_wasm_bindgen::to_value(&zones)?)
+}
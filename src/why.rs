@@ -1,8 +1,11 @@
-use std::f64::consts::PI;
+use core::f64::consts::PI;
 
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::math::Float as _;
 use crate::{
     kepler::{Date, Eras},
-    orbit::Season,
+    orbit::{Hemisphere, Season},
     planets::EARTH_ROTATIONAL_PERIOD,
 };
 
@@ -116,7 +119,7 @@ impl Example {
                 / (1.0 - self.orbital_eccentricity() * zx0.cos());
 
             // En = En + En+1
-            zx0 = zx0 + zdx;
+            zx0 += zdx;
         }
 
         if self.mean_motion(day) < 0.0 {
@@ -134,7 +137,7 @@ impl Example {
 
         // println!("Zteta: {zteta}");
 
-        return zteta;
+        zteta
     }
 
     /// o
@@ -155,7 +158,7 @@ impl Example {
         }
 
         // println!("Degrees {}", ls.to_degrees());
-        return ls.to_degrees();
+        ls.to_degrees()
     }
 
     /// o
@@ -179,7 +182,7 @@ impl Example {
         let year = tmp_year;
         let month = 1.0 + (ls / self.average_ls()).floor();
         let day = 1.0 + tmp_day.floor();
-        let season = Season::default().from(ls as u32);
+        let season = Season::from_ls(ls, Hemisphere::North);
 
         // callibrates era according to year's coefficient type (- or +)
         let era = match year as i32 > 0 {
@@ -188,13 +191,14 @@ impl Example {
         };
 
         // AD vs BD
-        return Date {
+        Date {
             era,
             year,
             month,
             day,
             ls,
             season,
-        };
+            sol: tmp_day,
+        }
     }
 }
@@ -0,0 +1,61 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        anomaly::{elliptical_halley, elliptical_newton, Anomaly, HALLEY_ECCENTRICITY},
+        kepler::Body,
+        orbit::Type,
+        planets::mars::Mars,
+    };
+
+    #[test]
+    pub fn halley_matches_newton_under_the_threshold() {
+        for e in [
+            0.0,
+            0.0167,
+            0.0934,
+            0.2056,
+            0.25,
+            HALLEY_ECCENTRICITY - 1e-9,
+        ] {
+            for i in -2000..=2000 {
+                // about three turns either way
+                let mean = f64::from(i) * 0.01;
+                let halley = elliptical_halley(mean, e);
+                let newton = elliptical_newton(mean, e).unwrap();
+
+                assert!((halley - newton).abs() < 1e-12, "e {e} M {mean}");
+                assert!((halley - e * halley.sin() - mean).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    pub fn the_fast_path_is_picked_by_eccentricity() {
+        let peri = Mars.perihelion();
+        let period = Mars.orbital_period();
+
+        for day in [0.0, 100.0, 321.5, 600.0] {
+            let mean = Anomaly.mean(day, peri, period);
+
+            for (e, solver) in [
+                (0.0934, elliptical_halley(mean, 0.0934)),
+                (0.5, elliptical_newton(mean, 0.5).unwrap()),
+                (0.9, elliptical_newton(mean, 0.9).unwrap()),
+            ] {
+                let eccentric = Anomaly.try_eccentric(Type::Elliptical, day, e, peri, period, 1.52);
+
+                assert_eq!(Ok(solver.abs()), eccentric.map(f64::abs));
+            }
+        }
+    }
+
+    #[test]
+    pub fn newton_settles_on_steps_back() {
+        // the first newton step here is negative, which used to end the loop early
+        let newton: f64 = elliptical_newton(1.5, 0.9).unwrap();
+
+        assert!((newton - 0.9 * newton.sin() - 1.5).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,65 @@
+#![cfg(all(feature = "ceres", feature = "earth", feature = "luna", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        asteroids::ceres::Ceres,
+        kepler::{Body, CustomBody},
+        moons::luna::Luna,
+        orbit::Perihelion,
+        planets::{earth::Earth, mars::Mars},
+    };
+
+    fn julian_dates() -> Vec<f64> {
+        (0..2000).map(|i| 2_400_000.0 + i as f64 * 97.3).collect()
+    }
+
+    fn matches_loop(body: &dyn Body) {
+        let julian_dates = julian_dates();
+        let dates = body.to_dates(&julian_dates);
+        let ls = body.to_ls_batch(&julian_dates);
+
+        assert_eq!(julian_dates.len(), dates.len());
+
+        for ((julian_date, date), ls) in julian_dates.iter().zip(&dates).zip(&ls) {
+            let looped = body.to_date(*julian_date);
+
+            assert_eq!(looped, *date, "{} at {julian_date}", body.name());
+            assert_eq!(looped.ls.to_bits(), ls.to_bits());
+        }
+    }
+
+    #[test]
+    pub fn batch_matches_loop() {
+        matches_loop(&Mars);
+        matches_loop(&Earth);
+        matches_loop(&Luna);
+        matches_loop(&Ceres);
+    }
+
+    #[test]
+    pub fn batch_matches_loop_circular() {
+        let body = CustomBody::builder()
+            .orbital_eccentricity(0.0)
+            .orbital_period(400.0)
+            .rotational_period(86_400.0)
+            .semimajor(1.1)
+            .perihelion(Perihelion::new((0.0, 40.0), (0.0, 36.0), 0.0).unwrap())
+            .build()
+            .unwrap();
+
+        matches_loop(&body);
+    }
+
+    #[test]
+    pub fn empty_batch() {
+        assert!(Mars.to_dates(&[]).is_empty());
+        assert!(Mars.to_ls_batch(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn batch_panics_on_nan() {
+        Mars.to_dates(&[2451545.0, f64::NAN]);
+    }
+}
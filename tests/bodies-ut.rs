@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use rust_solar::bodies::{find, registry};
+
+    #[test]
+    pub fn only_enabled_bodies_are_registered() {
+        let enabled: Vec<&str> = [
+            ("Ceres", cfg!(feature = "ceres")),
+            ("Earth", cfg!(feature = "earth")),
+            ("Jupiter", cfg!(feature = "jupiter")),
+            ("Luna", cfg!(feature = "luna")),
+            ("Mars", cfg!(feature = "mars")),
+            ("Saturn", cfg!(feature = "saturn")),
+        ]
+        .into_iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(name, _)| name)
+        .collect();
+        let registered: Vec<&str> = registry().iter().map(|body| body.name()).collect();
+
+        assert_eq!(enabled, registered);
+    }
+
+    #[test]
+    #[cfg(feature = "mars")]
+    pub fn find_mars() {
+        let mars = find("mars").unwrap();
+
+        assert_eq!(mars.name(), "Mars");
+        assert_eq!(mars.orbital_period(), 668.6);
+        assert!(find("MARS").is_some());
+    }
+
+    #[test]
+    pub fn find_unknown_body() {
+        assert!(find("vulcan").is_none());
+    }
+
+    #[test]
+    pub fn registry_names_are_unique() {
+        for (i, body) in registry().iter().enumerate() {
+            assert!(registry()[i + 1..]
+                .iter()
+                .all(|other| !other.name().eq_ignore_ascii_case(body.name())));
+        }
+    }
+}
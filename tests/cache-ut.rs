@@ -0,0 +1,120 @@
+#![cfg(all(feature = "ceres", feature = "earth", feature = "luna", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        asteroids::ceres::Ceres,
+        kepler::{Body, BodyCache, CustomBody, Date},
+        moons::luna::Luna,
+        orbit::{Hemisphere, Perihelion, SolarLongitude, Type},
+        planets::{earth::Earth, mars::Mars},
+        Error,
+    };
+
+    fn julian_dates() -> Vec<f64> {
+        (0..1000).map(|i| 2_400_000.0 + i as f64 * 173.9).collect()
+    }
+
+    fn custom(orbital_eccentricity: f64) -> CustomBody {
+        CustomBody::builder()
+            .orbital_eccentricity(orbital_eccentricity)
+            .orbital_period(400.0)
+            .rotational_period(86_400.0)
+            .semimajor(1.1)
+            .perihelion(Perihelion::new((0.0, 40.0), (0.0, 36.0), 250.0).unwrap())
+            .build()
+            .unwrap()
+    }
+
+    /// The date of every julian date, with nothing derived ahead of time
+    fn uncached(body: &dyn Body, julian_date: f64, hemisphere: Hemisphere) -> Result<Date, Error> {
+        let date = Date::default().compute(
+            julian_date,
+            body.epoch(),
+            body.epoch_year(),
+            body.rotational_period(),
+            body.perihelion(),
+            body.semimajor(),
+            body.orbital_eccentricity(),
+            body.orbital_period(),
+            hemisphere,
+        )?;
+        let ls = SolarLongitude.compute(
+            Type::default().shape(body.orbital_eccentricity()),
+            date.sol,
+            body.orbital_eccentricity(),
+            body.perihelion(),
+            body.orbital_period(),
+            body.semimajor(),
+        )?;
+
+        assert_eq!(ls.to_bits(), date.ls.to_bits(), "{}", body.name());
+
+        Ok(date)
+    }
+
+    fn matches_uncached(body: &dyn Body) {
+        let cache = BodyCache::new(body).unwrap();
+
+        for julian_date in julian_dates() {
+            for hemisphere in [Hemisphere::North, Hemisphere::South] {
+                let cached = cache.date_in(julian_date, hemisphere);
+
+                assert_eq!(uncached(body, julian_date, hemisphere), cached);
+            }
+
+            let cached = cache.date(julian_date).unwrap();
+
+            assert_eq!(
+                cached.ls.to_bits(),
+                cache.ls(julian_date).unwrap().to_bits()
+            );
+            assert_eq!(
+                cached.ls.to_bits(),
+                body.try_to_date(julian_date).unwrap().ls.to_bits(),
+                "{} at {julian_date}",
+                body.name()
+            );
+        }
+    }
+
+    #[test]
+    pub fn cache_matches_uncached() {
+        matches_uncached(&Mars);
+        matches_uncached(&Earth);
+        matches_uncached(&Luna);
+        matches_uncached(&Ceres);
+    }
+
+    #[test]
+    pub fn cache_matches_uncached_on_every_solver() {
+        // circular, the fast path, and newton
+        matches_uncached(&custom(0.0));
+        matches_uncached(&custom(0.2));
+        matches_uncached(&custom(0.9));
+    }
+
+    #[test]
+    pub fn custom_bodies_keep_their_cache() {
+        let body = custom(0.2);
+
+        assert_eq!(Ok(BodyCache::new(&body).unwrap()), body.cache());
+        assert_eq!(Type::Elliptical, body.cache().unwrap().orbit().shape());
+        assert_eq!(36.0, body.cache().unwrap().orbit().avg_ls());
+    }
+
+    #[test]
+    pub fn cache_checks_the_body() {
+        let hyperbolic = BodyCache::new(&custom(1.5)).unwrap();
+
+        assert_eq!(
+            Err(Error::NonFinite),
+            BodyCache::new(&Mars).unwrap().date(f64::NAN)
+        );
+        assert_eq!(Type::Hyperbolic, hyperbolic.orbit().shape());
+        assert_eq!(
+            uncached(&custom(1.5), 2_451_645.0, Hemisphere::North).map(|date| date.ls.to_bits()),
+            hyperbolic.ls(2_451_645.0).map(f64::to_bits)
+        );
+    }
+}
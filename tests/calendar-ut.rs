@@ -0,0 +1,391 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        calendar::{
+            month_grid, Calendar, CalendarView, Calendars, DarianCalendar, IntercalatedCalendar,
+            LeapCycle, LeapRule, LsCalendar,
+        },
+        fixtures,
+        kepler::{Body, CustomBody, Date},
+        planets::mars::Mars,
+        Error,
+    };
+
+    #[test]
+    pub fn mars_year_grid() {
+        let mut days = Vec::new();
+
+        for month in 1..=12 {
+            let grid = month_grid(&Mars, 36.0, month, 7).unwrap();
+            let cells: Vec<u32> = grid.rows.iter().flatten().flatten().copied().collect();
+
+            assert!(!grid.sols.is_empty(), "{month}");
+            assert!(grid.rows.iter().all(|week| week.len() == 7));
+            assert_eq!(
+                grid.sols.iter().map(|sol| sol.day).collect::<Vec<_>>(),
+                cells
+            );
+            // the first sol sits on its sol of the week
+            let first = Date::from_ordinal(36.0, f64::from(grid.sols[0].day), &Mars).unwrap();
+
+            assert_eq!(
+                Some(grid.sols[0].day),
+                grid.rows[0][usize::from(first.sol_of_week(&Mars, 7).unwrap() - 1)]
+            );
+            days.extend(cells);
+        }
+
+        assert_eq!(Mars.orbital_period().round() as usize, days.len());
+
+        let mut unique = days.clone();
+
+        unique.sort();
+        unique.dedup();
+
+        assert_eq!(days.len(), unique.len());
+        assert_eq!(days, unique);
+    }
+
+    #[test]
+    pub fn months_follow_the_orbit() {
+        let grid = month_grid(&Mars, 36.0, 12, 10).unwrap();
+        let last = grid.sols.last().unwrap();
+
+        // the leftover sols of the year end the last month
+        assert_eq!(Mars.orbital_period().ceil() as u32, last.day);
+        assert!(grid.sols.iter().all(|sol| (330.0..360.0).contains(&sol.ls)));
+
+        // mars is slow near its aphelion (Ls 71), so its months are longer there
+        let aphelion = month_grid(&Mars, 36.0, 3, 7).unwrap();
+        let perihelion = month_grid(&Mars, 36.0, 9, 7).unwrap();
+
+        assert!(aphelion.sols.len() > perihelion.sols.len() + 15);
+    }
+
+    #[test]
+    pub fn invalid_grids() {
+        assert_eq!(
+            Err(Error::InvalidDate("the week has no sols")),
+            month_grid(&Mars, 36.0, 1, 0)
+        );
+        assert_eq!(
+            Err(Error::InvalidDate("the month is outside of the year")),
+            month_grid(&Mars, 36.0, 13, 7)
+        );
+        assert_eq!(
+            Err(Error::InvalidDate("the month is outside of the year")),
+            month_grid(&Mars, 36.0, 0, 7)
+        );
+        assert_eq!(Err(Error::NonFinite), month_grid(&Mars, f64::NAN, 1, 7));
+    }
+
+    #[test]
+    pub fn weeks_run_on_through_the_years() {
+        let mut date = Date::from_ordinal(35.0, 600.0, &Mars).unwrap();
+        let mut last = date.sol_of_week(&Mars, 7).unwrap();
+        let mut week = date.week_since_epoch(&Mars, 7).unwrap();
+        let mut crossed = false;
+
+        // sol by sol over the end of mars year 35, the short last sol is a sol of the week too
+        for _ in 0..150 {
+            date = match date.day < Mars.orbital_period().ceil() {
+                true => Date::from_ordinal(date.year, date.day + 1.0, &Mars).unwrap(),
+                false => {
+                    crossed = true;
+                    Date::from_ordinal(date.year + 1.0, 1.0, &Mars).unwrap()
+                }
+            };
+
+            let sol = date.sol_of_week(&Mars, 7).unwrap();
+            let since = date.week_since_epoch(&Mars, 7).unwrap();
+
+            assert_eq!(last % 7 + 1, sol, "{date:?}");
+            assert_eq!(week + f64::from(u8::from(sol == 1)), since);
+
+            if date.day == 1.0 {
+                assert_eq!(36.0, date.year);
+                assert_eq!(1, date.week_of_year(&Mars, 7).unwrap());
+                // 24 years of 669 sols since mars year 12 aren't whole weeks
+                assert_eq!(6, sol);
+            }
+
+            last = sol;
+            week = since;
+        }
+
+        assert!(crossed);
+        assert_eq!(
+            Err(Error::InvalidDate("the week has no sols")),
+            date.week_of_year(&Mars, 0)
+        );
+    }
+
+    #[test]
+    pub fn week_of_year_counts_from_the_first_sol() {
+        let first = Date::from_ordinal(36.0, 1.0, &Mars).unwrap();
+        let next_week = Date::from_ordinal(36.0, 3.0, &Mars).unwrap();
+        let last = Date::from_ordinal(36.0, 669.0, &Mars).unwrap();
+
+        // the first sol is the 6th of its week, so the 3rd sol starts the second week
+        assert_eq!(1, first.week_of_year(&Mars, 7).unwrap());
+        assert_eq!(2, next_week.week_of_year(&Mars, 7).unwrap());
+        assert_eq!(97, last.week_of_year(&Mars, 7).unwrap());
+        assert_eq!(
+            first.week_since_epoch(&Mars, 7).unwrap() + 96.0,
+            last.week_since_epoch(&Mars, 7).unwrap()
+        );
+        // a body can start its weeks on another sol
+        let barsoom = CustomBody::builder()
+            .epoch(Mars.epoch())
+            .epoch_year(Mars.epoch_year())
+            .week_anchor(5.0)
+            .orbital_eccentricity(Mars.orbital_eccentricity())
+            .orbital_period(Mars.orbital_period())
+            .rotational_period(Mars.rotational_period())
+            .semimajor(Mars.semimajor())
+            .perihelion(Mars.perihelion())
+            .build()
+            .unwrap();
+
+        assert_eq!(Ok(1), first.sol_of_week(&barsoom, 7));
+        assert_eq!(Ok(1), first.week_of_year(&barsoom, 7));
+        // the weeks count from the first sol of mars year 12
+        assert_eq!(
+            Ok(0.0),
+            Date::from_ordinal(12.0, 7.0, &Mars)
+                .unwrap()
+                .week_since_epoch(&Mars, 7)
+        );
+        assert_eq!(
+            Ok(-1.0),
+            Date::from_ordinal(11.0, 669.0, &Mars)
+                .unwrap()
+                .week_since_epoch(&Mars, 7)
+        );
+    }
+
+    /// The darian years one by one, through the default sums of the trait
+    struct Summed(IntercalatedCalendar);
+
+    impl Calendar for Summed {
+        fn name(&self) -> &'static str {
+            "Summed"
+        }
+
+        fn sols_in_year(&self, year: i64) -> u32 {
+            self.0.sols_in_year(year)
+        }
+    }
+
+    #[test]
+    pub fn darian_years_keep_to_the_orbit() {
+        let darian = IntercalatedCalendar {
+            base_sols: 668,
+            rule: LeapRule::DARIAN,
+        };
+        let sols: i64 = (1..=100)
+            .map(|year| i64::from(darian.sols_in_year(year)))
+            .sum();
+
+        assert!((sols as f64 - 100.0 * 668.5991).abs() < 1.0, "{sols}");
+        assert_eq!(sols, darian.year_start(101) - darian.year_start(1));
+        assert_eq!(500, LeapRule::DARIAN.period());
+        assert_eq!(
+            296,
+            (0..500).filter(|year| darian.is_leap_year(*year)).count()
+        );
+        assert!(darian.is_leap_year(1) && darian.is_leap_year(10) && darian.is_leap_year(500));
+        assert!(!darian.is_leap_year(2) && !darian.is_leap_year(100));
+    }
+
+    #[test]
+    pub fn intercalated_sols_round_trip() {
+        let darian = IntercalatedCalendar {
+            base_sols: 668,
+            rule: LeapRule::DARIAN,
+        };
+        let summed = Summed(darian);
+
+        // over the year 0 and the leap years of the 100 and 500 years
+        for year in (-520..-480).chain(-20..120).chain(480..520) {
+            let last = darian.sols_in_year(year);
+
+            assert_eq!(
+                darian.to_sols(year + 1, 1),
+                darian.to_sols(year, last).map(|sols| sols + 1)
+            );
+
+            for ordinal in [1, 2, last - 1, last] {
+                let sols = darian.to_sols(year, ordinal).unwrap();
+
+                assert_eq!(Ok((year, ordinal)), darian.to_ordinal(sols));
+            }
+
+            assert_eq!(
+                Err(Error::InvalidDate("the day is outside of the year")),
+                darian.to_sols(year, last + 1)
+            );
+        }
+
+        // the sums of the trait agree with the rule, a leap year apart
+        for year in -120..120 {
+            let sols = darian.to_sols(year, darian.sols_in_year(year)).unwrap();
+
+            assert_eq!(summed.year_start(year), darian.year_start(year), "{year}");
+            assert_eq!(darian.to_ordinal(sols), summed.to_ordinal(sols));
+        }
+
+        assert_eq!(
+            Err(Error::InvalidDate("the day is outside of the year")),
+            darian.to_sols(1, 0)
+        );
+    }
+
+    #[test]
+    pub fn leap_rules() {
+        const EVERY_OTHER: [LeapCycle; 2] = [
+            LeapCycle {
+                every: 2,
+                offset: 0,
+                leap: true,
+            },
+            LeapCycle {
+                every: 10,
+                offset: 0,
+                leap: false,
+            },
+        ];
+        const EMPTY: [LeapCycle; 1] = [LeapCycle {
+            every: 0,
+            offset: 0,
+            leap: true,
+        }];
+        let rule = LeapRule::new(&EVERY_OTHER).unwrap();
+
+        // every 2 years except every 10
+        assert_eq!(
+            vec![2, 4, 6, 8, 12],
+            (1..13)
+                .filter(|year| rule.is_leap(*year))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(10, rule.period());
+        assert_eq!(
+            Err(Error::InvalidDate(
+                "the leap cycle is empty or its offset is outside of it"
+            )),
+            LeapRule::new(&EMPTY)
+        );
+        assert!(LeapRule::new(&[]).is_ok_and(|rule| !rule.is_leap(0)));
+    }
+
+    #[test]
+    pub fn one_body_in_many_calendars() {
+        let ls = LsCalendar::new(&Mars).unwrap();
+        let calendars = Calendars::new(&Mars)
+            .register(&DarianCalendar)
+            .register(&ls);
+        let dates = calendars.compare(2459580.5).unwrap();
+
+        // the darian months are shorter, 24 of them a year, and the years count from 1609
+        assert_eq!("Darian", dates[0].0);
+        assert_eq!("Ls", dates[1].0);
+        assert_eq!((219.0, 12.0), (dates[0].1.year, dates[0].1.month));
+        assert_eq!((36.0, 6.0), (dates[1].1.year, dates[1].1.month));
+        assert_eq!(Mars.to_date(2459580.5), dates[1].1);
+        assert_eq!(dates[0].1.ls, dates[1].1.ls);
+
+        for view in calendars.views() {
+            for step in 0..300 {
+                let julian_date = 2459000.5 + f64::from(step) * 7.3;
+                let date = view.to_date(julian_date).unwrap();
+                let start = view.to_julian(&date).unwrap();
+                let again = view.to_date(start + 1e-6).unwrap();
+
+                assert!(
+                    start <= julian_date && julian_date - start < 1.03,
+                    "{view:?}"
+                );
+                // the months of the ls start in the middle of a sol, so only the sol is the same
+                assert_eq!(
+                    (date.year, date.day),
+                    (again.year, again.day),
+                    "{view:?} {julian_date}"
+                );
+                assert!(date.month - again.month <= 1.0);
+            }
+        }
+    }
+
+    #[test]
+    pub fn darian_months() {
+        let months: Vec<u32> = (1..=669).map(|sol| DarianCalendar.month(37, sol)).collect();
+
+        for month in 1..=24 {
+            let sols = months.iter().filter(|each| **each == month).count();
+            let expected = match month {
+                24 => 28,
+                _ if month % 6 == 0 => 27,
+                _ => 28,
+            };
+
+            assert_eq!(expected, sols, "{month}");
+        }
+
+        let darian = CalendarView::new(&Mars, &DarianCalendar);
+
+        for step in 0..100 {
+            let date = darian.to_date(2459000.5 + f64::from(step) * 13.7).unwrap();
+            let start = darian
+                .to_date(darian.to_julian(&date).unwrap() + 1e-6)
+                .unwrap();
+
+            assert_eq!(
+                (date.year, date.month, date.day),
+                (start.year, start.month, start.day)
+            );
+        }
+
+        // the leap sol ends the last month
+        assert!(DarianCalendar.is_leap_year(219));
+        assert_eq!(669, DarianCalendar.sols_in_year(219));
+        assert_eq!(668, DarianCalendar.sols_in_year(218));
+        assert_eq!(
+            Err(Error::InvalidDate("the day is outside of the year")),
+            CalendarView::new(&Mars, &DarianCalendar).to_julian(&Date {
+                year: 218.0,
+                day: 669.0,
+                ..Date::default()
+            })
+        );
+    }
+
+    #[test]
+    pub fn darian_sols_are_mars_sol_dates() {
+        let darian = CalendarView::new(&Mars, &DarianCalendar);
+
+        // the year 0 starts at the MSD -94129 of the telescopic epoch
+        for fixture in fixtures::MARS
+            .iter()
+            .filter(|fixture| fixture.utc.0 >= 2000)
+        {
+            let date = darian.to_date(fixture.julian_date).unwrap();
+            let sols = DarianCalendar.to_sols(date.year as i64, date.day as u32);
+
+            assert_eq!(
+                Ok(fixture.msd.unwrap().floor() as i64 + 94_129),
+                sols,
+                "{}",
+                fixture.label
+            );
+        }
+
+        // the darian year is about 183 years ahead of the mars year
+        let date = darian.to_date(2459264.371527778).unwrap();
+
+        assert_eq!((219.0, 1.0, 13.0), (date.year, date.month, date.day));
+        assert_eq!(36.0, Mars.to_date(2459264.371527778).year);
+    }
+}
@@ -0,0 +1,159 @@
+#![cfg(all(feature = "luna", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        kepler::{Body, DateTime, TimeZone},
+        moons::luna::{Luna, Lunar},
+        planets::{
+            mars::{Mars, Martian},
+            EARTH_ROTATIONAL_PERIOD,
+        },
+        Error,
+    };
+
+    /// A small xorshift generator, so the dates are random but the same on every run
+    struct Random(u64);
+
+    impl Random {
+        fn next(&mut self) -> f64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+
+            (self.0 >> 11) as f64 / (1u64 << 53) as f64
+        }
+    }
+
+    fn round_trip(body: &dyn Body, zone: &dyn TimeZone, julian_date: f64) {
+        let date_time = body.to_datetime(julian_date, zone);
+        let text = date_time.to_canonical_string();
+        let parsed = DateTime::from_canonical_str(&text).unwrap_or_else(|error| {
+            panic!("{text} didn't parse: {error}");
+        });
+        // the clock resolves a millisecond of its 24 hour sol
+        let resolution = body.rotational_period() / EARTH_ROTATIONAL_PERIOD / 86_400_000.0;
+
+        assert_eq!(text, parsed.to_canonical_string());
+        assert_eq!(
+            (
+                date_time.date().year,
+                date_time.date().month,
+                date_time.date().day
+            ),
+            (parsed.date().year, parsed.date().month, parsed.date().day)
+        );
+        assert!(
+            (parsed.to_julian() - julian_date).abs() <= resolution,
+            "{text}"
+        );
+    }
+
+    #[test]
+    pub fn mars_round_trips() {
+        let mut random = Random(0x5eed);
+
+        for _ in 0..500 {
+            let julian_date = 2_400_000.0 + random.next() * 200_000.0;
+            let zone = Martian::ALL[(random.next() * Martian::ALL.len() as f64) as usize];
+
+            round_trip(&Mars, &zone, julian_date);
+        }
+    }
+
+    #[test]
+    pub fn luna_round_trips() {
+        let mut random = Random(0xface);
+
+        for _ in 0..200 {
+            let julian_date = 2_440_000.0 + random.next() * 40_000.0;
+            let zone = Lunar::ALL[(random.next() * Lunar::ALL.len() as f64) as usize];
+
+            round_trip(&Luna, &zone, julian_date);
+        }
+    }
+
+    #[test]
+    pub fn format() {
+        let text = Mars
+            .to_datetime(2451545.0, &Martian::MTCp2)
+            .to_canonical_string();
+
+        assert!(text.starts_with("MARS:0024-10-52"), "{text}");
+        assert!(text.ends_with("+05:00[HT]"), "{text}");
+        assert!(Mars
+            .to_datetime(2400000.0, &Martian::MTC)
+            .to_canonical_string()
+            .starts_with("MARS:-0"));
+    }
+
+    #[test]
+    pub fn parse_ignores_case() {
+        let upper = DateTime::from_canonical_str("MARS:0024-10-522T02:23:02.141-12:30[AMT]");
+        let lower = DateTime::from_canonical_str("mars:0024-10-522T02:23:02.141-12:30[amt]");
+
+        assert_eq!(upper.unwrap(), lower.unwrap());
+    }
+
+    #[test]
+    pub fn malformed_strings() {
+        let cases = [
+            "0024-10-522T02:23:02-12:30[AMT]",
+            ":0024-10-522T02:23:02-12:30[AMT]",
+            "VULCAN:0024-10-522T02:23:02-12:30[AMT]",
+            "MARS:0024-10-522 02:23:02-12:30[AMT]",
+            "MARS:24-10-522T02:23:02-12:30[AMT]",
+            "MARS:0024-10-22T02:23:02-12:30[AMT]",
+            "MARS:0024-10-522T2:23:02-12:30[AMT]",
+            "MARS:0024-10-522T02:23:02.14-12:30[AMT]",
+            "MARS:0024-10-522T02:23:02-1230[AMT]",
+            "MARS:0024-10-522T02:23:02*12:30[AMT]",
+            "MARS:0024-10-522T02:23:02-12:30",
+            "MARS:0024-10-522T02:23:02-12:30[AMT",
+        ];
+
+        for case in cases {
+            assert!(
+                matches!(
+                    DateTime::from_canonical_str(case),
+                    Err(Error::InvalidFormat(_))
+                ),
+                "{case}"
+            );
+        }
+    }
+
+    #[test]
+    pub fn invalid_values() {
+        assert_eq!(
+            Err(Error::InvalidTime("the minute is outside of the hour")),
+            DateTime::from_canonical_str("MARS:0024-10-522T02:75:02-12:30[AMT]")
+        );
+        assert!(matches!(
+            DateTime::from_canonical_str("MARS:0024-10-522T24:00:00-12:30[AMT]"),
+            Err(Error::InvalidTime(_))
+        ));
+        assert_eq!(
+            Err(Error::InvalidFormat(
+                "the offset doesn't match the timezone"
+            )),
+            DateTime::from_canonical_str("MARS:0024-10-522T02:23:02+02:30[AMT]")
+        );
+        assert!(matches!(
+            DateTime::from_canonical_str("MARS:0024-10-522T02:23:02-12:75[AMT]"),
+            Err(Error::InvalidFormat(_))
+        ));
+        assert_eq!(
+            Err(Error::UnknownTimeZone),
+            DateTime::from_canonical_str("MARS:0024-10-522T02:23:02-12:30[XYZ]")
+        );
+        assert!(matches!(
+            DateTime::from_canonical_str("MARS:0024-03-522T02:23:02-12:30[AMT]"),
+            Err(Error::InvalidDate(_))
+        ));
+        assert!(matches!(
+            DateTime::from_canonical_str("MARS:0024-10-700T02:23:02-12:30[AMT]"),
+            Err(Error::InvalidDate(_))
+        ));
+    }
+}
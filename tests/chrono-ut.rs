@@ -0,0 +1,59 @@
+#![cfg(all(feature = "chrono", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, TimeZone as _, Utc};
+    use rust_solar::{
+        julian::{from_chrono, to_chrono, SplitJd},
+        kepler::{Body, TimeZone},
+        planets::mars::{Mars, Martian},
+    };
+
+    fn instants() -> [DateTime<Utc>; 4] {
+        [
+            Utc.with_ymd_and_hms(1969, 7, 20, 20, 17, 40).unwrap() + Duration::milliseconds(500),
+            Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2012, 8, 6, 5, 17, 57).unwrap()
+                + Duration::nanoseconds(123_456_789),
+            Utc.with_ymd_and_hms(2024, 2, 29, 23, 59, 59).unwrap()
+                + Duration::microseconds(999_999),
+        ]
+    }
+
+    #[test]
+    pub fn unix_epoch_from_chrono() {
+        assert_eq!(from_chrono(Utc.timestamp_opt(0, 0).unwrap()), 2440587.5);
+        assert_eq!(to_chrono(2440587.5), Ok(Utc.timestamp_opt(0, 0).unwrap()));
+    }
+
+    #[test]
+    pub fn chrono_round_trip() {
+        for instant in instants() {
+            let split = to_chrono(SplitJd::from(instant)).unwrap();
+            let single = to_chrono(from_chrono(instant)).unwrap();
+
+            // a split julian date keeps the nanoseconds, a f64 keeps about 40µs
+            assert!(
+                (split - instant).num_nanoseconds().unwrap().abs() <= 100,
+                "{split}"
+            );
+            assert!(
+                (single - instant).num_microseconds().unwrap().abs() <= 50,
+                "{single}"
+            );
+        }
+    }
+
+    #[test]
+    pub fn bodies_and_timezones_from_chrono() {
+        for instant in instants() {
+            let jd = from_chrono(instant);
+
+            assert_eq!(Mars.date_at(instant), Mars.to_date(jd));
+            assert_eq!(
+                format!("{:.3}", Martian::MTCp1.at_chrono(instant)),
+                format!("{:.3}", Martian::MTCp1.at_split(instant.into()))
+            );
+        }
+    }
+}
@@ -0,0 +1,194 @@
+#![cfg(all(feature = "earth", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use rust_solar::{
+        clock::{BodyClock, ClockCalibration, SimulationClock},
+        julian::{JulianDate, TimeScale},
+        kepler::{Body, Time, TimeZone},
+        planets::{
+            earth::Earth,
+            mars::{Mars, Martian, MARS_CALIBRATION},
+        },
+    };
+
+    const PINNED: [f64; 6] = [
+        2440587.5,
+        2451545.0,
+        2451549.5,
+        2456145.08,
+        2459000.5,
+        2460000.123,
+    ];
+
+    /// The martian clock before the clock engine
+    fn legacy(julian_date: f64, offset: f64) -> Time {
+        let jd_tt = JulianDate::utc(julian_date).to_scale(TimeScale::TT).value;
+        let msd = (jd_tt - 2451545.0 - 4.5) / 1.027491252 + 44796.0 - 0.00096;
+
+        Time::from_sol_fraction((msd + offset / 24.0).rem_euclid(1.0))
+    }
+
+    fn millis(time: &Time) -> i64 {
+        i64::from(time.hour) * 3_600_000
+            + i64::from(time.minute) * 60_000
+            + i64::from(time.second) * 1000
+            + i64::from(time.millisecond)
+    }
+
+    #[test]
+    pub fn martian_matches_the_legacy_clock() {
+        let zones = [
+            (Martian::MTCn5, -12.5),
+            (Martian::MTCn2, -5.0),
+            (Martian::MTC, 0.0),
+            (Martian::MTCp3, 7.5),
+            (Martian::MTCp5, 12.5),
+        ];
+
+        for jd in PINNED {
+            for (zone, offset) in zones {
+                let diff =
+                    (millis(&zone.at(jd)) - millis(&legacy(jd, offset))).rem_euclid(86_400_000);
+
+                assert!(diff <= 1 || diff >= 86_400_000 - 1, "{jd} {zone:?}");
+            }
+        }
+    }
+
+    #[test]
+    pub fn clock_for_any_body() {
+        let clock = BodyClock::from_body(Earth);
+
+        assert_eq!(clock.calibration, ClockCalibration::from_body(&Earth));
+        // a sol on earth is a day
+        assert!((clock.sol_date(Earth.epoch() + 2.5, 0.0) - 2.5).abs() < 1e-3);
+        assert_eq!(clock.sol_carry(Earth.epoch() + 0.75, 6.0), 1);
+
+        let time = clock.at(Earth.epoch() + 0.25, 3.0);
+        assert_eq!((time.hour, time.minute), (9, 0));
+    }
+
+    #[test]
+    pub fn mars_clock_is_the_mars_sol_date() {
+        let clock = BodyClock::new(Mars, MARS_CALIBRATION);
+
+        for jd in PINNED {
+            assert_eq!(clock.sol_date(jd, 0.0), Mars.msd(jd));
+        }
+    }
+
+    #[test]
+    pub fn simulation_runs_a_year_a_day() {
+        // 668.6 sols each real day
+        let clock = SimulationClock {
+            body: Mars,
+            zone: Martian::MTC,
+            epoch_jd: 2451545.0,
+            rate: 668.6 * Mars.rotational_period() / 86_400.0,
+        };
+        let day = Duration::from_secs(86_400);
+        let start = clock.datetime(Duration::ZERO);
+        let later = clock.datetime(day);
+
+        assert_eq!(start.date().year + 1.0, later.date().year);
+        assert!((start.date().day - later.date().day).abs() <= 1.0, "{later}");
+        assert!((start.date().ls - later.date().ls).abs() < 0.1, "{later}");
+
+        // rewinding goes back the same year
+        let rewind = SimulationClock {
+            rate: -clock.rate,
+            ..clock
+        };
+        let earlier = rewind.datetime(day);
+
+        assert_eq!(start.date().year - 1.0, earlier.date().year);
+        assert!((clock.current_jd(day) + rewind.current_jd(day) - 2.0 * 2451545.0).abs() < 1e-6);
+
+        // real time, a second at a time
+        let real = SimulationClock { rate: 1.0, ..clock };
+
+        assert_eq!(2451545.0, real.current_jd(Duration::ZERO));
+        assert!(
+            (real.current_jd(Duration::from_secs(3_600)) - (2451545.0 + 1.0 / 24.0)).abs() < 1e-9
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    pub fn running_clock_reads_the_system_clock() {
+        let running = SimulationClock {
+            body: Mars,
+            zone: Martian::MTC,
+            epoch_jd: 2451545.0,
+            rate: 10_000.0,
+        }
+        .start();
+
+        std::thread::sleep(Duration::from_millis(10));
+
+        // at least 100 seconds of the simulation, and not hours
+        let elapsed = (running.current_jd() - 2451545.0) * 86_400.0;
+
+        assert!((100.0..3_600.0).contains(&elapsed), "{elapsed}");
+        assert!(running.datetime().to_julian() >= 2451545.0);
+    }
+
+    #[test]
+    pub fn ticks_do_not_drift() {
+        let start = 2451545.0;
+        let ticks: Vec<_> = Martian::MTCn5.ticks(start, 86_400.0).take(1_001).collect();
+        let (last, time) = ticks[1_000];
+
+        // the start plus 1000 sols, not 1000 additions of a sol
+        assert_eq!(start + 1_000.0 * MARS_CALIBRATION.sol_length, last);
+        assert_eq!(Martian::MTCn5.at(last), time);
+        assert_eq!(
+            Martian::MTCn5.ticks(start, 86_400.0).nth(1_000),
+            Some((last, time))
+        );
+
+        for (julian_date, time) in &ticks {
+            assert_eq!(ticks[0].1.to_string(), time.to_string(), "{julian_date}");
+        }
+
+        // a second of the clock is a bit more than a second of earth
+        let seconds = Martian::MTC.ticks(start, 1.0);
+
+        assert_eq!(88_775, (seconds.earth_interval() * 86_400).as_secs());
+        assert!(Martian::MTC
+            .ticks(start, -60.0)
+            .take(3)
+            .all(|(julian_date, _)| julian_date <= start));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(start_paused = true)]
+    pub async fn tick_stream_waits_between_ticks() {
+        use core::future::poll_fn;
+        use core::pin::pin;
+
+        use futures_core::Stream;
+        use tokio::time::Instant;
+
+        let started = Instant::now();
+        let mut stream = pin!(Martian::MTC.ticks(2451545.0, 60.0).into_stream());
+        let mut ticks = Vec::new();
+
+        for _ in 0..3 {
+            ticks.push(poll_fn(|cx| stream.as_mut().poll_next(cx)).await.unwrap());
+        }
+
+        // two waits of a minute of the clock, 61.65 seconds of earth each
+        assert_eq!(123, started.elapsed().as_secs());
+        assert_eq!(
+            Martian::MTC
+                .ticks(2451545.0, 60.0)
+                .take(3)
+                .collect::<Vec<_>>(),
+            ticks
+        );
+    }
+}
@@ -0,0 +1,83 @@
+#![cfg(all(feature = "earth", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        comms::{as_minutes, delays, one_way_delay, round_trip_delay},
+        conversions::{KM_PER_AU, SPEED_OF_LIGHT_KM_S},
+        kepler::{Body, CustomBody},
+        planets::{earth::Earth, mars::Mars},
+        position::{separation, Origin},
+        Error,
+    };
+
+    #[test]
+    pub fn mars_envelope() {
+        // a synodic period of mars, from 2020 to 2022
+        let minutes: Vec<f64> = delays(&Mars, 2458849.5, 2459629.5, 5.0)
+            .unwrap()
+            .map(|sample| as_minutes(sample.unwrap().1))
+            .collect();
+        let nearest = minutes.iter().copied().fold(f64::MAX, f64::min);
+        let farthest = minutes.iter().copied().fold(0.0, f64::max);
+
+        assert_eq!(157, minutes.len());
+        assert!((3.0..5.0).contains(&nearest), "{nearest}");
+        assert!((20.0..22.5).contains(&farthest), "{farthest}");
+    }
+
+    #[test]
+    pub fn distance_over_light() {
+        for julian_date in [2451545.0, 2459132.5, 2460000.5] {
+            let distance = separation(&Earth, &Mars, julian_date, Origin::Heliocentric).unwrap();
+            let delay = one_way_delay(&Mars, julian_date).unwrap();
+            let seconds = distance.0 * KM_PER_AU / SPEED_OF_LIGHT_KM_S;
+
+            assert!((delay.as_secs_f64() - seconds).abs() < 1e-9);
+            assert_eq!(delay * 2, round_trip_delay(&Mars, julian_date).unwrap());
+            assert!((seconds / 60.0 - as_minutes(delay)).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    pub fn delays_range() {
+        let samples: Vec<_> = delays(&Mars, 2451545.0, 2451546.0, 0.25)
+            .unwrap()
+            .map(|sample| sample.unwrap().0)
+            .collect();
+
+        assert_eq!(
+            vec![2451545.0, 2451545.25, 2451545.5, 2451545.75, 2451546.0],
+            samples
+        );
+        assert_eq!(
+            Err(Error::InvalidTime("the step isn't positive")),
+            delays(&Mars, 2451545.0, 2451546.0, 0.0).map(|_| ())
+        );
+        assert_eq!(
+            Err(Error::InvalidTime("the range is reversed")),
+            delays(&Mars, 2451546.0, 2451545.0, 1.0).map(|_| ())
+        );
+        assert_eq!(
+            Err(Error::NonFinite),
+            delays(&Mars, f64::NAN, 2451545.0, 1.0).map(|_| ())
+        );
+    }
+
+    #[test]
+    pub fn delay_too_long_for_a_duration() {
+        // a body as far as 1e300 AU, whose light would take longer than a Duration can hold
+        let far = CustomBody::builder()
+            .epoch(Mars.epoch())
+            .orbital_eccentricity(Mars.orbital_eccentricity())
+            .orbital_period(Mars.orbital_period())
+            .rotational_period(Mars.rotational_period())
+            .semimajor(1e300)
+            .perihelion(Mars.perihelion())
+            .build()
+            .unwrap();
+
+        assert_eq!(Err(Error::NonFinite), one_way_delay(&far, 2451545.0));
+        assert_eq!(Err(Error::NonFinite), round_trip_delay(&far, 2451545.0));
+    }
+}
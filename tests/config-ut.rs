@@ -0,0 +1,176 @@
+#![cfg(all(feature = "config", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+
+    use rust_solar::{
+        bodies::{load_json, load_toml},
+        error::{ConfigError, Error},
+        fixtures,
+        kepler::{Body, Eras},
+        planets::mars::Mars,
+    };
+
+    const EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/bodies.toml");
+
+    fn json_body(fields: &str) -> String {
+        format!(
+            r#"{{ "bodies": [{{
+                "name": "Vulcan",
+                "orbital_period": 120.0,
+                "rotational_period": 100000.0,
+                "semimajor": 0.3,
+                "perihelion": {{ "month": [40.0, 50.0], "ls": [90.0, 120.0], "perihelion": 100.0 }},
+                {fields}
+            }}] }}"#
+        )
+    }
+
+    fn field_of(error: ConfigError) -> (String, String) {
+        match error {
+            ConfigError::Field { entry, field, .. } => (entry, field),
+            ConfigError::Invalid { entry, field, .. } => (entry, field),
+            other => panic!("expected a field error, got {other}"),
+        }
+    }
+
+    #[test]
+    pub fn example_file_loads() {
+        let bodies = load_toml(EXAMPLE).unwrap();
+
+        assert_eq!(2, bodies.len());
+        assert_eq!("Barsoom", bodies[0].name());
+        assert_eq!("Arrakis", bodies[1].name());
+        assert_eq!(1, bodies[0].timezones().len());
+        assert_eq!(2, bodies[1].timezones().len());
+    }
+
+    #[test]
+    pub fn reloads_share_the_timezone_texts() {
+        let first = load_toml(EXAMPLE).unwrap();
+        let again = load_toml(EXAMPLE).unwrap();
+        let (code, reloaded) = (first[1].timezones()[1].code, again[1].timezones()[1].code);
+
+        // the names are owned by the bodies, the timezone texts are kept once
+        assert_eq!(first[1].name(), again[1].name());
+        assert_ne!(first[1].name().as_ptr(), again[1].name().as_ptr());
+        assert_eq!("ET", code);
+        assert!(std::ptr::eq(code, reloaded));
+    }
+
+    #[test]
+    pub fn barsoom_is_mars() {
+        let barsoom = load_toml(EXAMPLE).unwrap().remove(0);
+
+        for fixture in fixtures::MARS {
+            let jd = fixture.julian_date;
+
+            assert_eq!(Mars.to_date(jd), barsoom.to_date(jd), "{}", fixture.label);
+        }
+    }
+
+    #[test]
+    pub fn arrakis_dates_by_hand() {
+        let arrakis = load_toml(EXAMPLE).unwrap().remove(1);
+
+        // a sol is an earth day and a year is 400 sols, starting at J2000
+        let date = arrakis.to_date(2451545.0 + 410.5);
+
+        assert_eq!(Eras::AD, date.era);
+        assert_eq!(2.0, date.year);
+        assert_eq!(11.0, date.day);
+        assert_eq!(10.5, date.sol);
+
+        let date = arrakis.to_date(2451545.0 - 1.0);
+
+        assert_eq!(Eras::BD, date.era);
+        assert_eq!(0.0, date.year);
+        assert_eq!(400.0, date.day);
+    }
+
+    #[test]
+    pub fn arrakis_timezones() {
+        let arrakis = load_toml(EXAMPLE).unwrap().remove(1);
+        let west = arrakis.time_at(2451545.25, "wt").unwrap();
+        let east = arrakis.time_at(2451545.25, "ET").unwrap();
+
+        assert_eq!((0, "WT"), (west.hour, west.code()));
+        assert_eq!((12, "ET"), (east.hour, east.code()));
+        assert!(arrakis.time_at(2451545.25, "HLT").is_err());
+    }
+
+    #[test]
+    pub fn negative_eccentricity_is_rejected() {
+        let error = load_json(&json_body(r#""orbital_eccentricity": -0.5"#)).unwrap_err();
+
+        assert_eq!(
+            ("Vulcan".to_string(), "orbital_eccentricity".to_string()),
+            field_of(error.clone())
+        );
+        assert!(error.to_string().contains("negative"));
+    }
+
+    #[test]
+    pub fn schema_errors_name_the_field() {
+        let missing =
+            load_json(r#"{ "bodies": [{ "orbital_eccentricity": 0.1, "orbital_period": 10.0 }] }"#);
+        let wrong_type = load_json(&json_body(r#""orbital_eccentricity": "low""#));
+        let unknown = load_json(&json_body(
+            r#""orbital_eccentricity": 0.1, "colour": "red""#,
+        ));
+        let perihelion = load_json(
+            &json_body(r#""orbital_eccentricity": 0.1"#).replace("[90.0, 120.0]", "[120.0, 90.0]"),
+        );
+
+        assert_eq!(
+            ("#1".to_string(), "rotational_period".to_string()),
+            field_of(missing.unwrap_err())
+        );
+        assert_eq!(
+            ("Vulcan".to_string(), "orbital_eccentricity".to_string()),
+            field_of(wrong_type.unwrap_err())
+        );
+        assert_eq!(
+            ("Vulcan".to_string(), "colour".to_string()),
+            field_of(unknown.unwrap_err())
+        );
+        assert_eq!(
+            ("Vulcan".to_string(), "perihelion".to_string()),
+            field_of(perihelion.unwrap_err())
+        );
+    }
+
+    #[test]
+    pub fn malformed_files_are_rejected() {
+        assert!(matches!(load_json("{"), Err(ConfigError::Parse(_))));
+        assert!(matches!(load_json("[]"), Err(ConfigError::Parse(_))));
+        assert!(matches!(
+            load_toml("examples/missing.toml"),
+            Err(ConfigError::Io(_))
+        ));
+    }
+
+    #[test]
+    pub fn library_errors_are_the_source() {
+        let error = load_json(
+            &json_body(r#""orbital_eccentricity": 0.1"#).replace("[90.0, 120.0]", "[120.0, 90.0]"),
+        )
+        .unwrap_err();
+        let source = error
+            .source()
+            .and_then(|source| source.downcast_ref::<Error>());
+
+        assert_eq!(
+            Some(&Error::InvalidPerihelion(
+                "the ls window is empty or reversed"
+            )),
+            source
+        );
+        assert_eq!(
+            "Vulcan: perihelion: invalid perihelion: the ls window is empty or reversed",
+            error.to_string()
+        );
+        assert!(load_json("{").unwrap_err().source().is_none());
+    }
+}
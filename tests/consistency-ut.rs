@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        bodies,
+        kepler::{validate, Body, ConsistencyWarning, CustomBody, CustomBodyBuilder, Date, Time},
+        orbit::Perihelion,
+        Error,
+    };
+
+    /// An earth like body, which keeps kepler's third law
+    fn earthlike() -> CustomBodyBuilder {
+        CustomBody::builder()
+            .orbital_eccentricity(0.0167)
+            .orbital_period(365.25)
+            .rotational_period(86_400.0)
+            .semimajor(1.0)
+            .perihelion(Perihelion::new((276.0, 306.0), (270.0, 300.0), 283.0).unwrap())
+    }
+
+    /// The earth like body, with a perihelion and a semiminor axis that were typed in by hand
+    struct Lopsided(CustomBody);
+
+    impl Body for Lopsided {
+        fn name(&self) -> &str {
+            "Lopsided"
+        }
+
+        fn epoch(&self) -> f64 {
+            self.0.epoch()
+        }
+
+        fn orbital_eccentricity(&self) -> f64 {
+            self.0.orbital_eccentricity()
+        }
+
+        fn orbital_period(&self) -> f64 {
+            self.0.orbital_period()
+        }
+
+        fn rotational_period(&self) -> f64 {
+            self.0.rotational_period()
+        }
+
+        fn perihelion(&self) -> Perihelion {
+            let perihelion = self.0.perihelion();
+
+            Perihelion {
+                month: (perihelion.month.1, perihelion.month.0),
+                ..perihelion
+            }
+        }
+
+        fn semimajor(&self) -> f64 {
+            self.0.semimajor()
+        }
+
+        fn semiminor(&self) -> f64 {
+            1.2
+        }
+
+        fn to_time(&self, date: Date) -> Time {
+            self.0.to_time(date)
+        }
+    }
+
+    #[test]
+    pub fn registry_is_consistent() {
+        for body in bodies::registry() {
+            assert_eq!(
+                Vec::<ConsistencyWarning>::new(),
+                validate(*body),
+                "{}",
+                body.name()
+            );
+        }
+    }
+
+    #[test]
+    pub fn custom_bodies_are_checked() {
+        assert!(validate(&earthlike().build().unwrap()).is_empty());
+
+        // the semimajor axis in km instead of au
+        let warnings = validate(&earthlike().semimajor(149_597_870.7).build().unwrap());
+
+        assert!(matches!(
+            warnings.as_slice(),
+            [ConsistencyWarning::KeplerThirdLaw { days, .. }] if *days == 365.25
+        ));
+
+        // the rotational period in hours instead of seconds, which also shortens the year
+        let warnings = validate(&earthlike().rotational_period(24.0).build().unwrap());
+
+        assert!(matches!(
+            warnings.as_slice(),
+            [
+                ConsistencyWarning::KeplerThirdLaw { expected, .. },
+                ConsistencyWarning::RotationalPeriod(period),
+            ] if (expected - 365.25).abs() < 0.01 && *period == 24.0
+        ));
+    }
+
+    #[test]
+    pub fn open_orbits_are_flagged() {
+        let warnings = validate(&earthlike().orbital_eccentricity(1.5).build().unwrap());
+
+        assert!(warnings.contains(&ConsistencyWarning::Eccentricity(1.5)));
+    }
+
+    #[test]
+    pub fn every_check_of_a_body_is_reported() {
+        let lopsided = Lopsided(earthlike().build().unwrap());
+
+        assert_eq!(
+            vec![
+                ConsistencyWarning::Perihelion(Error::InvalidPerihelion(
+                    "the month window is empty or reversed"
+                )),
+                ConsistencyWarning::Semiminor {
+                    semiminor: 1.2,
+                    semimajor: 1.0,
+                },
+            ],
+            validate(&lopsided)
+        );
+        assert_eq!(
+            "the semiminor axis 1.2 is longer than the semimajor axis 1",
+            validate(&lopsided)[1].to_string()
+        );
+    }
+}
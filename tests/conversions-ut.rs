@@ -0,0 +1,77 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::{PI, TAU};
+
+    use rust_solar::{
+        conversions::{
+            au_to_km, deg_to_hours, deg_to_rad, hours_to_deg, km_to_au, normalize_degrees,
+            normalize_radians, rad_to_deg, radians_in_circle,
+        },
+        planets::mars::Martian,
+    };
+
+    #[test]
+    pub fn negative_angles() {
+        assert_eq!(270.0, normalize_degrees(-90.0));
+        assert_eq!(0.5, normalize_degrees(-359.5));
+        assert_eq!(180.0, normalize_degrees(-540.0));
+        assert_eq!(PI, normalize_radians(-PI));
+        assert_eq!(-0.25, deg_to_hours(-3.75));
+        assert_eq!(-PI, deg_to_rad(-180.0));
+    }
+
+    #[test]
+    pub fn exact_multiples_of_the_circle() {
+        for turns in [-3.0, -1.0, 0.0, 1.0, 2.0, 1000.0] {
+            assert_eq!(0.0, normalize_degrees(360.0 * turns));
+        }
+
+        for turns in [-2.0, -1.0, 0.0, 1.0, 4.0] {
+            assert_eq!(0.0, normalize_radians(radians_in_circle() * turns));
+        }
+
+        // a tiny negative angle would round up to the whole circle
+        assert_eq!(0.0, normalize_degrees(-1e-20));
+        assert!(normalize_radians(-1e-20) < TAU);
+    }
+
+    #[test]
+    pub fn very_large_angles() {
+        let naive = |degrees: f64| degrees - 360.0 * (degrees / 360.0).floor();
+
+        // 10^20 is 280 past a whole turn
+        assert_eq!(280.0, normalize_degrees(1e20));
+        assert_ne!(280.0, naive(1e20));
+
+        // 2^40 degrees, still exact a quarter turn later
+        let large = (1u64 << 40) as f64;
+
+        assert_eq!(
+            normalize_degrees(large) + 90.0,
+            normalize_degrees(large + 90.0)
+        );
+        assert!((0.0..360.0).contains(&normalize_degrees(-1e300)));
+        assert!(normalize_degrees(f64::INFINITY).is_nan());
+    }
+
+    #[test]
+    pub fn round_trips() {
+        for degrees in [-720.0, -45.0, 0.0, 12.5, 251.0, 359.999] {
+            assert!((rad_to_deg::<f64>(deg_to_rad(degrees)) - degrees).abs() < 1e-12);
+            assert_eq!(degrees, hours_to_deg(deg_to_hours(degrees)));
+        }
+
+        assert!((km_to_au(au_to_km(1.52)) - 1.52).abs() < 1e-15);
+        assert_eq!(90.0_f32, rad_to_deg(core::f32::consts::FRAC_PI_2));
+    }
+
+    #[test]
+    pub fn martian_longitudes() {
+        assert_eq!(Some(Martian::MTCn5), Martian::from_longitude(-180.0));
+        assert_eq!(Some(Martian::MTCn5), Martian::from_longitude(180.0));
+        assert_eq!(Some(Martian::MTC), Martian::from_longitude(720.0));
+        assert_eq!(Some(Martian::MTCp2), Martian::from_longitude(60.0 - 3600.0));
+    }
+}
@@ -0,0 +1,44 @@
+#![cfg(all(feature = "ceres", feature = "earth", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        asteroids::ceres::Ceres,
+        kepler::{convert_date, Body, Eras},
+        planets::{earth::Earth, mars::Mars},
+    };
+
+    #[test]
+    pub fn mars_through_earth_and_back() {
+        let mars = Mars.to_date(2440587.5);
+        let earth = convert_date(&Mars, &mars, &Earth).unwrap();
+        let back = convert_date(&Earth, &earth, &Mars).unwrap();
+
+        // The solar longitude is of the instant converted, which is within the same sol
+        assert_eq!(
+            (back.year, back.month, back.day, back.season),
+            (mars.year, mars.month, mars.day, mars.season)
+        );
+        assert!((back.ls - mars.ls).abs() < 1.0);
+    }
+
+    #[test]
+    pub fn mars_to_ceres() {
+        let mars = Mars.to_date(2459000.5);
+        let ceres = convert_date(&Mars, &mars, &Ceres).unwrap();
+        let direct = Ceres.to_date(Mars.to_julian_midsol(&mars).unwrap());
+
+        assert_eq!(
+            (ceres.year, ceres.month, ceres.day, ceres.ls),
+            (direct.year, direct.month, direct.day, direct.ls)
+        );
+    }
+
+    #[test]
+    pub fn convert_date_with_mismatched_era() {
+        let mut mars = Mars.to_date(2459000.5);
+        mars.era = Eras::BD;
+
+        assert!(convert_date(&Mars, &mars, &Earth).is_err());
+    }
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        fixtures,
+        kepler::{convert_date, Body, CustomBody, CustomBodyBuilder},
+        orbit::Perihelion,
+        planets::mars::Mars,
+        Error,
+    };
+
+    fn mars_like() -> CustomBodyBuilder {
+        CustomBody::builder()
+            .name("Barsoom")
+            .epoch(Mars.epoch())
+            .epoch_year(Mars.epoch_year())
+            .orbital_eccentricity(Mars.orbital_eccentricity())
+            .orbital_period(Mars.orbital_period())
+            .rotational_period(Mars.rotational_period())
+            .semimajor(Mars.semimajor())
+            .perihelion(Mars.perihelion())
+            .axial_tilt(Mars.axial_tilt())
+    }
+
+    #[test]
+    pub fn custom_mars_agrees_with_mars() {
+        let barsoom = mars_like().build().unwrap();
+
+        let fixtures = fixtures::MARS.iter().map(|fixture| fixture.julian_date);
+
+        for jd in fixtures.chain([2442765.667, 2451545.0, 2400000.5]) {
+            assert_eq!(Mars.to_date(jd), barsoom.to_date(jd));
+        }
+
+        assert_eq!(
+            Mars.solar_declination(90.0),
+            barsoom.solar_declination(90.0)
+        );
+    }
+
+    #[test]
+    pub fn custom_body_converts_like_a_built_in_body() {
+        let barsoom = mars_like().build().unwrap();
+        let date = Mars.to_date(2451545.0);
+
+        assert_eq!(
+            convert_date(&Mars, &date, &Mars).unwrap(),
+            convert_date(&barsoom, &date, &barsoom).unwrap()
+        );
+    }
+
+    #[test]
+    pub fn builder_defaults() {
+        let body = mars_like().build().unwrap();
+        let unnamed = CustomBody::builder()
+            .orbital_eccentricity(0.0)
+            .orbital_period(100.0)
+            .rotational_period(86_400.0)
+            .semimajor(1.0)
+            .perihelion(Mars.perihelion())
+            .build()
+            .unwrap();
+
+        assert_eq!("Barsoom", body.name());
+        assert_eq!("Custom", unnamed.name());
+        assert_eq!(2451545.0, unnamed.epoch());
+        assert_eq!(1.0, unnamed.epoch_year());
+        assert_eq!(0.0, unnamed.week_anchor());
+        assert_eq!(0.0, unnamed.axial_tilt());
+    }
+
+    #[test]
+    pub fn builder_rejects_invalid_elements() {
+        assert_eq!(
+            Err(Error::InvalidEccentricity(-0.5)),
+            mars_like().orbital_eccentricity(-0.5).build()
+        );
+        assert!(matches!(
+            mars_like().orbital_period(0.0).build(),
+            Err(Error::InvalidOrbit(_))
+        ));
+        assert!(matches!(
+            mars_like().rotational_period(-1.0).build(),
+            Err(Error::InvalidOrbit(_))
+        ));
+        assert!(matches!(
+            mars_like().semimajor(0.0).build(),
+            Err(Error::InvalidOrbit(_))
+        ));
+        assert_eq!(Err(Error::NonFinite), mars_like().epoch(f64::NAN).build());
+        assert!(matches!(
+            mars_like()
+                .perihelion(Perihelion {
+                    month: (1.0, 2.0),
+                    ls: (10.0, 5.0),
+                    perihelion: 7.0,
+                })
+                .build(),
+            Err(Error::InvalidPerihelion(_))
+        ));
+    }
+
+    #[test]
+    pub fn builder_requires_the_orbit() {
+        assert!(matches!(
+            CustomBody::builder().build(),
+            Err(Error::InvalidOrbit(_))
+        ));
+        assert!(matches!(
+            CustomBody::builder()
+                .orbital_eccentricity(0.0)
+                .orbital_period(100.0)
+                .rotational_period(86_400.0)
+                .semimajor(1.0)
+                .build(),
+            Err(Error::InvalidPerihelion(_))
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    pub fn registered_bodies_are_found() {
+        use rust_solar::bodies::{all, find, register, registry};
+
+        let body = register(mars_like().name("Helium").build().unwrap());
+
+        assert_eq!("Helium", body.name());
+        assert_eq!("Helium", find("helium").unwrap().name());
+        assert_eq!(
+            Mars.to_date(2451545.0),
+            find("HELIUM").unwrap().to_date(2451545.0)
+        );
+        assert!(all().len() > registry().len());
+        assert!(registry().iter().all(|body| body.name() != "Helium"));
+    }
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use rust_solar::julian::jd_now;
+    use rust_solar::{
+        kepler::{Body, Date, DateTime, EraPosition, Eras, FormatOptions, HourType, TimeZone},
+        planets::mars::{coordinated_mars_time, Mars, Martian},
+    };
+
+    #[test]
+    pub fn datetime_round_trip() {
+        for jd in [2440587.5, 2451545.0, 2459000.5, 2460000.123] {
+            let datetime = Mars.to_datetime(jd, &Martian::MTC);
+
+            assert_eq!(datetime.to_julian(), jd);
+            assert_eq!(datetime.date().ls, Mars.to_date(jd).ls);
+
+            // rebuilt from its date and its time alone, it lands on the same julian date, to the millisecond
+            let rebuilt = DateTime::from_canonical_str(&datetime.to_canonical_string()).unwrap();
+
+            assert!((rebuilt.to_julian() - jd).abs() < 1e-8, "{jd}");
+            assert_eq!(rebuilt.date().day, datetime.date().day);
+            assert_eq!(rebuilt.time(), datetime.time());
+        }
+    }
+
+    #[test]
+    pub fn datetime_display() {
+        let snapshots = [
+            (
+                2440587.5,
+                "AD 0008-10-555 Ls 295.3 (Winter Solstice) 07:05:35 NT (+00:00)",
+            ),
+            (
+                2451545.0,
+                "AD 0024-10-522 Ls 274.3 (Winter Solstice) 14:53:02 NT (+00:00)",
+            ),
+        ];
+
+        for (jd, snapshot) in snapshots {
+            assert_eq!(Mars.to_datetime(jd, &Martian::MTC).to_string(), snapshot);
+            assert_eq!(
+                Mars.to_datetime(jd, &Martian::MTC)
+                    .format_with(&FormatOptions::default()),
+                snapshot
+            );
+        }
+    }
+
+    #[test]
+    pub fn datetime_format_with() {
+        let datetime = Mars.to_datetime(2451545.0, &Martian::MTC);
+        let table = FormatOptions {
+            pad_year: 6,
+            decimal_ls: 3,
+            uppercase_era: false,
+            era: EraPosition::After,
+            ..FormatOptions::default()
+        };
+        let prose = FormatOptions {
+            pad_year: 0,
+            decimal_ls: 0,
+            month_names: true,
+            ..FormatOptions::default()
+        };
+
+        assert_eq!(
+            "000024-10-522 ad Ls 274.262 (Winter Solstice) 14:53:02 NT (+00:00)",
+            datetime.format_with(&table)
+        );
+        assert_eq!(
+            "AD Year 24, Month of Makara, Sol 522 Ls 274 (Winter Solstice)",
+            datetime.date().format_with(&prose)
+        );
+
+        // before the discovery, the sign is part of the padding like with the display
+        let before = Date {
+            era: Eras::BD,
+            year: -5.0,
+            month: 13.0,
+            day: 7.0,
+            ls: 359.96,
+            ..Date::default()
+        };
+
+        assert_eq!("BD -005-13-007 Ls 0.0 (N/A)", before.to_string());
+        assert_eq!(
+            "BD Year -005, Month 13, Sol 7 Ls 359.96 (N/A)",
+            before.format_with(&FormatOptions {
+                decimal_ls: 2,
+                month_names: true,
+                ..FormatOptions::default()
+            })
+        );
+    }
+
+    #[test]
+    pub fn datetime_ordering() {
+        let earlier = Mars.to_datetime(2451545.0, &Martian::MTC);
+        let later = Mars.to_datetime(2451545.5, &Martian::MTCp5);
+
+        assert!(earlier < later);
+        assert!(earlier == Mars.to_datetime(2451545.0, &Martian::MTCn5));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    pub fn datetime_now() {
+        let before = jd_now();
+        let now = Mars.now_in(&Martian::MTC);
+        let after = jd_now();
+
+        assert!(before <= now.to_julian() && now.to_julian() <= after);
+        // 2024 January 1
+        assert!(now.to_julian() > 2_460_310.5);
+    }
+
+    #[test]
+    pub fn datetime_offset_carries_into_next_sol() {
+        // the instant when MTC is 23:30:00
+        let jd = 2451545.0 + (23.5 - coordinated_mars_time(2451545.0)) / 24.0 * 1.027491252;
+        let mtc = Martian::MTC.at(jd);
+        let ahead = Martian::MTCp1.at(jd);
+
+        assert_eq!((mtc.hour, mtc.minute, mtc.second), (23, 30, 0));
+        assert_eq!((ahead.hour, ahead.minute, ahead.second), (2, 0, 0));
+        assert_eq!(ahead.hour_type, HourType::AM);
+        assert_eq!(Martian::MTCp1.sol_carry(jd), 1);
+        assert_eq!(Martian::MTCn1.sol_carry(jd), 0);
+
+        let datetime = Mars.to_datetime(jd, &Martian::MTCp1);
+
+        assert_eq!(datetime.date().day, Mars.to_date(jd).day + 1.0);
+        assert_eq!(datetime.to_julian(), jd);
+    }
+}
@@ -0,0 +1,102 @@
+#![cfg(all(
+    feature = "ceres",
+    feature = "earth",
+    feature = "luna",
+    feature = "mars"
+))]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        asteroids::ceres::Ceres,
+        bodies,
+        julian::{greg2jd, CalendarSystem},
+        kepler::{Body, CustomBody, Discovery, Eras},
+        moons::luna::Luna,
+        orbit::Perihelion,
+        planets::{earth::Earth, mars::Mars},
+        Error,
+    };
+
+    /// A body whose calendar starts at its discovery
+    fn discovered_at_epoch(jd: f64) -> Result<CustomBody, Error> {
+        CustomBody::builder()
+            .name("Vulcan")
+            .epoch(2_451_545.0)
+            .orbital_eccentricity(0.02)
+            .orbital_period(120.0)
+            .rotational_period(100_000.0)
+            .semimajor(0.3)
+            .perihelion(Perihelion::new((40.0, 50.0), (90.0, 120.0), 100.0).unwrap())
+            .discovery(Discovery {
+                jd,
+                year: 2000,
+                discoverer: "Le Verrier",
+            })
+            .build()
+    }
+
+    #[test]
+    pub fn discoveries() {
+        let vulcan = discovered_at_epoch(2_451_545.0).unwrap();
+        let ceres = Ceres.discovery().unwrap();
+
+        // the calendar of vulcan starts at its discovery
+        assert_eq!(
+            Some(vulcan.epoch()),
+            vulcan.discovery().map(|found| found.jd)
+        );
+
+        // ceres counts from the equinox observed by dawn, long after piazzi
+        assert_eq!(
+            Ok(ceres.jd),
+            greg2jd(1801, 1, 1, 0, 0, 0.0, CalendarSystem::Gregorian)
+        );
+        assert_eq!((1801, "Giuseppe Piazzi"), (ceres.year, ceres.discoverer));
+        assert!(ceres.jd < Ceres.epoch());
+        assert_eq!(Eras::BD, Ceres.to_date(ceres.jd).era);
+
+        // known since antiquity
+        for body in [&Mars as &dyn Body, &Earth, &Luna] {
+            assert_eq!(None, body.discovery(), "{}", body.name());
+        }
+
+        for body in bodies::registry() {
+            if let Some(discovery) = body.discovery() {
+                assert!(discovery.jd.is_finite(), "{}", body.name());
+            }
+        }
+
+        assert_eq!(Err(Error::NonFinite), discovered_at_epoch(f64::NAN));
+    }
+
+    #[test]
+    pub fn age_at() {
+        let year = Mars.orbital_period_earth_days();
+
+        assert_eq!(0.0, Mars.age_at(Mars.epoch()));
+        assert!((Mars.age_at(Mars.epoch() + 2.5 * year) - 2.5).abs() < 1e-12);
+        assert!((Mars.age_at(Mars.epoch() - year) + 1.0).abs() < 1e-12);
+
+        for jd in [2_440_000.3, 2_451_545.0, 2_460_000.7] {
+            let date = Mars.to_date(jd);
+
+            assert_eq!(date.year, Mars.epoch_year() + Mars.age_at(jd).floor());
+        }
+    }
+
+    #[test]
+    pub fn anchored_dates() {
+        let date = Ceres.to_date(2_460_000.5);
+
+        assert_eq!(
+            format!("{date} (anchored to 2015-11-05 epoch)"),
+            date.anchored(&Ceres).to_string()
+        );
+        assert!(Earth
+            .to_date(2_451_545.0)
+            .anchored(&Earth)
+            .to_string()
+            .ends_with("(anchored to 2000-03-20 epoch)"));
+    }
+}
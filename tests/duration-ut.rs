@@ -0,0 +1,110 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use rust_solar::{
+        kepler::{Body, SolDuration},
+        planets::mars::Mars,
+        Error,
+    };
+
+    #[test]
+    pub fn mars_durations_both_ways() {
+        // a sol of mars is 88775.244 seconds
+        let sol = SolDuration::from_sols(1.0).to_duration(&Mars).unwrap();
+
+        assert!((sol.as_secs_f64() - Mars.rotational_period()).abs() < 1e-6);
+        assert_eq!(
+            SolDuration::from_sols(1.0),
+            SolDuration::from_duration(sol, &Mars)
+        );
+
+        // an earth day is a bit less than a sol
+        let day = SolDuration::from_duration(Duration::from_secs(86_400), &Mars);
+
+        assert_eq!(0, day.sols);
+        assert_eq!("0 sols 23:21:28", day.to_string());
+
+        for sols in [0.25, 1.0, 3.175, 668.6, 10_000.5] {
+            let duration = SolDuration::from_sols(sols);
+            let back = SolDuration::from_duration(duration.to_duration(&Mars).unwrap(), &Mars);
+
+            assert!((back.as_sols() - sols).abs() < 1e-9, "{sols}");
+            assert!((duration.to_seconds(&Mars).0 - sols * Mars.rotational_period()).abs() < 1e-6);
+        }
+
+        // an hour of the clock is a 24th of the sol
+        assert_eq!(SolDuration::from_sols(1.0), SolDuration::from_hours(24.0));
+        assert_eq!(
+            SolDuration::from_hours(1.5),
+            SolDuration::from_minutes(90.0)
+        );
+        assert_eq!(
+            Err(Error::InvalidTime("the duration is negative")),
+            SolDuration::from_sols(-1.0).to_duration(&Mars)
+        );
+    }
+
+    #[test]
+    pub fn duration_arithmetic() {
+        let duration = SolDuration::new(3, 15_129.0);
+
+        assert_eq!("3 sols 04:12:09", duration.to_string());
+        assert_eq!("1 sol 00:00:00", SolDuration::from_sols(1.0).to_string());
+        assert_eq!(SolDuration::new(4, 0.0), SolDuration::new(2, 172_800.0));
+
+        // the seconds stay within the sol, so a negative duration has negative sols
+        let back = -SolDuration::from_hours(12.0);
+
+        assert_eq!(SolDuration::new(-1, 43_200.0), back);
+        assert_eq!("-0 sols 12:00:00", back.to_string());
+        assert_eq!(SolDuration::default(), duration + -duration);
+        assert_eq!(duration, duration - back + back);
+        assert_eq!(
+            SolDuration::new(2, 58_329.0),
+            duration - SolDuration::from_hours(12.0)
+        );
+        assert!(back < SolDuration::default() && SolDuration::default() < duration);
+    }
+
+    #[test]
+    pub fn add_then_subtract_is_the_identity() {
+        let durations = [
+            SolDuration::from_sols(0.5),
+            SolDuration::new(3, 15_129.0),
+            SolDuration::from_sols(700.25),
+            -SolDuration::from_sols(1_500.75),
+        ];
+
+        for julian_date in [2451545.0, 2459264.371527778, 2460000.123] {
+            let date = Mars.to_date(julian_date);
+
+            for duration in durations {
+                let later = Mars.add_sols(&date, duration).unwrap();
+                let again = Mars.add_sols(&later, -duration).unwrap();
+
+                assert_eq!((date.year, date.day), (again.year, again.day));
+                assert!((date.sol - again.sol).abs() < 1e-6);
+                assert!(
+                    (Mars.sols_between(&date, &later).as_sols() - duration.as_sols()).abs() < 1e-6
+                );
+                assert!(
+                    (Mars.sols_between(&later, &date).as_sols() + duration.as_sols()).abs() < 1e-6
+                );
+            }
+        }
+
+        assert_eq!(
+            Err(Error::NonFinite),
+            Mars.add_sols(
+                &rust_solar::kepler::Date {
+                    year: f64::NAN,
+                    ..Mars.to_date(2451545.0)
+                },
+                SolDuration::from_sols(1.0)
+            )
+        );
+    }
+}
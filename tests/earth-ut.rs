@@ -0,0 +1,57 @@
+#![cfg(feature = "earth")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        calendar::month_grid,
+        kepler::Body,
+        planets::earth::{Earth, EARTH_RADIUS_KM},
+    };
+
+    #[test]
+    pub fn earth_to_date() {
+        // 2001 March 20 (Vernal Equinox) and 2001 June 21 (Summer Solstice)
+        let spring = Earth.to_date(2_451_989.0);
+        let summer = Earth.to_date(2_452_081.5);
+
+        assert!(spring.ls < 1.0 || spring.ls > 359.0, "{}", spring.ls);
+        assert!((summer.ls - 90.0).abs() < 1.0, "{}", summer.ls);
+    }
+
+    #[test]
+    pub fn earth_solar_declination() {
+        assert!((Earth.solar_declination(90.0) - 23.44).abs() < 1e-9);
+        assert!((Earth.solar_declination(270.0) + 23.44).abs() < 1e-9);
+    }
+
+    #[test]
+    pub fn earth_escape_velocity() {
+        let velocity = Earth.escape_velocity(EARTH_RADIUS_KM).unwrap();
+
+        assert!((velocity - 11.19).abs() < 0.01, "{velocity}");
+    }
+
+    #[test]
+    pub fn earth_epoch_year() {
+        // Year 1 begins at the epoch and the year before it is in the BD era
+        assert_eq!(Earth.to_date(Earth.epoch()).year, 1.0);
+        assert_eq!(Earth.to_date(Earth.epoch() - 1.0).year, 0.0);
+    }
+
+    #[test]
+    pub fn earth_year_grid() {
+        let mut days = Vec::new();
+
+        for month in 1..=12 {
+            let grid = month_grid(&Earth, 5.0, month, 7).unwrap();
+            let sols: Vec<u32> = grid.sols.iter().map(|sol| sol.day).collect();
+
+            // the days of a month follow each other
+            assert!(sols.windows(2).all(|pair| pair[1] == pair[0] + 1), "{month}: {sols:?}");
+            days.extend(sols);
+        }
+
+        // the last day of the year is only a quarter of a day, but still in the last month
+        assert_eq!((1..=Earth.orbital_period().ceil() as u32).collect::<Vec<_>>(), days);
+    }
+}
@@ -0,0 +1,119 @@
+#![cfg(all(feature = "std", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, ErrorKind, Write};
+
+    use rust_solar::{
+        define_body,
+        ephemeris::{to_csv, Column, CsvExport},
+        kepler::Body,
+        orbit::{Season, SeasonTable},
+        planets::mars::Mars,
+        Error,
+    };
+
+    define_body! {
+        /// A body whose seasons need quoting
+        struct Dusty {
+            name: "Dusty",
+            epoch: 2_451_545.0,
+            orbital_eccentricity: 0.02,
+            orbital_period: 120.0,
+            rotational_period: 100_000.0,
+            semimajor: 0.3,
+            perihelion: ((40.0, 50.0), (90.0, 120.0), 100.0),
+        }
+        impl {
+            fn season_table(&self) -> Option<SeasonTable> {
+                SeasonTable::new(vec![(0.0, 360.0, Season::Custom("Dusty, \"windy\""))])
+                .ok()
+            }
+        }
+    }
+
+    /// A writer that's always full
+    struct Full;
+
+    impl Write for Full {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(ErrorKind::StorageFull.into())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    pub fn every_column() {
+        let mut out = Vec::new();
+
+        to_csv(&Mars, 2_451_545.0, 2_451_555.0, 2.0, &mut out).unwrap();
+
+        let table = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(6, lines.len());
+        assert_eq!(
+            "jd,year,month,day,ls,season,distance_au,x_au,y_au,z_au",
+            lines[0]
+        );
+        assert_eq!(
+            "2451549.109965,24,10,526,276.830804,Winter Solstice,1.389894656,1.388730304,0.046206656,-0.033169910",
+            lines[3]
+        );
+        assert!(table.ends_with('\n'));
+    }
+
+    #[test]
+    pub fn selected_columns() {
+        let export = CsvExport::builder()
+            .columns(&[Column::Ls, Column::JulianDate])
+            .column(Column::Season)
+            .build()
+            .unwrap();
+        let mut out = Vec::new();
+
+        export
+            .write(&Mars, 2_451_545.0, 2_451_549.0, 2.0, &mut out)
+            .unwrap();
+
+        assert_eq!(
+            "ls,jd,season\n274.262038,2451545.000000,Winter Solstice\n275.547451,2451547.054983,Winter Solstice\n",
+            String::from_utf8(out).unwrap()
+        );
+        assert_eq!(Column::ALL, CsvExport::default().columns());
+        assert_eq!(
+            Err(Error::InvalidFormat("the table has no columns")),
+            CsvExport::builder().build()
+        );
+    }
+
+    #[test]
+    pub fn quoted_seasons() {
+        let export = CsvExport::builder().column(Column::Season).build().unwrap();
+        let mut out = Vec::new();
+
+        export
+            .write(&Dusty, Dusty.epoch(), Dusty.epoch(), 1.0, &mut out)
+            .unwrap();
+
+        assert_eq!(
+            "season\n\"Dusty, \"\"windy\"\"\"\n",
+            String::from_utf8(out).unwrap()
+        );
+    }
+
+    #[test]
+    pub fn failures() {
+        assert_eq!(
+            Err(Error::Io(ErrorKind::StorageFull)),
+            to_csv(&Mars, 2_451_545.0, 2_451_546.0, 1.0, Full)
+        );
+        assert_eq!(
+            Err(Error::NonFinite),
+            to_csv(&Mars, f64::NAN, 2_451_546.0, 1.0, Vec::new())
+        );
+    }
+}
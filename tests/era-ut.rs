@@ -0,0 +1,65 @@
+#![cfg(all(feature = "earth", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        kepler::{Body, Eras},
+        planets::{earth::Earth, mars::Mars, EARTH_ROTATIONAL_PERIOD},
+    };
+
+    /// Walks one sol at a time across the epoch, asserting the days are continuous
+    fn walk_across_epoch(body: &dyn Body) {
+        let sol = body.rotational_period() / EARTH_ROTATIONAL_PERIOD;
+        let mut previous = body.to_date(body.epoch() - 10.5 * sol);
+        let mut rollovers = 0;
+
+        for step in -10..10 {
+            let date = body.to_date(body.epoch() + (f64::from(step) + 0.5) * sol);
+
+            if date.year == previous.year {
+                assert_eq!(date.day, previous.day + 1.0, "{date}");
+            } else {
+                rollovers += 1;
+
+                assert_eq!(date.year, previous.year + 1.0);
+                assert_eq!(date.day, 1.0);
+                // the last sol is partial, so it can be stepped over
+                assert!(previous.day >= body.orbital_period().floor(), "{previous}");
+            }
+
+            // the solar longitude doesn't jump when the year rolls over
+            let ls = (date.ls - previous.ls).rem_euclid(360.0);
+            assert!(ls < 2.0, "{previous} -> {date}");
+
+            previous = date;
+        }
+
+        assert_eq!(rollovers, 1);
+    }
+
+    #[test]
+    pub fn earth_days_are_continuous_across_the_era() {
+        walk_across_epoch(&Earth);
+
+        let before = Earth.to_date(Earth.epoch() - 0.5);
+        let after = Earth.to_date(Earth.epoch() + 0.5);
+
+        assert_eq!((before.era, before.year), (Eras::BD, 0.0));
+        assert_eq!((after.era, after.year), (Eras::AD, 1.0));
+    }
+
+    #[test]
+    pub fn mars_days_are_continuous_across_the_epoch() {
+        walk_across_epoch(&Mars);
+    }
+
+    #[test]
+    pub fn dates_far_before_the_epoch() {
+        // about 50,000 years before the epoch
+        let jd = Earth.epoch() - 50_000.0 * Earth.orbital_period() + 10.25;
+        let date = Earth.to_date(jd);
+
+        assert_eq!((date.era, date.year, date.day), (Eras::BD, -49_999.0, 11.0));
+        assert!((Earth.to_julian(&date).unwrap() - (jd - 0.25)).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        anomaly::{Anomaly, MAX_ITERATIONS},
+        kepler::{Body, DateTime},
+        orbit::{SolarLongitude, Type},
+        planets::mars::{Mars, Martian},
+        Error,
+    };
+
+    #[test]
+    pub fn messages_are_shown_verbatim() {
+        let messages = [
+            (Error::NonFinite, "expected a finite number"),
+            (
+                Error::InvalidDate("the day is outside of the year"),
+                "invalid date: the day is outside of the year",
+            ),
+            (
+                Error::InvalidOrbit("the orbital period isn't positive"),
+                "invalid orbit: the orbital period isn't positive",
+            ),
+            (
+                Error::InvalidEccentricity(-0.5),
+                "invalid eccentricity: -0.5 isn't 0 or more",
+            ),
+            (
+                Error::NoConvergence(100),
+                "the anomaly didn't converge after 100 iterations",
+            ),
+            (
+                Error::InvalidPerihelion("the ls window is empty or reversed"),
+                "invalid perihelion: the ls window is empty or reversed",
+            ),
+            (
+                Error::InvalidSeasonTable("the seasons overlap"),
+                "invalid season table: the seasons overlap",
+            ),
+            (Error::UnknownTimeZone, "unknown timezone"),
+            (Error::NoCrossing, "no crossing within the bracket"),
+            (
+                Error::InvalidTimeZone("the offset or the longitudes aren't finite"),
+                "invalid timezone: the offset or the longitudes aren't finite",
+            ),
+            (
+                Error::InvalidTime("the hour is outside of the sol"),
+                "invalid time: the hour is outside of the sol",
+            ),
+            (
+                Error::InvalidFormat("the body prefix is missing"),
+                "invalid format: the body prefix is missing",
+            ),
+        ];
+
+        for (error, message) in messages {
+            assert_eq!(message, error.to_string());
+        }
+    }
+
+    #[test]
+    pub fn the_solver_gives_up() {
+        let eccentric = Anomaly.try_eccentric(
+            Type::Hyperbolic,
+            100.0,
+            1.1,
+            Mars.perihelion(),
+            Mars.orbital_period(),
+            1.0,
+        );
+
+        assert_eq!(Err(Error::NoConvergence(MAX_ITERATIONS)), eccentric);
+        assert!(Anomaly
+            .eccentric(
+                Type::Hyperbolic,
+                100.0,
+                1.1,
+                Mars.perihelion(),
+                Mars.orbital_period(),
+                1.0
+            )
+            .is_nan());
+    }
+
+    #[test]
+    pub fn shapes_without_an_anomaly_are_errors() {
+        let ls = SolarLongitude.compute(
+            Type::Unknown,
+            100.0,
+            Mars.orbital_eccentricity(),
+            Mars.perihelion(),
+            Mars.orbital_period(),
+            Mars.semimajor(),
+        );
+
+        assert_eq!(
+            Err(Error::InvalidOrbit(
+                "the orbit is straight or of an unknown shape, so it has no anomaly"
+            )),
+            ls
+        );
+    }
+
+    #[test]
+    pub fn errors_surface_from_the_api() {
+        assert_eq!(
+            "expected a finite number",
+            Mars.try_to_date(f64::NAN).unwrap_err().to_string()
+        );
+        assert_eq!(
+            "unknown timezone",
+            "HLT".parse::<Martian>().unwrap_err().to_string()
+        );
+        assert_eq!(
+            "invalid format: the body prefix is missing",
+            DateTime::from_canonical_str("0024-10-522T02:23:02-12:30[AMT]")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
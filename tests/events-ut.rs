@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use rust_solar::{
+        events::{
+            find_crossing, find_crossings, find_extremum, scan, try_find_crossing, try_scan,
+            Crossing, Extremum,
+        },
+        Error,
+    };
+
+    #[test]
+    pub fn many_roots() {
+        let roots =
+            find_crossings(|x| x.sin(), (1.0, 20.0), 0.25, Crossing::Either, 1e-12).unwrap();
+
+        assert_eq!(6, roots.len());
+
+        for (i, root) in roots.iter().enumerate() {
+            assert!((root - PI * (i + 1) as f64).abs() < 1e-12, "{roots:?}");
+        }
+    }
+
+    #[test]
+    pub fn directions() {
+        let rising: Vec<_> = scan(|x| x.sin(), (1.0, 20.0), 0.25, Crossing::Rising)
+            .unwrap()
+            .collect();
+        let falling: Vec<_> = scan(|x| x.sin(), (1.0, 20.0), 0.25, Crossing::Falling)
+            .unwrap()
+            .collect();
+
+        // sin goes up through 2π, 4π and 6π, and down through π, 3π and 5π
+        assert_eq!(3, rising.len());
+        assert_eq!(3, falling.len());
+        assert!(rising[0].0 < 2.0 * PI && 2.0 * PI <= rising[0].1);
+        assert!(falling[0].0 < PI && PI <= falling[0].1);
+    }
+
+    #[test]
+    pub fn samples_on_a_root() {
+        // the root at 2 is a sample, it's found once and on its own
+        let brackets: Vec<_> = scan(|x| x - 2.0, (0.0, 4.0), 1.0, Crossing::Either)
+            .unwrap()
+            .collect();
+
+        assert_eq!(vec![(1.0, 2.0)], brackets);
+        assert_eq!(Ok(2.0), find_crossing(|x| x - 2.0, (1.0, 2.0), 1e-9));
+    }
+
+    #[test]
+    pub fn no_root() {
+        assert_eq!(
+            Err(Error::NoCrossing),
+            find_crossing(|x| x * x + 1.0, (-1.0, 1.0), 1e-9)
+        );
+        // both roots are inside, so the ends are on the same side
+        assert_eq!(
+            Err(Error::NoCrossing),
+            find_crossing(|x| x * x - 1.0, (-2.0, 2.0), 1e-9)
+        );
+        assert_eq!(
+            0,
+            scan(|x| x * x + 1.0, (-10.0, 10.0), 1.0, Crossing::Either)
+                .unwrap()
+                .count()
+        );
+    }
+
+    #[test]
+    pub fn invalid_input() {
+        assert_eq!(
+            Err(Error::NonFinite),
+            find_crossing(|x| x, (f64::NAN, 1.0), 1e-9)
+        );
+        assert_eq!(
+            Err(Error::InvalidTime("the tolerance isn't positive")),
+            find_crossing(|x| x, (-1.0, 1.0), 0.0)
+        );
+        assert_eq!(
+            Err(Error::NonFinite),
+            find_crossing(|x| 1.0 / x - 1.0, (0.0, 2.0), 1e-9)
+        );
+        assert!(matches!(
+            scan(|x| x, (0.0, 1.0), 0.0, Crossing::Either),
+            Err(Error::InvalidTime("the step isn't positive"))
+        ));
+        assert!(matches!(
+            scan(|x| x, (1.0, 0.0), 0.1, Crossing::Either),
+            Err(Error::InvalidTime("the range is reversed"))
+        ));
+        // below the precision of a julian date, the samples would never move
+        assert!(matches!(
+            scan(
+                |x| x - 2451545.5,
+                (2451545.0, 2451546.0),
+                1e-11,
+                Crossing::Either
+            ),
+            Err(Error::InvalidTime("the step is too small for the range"))
+        ));
+        // about the precision of a julian date, the samples still get to the end
+        assert_eq!(
+            1,
+            scan(
+                |x| x - (2451545.0 + 5e-8),
+                (2451545.0, 2451545.0 + 1e-7),
+                5e-10,
+                Crossing::Either
+            )
+            .unwrap()
+            .count()
+        );
+    }
+
+    #[test]
+    pub fn errors_stop_the_search() {
+        let failing = |x: f64| match x < 3.0 {
+            true => Ok(x.sin()),
+            false => Err(Error::InvalidDate("the day is outside of the year")),
+        };
+
+        let brackets: Vec<_> = try_scan(failing, (0.5, 5.0), 1.0, Crossing::Either)
+            .unwrap()
+            .collect();
+
+        assert_eq!(
+            vec![Err(Error::InvalidDate("the day is outside of the year"))],
+            brackets
+        );
+        assert_eq!(
+            Err(Error::InvalidDate("the day is outside of the year")),
+            try_find_crossing(failing, (2.0, 4.0), 1e-9)
+        );
+    }
+
+    #[test]
+    pub fn extrema() {
+        let minimum = find_extremum(|x| x.cos(), (2.0, 4.0), 1e-8, Extremum::Minimum).unwrap();
+        let maximum = find_extremum(|x| x.cos(), (5.0, 7.0), 1e-8, Extremum::Maximum).unwrap();
+
+        assert!((minimum - PI).abs() < 1e-6, "{minimum}");
+        assert!((maximum - 2.0 * PI).abs() < 1e-6, "{maximum}");
+
+        // the lowest value of a slope is at the end of the bracket
+        let end = find_extremum(|x| x, (0.0, 1.0), 1e-8, Extremum::Minimum).unwrap();
+
+        assert!(end < 1e-7, "{end}");
+    }
+
+    #[cfg(feature = "mars")]
+    #[test]
+    pub fn user_defined_events() {
+        use rust_solar::{kepler::Body, planets::mars::Mars};
+
+        let distance = |jd: f64| Mars.heliocentric_position(jd).unwrap().distance().0;
+        // the first time mars comes within 1.5 AU of the sun after J2000
+        let inward = find_crossings(
+            |jd| distance(jd) - 1.5,
+            (2_451_545.0, 2_451_545.0 + 687.0),
+            10.0,
+            Crossing::Falling,
+            1e-8,
+        )
+        .unwrap()[0];
+        let perihelion =
+            find_extremum(distance, (inward, inward + 200.0), 1e-6, Extremum::Minimum).unwrap();
+
+        assert!((distance(inward) - 1.5).abs() < 1e-9);
+        assert!(distance(perihelion) < distance(inward));
+        assert!((Mars.to_date(perihelion).ls - Mars.perihelion().perihelion).abs() < 0.01);
+
+        #[cfg(feature = "earth")]
+        {
+            use rust_solar::{
+                planets::earth::Earth,
+                transfer::{hohmann, next_departure_window, phase_angle},
+            };
+
+            let window = next_departure_window(&Earth, &Mars, 2_451_545.0).unwrap();
+            let angle = phase_angle(&Earth, &Mars, window).unwrap();
+
+            assert!((angle - hohmann(&Earth, &Mars).phase_angle).abs() < 1e-6);
+        }
+    }
+}
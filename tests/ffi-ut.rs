@@ -0,0 +1,129 @@
+#![cfg(feature = "ffi")]
+
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CStr, os::raw::c_char, ptr};
+
+    use rust_solar::{
+        bodies::registry,
+        ffi::{
+            rs_solar_list_bodies, rs_solar_mars_to_date, rs_solar_time_at, CBody, CDate, CTime,
+            RS_SOLAR_ERA_AD, RS_SOLAR_NON_FINITE, RS_SOLAR_NULL_POINTER, RS_SOLAR_OK,
+            RS_SOLAR_UNKNOWN_TIME_ZONE,
+        },
+        fixtures,
+        kepler::{Body, TimeZone},
+        moons::luna::Lunar,
+        planets::mars::{Mars, Martian},
+    };
+
+    fn text(buffer: &[c_char]) -> &str {
+        // SAFETY: the buffers written by the library are always nul terminated.
+        unsafe { CStr::from_ptr(buffer.as_ptr()) }.to_str().unwrap()
+    }
+
+    #[test]
+    pub fn mars_to_date_round_trips() {
+        for jd in fixtures::MARS.map(|fixture| fixture.julian_date) {
+            let mut out = CDate::default();
+
+            assert_eq!(RS_SOLAR_OK, unsafe { rs_solar_mars_to_date(jd, &mut out) });
+
+            let date = Mars.to_date(jd);
+
+            assert_eq!(RS_SOLAR_ERA_AD, out.era);
+            assert_eq!(date.year, out.year);
+            assert_eq!(date.month, out.month);
+            assert_eq!(date.day, out.day);
+            assert_eq!(date.ls, out.ls);
+            assert_eq!(date.sol, out.sol);
+            assert_eq!(date.season_str(), text(&out.season));
+        }
+    }
+
+    #[test]
+    pub fn mars_to_date_reports_errors() {
+        let mut out = CDate::default();
+
+        assert_eq!(RS_SOLAR_NON_FINITE, unsafe {
+            rs_solar_mars_to_date(f64::NAN, &mut out)
+        });
+        assert_eq!(CDate::default(), out);
+        assert_eq!(RS_SOLAR_NULL_POINTER, unsafe {
+            rs_solar_mars_to_date(2451545.0, ptr::null_mut())
+        });
+    }
+
+    #[test]
+    pub fn time_at_round_trips() {
+        let mut out = CTime::default();
+
+        assert_eq!(RS_SOLAR_OK, unsafe {
+            rs_solar_time_at(2451545.0, c"AMT".as_ptr(), &mut out)
+        });
+
+        let time = Martian::MTCn5.at(2451545.0);
+
+        assert_eq!(time.hour, out.hour);
+        assert_eq!(time.minute, out.minute);
+        assert_eq!(time.second, out.second);
+        assert_eq!(time.millisecond, out.millisecond);
+        assert_eq!(time.hours_per_day, out.hours_per_day);
+        assert_eq!(time.code(), text(&out.code));
+        assert_eq!(time.name(), text(&out.name));
+        assert_eq!(time.offset_name, text(&out.offset_name));
+        assert_eq!(time.hour_type.as_ref(), text(&out.hour_type));
+    }
+
+    #[test]
+    pub fn time_at_finds_lunar_zones() {
+        let mut out = CTime::default();
+
+        assert_eq!(RS_SOLAR_OK, unsafe {
+            rs_solar_time_at(2451545.0, c"CRT".as_ptr(), &mut out)
+        });
+        assert_eq!(Lunar::LTCp1.at(2451545.0).hour, out.hour);
+    }
+
+    #[test]
+    pub fn time_at_reports_errors() {
+        let mut out = CTime::default();
+
+        assert_eq!(RS_SOLAR_UNKNOWN_TIME_ZONE, unsafe {
+            rs_solar_time_at(2451545.0, c"Nowhere".as_ptr(), &mut out)
+        });
+        assert_eq!(RS_SOLAR_NON_FINITE, unsafe {
+            rs_solar_time_at(f64::INFINITY, c"AMT".as_ptr(), &mut out)
+        });
+        assert_eq!(RS_SOLAR_NULL_POINTER, unsafe {
+            rs_solar_time_at(2451545.0, ptr::null(), &mut out)
+        });
+        assert_eq!(CTime::default(), out);
+    }
+
+    #[test]
+    pub fn list_bodies_sizes_and_fills() {
+        let count = unsafe { rs_solar_list_bodies(ptr::null_mut(), 0) };
+
+        assert_eq!(registry().len() as i32, count);
+
+        let mut out = vec![
+            CBody {
+                name: [0; 32],
+                orbital_period: 0.0,
+                rotational_period: 0.0,
+            };
+            count as usize
+        ];
+
+        assert_eq!(count, unsafe {
+            rs_solar_list_bodies(out.as_mut_ptr(), out.len())
+        });
+
+        for (body, c_body) in registry().iter().zip(&out) {
+            assert_eq!(body.name(), text(&c_body.name));
+            assert_eq!(body.orbital_period(), c_body.orbital_period);
+            assert_eq!(body.rotational_period(), c_body.rotational_period);
+        }
+    }
+}
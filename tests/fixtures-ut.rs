@@ -0,0 +1,90 @@
+#![cfg(all(feature = "earth", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        bodies,
+        fixtures::{self, Fixture, EARTH_KEPLER_DRIFT, MARS_KEPLER_DRIFT},
+        julian::{greg2jd, CalendarSystem},
+        planets::mars::{coordinated_mars_time, mars24, mars_sol_date},
+    };
+
+    /// The difference of two angles, from -180 to 180
+    fn apart(a: f64, b: f64) -> f64 {
+        (a - b + 540.0) % 360.0 - 180.0
+    }
+
+    /// This checks what the library computes of a fixture
+    fn computes(fixture: &Fixture, drift: f64) {
+        let (year, month, day, hour, minute, second) = fixture.utc;
+        let body = bodies::find(fixture.body).unwrap();
+        let date = body.to_date(fixture.julian_date);
+
+        assert_eq!(
+            Ok(fixture.julian_date),
+            greg2jd(
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+                CalendarSystem::Gregorian
+            ),
+            "{}",
+            fixture.label
+        );
+        assert!(
+            apart(date.ls, fixture.ls).abs() <= drift,
+            "{}: {} isn't {}",
+            fixture.label,
+            date.ls,
+            fixture.ls
+        );
+    }
+
+    #[test]
+    pub fn there_are_enough_fixtures() {
+        let fixtures = || fixtures::MARS.iter().chain(fixtures::EARTH.iter());
+        let years = fixtures().map(|fixture| fixture.utc.0);
+
+        assert!(fixtures().count() >= 10);
+        assert_eq!(Some(1960), years.clone().min());
+        assert_eq!(Some(2040), years.max());
+    }
+
+    #[test]
+    pub fn mars_fixtures_are_truthful() {
+        for fixture in &fixtures::MARS {
+            let ls = mars24::ls(fixture.julian_date).unwrap();
+            let msd = fixture.msd.unwrap();
+
+            computes(fixture, MARS_KEPLER_DRIFT);
+            assert!(
+                apart(ls, fixture.ls).abs() <= fixture.ls_tolerance,
+                "{}: {ls} isn't {}",
+                fixture.label,
+                fixture.ls
+            );
+            assert!(
+                (mars_sol_date(fixture.julian_date) - msd).abs() < 1e-5,
+                "{}",
+                fixture.label
+            );
+            assert!(
+                (coordinated_mars_time(fixture.julian_date) - fixture.mtc.unwrap()).abs() < 1e-5,
+                "{}",
+                fixture.label
+            );
+        }
+    }
+
+    #[test]
+    pub fn earth_fixtures_are_truthful() {
+        for fixture in &fixtures::EARTH {
+            computes(fixture, EARTH_KEPLER_DRIFT);
+            assert_eq!(0.0, fixture.ls % 90.0, "{}", fixture.label);
+            assert_eq!((None, None), (fixture.msd, fixture.mtc));
+        }
+    }
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        anomaly::{tolerance, Anomaly},
+        kepler::Body,
+        orbit::{MeanMotion, Perihelion, SemiAxis, SolarLongitude, Type},
+        planets::mars::Mars,
+    };
+
+    fn perihelion() -> (Perihelion<f32>, Perihelion<f64>) {
+        let peri = Mars.perihelion();
+        let single = Perihelion::new(
+            (peri.month.0 as f32, peri.month.1 as f32),
+            (peri.ls.0 as f32, peri.ls.1 as f32),
+            peri.perihelion as f32,
+        )
+        .unwrap();
+
+        (single, peri)
+    }
+
+    #[test]
+    pub fn elliptical_kepler_solver_in_both_precisions() {
+        let (single, double) = perihelion();
+
+        for e in [0.01, 0.0934, 0.2, 0.35, 0.5] {
+            for day in (0..668).step_by(7) {
+                let eccentric_f32 = Anomaly.eccentric(
+                    Type::Elliptical,
+                    day as f32,
+                    e as f32,
+                    single,
+                    668.6_f32,
+                    1.5237_f32,
+                );
+                let eccentric_f64 =
+                    Anomaly.eccentric(Type::Elliptical, f64::from(day), e, double, 668.6, 1.5237);
+
+                assert!(
+                    (f64::from(eccentric_f32) - eccentric_f64).abs() < 1e-4,
+                    "e = {e}, day = {day}: {eccentric_f32} != {eccentric_f64}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    pub fn solar_longitude_in_both_precisions() {
+        let (single, double) = perihelion();
+
+        for day in (0..668).step_by(11) {
+            let ls_f32 = SolarLongitude
+                .compute(
+                    Type::Elliptical,
+                    day as f32,
+                    Mars.orbital_eccentricity() as f32,
+                    single,
+                    Mars.orbital_period() as f32,
+                    Mars.semimajor() as f32,
+                )
+                .unwrap();
+            let ls_f64 = SolarLongitude
+                .compute(
+                    Type::Elliptical,
+                    f64::from(day),
+                    Mars.orbital_eccentricity(),
+                    double,
+                    Mars.orbital_period(),
+                    Mars.semimajor(),
+                )
+                .unwrap();
+
+            // an f32 day of the year only holds about 5 digits
+            assert!(
+                (f64::from(ls_f32) - ls_f64).abs() < 1e-2,
+                "{ls_f32} != {ls_f64}"
+            );
+        }
+    }
+
+    #[test]
+    pub fn solar_longitude_wraps_below_360() {
+        let (single, _) = perihelion();
+        let ls = |day: f32| {
+            SolarLongitude
+                .compute(
+                    Type::Elliptical,
+                    day,
+                    Mars.orbital_eccentricity() as f32,
+                    single,
+                    Mars.orbital_period() as f32,
+                    Mars.semimajor() as f32,
+                )
+                .unwrap()
+        };
+        let (mut low, mut high) = (-1.0_f32, 1.0_f32);
+
+        // the day of the wrap, where the ls goes from near 360 to near 0
+        while high - low > 1e-6 {
+            let middle = (low + high) / 2.0;
+
+            match ls(middle) > 180.0 {
+                true => low = middle,
+                false => high = middle,
+            }
+        }
+
+        let mut day = low - 1e-5;
+
+        // an f32 angle a hair below a full turn converts to 360
+        while day < high + 1e-5 {
+            let degrees = ls(day);
+
+            assert!((0.0..360.0).contains(&degrees), "{day}: {degrees}");
+            day = day.next_up();
+        }
+    }
+
+    #[test]
+    pub fn f64_is_the_default() {
+        let (_, double) = perihelion();
+        let semi: SemiAxis = SemiAxis(Mars.semimajor());
+
+        assert_eq!(1e-7, tolerance::<f64>());
+        assert!(tolerance::<f32>() > f32::EPSILON);
+        assert_eq!(Mars.semiminor(), semi.minor(Mars.orbital_eccentricity()));
+        assert!(
+            (MeanMotion.by(100.0, double, 668.6) as f32
+                - MeanMotion.by(100.0_f32, perihelion().0, 668.6_f32))
+            .abs()
+                < 1e-5
+        );
+        assert!(matches!(Type::default().shape(0.5_f32), Type::Elliptical));
+        assert!(matches!(Type::default().shape(1.0_f64), Type::Parabolic));
+    }
+}
@@ -0,0 +1,112 @@
+#![cfg(all(feature = "horizons", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        conversions::{Au, Days, KM_PER_AU},
+        horizons::{compare, parse_elements, parse_vectors},
+        julian::{JulianDate, TimeScale},
+        planets::mars::Mars,
+        Error,
+    };
+
+    /// Synthetic tables in the layout of Horizons, not exports of it, see the header of each file
+    const ELEMENTS: &str = include_str!("data/horizons-mars-elements.txt");
+    const VECTORS: &str = include_str!("data/horizons-mars-vectors.txt");
+
+    #[test]
+    pub fn elements() {
+        let records = parse_elements(ELEMENTS).unwrap();
+        let (first_jd, first) = records[0];
+        let (last_jd, last) = records[records.len() - 1];
+
+        assert_eq!(5, records.len());
+        assert_eq!(JulianDate::new(2_451_545.0, TimeScale::TDB), first_jd);
+        assert_eq!(0.09339410, first.eccentricity);
+        assert_eq!(Au(1.381404784135006), first.periapsis);
+        assert_eq!(1.84969142, first.inclination);
+        assert_eq!(49.55953891, first.ascending_node);
+        assert_eq!(286.4968315, first.argument_of_periapsis);
+        assert_eq!(2_451_507.998_137_971, first.periapsis_jd);
+        assert_eq!(5.240330209559919E-01, first.mean_motion);
+        assert_eq!(19.39019754, first.mean_anomaly);
+        assert_eq!(23.37723882345258, first.true_anomaly);
+        assert_eq!(Au(1.52371034), first.semimajor);
+        assert_eq!(Au(1.666015895864994), first.apoapsis);
+        assert_eq!(Days(686.9796093064003), first.period);
+
+        assert_eq!(JulianDate::new(2_451_585.0, TimeScale::TDB), last_jd);
+        assert_eq!(9.339418631895961E-02, last.eccentricity);
+        assert_eq!(40.35102795654758, last.mean_anomaly);
+        assert_eq!(47.93168843807869, last.true_anomaly);
+        assert_eq!(Days(686.9796229858729), last.period);
+    }
+
+    #[test]
+    pub fn vectors() {
+        let records = parse_vectors(VECTORS).unwrap();
+        let (first_jd, first) = records[0];
+        let (last_jd, last) = records[records.len() - 1];
+
+        assert_eq!(5, records.len());
+        assert_eq!(2_451_545.0, first_jd.value);
+        // the table is in km and km/s
+        assert_eq!(2.080409339037969E+08 / KM_PER_AU, first.position.x);
+        assert_eq!(-2.003274684493425E+06 / KM_PER_AU, first.position.y);
+        assert_eq!(-5.155331001447282E+06 / KM_PER_AU, first.position.z);
+        assert_eq!(
+            [1.164559580077003, 26.29745310723079, 0.5222704744098834],
+            first.velocity
+        );
+
+        assert_eq!(2_451_585.0, last_jd.value);
+        assert_eq!(1.942453741393927E+08 / KM_PER_AU, last.position.x);
+        assert_eq!(-8.925558431687584, last.velocity[0]);
+    }
+
+    #[test]
+    pub fn header_noise() {
+        let text = "GARBAGE = 12\nOutput units    : AU-D\n$$SOE\n\
+            2451545.0 = A.D. 2000-Jan-01 12:00:00.0000 UT\n X = 1.5 Y =-0.5 Z = 0.0\n\
+            VX= 0.01 VY= 0.0 VZ= 0.0\n$$EOE\nnoise";
+        let records = parse_vectors(text).unwrap();
+
+        assert_eq!(TimeScale::UTC, records[0].0.scale);
+        assert_eq!(-0.5, records[0].1.position.y);
+        // an astronomical unit a day in km/s
+        assert!((records[0].1.velocity[0] - 0.01 * KM_PER_AU / 86_400.0).abs() < 1e-12);
+
+        assert_eq!(
+            Err(Error::InvalidFormat(
+                "the ephemeris has no $$SOE and $$EOE block"
+            )),
+            parse_vectors("X = 1.0")
+        );
+        assert_eq!(
+            Err(Error::InvalidFormat(
+                "a record of the ephemeris is missing a field"
+            )),
+            parse_vectors("$$SOE\n2451545.0 = A.D.\n X = 1.0 Y = 2.0\n$$EOE")
+        );
+        assert_eq!(
+            Err(Error::InvalidFormat(
+                "a field of the ephemeris isn't a number"
+            )),
+            parse_elements("$$SOE\n2451545.0 = A.D.\n EC= n/a\n$$EOE")
+        );
+    }
+
+    #[test]
+    pub fn deviation() {
+        let deviation = compare(&Mars, &parse_vectors(VECTORS).unwrap()).unwrap();
+
+        assert_eq!(5, deviation.records);
+        assert!(deviation.rms <= deviation.max);
+        // the catalog of mars is rounded, so it's within a hundredth of an AU
+        assert!(deviation.max.0 < 0.03, "{deviation:?}");
+        assert_eq!(
+            Err(Error::InvalidFormat("there are no records to compare")),
+            compare(&Mars, &[])
+        );
+    }
+}
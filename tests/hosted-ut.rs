@@ -0,0 +1,189 @@
+#![cfg(all(feature = "luna", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        conversions::normalize_degrees,
+        kepler::{validate, Body, HostedBody, SUN_GRAVITATIONAL_PARAMETER},
+        moons::luna::{phases::elongation, Luna},
+        planets::{
+            earth::Earth,
+            mars::{Deimos, Mars, Phobos, PHOBOS_SEMIMAJOR_KM},
+        },
+    };
+
+    #[test]
+    pub fn phobos_orbits_with_the_gm_of_mars() {
+        let speed = Phobos.mean_orbital_speed();
+
+        assert!((speed - 2.14).abs() < 0.01, "{speed}");
+
+        // the gravitational parameter of the sun would throw phobos around mars at kilometers a millisecond
+        let sun = (SUN_GRAVITATIONAL_PARAMETER / PHOBOS_SEMIMAJOR_KM).sqrt();
+
+        assert!(sun > 3_000.0, "{sun}");
+        assert!((Phobos.host_gravitational_parameter() - 42_828.0).abs() < 5.0);
+        // 7 hours and 39 minutes, and deimos in 30 hours and 18 minutes
+        assert!((Phobos.sidereal_period().0 - 0.3189).abs() < 0.001);
+        assert!((Deimos.sidereal_period().0 - 1.2624).abs() < 0.001);
+    }
+
+    #[test]
+    pub fn hosted_bodies_keep_the_orbit_of_their_host() {
+        assert_eq!(Phobos.host().name(), "Mars");
+        assert_eq!(
+            Phobos.gravitational_parameter(),
+            SUN_GRAVITATIONAL_PARAMETER
+        );
+        assert!(validate(&Phobos).is_empty());
+        assert!(validate(&Deimos).is_empty());
+        assert!(validate(&Luna).is_empty());
+        // the year of a moon of mars is the year of mars
+        assert!(
+            (Phobos.orbital_period_earth_days() - Mars.orbital_period_earth_days()).abs() < 0.1
+        );
+        assert_eq!(Mars.to_date(2451545.0).year, Phobos.to_date(2451545.0).year);
+    }
+
+    #[test]
+    pub fn luna_orbits_earth() {
+        assert_eq!(Luna.host().name(), "Earth");
+        // the sidereal month, with the mass of luna
+        assert!((Luna.sidereal_period().0 - 27.32).abs() < 0.05);
+
+        let perigee = Luna.host_semimajor_km() * (1.0 - Luna.host_orbital_eccentricity());
+        let apogee = Luna.host_semimajor_km() * (1.0 + Luna.host_orbital_eccentricity());
+
+        assert!(Luna.orbital_speed(perigee) > Luna.mean_orbital_speed());
+        assert!(Luna.orbital_speed(apogee) < Luna.mean_orbital_speed());
+    }
+
+    #[test]
+    pub fn luna_at_perigee() {
+        // the perigee of 2011 March 19, 19:09 UTC, 356 577 km from earth
+        let perigee = 2455640.298;
+        let anomaly = Luna.host_mean_anomaly(perigee).unwrap();
+        let distance = Luna.host_distance_km(perigee).unwrap();
+
+        assert!(!(10.0..350.0).contains(&anomaly), "{anomaly}");
+        assert!((distance - 363_300.0).abs() < 500.0, "{distance}");
+        // half an anomalistic month later is the apogee
+        let apogee = Luna.host_distance_km(perigee + 13.777).unwrap();
+
+        assert!((apogee - 405_500.0).abs() < 500.0, "{apogee}");
+        assert!(Luna.host_distance_km(f64::NAN).is_err());
+    }
+
+    #[test]
+    pub fn phobos_anomalies() {
+        let periapsis = Phobos.host_periapsis_jd();
+
+        assert_eq!(Phobos.host_mean_anomaly(periapsis).unwrap(), 0.0);
+        assert_eq!(Phobos.host_true_anomaly(periapsis).unwrap(), 0.0);
+        assert!((Phobos.host_mean_motion() - 360.0 / 0.3189).abs() < 1.0);
+
+        // a quarter of the orbit on, the true anomaly is ahead of the mean one
+        let quarter = periapsis + Phobos.sidereal_period().0 / 4.0;
+        let mean = Phobos.host_mean_anomaly(quarter).unwrap();
+        let truly = Phobos.host_true_anomaly(quarter).unwrap();
+
+        assert!((mean - 90.0).abs() < 1e-6, "{mean}");
+        assert!(truly > mean, "{truly}");
+        assert!((Phobos.host_distance_km(quarter).unwrap() - PHOBOS_SEMIMAJOR_KM).abs() < 5.0);
+    }
+
+    #[test]
+    pub fn host_longitude_is_from_the_sun_line() {
+        // the new moon of 2024 January 11, 11:57 UTC
+        let new = Luna.host_longitude(2460321.0).unwrap();
+
+        assert!(!(1.0..359.0).contains(&new), "{new}");
+        assert_eq!(
+            Luna.host_longitude(2460335.246).unwrap(),
+            elongation(2460335.246).unwrap()
+        );
+        assert!(Luna.host_longitude(f64::NAN).is_err());
+
+        // half a sol of a moon of mars goes half way around from the sun line,
+        // give or take the eccentricity of phobos (1.7 degrees)
+        for moon in [&Phobos as &dyn HostedBody, &Deimos] {
+            let sol = moon.rotational_period() / 86_400.0;
+            let start = moon.host_longitude(2451545.0).unwrap();
+            let half = moon.host_longitude(2451545.0 + sol / 2.0).unwrap();
+            let whole = moon.host_longitude(2451545.0 + sol).unwrap();
+
+            assert!(
+                (normalize_degrees(half - start) - 180.0).abs() < 2.0,
+                "{}",
+                moon.name()
+            );
+            assert!(
+                (normalize_degrees(whole - start + 180.0) - 180.0).abs() < 2.0,
+                "{}",
+                moon.name()
+            );
+        }
+    }
+
+    #[test]
+    pub fn sun_looks_smaller_from_mars() {
+        let earth = Earth.solar_angular_diameter(2451545.0);
+
+        // earth is at its perihelion in january, the sun is 0.52 to 0.55 degrees wide over the year
+        assert!((0.53..0.55).contains(&earth), "{earth}");
+
+        // a year of mars, every 10 days
+        let mars: Vec<f64> = (0..69)
+            .map(|step| Mars.solar_angular_diameter(2451545.0 + step as f64 * 10.0))
+            .collect();
+        let smallest = mars.iter().copied().fold(f64::MAX, f64::min);
+        let largest = mars.iter().copied().fold(0.0, f64::max);
+
+        assert!((0.31..0.33).contains(&smallest), "{smallest}");
+        assert!((0.38..0.40).contains(&largest), "{largest}");
+        // a moon sees the sun from its host
+        assert_eq!(
+            Luna.solar_angular_diameter(2451545.0),
+            Earth.solar_angular_diameter(2451545.0)
+        );
+    }
+
+    #[test]
+    pub fn host_angular_diameter() {
+        let mars = Phobos.host_angular_diameter().unwrap();
+        let earth = Luna.host_angular_diameter().unwrap();
+
+        assert!((40.0..45.0).contains(&mars), "{mars}");
+        assert!((earth - 1.9).abs() < 0.05, "{earth}");
+    }
+
+    #[test]
+    pub fn sunlight_and_temperature() {
+        let earth = Earth.solar_irradiance(2451545.0);
+
+        // the solar constant, 3% higher at the perihelion of january
+        assert!((earth / 1361.0 - 1.034).abs() < 0.005, "{earth}");
+
+        // the solar constant is the mean over a year
+        let mean = (0..365)
+            .map(|day| Earth.solar_irradiance(2451545.0 + day as f64))
+            .sum::<f64>()
+            / 365.0;
+
+        assert!((mean - 1361.0).abs() < 1.0, "{mean}");
+
+        let mars: Vec<f64> = (0..69)
+            .map(|step| Mars.solar_irradiance(2451545.0 + step as f64 * 10.0))
+            .collect();
+        let lowest = mars.iter().copied().fold(f64::MAX, f64::min);
+        let highest = mars.iter().copied().fold(0.0, f64::max);
+
+        assert!((490.0..500.0).contains(&lowest), "{lowest}");
+        assert!((705.0..720.0).contains(&highest), "{highest}");
+
+        let temperature = Mars.equilibrium_temperature(2451545.0, 0.25);
+
+        assert!((temperature - 210.0).abs() < 10.0, "{temperature}");
+        assert!(Mars.equilibrium_temperature(2451545.0, 0.0) > temperature);
+    }
+}
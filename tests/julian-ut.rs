@@ -0,0 +1,497 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rust_solar::{
+        define_body,
+        julian::{
+            greg2jd, jd2greg, jd_from_unix_millis, jd_from_unix_secs, tai_to_tt, tai_to_utc,
+            tdb_to_tt,
+            time_scales::{tt_minus_utc, TT_MINUS_TAI},
+            tt_to_tai, tt_to_tdb, tt_to_utc, unix_millis_from_jd, unix_secs_from_jd, utc_to_tai,
+            utc_to_tt, CalendarSystem, Epoch, GregorianDate, JulianDate, TimeScale,
+            GREGORIAN_REFORM_JD, UNIX_EPOCH_JD,
+        },
+        kepler::{Body, CustomBody, Time, TimeZone},
+        planets::mars::{Mars, Martian},
+        Error,
+    };
+
+    define_body! {
+        /// A body with elements from the epoch of its first observation
+        struct Observed {
+            name: "Observed",
+            epoch: 2_440_000.0,
+            orbital_eccentricity: 0.1,
+            orbital_period: 400.0,
+            rotational_period: 90_000.0,
+            semimajor: 1.1,
+            perihelion: ((100.0, 150.0), (240.0, 270.0), 250.0),
+        }
+        impl {
+            fn reference_epoch(&self) -> Epoch {
+                Epoch::Custom(2_440_000.25)
+            }
+        }
+    }
+
+    #[test]
+    pub fn tt_minus_utc_in_1972() {
+        // 1972 March 1
+        assert_eq!(tt_minus_utc(2_441_377.5), TT_MINUS_TAI + 10.0);
+    }
+
+    #[test]
+    pub fn tt_minus_utc_today() {
+        // 2024 January 1
+        assert_eq!(tt_minus_utc(2_460_310.5), TT_MINUS_TAI + 37.0);
+    }
+
+    #[test]
+    pub fn tt_minus_utc_before_1972() {
+        // 1960 January 1
+        assert_eq!(tt_minus_utc(2_436_934.5), TT_MINUS_TAI + 10.0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    pub fn tt_minus_utc_with_added_leap_second() {
+        use rust_solar::julian::time_scales::add_leap_second;
+
+        // 2200 January 1, far enough to not disturb the other tests
+        add_leap_second(2_524_593.5, 38.0).unwrap();
+
+        assert_eq!(tt_minus_utc(2_524_593.4), TT_MINUS_TAI + 37.0);
+        assert_eq!(tt_minus_utc(2_524_600.0), TT_MINUS_TAI + 38.0);
+        assert!(add_leap_second(f64::NAN, 39.0).is_err());
+    }
+
+    #[test]
+    pub fn julian_date_tt_minus_utc() {
+        let utc = JulianDate::utc(2_460_310.5);
+        let tt = utc.to_scale(TimeScale::TT);
+
+        assert_eq!(tt.scale, TimeScale::TT);
+        assert!(((tt.value - utc.value) * 86_400.0 - 69.184).abs() < 1e-4);
+    }
+
+    #[test]
+    pub fn julian_date_through_tai_is_lossless() {
+        for value in [2_441_317.5, 2_451_545.0, 2_457_754.25, 2_460_310.5] {
+            let utc = JulianDate::utc(value);
+            let back = utc.to_scale(TimeScale::TAI).to_scale(TimeScale::UTC);
+
+            assert_eq!(back.scale, TimeScale::UTC);
+            assert!((back.value - value).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    pub fn julian_date_through_tdb() {
+        let tt = JulianDate::new(2_451_545.0, TimeScale::TT);
+        let tdb = tt.to_scale(TimeScale::TDB);
+
+        assert!((tdb.value - tt.value).abs() * 86_400.0 < 0.002);
+        assert!((tdb.to_scale(TimeScale::TT).value - tt.value).abs() < 1e-9);
+    }
+
+    #[test]
+    pub fn julian_date_scales_are_converted_for_bodies() {
+        let utc = JulianDate::utc(2_460_310.5);
+        let tt = utc.to_scale(TimeScale::TT);
+
+        assert_eq!(Mars.to_date_jd(tt).ls, Mars.to_date(utc.value).ls);
+        assert_eq!(Martian::MTC.at_jd(tt).minute, Martian::MTC.at(utc.value).minute);
+    }
+
+    /// The milliseconds of the sol of a time
+    fn sol_millis(time: &Time) -> i64 {
+        ((i64::from(time.hour) * 60 + i64::from(time.minute)) * 60 + i64::from(time.second)) * 1000
+            + i64::from(time.millisecond)
+    }
+
+    #[test]
+    pub fn tt_and_utc_instants_agree_once_honored() {
+        let utc = JulianDate::utc(2_460_310.5);
+        let tt = utc.to_scale(TimeScale::TT);
+        let honored = (Martian::MTC.at_jd(tt), Martian::MTC.at_jd(utc));
+        // 69.184 seconds of TT - UTC, in the seconds of a mars sol
+        let late = 69_184.0 / (Mars.rotational_period() / 86_400.0);
+
+        assert_eq!(Mars.to_date_jd(utc), Mars.to_date_jd(tt));
+        assert_eq!(sol_millis(&honored.0), sol_millis(&honored.1));
+        assert!(
+            (sol_millis(&Martian::MTC.at(tt.value))
+                - sol_millis(&Martian::MTC.at(utc.value))
+                - late as i64)
+                .abs()
+                <= 1
+        );
+    }
+
+    #[test]
+    pub fn epochs_are_compared_in_utc() {
+        let epoch = JulianDate::utc(2_451_545.0);
+        let body = |epoch: JulianDate| {
+            CustomBody::builder()
+                .epoch(epoch.value)
+                .epoch_scale(epoch.scale)
+                .orbital_eccentricity(Mars.orbital_eccentricity())
+                .orbital_period(Mars.orbital_period())
+                .rotational_period(Mars.rotational_period())
+                .semimajor(Mars.semimajor())
+                .perihelion(Mars.perihelion())
+                .build()
+                .unwrap()
+        };
+        let (utc, tt) = (body(epoch), body(epoch.to_scale(TimeScale::TT)));
+
+        assert_eq!(TimeScale::UTC, Mars.epoch_scale());
+        assert_eq!(JulianDate::utc(Mars.epoch()), Mars.epoch_jd());
+        assert_eq!(TimeScale::TT, tt.epoch_scale());
+
+        for jd in [2_440_000.25, 2_451_545.0, 2_460_310.5] {
+            let (a, b) = (utc.to_date(jd), tt.to_date(jd));
+
+            assert_eq!((a.year, a.month, a.day), (b.year, b.month, b.day));
+            assert!((a.ls - b.ls).abs() < 1e-9);
+            assert!((utc.age_at(jd) - tt.age_at(jd)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    pub fn julian_date_from_unix_millis() {
+        // 2023-11-14 22:13:20 UTC
+        let jd = jd_from_unix_millis(1_700_000_000_000.0);
+
+        assert!((jd - 2460263.425926).abs() < 1e-6);
+        assert_eq!(jd_from_unix_millis(0.0), 2440587.5);
+    }
+
+    #[test]
+    pub fn julian_date_from_unix_secs() {
+        assert_eq!(jd_from_unix_secs(0.0), UNIX_EPOCH_JD);
+        assert_eq!(unix_secs_from_jd(UNIX_EPOCH_JD), 0.0);
+        // 2023-11-14 22:13:20 UTC
+        assert!((jd_from_unix_secs(1_700_000_000.0) - 2460263.425926).abs() < 1e-6);
+        // 1969-07-20 20:17:40 UTC, before the unix epoch
+        let jd = jd_from_unix_secs(-14_182_940.0);
+
+        assert!((jd - 2440423.345602).abs() < 1e-6);
+        assert_eq!(
+            jd2greg(jd, CalendarSystem::Gregorian).to_string(),
+            "1969-07-20 20:17:40"
+        );
+    }
+
+    #[test]
+    pub fn unix_timestamps_round_trip() {
+        for millis in [
+            0.0,
+            0.25,
+            -14_182_940_000.5,
+            1_700_000_000_123.4,
+            -210_866_760_000_000.0,
+        ] {
+            let jd = jd_from_unix_millis(millis);
+
+            assert!((unix_millis_from_jd(jd) - millis).abs() < 0.1, "{millis}");
+            assert!(
+                (unix_secs_from_jd(jd) - millis / 1000.0).abs() < 1e-4,
+                "{millis}"
+            );
+        }
+    }
+
+    #[test]
+    pub fn gregorian_to_julian_date() {
+        assert_eq!(
+            greg2jd(2000, 1, 1, 12, 0, 0.0, CalendarSystem::Gregorian),
+            Ok(2451545.0)
+        );
+        assert_eq!(
+            greg2jd(1970, 1, 1, 0, 0, 0.0, CalendarSystem::Gregorian),
+            Ok(2440587.5)
+        );
+        // the first day of the gregorian reform
+        assert_eq!(
+            greg2jd(1582, 10, 15, 0, 0, 0.0, CalendarSystem::Gregorian),
+            Ok(2299160.5)
+        );
+        // proleptic, so the days skipped by the reform exist
+        assert_eq!(
+            greg2jd(1582, 10, 10, 0, 0, 0.0, CalendarSystem::Gregorian),
+            Ok(2299155.5)
+        );
+        // 4713 BC November 24 is the start of the julian period
+        assert_eq!(
+            greg2jd(-4713, 11, 24, 12, 0, 0.0, CalendarSystem::Gregorian),
+            Ok(0.0)
+        );
+    }
+
+    #[test]
+    pub fn gregorian_rejects_invalid_dates() {
+        assert!(greg2jd(2000, 2, 29, 0, 0, 0.0, CalendarSystem::Gregorian).is_ok());
+        assert!(greg2jd(2024, 2, 29, 0, 0, 0.0, CalendarSystem::Gregorian).is_ok());
+
+        for (year, month, day) in [(2023, 2, 29), (1900, 2, 29), (2024, 4, 31), (2024, 13, 1)] {
+            assert!(matches!(
+                greg2jd(year, month, day, 0, 0, 0.0, CalendarSystem::Gregorian),
+                Err(Error::InvalidDate(_))
+            ));
+        }
+
+        assert!(matches!(
+            greg2jd(2024, 1, 1, 24, 0, 0.0, CalendarSystem::Gregorian),
+            Err(Error::InvalidTime(_))
+        ));
+        assert!(matches!(
+            greg2jd(2024, 1, 1, 0, 0, 60.0, CalendarSystem::Gregorian),
+            Err(Error::InvalidTime(_))
+        ));
+        assert_eq!(
+            greg2jd(2024, 1, 1, 0, 0, f64::NAN, CalendarSystem::Gregorian),
+            Err(Error::NonFinite)
+        );
+    }
+
+    #[test]
+    pub fn gregorian_matches_chrono() {
+        let dates = [
+            (1858, 11, 17, 0, 0, 0),
+            (1899, 12, 31, 23, 59, 59),
+            (1900, 1, 1, 0, 0, 0),
+            (1996, 2, 29, 6, 30, 15),
+            (2000, 2, 29, 18, 0, 0),
+            (2020, 12, 31, 12, 0, 1),
+            (2024, 2, 29, 23, 0, 0),
+        ];
+
+        for (year, month, day, hour, minute, second) in dates {
+            let jd = greg2jd(
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                f64::from(second),
+                CalendarSystem::Gregorian,
+            )
+            .unwrap();
+            let timestamp = NaiveDate::from_ymd_opt(year, month, day)
+                .and_then(|date| date.and_hms_opt(hour, minute, second))
+                .unwrap()
+                .and_utc()
+                .timestamp();
+
+            assert!(
+                (jd - (2440587.5 + timestamp as f64 / 86400.0)).abs() < 1e-8,
+                "{jd}"
+            );
+        }
+    }
+
+    #[test]
+    pub fn julian_date_to_gregorian() {
+        assert_eq!(
+            jd2greg(2440587.5, CalendarSystem::Gregorian),
+            GregorianDate {
+                year: 1970,
+                month: 1,
+                day: 1,
+                hour: 0,
+                minute: 0,
+                second: 0.0
+            }
+        );
+        assert_eq!(
+            jd2greg(2451545.0, CalendarSystem::Gregorian),
+            GregorianDate {
+                year: 2000,
+                month: 1,
+                day: 1,
+                hour: 12,
+                minute: 0,
+                second: 0.0
+            }
+        );
+        assert_eq!(
+            jd2greg(2451545.0, CalendarSystem::Gregorian).to_string(),
+            "2000-01-01 12:00:00"
+        );
+        assert_eq!(
+            format!("{:.3}", jd2greg(2451545.25, CalendarSystem::Gregorian)),
+            "2000-01-01 18:00:00.000"
+        );
+    }
+
+    #[test]
+    pub fn gregorian_never_shows_second_60() {
+        // 23:59:59.9999 rounds into the next day
+        let jd = greg2jd(1999, 12, 31, 23, 59, 59.9999, CalendarSystem::Gregorian).unwrap();
+
+        assert_eq!(
+            jd2greg(jd, CalendarSystem::Gregorian).to_string(),
+            "2000-01-01 00:00:00"
+        );
+    }
+
+    #[test]
+    pub fn gregorian_round_trip() {
+        let dates = [
+            (-4713, 11, 24, 12, 0, 0.0),
+            (1582, 10, 15, 0, 0, 0.0),
+            (1900, 2, 28, 23, 59, 59.5),
+            (2000, 2, 29, 6, 30, 15.25),
+            (2024, 12, 31, 18, 45, 1.0),
+        ];
+
+        for (year, month, day, hour, minute, second) in dates {
+            let jd = greg2jd(
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+                CalendarSystem::Gregorian,
+            )
+            .unwrap();
+            let date = jd2greg(jd, CalendarSystem::Gregorian);
+
+            assert_eq!(
+                (date.year, date.month, date.day, date.hour, date.minute),
+                (year, month as u8, day as u8, hour as u8, minute as u8)
+            );
+            assert!((date.second - second).abs() < 1e-3, "{date}");
+        }
+    }
+
+    #[test]
+    pub fn auto_calendar_skips_the_reform_gap() {
+        let last_julian = greg2jd(1582, 10, 4, 0, 0, 0.0, CalendarSystem::Auto).unwrap();
+        let first_gregorian = greg2jd(1582, 10, 15, 0, 0, 0.0, CalendarSystem::Auto).unwrap();
+
+        assert_eq!(first_gregorian - last_julian, 1.0);
+        assert_eq!(first_gregorian, GREGORIAN_REFORM_JD);
+        assert_eq!(
+            greg2jd(1582, 10, 10, 0, 0, 0.0, CalendarSystem::Auto),
+            Err(Error::InvalidDate(
+                "the date was skipped by the gregorian reform"
+            ))
+        );
+        assert_eq!(
+            jd2greg(last_julian, CalendarSystem::Auto).to_string(),
+            "1582-10-04 00:00:00"
+        );
+        assert_eq!(
+            jd2greg(first_gregorian, CalendarSystem::Auto).to_string(),
+            "1582-10-15 00:00:00"
+        );
+        // after the reform, auto is gregorian
+        assert_eq!(
+            jd2greg(2451545.0, CalendarSystem::Auto),
+            jd2greg(2451545.0, CalendarSystem::Gregorian)
+        );
+    }
+
+    #[test]
+    pub fn proleptic_julian_calendar() {
+        // the julian period starts at noon of 4713 BC January 1
+        assert_eq!(
+            greg2jd(-4712, 1, 1, 12, 0, 0.0, CalendarSystem::Julian),
+            Ok(0.0)
+        );
+        // the ides of march of 44 BC
+        let ides = greg2jd(-43, 3, 15, 12, 0, 0.0, CalendarSystem::Julian).unwrap();
+
+        assert_eq!(ides, 1705426.0);
+        assert_eq!(
+            greg2jd(-43, 3, 15, 12, 0, 0.0, CalendarSystem::Auto),
+            Ok(ides)
+        );
+        assert_eq!(
+            jd2greg(ides, CalendarSystem::Julian).to_string(),
+            "-043-03-15 12:00:00"
+        );
+        // every fourth year is a leap year
+        assert!(greg2jd(1900, 2, 29, 0, 0, 0.0, CalendarSystem::Julian).is_ok());
+        assert!(greg2jd(1900, 2, 29, 0, 0, 0.0, CalendarSystem::Gregorian).is_err());
+        assert!(greg2jd(-44, 2, 29, 0, 0, 0.0, CalendarSystem::Julian).is_ok());
+
+        for jd in [0.0, 1000000.25, ides, 2299159.5, 2451545.0] {
+            let date = jd2greg(jd, CalendarSystem::Julian);
+            let back = greg2jd(
+                date.year,
+                u32::from(date.month),
+                u32::from(date.day),
+                u32::from(date.hour),
+                u32::from(date.minute),
+                date.second,
+                CalendarSystem::Julian,
+            );
+
+            assert_eq!(back, Ok(jd), "{date}");
+        }
+    }
+
+    #[test]
+    pub fn epochs() {
+        assert_eq!(Epoch::J2000.jd(), 2451545.0);
+        assert_eq!(Epoch::B1950.jd(), 2433282.4235);
+        assert_eq!(Epoch::Unix.jd(), UNIX_EPOCH_JD);
+        assert_eq!(Epoch::Custom(12.5).jd(), 12.5);
+        assert_eq!(Epoch::default(), Epoch::J2000);
+        assert_eq!(Epoch::J2000.days_since(2451546.5), 1.5);
+        assert_eq!(Mars.reference_epoch(), Epoch::J2000);
+    }
+
+    #[test]
+    pub fn custom_epoch_flows_through_dates() {
+        let epoch = Observed.reference_epoch();
+
+        assert_eq!(epoch, Epoch::Custom(2_440_000.25));
+        assert_eq!(epoch.jd(), 2_440_000.25);
+
+        let date = Observed.to_date(Observed.reference_epoch().jd());
+        assert_eq!((date.year, date.day), (1.0, 1.0));
+        // the sol of the epoch starts at the body's epoch, a quarter of a day earlier
+        assert_eq!(Observed.to_julian(&date), Ok(Observed.epoch()));
+        assert_eq!(
+            Observed
+                .reference_epoch()
+                .days_since(Observed.to_julian(&date).unwrap()),
+            -0.25
+        );
+    }
+
+    #[test]
+    pub fn time_scale_functions_round_trip() {
+        for jd in [
+            2440587.5,
+            2441317.5,
+            2451545.0,
+            2457754.4999,
+            2458849.5,
+            2460000.123,
+        ] {
+            assert!((tai_to_utc(utc_to_tai(jd)) - jd).abs() < 1e-9, "{jd}");
+            assert!((tt_to_tai(tai_to_tt(jd)) - jd).abs() < 1e-9, "{jd}");
+            assert!((tdb_to_tt(tt_to_tdb(jd)) - jd).abs() < 1e-9, "{jd}");
+            assert!((tt_to_utc(utc_to_tt(jd)) - jd).abs() < 1e-9, "{jd}");
+            assert_eq!(
+                utc_to_tt(jd),
+                JulianDate::utc(jd).to_scale(TimeScale::TT).value
+            );
+        }
+    }
+
+    #[test]
+    pub fn utc_to_tt_in_2020() {
+        let jd = greg2jd(2020, 1, 1, 0, 0, 0.0, CalendarSystem::Gregorian).unwrap();
+
+        assert!(((utc_to_tt(jd) - jd) * 86400.0 - 69.184).abs() < 1e-4);
+    }
+}
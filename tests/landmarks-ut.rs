@@ -0,0 +1,52 @@
+#![cfg(all(feature = "ceres", feature = "earth", feature = "luna", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        asteroids::ceres::{Ceres, CERES_EPOCH_JD},
+        julian::{
+            jd2greg,
+            landmarks::{
+                CURIOSITY_LANDING, GREGORIAN_REFORM, J2000, MARS_YEAR_1_START,
+                PERSEVERANCE_LANDING, UNIX_EPOCH_JD, VIKING_1_LANDING,
+            },
+            CalendarSystem,
+        },
+        kepler::Body,
+        moons::luna::{Luna, LUNA_EPOCH_JD},
+        planets::{
+            earth::{Earth, EARTH_EPOCH_JD},
+            mars::{Mars, MARS_EPOCH_JD},
+        },
+    };
+
+    #[test]
+    pub fn landmarks_are_on_their_dates() {
+        let landmarks = [
+            (UNIX_EPOCH_JD, "1970-01-01 00:00:00"),
+            (J2000, "2000-01-01 12:00:00"),
+            (GREGORIAN_REFORM, "1582-10-15 00:00:00"),
+            (MARS_YEAR_1_START, "1955-04-11 00:00:00"),
+            (VIKING_1_LANDING, "1976-07-20 11:53:06"),
+            (CURIOSITY_LANDING, "2012-08-06 05:17:57"),
+            (PERSEVERANCE_LANDING, "2021-02-18 20:55:00"),
+            (MARS_EPOCH_JD, "1975-12-19 04:00:28"),
+            (EARTH_EPOCH_JD, "2000-03-20 07:35:02"),
+            (CERES_EPOCH_JD, "2015-11-05 00:00:00"),
+        ];
+
+        for (jd, date) in landmarks {
+            assert_eq!(jd2greg(jd, CalendarSystem::Gregorian).to_string(), date);
+        }
+    }
+
+    #[test]
+    pub fn bodies_use_their_epochs() {
+        assert_eq!(Mars.epoch(), MARS_EPOCH_JD);
+        assert_eq!(Earth.epoch(), EARTH_EPOCH_JD);
+        assert_eq!(Ceres.epoch(), CERES_EPOCH_JD);
+        assert_eq!(Luna.epoch(), LUNA_EPOCH_JD);
+        // mars year 1 is eleven mars years before the epoch
+        assert_eq!(Mars.to_date(MARS_YEAR_1_START + 1.0).year, 1.0);
+    }
+}
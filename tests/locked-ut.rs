@@ -0,0 +1,82 @@
+#![cfg(feature = "luna")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        clock::{BodyClock, ClockCalibration},
+        define_body,
+        kepler::{locked_solar_day, Body, CustomBody, HostedBody},
+        moons::luna::{Luna, LUNA_CALIBRATION},
+        orbit::Perihelion,
+        planets::earth::Earth,
+        Error,
+    };
+
+    define_body! {
+        /// A planet that turns once a year, so it always shows the sun the same face
+        struct Locked {
+            name: "Locked",
+            epoch: 2_451_545.0,
+            orbital_eccentricity: 0.02,
+            orbital_period: 1.0,
+            rotational_period: 11.19 * 86_400.0,
+            semimajor: 0.0485,
+            perihelion: ((0.1, 0.2), (30.0, 60.0), 45.0),
+        }
+    }
+
+    #[test]
+    pub fn luna_sol_is_the_synodic_month() {
+        assert!(Luna.is_tidally_locked());
+        assert!(!Earth.is_tidally_locked());
+
+        let sol = Luna.locked_solar_day().0;
+
+        assert!((sol - 29.5).abs() < 0.1, "{sol}");
+        // the clock of luna counts the same sol
+        assert!((LUNA_CALIBRATION.sol_length - sol).abs() < 0.1);
+        assert!((Luna.rotational_period_seconds().to_days().0 - sol).abs() < 0.1);
+
+        let new_moon = Luna.sol_date(2460321.0);
+        let next = Luna.sol_date(2460321.0 + LUNA_CALIBRATION.sol_length);
+
+        assert!((next - new_moon - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    pub fn locked_to_the_sun_has_no_sol() {
+        assert_eq!(locked_solar_day(11.19, None), f64::INFINITY);
+        assert!(matches!(
+            Locked.try_to_date(2_451_600.0),
+            Err(Error::InvalidOrbit(_))
+        ));
+
+        let built = CustomBody::builder()
+            .orbital_eccentricity(0.02)
+            .orbital_period(1.0)
+            .rotational_period(11.19 * 86_400.0)
+            .semimajor(0.0485)
+            .perihelion(Perihelion::new((0.1, 0.2), (30.0, 60.0), 45.0).unwrap())
+            .build();
+
+        assert!(matches!(built, Err(Error::InvalidOrbit(_))));
+    }
+
+    #[test]
+    pub fn locked_to_the_sun_stops_the_clock() {
+        let calibration = ClockCalibration::from_body(&Locked);
+
+        assert_eq!(calibration.sol_length, f64::INFINITY);
+        assert_eq!(Locked.sol_date(2_460_000.0), 0.0);
+
+        let clock = BodyClock::new(Locked, calibration);
+
+        // the sun stands still, so every longitude keeps its time of day
+        assert_eq!(
+            clock.at(2_451_545.0, 6.0).to_string(),
+            clock.at(2_460_000.0, 6.0).to_string()
+        );
+        assert_eq!(clock.at(2_460_000.0, 6.0).hour, 6);
+        assert_eq!(Locked.lmst(90.0, 2_460_000.0).hour, 6);
+    }
+}
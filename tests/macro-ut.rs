@@ -0,0 +1,78 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{define_body, kepler::Body, planets::mars::Mars};
+
+    define_body! {
+        /// A moon that always shows the same face to its planet
+        struct Locked {
+            name: "Locked",
+            epoch: 2_451_545.0,
+            orbital_eccentricity: 0.05,
+            orbital_period: 30.0,
+            rotational_period: 2_592_000.0,
+            semimajor: 1.0,
+            perihelion: ((5.0, 10.0), (30.0, 60.0), 45.0),
+        }
+        impl {
+            fn axial_tilt(&self) -> f64 {
+                1.5
+            }
+
+            fn sol_date(&self, _julian_date: f64) -> f64 {
+                0.0
+            }
+        }
+    }
+
+    #[test]
+    pub fn mars_is_unchanged() {
+        // (julian date, year, day, ls, sol) from before mars used the macro,
+        // the ls is of the fully solved anomaly, the newton loop used to stop on its first step back
+        let snapshots = [
+            (2442765.667, 12.0, 1.0, 359.9827086322139, 0.0),
+            (2451545.0, 24.0, 522.0, 274.26203796033553, 521.235683618881),
+            (
+                2459000.5,
+                35.0,
+                423.0,
+                210.64239875298526,
+                422.65846339267955,
+            ),
+            (
+                2460000.123,
+                37.0,
+                59.0,
+                28.64722688329171,
+                58.33583703453405,
+            ),
+        ];
+
+        for (jd, year, day, ls, sol) in snapshots {
+            let date = Mars.to_date(jd);
+
+            assert_eq!(
+                (date.year, date.day, date.ls, date.sol),
+                (year, day, ls, sol)
+            );
+        }
+
+        assert_eq!(Mars.name(), "Mars");
+        assert_eq!(Mars.semiminor(), 1.5067401888);
+        assert_eq!(Mars.mean_motion(100.0), 2.6613477939608656);
+        assert_eq!(
+            Mars.lmst(10.0, 2451545.0).to_string(),
+            "15:33:02 LMST (+00:40)"
+        );
+    }
+
+    #[test]
+    pub fn macro_overrides_methods() {
+        assert_eq!(Locked.name(), "Locked");
+        assert_eq!(Locked.axial_tilt(), 1.5);
+        assert_eq!(Locked.sol_date(2_460_000.0), 0.0);
+        assert_eq!(Locked.perihelion().perihelion, 45.0);
+        assert_eq!(Locked.to_date(2_451_545.0).year, 1.0);
+    }
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        kepler::HostedBody,
+        planets::mars::{
+            mars24::Mars24, moon_events, Deimos, MarsMoon, Phobos, DEIMOS_RADIUS_KM,
+            PHOBOS_RADIUS_KM,
+        },
+        Error,
+    };
+
+    /// Curiosity in Gale crater, planetocentric latitude and east longitude
+    const GALE: (f64, f64) = (-4.5895, 137.4417);
+
+    /// Perseverance in Jezero crater, planetocentric latitude and east longitude
+    const JEZERO: (f64, f64) = (18.4447, 77.4508);
+
+    /// This function returns the sol of a rover, counted in the local mean solar time of its site
+    fn sol(julian_date: f64, lon: f64, landing_msd: f64) -> f64 {
+        (Mars24::at(julian_date).unwrap().msd + lon / 360.0).floor() - landing_msd
+    }
+
+    #[test]
+    pub fn curiosity_phobos() {
+        // curiosity filmed phobos crossing the sun on sol 2359, 2019 March 26
+        let events = moon_events(GALE.0, GALE.1, 2458567.5, 2458570.5).unwrap();
+        let phobos = events
+            .iter()
+            .find(|event| event.moon == MarsMoon::Phobos)
+            .unwrap();
+
+        assert_eq!(2359.0, sol(phobos.peak_jd, GALE.1, 49269.0));
+        assert!(phobos.start_jd < phobos.peak_jd && phobos.peak_jd < phobos.end_jd);
+        // the transit lasts about half a minute
+        assert!((phobos.end_jd - phobos.start_jd) * 86400.0 < 60.0);
+        assert!(phobos.separation < phobos.sun_radius + phobos.moon_radius);
+        assert!(phobos.moon_radius < phobos.sun_radius);
+        // phobos is between mars and the sun, off by the parallax of the site (up to 21 degrees)
+        let longitude = Phobos.host_longitude(phobos.peak_jd).unwrap();
+
+        assert!(!(25.0..335.0).contains(&longitude), "{longitude}");
+    }
+
+    #[test]
+    pub fn curiosity_deimos() {
+        // curiosity saw deimos crossing the sun on sol 2350, 2019 March 17
+        let events = moon_events(GALE.0, GALE.1, 2458558.5, 2458561.5).unwrap();
+        let deimos = events
+            .iter()
+            .find(|event| event.moon == MarsMoon::Deimos)
+            .unwrap();
+
+        assert_eq!(2350.0, sol(deimos.peak_jd, GALE.1, 49269.0));
+        // deimos is a dot on the sun
+        assert!(deimos.moon_radius < deimos.sun_radius / 5.0);
+        let longitude = Deimos.host_longitude(deimos.peak_jd).unwrap();
+
+        assert!(!(10.0..350.0).contains(&longitude), "{longitude}");
+    }
+
+    #[test]
+    pub fn perseverance_phobos() {
+        // perseverance filmed phobos crossing the sun on sol 397, 2022 April 2
+        let events = moon_events(JEZERO.0, JEZERO.1, 2459670.5, 2459672.5).unwrap();
+        let phobos = events
+            .iter()
+            .find(|event| {
+                event.moon == MarsMoon::Phobos && sol(event.peak_jd, JEZERO.1, 52304.0) == 397.0
+            })
+            .unwrap();
+
+        // NASA published that the eclipse lasted a little over 40 seconds
+        let seconds = (phobos.end_jd - phobos.start_jd) * 86400.0;
+
+        assert!((seconds - 40.0).abs() < 10.0, "{seconds}");
+    }
+
+    #[test]
+    pub fn moon_positions() {
+        for moon in MarsMoon::ALL {
+            let [x, y, z] = moon.position(2451545.0).unwrap();
+            let distance = (x * x + y * y + z * z).sqrt();
+
+            assert!((distance - moon.semimajor_km()).abs() < 1e-6);
+            assert_eq!(moon.semimajor_km(), moon.body().host_semimajor_km());
+            assert_eq!(Some(moon.radius_km()), moon.body().mean_radius_km());
+            assert_eq!(moon.as_ref(), moon.body().name());
+            // the orbits are close to the equator of mars
+            assert!((z / distance).asin().to_degrees().abs() < 3.0);
+        }
+
+        assert_eq!(11.08, PHOBOS_RADIUS_KM);
+        assert_eq!(6.2, DEIMOS_RADIUS_KM);
+        assert_eq!(
+            Err(Error::NonFinite),
+            moon_events(f64::NAN, 0.0, 2451545.0, 2451546.0)
+        );
+    }
+}
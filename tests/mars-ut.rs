@@ -1,19 +1,27 @@
+#![cfg(feature = "mars")]
+
 #[cfg(test)]
 mod tests {
     use rust_solar::{
-        julian::jd2greg,
-        kepler::{Body, TimeZone},
-        planets::mars::{Mars, Martian},
+        fixtures,
+        julian::{jd2greg, CalendarSystem},
+        kepler::{Body, Date, Time, TimeZone, SUN_GRAVITATIONAL_PARAMETER},
+        planets::mars::{coordinated_mars_time, mars_sol_date, Mars, Martian, MARS_RADIUS_KM},
+        Error,
     };
 
     #[test]
     pub fn mars_to_date() {
         let jd = 2440587.5;
         let date = Mars.to_date(jd);
-        jd2greg(jd);
+        assert_eq!(
+            jd2greg(jd, CalendarSystem::Gregorian).to_string(),
+            "1970-01-01 00:00:00"
+        );
         println!("The date is {:?}", date);
     }
 
+    #[cfg(feature = "std")]
     #[test]
     pub fn mars_to_time() {
         let time = Martian::MTCp5.new();
@@ -21,5 +29,194 @@ mod tests {
         println!("Time now: {:?}", time);
     }
 
-}
+    #[test]
+    pub fn mars_to_utc_round_trip() {
+        for zone in Martian::iter() {
+            for jd in [2440587.5, 2451545.0, 2459000.5, 2460000.123, 2460000.6] {
+                let datetime = Mars.to_datetime(jd, &zone);
+                let utc = zone.to_utc(&datetime.date(), &datetime.time()).unwrap();
+
+                assert!(
+                    (utc - jd).abs() * 86_400.0 < 1.0,
+                    "{zone:?}: {jd} came back as {utc}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    pub fn mars_to_unix_millis() {
+        let jd = 2460000.123;
+        let date = Mars.to_date(jd);
+        let time = Martian::MTC.at(jd);
+        let millis = Martian::MTC.to_unix_millis(&date, &time).unwrap();
+
+        assert!((millis - (jd - 2440587.5) * 86_400_000.0).abs() < 1000.0);
+    }
+
+    #[test]
+    pub fn mars_to_utc_rejects_invalid_time() {
+        let date = Mars.to_date(2451545.0);
+        let time = Time {
+            minute: 75,
+            ..Time::default()
+        };
+
+        assert!(matches!(
+            Martian::MTC.to_utc(&date, &time),
+            Err(Error::InvalidTime(_))
+        ));
+    }
+
+    #[test]
+    pub fn mars_solar_declination() {
+        assert!((Mars.solar_declination(90.0) - 25.19).abs() < 1e-9);
+        assert!((Mars.solar_declination(270.0) + 25.19).abs() < 1e-9);
+        assert!(Mars.solar_declination(0.0).abs() < 1e-9);
+        assert!(Mars.solar_declination(180.0).abs() < 1e-9);
+    }
+
+    #[test]
+    pub fn mars_escape_velocity() {
+        let velocity = Mars.escape_velocity(MARS_RADIUS_KM).unwrap();
+
+        assert!((velocity - 5.03).abs() < 0.01, "{velocity}");
+        assert_eq!(Mars.gravitational_parameter(), SUN_GRAVITATIONAL_PARAMETER);
+    }
+
+    #[test]
+    pub fn mars_to_date_across_threads() {
+        let body: &dyn Body = &Mars;
+        let jds = [2440587.5, 2451545.0, 2459000.5];
+
+        let dates = std::thread::scope(|scope| {
+            jds.map(|jd| scope.spawn(move || body.to_date(jd)))
+                .map(|handle| handle.join().unwrap())
+        });
+
+        for (jd, date) in jds.iter().zip(dates) {
+            assert_eq!(date.ls, Mars.to_date(*jd).ls);
+        }
+    }
+
+    #[test]
+    pub fn mars_lmst() {
+        for jd in [2451545.0, 2459000.5, 2460000.123] {
+            let mtc = Martian::MTC.at(jd);
+            let prime = Mars.lmst(0.0, jd);
+            let east = Mars.lmst(90.0, jd);
+            let quarter = prime.add_hours(6);
+
+            assert_eq!(
+                (prime.hour, prime.minute, prime.second),
+                (mtc.hour, mtc.minute, mtc.second)
+            );
+            assert_eq!((east.hour, east.minute), (quarter.hour, quarter.minute));
+        }
+    }
+
+    #[test]
+    pub fn mars_lmst_longitude_conventions() {
+        let jd = 2459000.5;
+        let west = Mars.lmst(-137.4, jd);
+        let east = Mars.lmst(222.6, jd);
+
+        assert_eq!(
+            (west.hour, west.minute, west.second),
+            (east.hour, east.minute, east.second)
+        );
+    }
+
+    #[test]
+    pub fn mars_ltst_within_equation_of_time() {
+        let start = 2459000.5;
+
+        for sol in (0..669).step_by(7) {
+            let jd = start + f64::from(sol) * 1.027491252;
+            let mean = Mars.lmst(0.0, jd);
+            let truly = Mars.ltst(0.0, jd);
+            let seconds = |time: &Time| {
+                i64::from(time.hour) * 3600 + i64::from(time.minute) * 60 + i64::from(time.second)
+            };
+
+            // wraps across midnight in either direction
+            let mut diff = (seconds(&truly) - seconds(&mean)).rem_euclid(86_400);
+            if diff > 43_200 {
+                diff -= 86_400;
+            }
+
+            // the martian equation of time swings from about -51 to +40 minutes
+            assert!(diff.abs() <= 52 * 60, "{diff} seconds at {jd}");
+            assert_eq!(truly.code(), "LTST");
+        }
+    }
+
+    #[test]
+    pub fn mars_equation_of_time_zero() {
+        assert!(Mars.equation_of_time(57.0).abs() < 0.25);
+        assert!(Mars.equation_of_time(57.0 - 20.0) * Mars.equation_of_time(57.0 + 20.0) < 0.0);
+    }
+
+    #[test]
+    pub fn mars_sol_date_mars24() {
+        let example = fixtures::MARS
+            .iter()
+            .find(|fixture| fixture.label == "Mars24 worked example")
+            .unwrap();
+        let jd = example.julian_date;
+
+        assert!((mars_sol_date(jd) - example.msd.unwrap()).abs() < 1e-4);
+        assert!((Mars.msd(jd) - mars_sol_date(jd)).abs() < f64::EPSILON);
+        // MTC 23:59:39
+        assert!((coordinated_mars_time(jd) - 23.9942).abs() < 1e-3);
+    }
 
+    #[test]
+    pub fn mars_epoch_year() {
+        // Mars Year 12 begins at the epoch
+        assert_eq!(Mars.to_date(Mars.epoch()).year, 12.0);
+        assert_eq!(Mars.to_date(Mars.epoch() - 1.0).year, 11.0);
+
+        // Mars Year 36 began on 2021 February 7
+        assert_eq!(Mars.to_date(2459252.5 + 1.0).year, 36.0);
+        assert_eq!(Mars.to_date(2459252.5 - 1.0).year, 35.0);
+    }
+
+    #[test]
+    pub fn year_and_orbit_progress() {
+        let first = Date::from_ordinal(36.0, 1.0, &Mars).unwrap();
+        let start = Mars.to_julian(&first).unwrap();
+        let sol = Mars.rotational_period() / 86_400.0;
+
+        assert_eq!(0.0, first.year_progress(&Mars));
+
+        let mut last = -1.0;
+
+        // the middle of every sol of the year
+        for day in 0..668 {
+            let progress = Mars
+                .to_date(start + (day as f64 + 0.5) * sol)
+                .year_progress(&Mars);
+
+            assert!(progress > last, "{day}");
+            last = progress;
+        }
+
+        // the last fraction of a sol of the year
+        let end = start + Mars.orbital_period() * sol;
+        let late = Mars.to_date(end - 1e-6);
+
+        assert_eq!(36.0, late.year);
+        assert!((0.999..1.0).contains(&late.year_progress(&Mars)));
+        assert_eq!(0.0, Mars.to_date(end + 1e-6).year_progress(&Mars).floor());
+        assert!(Mars.orbit_progress(end - 1e-6) < 1.0);
+
+        // at the perihelion the orbit starts over, while the year is mostly gone
+        let perihelion = Mars.jd_at_ls(Mars.perihelion().perihelion, start).unwrap();
+        let orbit = Mars.orbit_progress(perihelion);
+        let year = Mars.to_date(perihelion).year_progress(&Mars);
+
+        assert!(!(0.005..=0.995).contains(&orbit), "{orbit}");
+        assert!((year - 0.72).abs() < 0.02, "{year}");
+    }
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        kepler::{Body, Eras, Time, TimeZone},
+        mission::MissionClock,
+        planets::mars::{Mars, Martian},
+    };
+
+    /// Curiosity landed 2012 August 6, 05:17:57 UTC (JD 2456145.7208) in the afternoon at Gale crater,
+    /// mission sols start at local midnight so sol 0 started about 0.62 sols earlier.
+    const CURIOSITY_SOL_0: f64 = 2_456_145.08;
+
+    #[test]
+    pub fn curiosity_sols() {
+        let curiosity = MissionClock::new(Mars, CURIOSITY_SOL_0, "Curiosity");
+        // (2015 May 31, sol 1000), (2018 March 22, sol 2000)
+        let published = [(2_457_173.5, 1000), (2_458_199.5, 2000)];
+
+        for (jd, sol) in published {
+            assert!((curiosity.sol(jd) - sol).abs() <= 1, "{}", curiosity.sol(jd));
+        }
+    }
+
+    #[test]
+    pub fn sols_before_landing() {
+        let curiosity = MissionClock::new(Mars, CURIOSITY_SOL_0, "Curiosity");
+
+        assert_eq!(curiosity.sol(CURIOSITY_SOL_0), 0);
+        assert_eq!(curiosity.sol(CURIOSITY_SOL_0 - 0.1), -1);
+        assert_eq!(curiosity.sol(CURIOSITY_SOL_0 - 1.1), -2);
+    }
+
+    #[test]
+    pub fn mission_date() {
+        let curiosity = MissionClock::new(Mars, CURIOSITY_SOL_0, "Curiosity");
+        let landing = curiosity.date(CURIOSITY_SOL_0 + 0.5);
+        let before = curiosity.date(CURIOSITY_SOL_0 - 0.5);
+
+        assert_eq!((landing.era, landing.year, landing.day), (Eras::AD, 1.0, 1.0));
+        assert_eq!((before.era, before.year), (Eras::BD, 0.0));
+        assert_eq!(before.day, Mars.orbital_period().ceil());
+        assert_eq!(landing.ls, Mars.to_date(CURIOSITY_SOL_0 + 0.5).ls);
+    }
+
+    #[test]
+    pub fn mission_time() {
+        let curiosity = MissionClock::new(Mars, CURIOSITY_SOL_0, "Curiosity");
+        let sol = Mars.rotational_period() / 86_400.0;
+        let hours = |time: Time| {
+            f64::from(time.hour) + f64::from(time.minute) / 60.0 + f64::from(time.second) / 3600.0
+        };
+
+        // midnight at the start of every mission sol, and noon halfway through it
+        for mission_sol in [-3, 0, 1, 1000] {
+            let start = CURIOSITY_SOL_0 + f64::from(mission_sol) * sol;
+
+            assert_eq!(
+                0.0,
+                hours(curiosity.time(start + 1e-9, &Martian::MTC)).round(),
+                "{mission_sol}"
+            );
+            assert_eq!(
+                12.0,
+                hours(curiosity.time(start + 0.5 * sol, &Martian::MTC)),
+                "{mission_sol}"
+            );
+        }
+
+        // the local mean time of Gale crater, 137.4°E, is about 9h10m ahead of the MTC
+        for jd in [2_457_173.5, 2_458_199.5] {
+            let ahead = (hours(curiosity.time(jd, &Martian::MTC)) - hours(Martian::MTC.at(jd)))
+                .rem_euclid(24.0);
+
+            assert!((ahead - 137.4 / 15.0).abs() < 0.25, "{ahead}");
+        }
+    }
+
+    #[test]
+    pub fn zoned_mission_time() {
+        let sol = Mars.rotational_period() / 86_400.0;
+        // the midnight on the prime meridian before the curiosity epoch
+        let midnight = CURIOSITY_SOL_0 - Martian::MTC.fractional_hours(CURIOSITY_SOL_0) / 24.0 * sol;
+        let clock = MissionClock::new(Mars, midnight, "Prime");
+        let milliseconds = |time: Time| {
+            i64::from(time.hour) * 3_600_000
+                + i64::from(time.minute) * 60_000
+                + i64::from(time.second) * 1000
+                + i64::from(time.millisecond)
+        };
+
+        // the zones count sols of the MSD in TT, the mission clock counts them in UTC, so they only agree near the epoch
+        for zone in [Martian::MTC, Martian::MTCp2, Martian::MTCn3] {
+            for jd in [midnight + 0.25 * sol, CURIOSITY_SOL_0, midnight + 3.6 * sol] {
+                let mission = clock.time(jd, &zone);
+                let zoned = zone.at(jd);
+                let apart = (milliseconds(mission) - milliseconds(zoned)).rem_euclid(86_400_000);
+
+                assert!(apart.min(86_400_000 - apart) < 10, "{zone:?} {jd}: {apart}");
+                assert_eq!(mission.offset_name, zoned.offset_name);
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+#![cfg(feature = "mpc")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        conversions::{Au, Days},
+        julian::{JulianDate, TimeScale},
+        kepler::Body,
+        mpc::{parse_file, parse_line, unpack_epoch},
+        Error,
+    };
+
+    const MPCORB: &str = include_str!("data/mpcorb-first-three.txt");
+
+    #[test]
+    pub fn packed_epochs() {
+        let jd = |packed| unpack_epoch(packed).map(|epoch| epoch.value);
+
+        assert_eq!(Ok(2_460_608.5), jd("K24AP"));
+        assert_eq!(Ok(2_459_000.5), jd("K205V"));
+        assert_eq!(Ok(2_451_544.5), jd("K0011"));
+        assert_eq!(Ok(2_450_083.5), jd("J9611"));
+        assert_eq!(Ok(2_415_020.5), jd("J0011"));
+        // the last day of the 19th century
+        assert_eq!(Ok(2_415_019.5), jd("I99CV"));
+        assert_eq!(TimeScale::TT, unpack_epoch("K24AP").unwrap().scale);
+    }
+
+    #[test]
+    pub fn invalid_packed_epochs() {
+        let invalid = Err(Error::InvalidFormat("the packed epoch isn't valid"));
+
+        assert_eq!(invalid, unpack_epoch("K24A"));
+        assert_eq!(invalid, unpack_epoch("K24APP"));
+        assert_eq!(invalid, unpack_epoch("H24AP"));
+        assert_eq!(invalid, unpack_epoch("K2XAP"));
+        assert_eq!(invalid, unpack_epoch("K2400"));
+        assert_eq!(invalid, unpack_epoch("K24AW"));
+        assert_eq!(invalid, unpack_epoch("k24ap"));
+        // the 13th month and february 30th
+        assert!(matches!(unpack_epoch("K24D1"), Err(Error::InvalidDate(_))));
+        assert!(matches!(unpack_epoch("K242U"), Err(Error::InvalidDate(_))));
+    }
+
+    #[test]
+    pub fn first_three() {
+        let bodies = parse_file(MPCORB).unwrap();
+        let names: Vec<_> = bodies.iter().map(|(body, _)| body.name()).collect();
+
+        assert_eq!(vec!["(1) Ceres", "(2) Pallas", "(3) Juno"], names);
+
+        let (ceres, elements) = &bodies[0];
+
+        assert_eq!(162.68631, elements.mean_anomaly);
+        assert_eq!(73.73161, elements.argument_of_periapsis);
+        assert_eq!(80.28698, elements.ascending_node);
+        assert_eq!(10.58862, elements.inclination);
+        assert_eq!(0.0775571, elements.eccentricity);
+        assert_eq!(0.21406009, elements.mean_motion);
+        assert_eq!(Au(2.7676569), elements.semimajor);
+        assert_eq!(Days(360.0 / 0.21406009), elements.period);
+        assert_eq!(Au(2.7676569 * (1.0 - 0.0775571)), elements.periapsis);
+        assert!((elements.true_anomaly - 165.1).abs() < 0.05, "{elements:?}");
+        assert_eq!(10.58862, ceres.inclination());
+        assert_eq!(2_459_000.5 - 162.68631 / 0.21406009, elements.periapsis_jd);
+
+        let (juno, elements) = &bodies[2];
+
+        assert_eq!(0.2569364, elements.eccentricity);
+        assert_eq!(Au(2.6682495), elements.semimajor);
+        assert!((juno.orbital_period() - 360.0 / 0.22613325).abs() < 1e-9);
+    }
+
+    #[test]
+    pub fn dates() {
+        for (body, elements) in parse_file(MPCORB).unwrap() {
+            let epoch = JulianDate::new(2_459_000.5, TimeScale::TT)
+                .to_scale(TimeScale::UTC)
+                .value;
+            let date = body.to_date(epoch);
+            let perihelion = body.to_date(
+                JulianDate::new(elements.periapsis_jd, TimeScale::TT)
+                    .to_scale(TimeScale::UTC)
+                    .value,
+            );
+
+            // the calendar starts at the ls of 0 before the epoch, so it's within the first year
+            assert_eq!(1.0, date.year, "{}", body.name());
+            assert!((0.0..360.0).contains(&date.ls));
+            assert!(
+                (perihelion.ls - body.perihelion().perihelion).abs() < 1e-6,
+                "{} {perihelion:?}",
+                body.name()
+            );
+        }
+    }
+
+    #[test]
+    pub fn positions() {
+        for (body, elements) in parse_file(MPCORB).unwrap() {
+            let epoch = JulianDate::new(2_459_000.5, TimeScale::TT)
+                .to_scale(TimeScale::UTC)
+                .value;
+            let position = body.heliocentric_position(epoch).unwrap();
+            let e = elements.eccentricity;
+            let distance = elements.semimajor.0 * (1.0 - e * e)
+                / (1.0 + e * elements.true_anomaly.to_radians().cos());
+            let u = (elements.argument_of_periapsis + elements.true_anomaly).to_radians();
+            let (node, inclination) = (
+                elements.ascending_node.to_radians(),
+                elements.inclination.to_radians(),
+            );
+            let expected = [
+                distance * (node.cos() * u.cos() - node.sin() * u.sin() * inclination.cos()),
+                distance * (node.sin() * u.cos() + node.cos() * u.sin() * inclination.cos()),
+                distance * u.sin() * inclination.sin(),
+            ];
+
+            assert!(
+                (position.x - expected[0]).abs() < 1e-6,
+                "{} {position:?}",
+                body.name()
+            );
+            assert!(
+                (position.y - expected[1]).abs() < 1e-6,
+                "{} {position:?}",
+                body.name()
+            );
+            assert!(
+                (position.z - expected[2]).abs() < 1e-6,
+                "{} {position:?}",
+                body.name()
+            );
+        }
+    }
+
+    #[test]
+    pub fn invalid_lines() {
+        let line = MPCORB.lines().nth(6).unwrap();
+
+        assert_eq!(
+            Err(Error::InvalidFormat(
+                "the line is too short for the MPCORB format"
+            )),
+            parse_line(&line[..90]).map(|_| ())
+        );
+        assert_eq!(
+            Err(Error::InvalidFormat("a column of the line isn't a number")),
+            parse_line(&line.replace("162.68631", "162.6863x")).map(|_| ())
+        );
+        assert_eq!(
+            Err(Error::InvalidOrbit(
+                "the orbit of the minor planet isn't elliptical"
+            )),
+            parse_line(&line.replace("0.0775571", "1.0775571")).map(|_| ())
+        );
+        // a line without its readable designation keeps the packed one
+        assert_eq!("00001", parse_line(&line[..160]).unwrap().0.name());
+    }
+}
@@ -0,0 +1,27 @@
+//! Builds without the std prelude, run with `cargo test --no-default-features --features mars`
+//!
+//! The test harness links std, so the float math here is still the one of std,
+//! the `libm` path is built for `thumbv7em-none-eabi` by `scripts/feature-matrix.sh`
+#![cfg(all(not(feature = "std"), feature = "mars"))]
+#![no_std]
+
+// the test harness needs std, the library doesn't
+extern crate std;
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        kepler::{Body, TimeZone},
+        planets::mars::{mars_sol_date, Mars, Martian},
+    };
+
+    #[test]
+    pub fn mars_without_std() {
+        let date = Mars.to_date(2451545.0);
+
+        assert_eq!((date.year, date.day), (24.0, 522.0));
+        assert!((date.ls - 274.262).abs() < 1e-3);
+        assert!((mars_sol_date(2451549.5) - 44795.99976).abs() < 1e-4);
+        assert_eq!(Martian::MTC.at(2451545.0).hour, 14);
+    }
+}
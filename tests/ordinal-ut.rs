@@ -0,0 +1,140 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        kepler::{Body, Date, Eras},
+        orbit::Hemisphere,
+        planets::mars::Mars,
+    };
+
+    /// The length of a sol in earth days
+    const SOL: f64 = 88_775.245 / 86_400.0;
+
+    #[test]
+    pub fn ordinal_matches_day() {
+        for jd in [2440587.5, 2451545.0, 2459000.5] {
+            let date = Mars.to_date(jd);
+
+            assert_eq!(date.ordinal().floor(), date.day);
+        }
+    }
+
+    #[test]
+    pub fn ordinal_rolls_over_the_year() {
+        // The last sol of mars year 12
+        let last = Mars.epoch() + (Mars.orbital_period() - 0.3) * SOL;
+        let date = Mars.to_date(last);
+        let next = Mars.to_date(last + SOL);
+
+        assert_eq!(date.ordinal().floor(), Mars.orbital_period().ceil());
+        assert_eq!(next.year, date.year + 1.0);
+        assert_eq!(next.ordinal().floor(), 1.0);
+    }
+
+    #[test]
+    pub fn from_ordinal_round_trip() {
+        for jd in [2440587.5, 2451545.0, 2459000.5, 2460000.123] {
+            let date = Mars.to_date(jd);
+
+            assert_eq!(
+                Date::from_ordinal(date.year, date.ordinal(), &Mars),
+                Ok(date)
+            );
+        }
+    }
+
+    #[test]
+    pub fn from_ordinal_in_the_south() {
+        for jd in [2440587.5, 2451545.0, 2459000.5, 2460000.123] {
+            let north = Mars.to_date(jd);
+            let south = Mars.to_date_in(jd, Hemisphere::South);
+
+            assert_eq!(
+                Date::from_ordinal_in(south.year, south.ordinal(), &Mars, Hemisphere::South),
+                Ok(south)
+            );
+            // the same sol, with the opposite season
+            assert_eq!(north.sol, south.sol);
+            assert_ne!(north.season, south.season);
+        }
+    }
+
+    #[test]
+    pub fn thirty_sols_share_their_month() {
+        // the third month of mars is its longest, around the aphelion
+        let start = Date::from_ordinal(36.0, 140.5, &Mars)
+            .unwrap()
+            .start_of_month(&Mars)
+            .unwrap();
+        let start_jd = Mars.to_julian(&start).unwrap() + start.sol.fract() * SOL;
+
+        assert_eq!(3.0, start.month);
+
+        for sol in 0..30 {
+            let date = Mars.to_date(start_jd + 0.5 * SOL + sol as f64 * SOL);
+            let bucket = date.start_of_month(&Mars).unwrap();
+
+            assert_eq!(start.month, date.month);
+            assert!((bucket.sol - start.sol).abs() < 1e-6, "{sol}");
+            assert_eq!(start.year, bucket.year);
+        }
+
+        // the sol before the month is in the month before
+        assert_eq!(2.0, Mars.to_date(start_jd - 0.5 * SOL).month);
+    }
+
+    #[test]
+    pub fn boundaries_are_idempotent() {
+        for (year, ordinal) in [(36.0, 1.5), (36.0, 300.25), (36.0, 668.5), (-3.0, 42.7)] {
+            let date = Date::from_ordinal(year, ordinal, &Mars).unwrap();
+            let month = date.start_of_month(&Mars).unwrap();
+            let year_start = date.start_of_year(&Mars).unwrap();
+            let rounded = date.round_to_sol(&Mars).unwrap();
+
+            assert_eq!(month, month.start_of_month(&Mars).unwrap());
+            assert_eq!(year_start, year_start.start_of_year(&Mars).unwrap());
+            assert_eq!(rounded, rounded.round_to_sol(&Mars).unwrap());
+            assert_eq!(date.month, month.month);
+            assert_eq!(0.0, year_start.sol);
+            assert_eq!(rounded.sol, rounded.sol.round());
+        }
+
+        // the bd era keeps its era
+        let bd = Date::from_ordinal(-3.0, 42.7, &Mars).unwrap();
+
+        assert_eq!(Eras::BD, bd.start_of_month(&Mars).unwrap().era);
+        assert_eq!(Eras::BD, bd.start_of_year(&Mars).unwrap().era);
+    }
+
+    #[test]
+    pub fn first_month_and_last_sol() {
+        // the year of mars starts just before the solar longitude of 0, in the last month
+        let first = Date::from_ordinal(36.0, 1.0, &Mars).unwrap();
+
+        assert_eq!(12.0, first.month);
+        assert_eq!(first, first.start_of_month(&Mars).unwrap());
+
+        // the first month starts a few hours into the year
+        let spring = Date::from_ordinal(36.0, 10.0, &Mars).unwrap();
+        let start = spring.start_of_month(&Mars).unwrap();
+
+        assert_eq!(1.0, start.month);
+        assert!(start.sol > 0.0 && start.sol < 1.0, "{}", start.sol);
+        assert!(start.ls < 1e-6 || start.ls > 359.999_999, "{}", start.ls);
+
+        // the last fraction of a sol of the year rounds into the next year
+        let late = Date::from_ordinal(36.0, Mars.orbital_period() + 0.9, &Mars).unwrap();
+        let rounded = late.round_to_sol(&Mars).unwrap();
+
+        assert_eq!((37.0, 0.0), (rounded.year, rounded.sol));
+        assert_eq!(
+            668.0,
+            Date::from_ordinal(36.0, 669.2, &Mars)
+                .unwrap()
+                .round_to_sol(&Mars)
+                .unwrap()
+                .sol
+        );
+    }
+}
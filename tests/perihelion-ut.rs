@@ -0,0 +1,128 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        kepler::{Body, CustomBody},
+        orbit::{Perihelion, PerihelionBuilder},
+        planets::mars::Mars,
+        Error,
+    };
+
+    fn mars() -> PerihelionBuilder {
+        Perihelion::builder()
+            .perihelion_ls(251.0)
+            .perihelion_sol_window(468.5, 514.6)
+            .ls_window(240.0, 270.0)
+    }
+
+    #[test]
+    pub fn builder_matches_mars() {
+        let perihelion = mars().build().unwrap();
+        let body = CustomBody::builder()
+            .name("Mars")
+            .epoch(Mars.epoch())
+            .epoch_year(Mars.epoch_year())
+            .orbital_eccentricity(Mars.orbital_eccentricity())
+            .orbital_period(Mars.orbital_period())
+            .rotational_period(Mars.rotational_period())
+            .semimajor(Mars.semimajor())
+            .perihelion(perihelion)
+            .build()
+            .unwrap();
+
+        assert_eq!(Mars.perihelion(), perihelion);
+        assert_eq!(30.0, perihelion.avg_ls());
+
+        for i in 0..1000 {
+            let julian_date = 2_440_000.0 + i as f64 * 37.7;
+
+            assert_eq!(Mars.to_date(julian_date), body.to_date(julian_date));
+        }
+    }
+
+    #[test]
+    pub fn builder_works_in_f32() {
+        let perihelion = Perihelion::<f32>::builder()
+            .perihelion_ls(251.0)
+            .perihelion_sol_window(468.5, 514.6)
+            .ls_window(240.0, 270.0)
+            .build()
+            .unwrap();
+
+        assert_eq!(251.0_f32, perihelion.perihelion);
+    }
+
+    #[test]
+    pub fn inverted_windows_fail() {
+        assert_eq!(
+            Err(Error::InvalidPerihelion(
+                "the ls window is empty or reversed"
+            )),
+            mars().ls_window(270.0, 240.0).build()
+        );
+        assert_eq!(
+            Err(Error::InvalidPerihelion(
+                "the month window is empty or reversed"
+            )),
+            mars().perihelion_sol_window(514.6, 468.5).build()
+        );
+    }
+
+    #[test]
+    pub fn out_of_range_fields_fail() {
+        assert_eq!(
+            Err(Error::InvalidPerihelion(
+                "the ls window is outside of 0 to 360"
+            )),
+            mars().ls_window(-30.0, 270.0).build()
+        );
+        assert_eq!(
+            Err(Error::InvalidPerihelion(
+                "the ls window is outside of 0 to 360"
+            )),
+            mars().ls_window(240.0, 390.0).build()
+        );
+        assert_eq!(
+            Err(Error::InvalidPerihelion(
+                "the perihelion is outside of the ls window"
+            )),
+            mars().perihelion_ls(300.0).build()
+        );
+        assert_eq!(
+            Err(Error::InvalidPerihelion(
+                "the sol window starts before the year"
+            )),
+            mars().perihelion_sol_window(-1.0, 514.6).build()
+        );
+        assert_eq!(
+            Err(Error::NonFinite),
+            mars().perihelion_ls(f64::NAN).build()
+        );
+    }
+
+    #[test]
+    pub fn missing_fields_fail() {
+        assert_eq!(
+            Err(Error::InvalidPerihelion("the perihelion is missing")),
+            Perihelion::builder()
+                .perihelion_sol_window(468.5, 514.6)
+                .ls_window(240.0, 270.0)
+                .build()
+        );
+        assert_eq!(
+            Err(Error::InvalidPerihelion("the sol window is missing")),
+            Perihelion::builder()
+                .perihelion_ls(251.0)
+                .ls_window(240.0, 270.0)
+                .build()
+        );
+        assert_eq!(
+            Err(Error::InvalidPerihelion("the ls window is missing")),
+            Perihelion::builder()
+                .perihelion_ls(251.0)
+                .perihelion_sol_window(468.5, 514.6)
+                .build()
+        );
+    }
+}
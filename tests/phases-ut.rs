@@ -0,0 +1,150 @@
+#![cfg(feature = "luna")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        julian::{greg2jd, CalendarSystem},
+        moons::luna::{next_phase, phase, phases::elongation, LunarPhase, Phase},
+        Error,
+    };
+
+    /// The new moons of 2024 (UTC), as published by the USNO
+    const NEW_MOONS_2024: [(u32, u32, u32, u32); 13] = [
+        (1, 11, 11, 57),
+        (2, 9, 22, 59),
+        (3, 10, 9, 0),
+        (4, 8, 18, 21),
+        (5, 8, 3, 22),
+        (6, 6, 12, 38),
+        (7, 5, 22, 57),
+        (8, 4, 11, 13),
+        (9, 3, 1, 55),
+        (10, 2, 18, 49),
+        (11, 1, 12, 47),
+        (12, 1, 6, 21),
+        (12, 30, 22, 27),
+    ];
+
+    /// The full moons of 2024 (UTC), as published by the USNO
+    const FULL_MOONS_2024: [(u32, u32, u32, u32); 12] = [
+        (1, 25, 17, 54),
+        (2, 24, 12, 30),
+        (3, 25, 7, 0),
+        (4, 23, 23, 49),
+        (5, 23, 13, 53),
+        (6, 22, 1, 8),
+        (7, 21, 10, 17),
+        (8, 19, 18, 26),
+        (9, 18, 2, 34),
+        (10, 17, 11, 26),
+        (11, 15, 21, 28),
+        (12, 15, 9, 2),
+    ];
+
+    /// The published times are to the minute, the series is within a few minutes, this allows a few hours
+    const TOLERANCE_HOURS: f64 = 2.0;
+
+    fn jd((month, day, hour, minute): (u32, u32, u32, u32)) -> f64 {
+        greg2jd(
+            2024,
+            month,
+            day,
+            hour,
+            minute,
+            0.0,
+            CalendarSystem::Gregorian,
+        )
+        .unwrap()
+    }
+
+    fn finds(published: &[(u32, u32, u32, u32)], target: Phase) {
+        let mut after = jd((1, 1, 0, 0));
+
+        for moment in published {
+            let found = next_phase(after, target).unwrap();
+            let hours = (found - jd(*moment)) * 24.0;
+
+            assert!(
+                hours.abs() < TOLERANCE_HOURS,
+                "{moment:?} is {hours} hours off"
+            );
+
+            after = found + 1.0;
+        }
+    }
+
+    #[test]
+    pub fn new_moons_of_2024() {
+        finds(&NEW_MOONS_2024, Phase::New);
+
+        for moment in NEW_MOONS_2024 {
+            let new = phase(jd(moment)).unwrap();
+
+            assert_eq!(Phase::New, new.phase);
+            assert!(new.illuminated < 1e-3, "{moment:?}");
+        }
+    }
+
+    #[test]
+    pub fn full_moons_of_2024() {
+        finds(&FULL_MOONS_2024, Phase::Full);
+
+        for moment in FULL_MOONS_2024 {
+            let full = phase(jd(moment)).unwrap();
+
+            assert_eq!(Phase::Full, full.phase);
+            assert!(full.illuminated > 0.999, "{moment:?}");
+        }
+    }
+
+    #[test]
+    pub fn phases_follow_the_elongation() {
+        let new = next_phase(jd((1, 1, 0, 0)), Phase::New).unwrap();
+        let mut previous: Option<f64> = None;
+
+        for target in Phase::ALL {
+            let moment = next_phase(new, target).unwrap();
+            let LunarPhase {
+                elongation,
+                illuminated,
+                phase: named,
+            } = phase(moment).unwrap();
+
+            assert_eq!(target, named);
+            assert_eq!(target, Phase::from_elongation(target.elongation()));
+            assert!(
+                ((elongation - target.elongation() + 180.0).rem_euclid(360.0) - 180.0).abs() < 1e-4,
+                "{target:?} {elongation}"
+            );
+            assert!(
+                (illuminated - (1.0 - target.elongation().to_radians().cos()) / 2.0).abs() < 1e-4
+            );
+
+            // the phases come in order within a month
+            if let Some(previous) = previous {
+                assert!(moment > previous && moment - new < 30.0, "{target:?}");
+            }
+
+            previous = Some(moment);
+        }
+
+        assert_eq!("Waxing Crescent", Phase::WaxingCrescent.as_ref());
+        assert_eq!(Phase::WaningCrescent, Phase::from_elongation(-10.0 - 22.5));
+        assert_eq!(Ok(new), next_phase(new, Phase::New));
+
+        // just past a new moon, the next one is a month later
+        let next = next_phase(new + 1e-3, Phase::New).unwrap();
+
+        assert!((next - new - 29.53).abs() < 0.5, "{}", next - new);
+    }
+
+    #[test]
+    pub fn phases_need_a_finite_date() {
+        assert_eq!(Err(Error::NonFinite), phase(f64::NAN));
+        assert_eq!(
+            Err(Error::NonFinite),
+            next_phase(f64::INFINITY, Phase::Full)
+        );
+        assert_eq!(Err(Error::NonFinite), elongation(f64::NAN));
+    }
+}
@@ -0,0 +1,99 @@
+#![cfg(all(
+    feature = "earth",
+    feature = "mars",
+    feature = "jupiter",
+    feature = "saturn"
+))]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        kepler::Body,
+        planets::{earth::Earth, jupiter::Jupiter, mars::Mars, saturn::Saturn},
+        position::{separation, sun_offset, Origin},
+    };
+
+    /// Every 100 days for about 30 years, a whole orbit of saturn
+    fn julian_dates() -> impl Iterator<Item = f64> {
+        (0..110).map(|i| 2_451_545.0 + f64::from(i) * 100.0)
+    }
+
+    #[test]
+    pub fn j2000_positions() {
+        // the heliocentric longitudes and distances of J2000
+        for (body, longitude, distance) in [
+            (&Earth as &dyn Body, 100.5, 0.983),
+            (&Mars, 359.4, 1.391),
+            (&Jupiter, 36.3, 4.965),
+            (&Saturn, 45.7, 9.183),
+        ] {
+            let position = body.heliocentric_position(2_451_545.0).unwrap();
+
+            assert!(
+                (position.longitude() - longitude).abs() < 2.0,
+                "{}",
+                body.name()
+            );
+            assert!(
+                (position.distance().0 - distance).abs() < 0.03,
+                "{}",
+                body.name()
+            );
+        }
+
+        assert_eq!(0.0, Earth.heliocentric_position(2_451_545.0).unwrap().z);
+        assert!(Mars.heliocentric_position(2_451_545.0).unwrap().z.abs() < 0.05);
+    }
+
+    #[test]
+    pub fn heliocentric_by_default() {
+        assert_eq!(Origin::Heliocentric, Origin::default());
+        assert_eq!(
+            Mars.heliocentric_position(2_451_545.0),
+            Mars.position(2_451_545.0, Origin::default())
+        );
+    }
+
+    #[test]
+    pub fn sun_offset_stays_small() {
+        for jd in julian_dates() {
+            let offset = sun_offset(jd).unwrap().distance().0;
+
+            // jupiter alone moves the sun by about 0.005 AU
+            assert!((0.001..0.01).contains(&offset), "{jd}: {offset}");
+        }
+    }
+
+    #[test]
+    pub fn sun_offset_at_j2000() {
+        // jupiter (4.965 AU at 36.3 degrees, 1/1047.35 of the sun)
+        // and saturn (9.183 AU at 45.7 degrees, 1/3497.9 of the sun)
+        let offset = sun_offset(2_451_545.0).unwrap();
+
+        assert!((offset.distance().0 - 0.00734).abs() < 0.0001, "{offset:?}");
+        assert!((offset.longitude() - 219.6).abs() < 0.5, "{offset:?}");
+    }
+
+    #[test]
+    pub fn earth_mars_separation() {
+        for jd in julian_dates() {
+            let heliocentric = separation(&Earth, &Mars, jd, Origin::Heliocentric).unwrap();
+            let barycentric = separation(&Earth, &Mars, jd, Origin::Barycentric).unwrap();
+            let sun = Mars.position(jd, Origin::Heliocentric).unwrap();
+            let barycenter = Mars.position(jd, Origin::Barycentric).unwrap();
+
+            // both move by the offset of the sun, so they stay as far apart
+            assert!((0.37..2.68).contains(&heliocentric.0), "{jd}");
+            assert!((heliocentric - barycentric).0.abs() < 1e-12, "{jd}");
+            assert!(
+                (barycenter - sun).distance_to(&sun_offset(jd).unwrap()).0 < 1e-12,
+                "{jd}"
+            );
+        }
+
+        // 2003 August 27, the closest approach in 60000 years was 0.3727 AU
+        let closest = separation(&Earth, &Mars, 2_452_878.9, Origin::Heliocentric).unwrap();
+
+        assert!((closest.0 - 0.3727).abs() < 0.01, "{closest:?}");
+    }
+}
@@ -0,0 +1,55 @@
+"""Run with `maturin develop --extras test && pytest tests/python`"""
+
+import pytest
+
+from rust_solar import Date, Mars, Time
+
+J2000 = 2451545.0
+
+
+def test_to_date():
+    date = Mars().to_date(J2000)
+
+    assert isinstance(date, Date)
+    assert date.era == "AD"
+    assert date.year == 24.0
+    assert 0.0 <= date.ls < 360.0
+    assert str(date).startswith("AD 0024")
+    assert repr(date).startswith("Date(era='AD', year=24")
+
+
+def test_to_datetime():
+    date, time = Mars().to_datetime(J2000, "AMT")
+
+    assert isinstance(date, Date)
+    assert isinstance(time, Time)
+    assert time.code == "AMT"
+    assert 0 <= time.hour < 24
+    assert str(time).endswith("AMT (-12:30)")
+    assert repr(time).startswith("Time(hour=")
+
+
+def test_jd_at_ls():
+    mars = Mars()
+    jd = mars.jd_at_ls(90.0, J2000)
+
+    assert jd >= J2000
+    assert mars.to_date(jd).ls == pytest.approx(90.0, abs=1e-4)
+
+
+def test_errors_are_exceptions():
+    mars = Mars()
+
+    with pytest.raises(ValueError):
+        mars.to_date(float("nan"))
+
+    with pytest.raises(ValueError, match="unknown timezone"):
+        mars.to_datetime(J2000, "Nowhere")
+
+    with pytest.raises(ValueError):
+        mars.jd_at_ls(360.0, J2000)
+
+
+def test_mars_repr():
+    assert repr(Mars()) == "Mars()"
+    assert str(Mars()) == "Mars"
@@ -0,0 +1,111 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        kepler::{Body, Date},
+        planets::{mars::Mars, EARTH_ROTATIONAL_PERIOD},
+    };
+
+    fn sol() -> f64 {
+        Mars.rotational_period() / EARTH_ROTATIONAL_PERIOD
+    }
+
+    #[test]
+    pub fn one_mars_month() {
+        // the first sol of a month in the year 36
+        let dates: Vec<_> = (1..=600)
+            .map(|day| Date::from_ordinal(36.0, day as f64, &Mars).unwrap())
+            .collect();
+        let first = dates
+            .windows(2)
+            .find(|pair| pair[0].month != pair[1].month)
+            .unwrap()[1];
+        let start_jd = Mars.to_julian(&first).unwrap() + sol() / 2.0;
+
+        let days: Vec<_> = Mars
+            .dates_between(start_jd, start_jd + 100.0 * sol(), 1.0)
+            .take_while(|(_, date)| date.month == first.month)
+            .collect();
+
+        assert!(days.len() > 40, "{}", days.len());
+        assert_eq!(first.day, days[0].1.day);
+
+        for pair in days.windows(2) {
+            assert_eq!(pair[0].1.day + 1.0, pair[1].1.day);
+            assert!((pair[1].0 - pair[0].0 - sol()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    pub fn matches_to_dates() {
+        let range: Vec<_> = Mars.dates_between(2451545.0, 2452545.0, 3.5).collect();
+        let julian_dates: Vec<f64> = range.iter().map(|(julian_date, _)| *julian_date).collect();
+        let dates = Mars.to_dates(&julian_dates);
+
+        assert!(range.iter().map(|(_, date)| *date).eq(dates));
+    }
+
+    #[test]
+    pub fn ends_at_or_before_end() {
+        let end = 2451545.0 + 10.5 * sol();
+        let mut range = Mars.dates_between(2451545.0, end, 1.0);
+
+        assert_eq!(11, range.len());
+
+        let last = range.by_ref().last().unwrap().0;
+
+        assert!(last <= end);
+        assert!(end - last < sol());
+
+        // exactly on the end
+        let end = 2451545.0 + 4.0 * sol();
+
+        assert_eq!(
+            end,
+            Mars.dates_between(2451545.0, end, 1.0).next_back().unwrap().0
+        );
+    }
+
+    #[test]
+    pub fn fused() {
+        let mut range = Mars.dates_between(2451545.0, 2451550.0, 1.0);
+
+        assert_eq!(5, range.by_ref().count());
+        assert!(range.next().is_none());
+        assert!(range.next().is_none());
+        assert!(range.next_back().is_none());
+    }
+
+    #[test]
+    pub fn reverse() {
+        let forward: Vec<_> = Mars.dates_between(2451545.0, 2451565.0, 2.0).collect();
+        let backward: Vec<_> = Mars.dates_between(2451565.0, 2451545.0, 2.0).collect();
+
+        assert_eq!(forward.len(), backward.len());
+        assert_eq!(2451565.0, backward[0].0);
+        assert!(backward.last().unwrap().0 >= 2451545.0);
+
+        for pair in backward.windows(2) {
+            assert!(pair[1].0 < pair[0].0);
+            assert!(pair[1].1.day < pair[0].1.day || pair[1].1.year < pair[0].1.year);
+        }
+
+        let rev: Vec<_> = Mars
+            .dates_between(2451545.0, 2451565.0, 2.0)
+            .rev()
+            .collect();
+
+        assert_eq!(forward.last(), rev.first());
+    }
+
+    #[test]
+    pub fn default_step() {
+        let default = Mars.dates_between(2451545.0, 2451555.0, 0.0);
+        let negative = Mars.dates_between(2451545.0, 2451555.0, -1.0);
+
+        assert!(default.eq(Mars.dates_between(2451545.0, 2451555.0, 1.0)));
+        assert!(negative.eq(Mars.dates_between(2451545.0, 2451555.0, f64::NAN)));
+        assert_eq!(1, Mars.dates_between(2451545.0, 2451545.0, 1.0).count());
+    }
+}
@@ -0,0 +1,49 @@
+#![cfg(all(feature = "rayon", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use rayon::ThreadPoolBuilder;
+    use rust_solar::{
+        bodies::{ephemeris_table, registry},
+        planets::mars::Mars,
+    };
+
+    fn julian_dates() -> Vec<f64> {
+        (0..5000).map(|i| 2_400_000.0 + i as f64 * 37.1).collect()
+    }
+
+    #[test]
+    pub fn parallel_matches_serial() {
+        let julian_dates = julian_dates();
+
+        for body in registry() {
+            assert_eq!(
+                body.to_dates(&julian_dates),
+                body.to_dates_par(&julian_dates)
+            );
+        }
+    }
+
+    #[test]
+    pub fn parallel_is_deterministic() {
+        let julian_dates = julian_dates();
+        let one = ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+        let four = ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+        let serial = one.install(|| ephemeris_table(registry(), &julian_dates));
+        let parallel = four.install(|| ephemeris_table(registry(), &julian_dates));
+
+        assert_eq!(serial, parallel);
+        assert_eq!(registry().len(), parallel.len());
+
+        for (body, row) in registry().iter().zip(&parallel) {
+            assert_eq!(body.to_dates(&julian_dates), *row);
+        }
+    }
+
+    #[test]
+    pub fn empty_table() {
+        assert!(ephemeris_table(&[], &[2451545.0]).is_empty());
+        assert!(ephemeris_table(&[&Mars], &[])[0].is_empty());
+    }
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        calendar::month_grid, kepler::Body, planets::mars::Mars, recurrence::Recurrence, Error,
+    };
+
+    #[test]
+    pub fn every_sols_is_exact() {
+        let cleaning = Recurrence::EverySols(30.0)
+            .occurrences(&Mars, 2451545.0, 100)
+            .unwrap();
+        let step = 30.0 * Mars.rotational_period() / 86_400.0;
+
+        assert_eq!(2451545.0, cleaning[0]);
+
+        for (index, julian_date) in cleaning.iter().enumerate() {
+            assert!((julian_date - (2451545.0 + step * index as f64)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    pub fn annual_at_ls_is_an_orbit_apart() {
+        let anniversaries = Recurrence::AnnualAtLs(251.0)
+            .occurrences(&Mars, 2451545.0, 5)
+            .unwrap();
+
+        for julian_date in &anniversaries {
+            assert!((Mars.to_date(*julian_date).ls - 251.0).abs() < 1e-4);
+        }
+
+        for pair in anniversaries.windows(2) {
+            assert!((pair[1] - pair[0] - Mars.orbital_period_days().0).abs() < 1e-4);
+        }
+
+        // the perihelion of 2000 is months after the start
+        assert!(anniversaries[0] > 2451545.0);
+        assert_eq!(
+            Err(Error::InvalidDate(
+                "the solar longitude is outside of 0 to 360"
+            )),
+            Recurrence::AnnualAtLs(360.0).occurrences(&Mars, 2451545.0, 1)
+        );
+    }
+
+    #[test]
+    pub fn monthly_keeps_to_short_months() {
+        let events = Recurrence::Monthly { sol: 60 }
+            .occurrences(&Mars, 2459300.0, 24)
+            .unwrap();
+
+        assert_eq!(24, events.len());
+        assert!(events.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(events[0] >= 2459300.0);
+
+        for julian_date in events {
+            let date = Mars.to_date(julian_date + 0.01);
+            let grid = month_grid(&Mars, date.year, date.month as u32, 7).unwrap();
+            let place = grid
+                .sols
+                .iter()
+                .position(|sol| sol.day as f64 == date.day)
+                .unwrap();
+
+            // the 60th sol, or the last one of a shorter month
+            assert_eq!(59.min(grid.sols.len() - 1), place, "{date:?}");
+        }
+
+        assert_eq!(
+            Err(Error::InvalidDate("the day is outside of the month")),
+            Recurrence::Monthly { sol: 0 }.occurrences(&Mars, 2459300.0, 1)
+        );
+        assert_eq!(
+            Err(Error::InvalidTime(
+                "the sols between events aren't positive"
+            )),
+            Recurrence::EverySols(0.0).occurrences(&Mars, 2459300.0, 1)
+        );
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        kepler::{validate, Body, CustomBody, Rotation},
+        orbit::Perihelion,
+    };
+
+    /// The sidereal rotation of venus in earth days
+    const VENUS_SIDEREAL: f64 = 243.0226;
+
+    /// The orbital period of venus in earth days
+    const VENUS_ORBIT: f64 = 224.701;
+
+    /// A venus, which spins backwards slower than it orbits
+    fn venus() -> CustomBody {
+        let sol = Rotation::Retrograde.solar_day(VENUS_SIDEREAL, VENUS_ORBIT);
+
+        CustomBody::builder()
+            .name("Venus")
+            .epoch(2_451_545.0)
+            .orbital_eccentricity(0.0068)
+            .orbital_period(VENUS_ORBIT / sol)
+            .rotational_period(sol * 86_400.0)
+            .rotation_direction(Rotation::Retrograde)
+            .semimajor(0.723)
+            .perihelion(Perihelion::new((0.5, 0.6), (120.0, 140.0), 131.0).unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    pub fn venus_solar_day() {
+        let sol = Rotation::Retrograde.solar_day(VENUS_SIDEREAL, VENUS_ORBIT);
+
+        assert!((sol - 116.75).abs() < 0.01, "{sol}");
+        // a prograde venus would have a sol of almost five years
+        assert!(Rotation::Prograde.solar_day(VENUS_SIDEREAL, VENUS_ORBIT) > 1_700.0);
+        assert!(validate(&venus()).is_empty());
+    }
+
+    #[test]
+    pub fn earth_solar_day() {
+        let sol = Rotation::Prograde.solar_day(86_164.090_5, 365.256_363 * 86_400.0);
+
+        assert!((sol - 86_400.0).abs() < 1.0, "{sol}");
+        assert_eq!(Rotation::default(), Rotation::Prograde);
+        assert_eq!(Rotation::Retrograde.as_ref(), "Retrograde");
+    }
+
+    #[test]
+    pub fn retrograde_local_times_go_backwards_to_the_east() {
+        let venus = venus();
+        let jd = 2_451_600.25;
+
+        assert_eq!(venus.rotation_direction(), Rotation::Retrograde);
+
+        // a quarter of the way east is a quarter of a sol earlier
+        let prime = venus.lmst(0.0, jd);
+        let east = venus.lmst(90.0, jd);
+        let hours = |time: &rust_solar::kepler::Time| {
+            time.hour as f64 + time.minute as f64 / 60.0 + time.second as f64 / 3_600.0
+        };
+
+        assert!(((hours(&prime) - hours(&east)).rem_euclid(24.0) - 6.0).abs() < 0.001);
+    }
+
+    #[test]
+    pub fn subsolar_longitude_follows_the_rotation() {
+        let venus = venus();
+        let jd = 2_451_600.25;
+        let later = jd + 1.0;
+
+        // the subsolar point is where it's noon
+        let lmst = venus.lmst(venus.subsolar_longitude(jd), jd);
+
+        assert_eq!((lmst.hour, lmst.minute), (12, 0));
+
+        // the sun of venus moves east, about 3 degrees an earth day
+        let moved = venus.subsolar_longitude(later) - venus.subsolar_longitude(jd);
+
+        assert!((moved - 360.0 / 116.75).abs() < 0.01, "{moved}");
+
+        let earthlike = CustomBody::builder()
+            .orbital_eccentricity(0.0167)
+            .orbital_period(365.25)
+            .rotational_period(86_400.0)
+            .semimajor(1.0)
+            .perihelion(Perihelion::new((0.0, 10.0), (270.0, 300.0), 283.0).unwrap())
+            .build()
+            .unwrap();
+
+        // a prograde sun moves west, and it's overhead where the lmst is noon
+        let moved = earthlike.subsolar_longitude(jd + 0.25) - earthlike.subsolar_longitude(jd);
+        let lmst = earthlike.lmst(earthlike.subsolar_longitude(jd), jd);
+
+        assert!((moved + 90.0).abs() < 1e-6, "{moved}");
+        assert_eq!((lmst.hour, lmst.minute), (12, 0));
+    }
+}
@@ -0,0 +1,270 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        kepler::{Body, CustomBody, Date},
+        orbit::{Hemisphere, Season, SeasonTable},
+        planets::mars::Mars,
+        Error,
+    };
+
+    /// Mars, but with the dust storm season
+    fn dusty_mars() -> CustomBody {
+        CustomBody::builder()
+            .name("Dusty Mars")
+            .epoch(Mars.epoch())
+            .epoch_year(Mars.epoch_year())
+            .orbital_eccentricity(Mars.orbital_eccentricity())
+            .orbital_period(Mars.orbital_period())
+            .rotational_period(Mars.rotational_period())
+            .semimajor(Mars.semimajor())
+            .perihelion(Mars.perihelion())
+            .season_table(dust_table().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    fn dust_table() -> Result<SeasonTable, Error> {
+        SeasonTable::new(vec![
+            (0.0, 180.0, Season::Custom("Clear")),
+            (180.0, 330.0, Season::Custom("Dusty")),
+            (330.0, 360.0, Season::Custom("Clear")),
+        ])
+    }
+
+    #[test]
+    pub fn season_boundaries() {
+        assert_eq!(Season::from_ls(0.0, Hemisphere::North), Season::VernalEquinox);
+        assert_eq!(Season::from_ls(90.0, Hemisphere::North), Season::SummerSolstice);
+        assert_eq!(Season::from_ls(180.0, Hemisphere::North), Season::AutumnEquinox);
+        assert_eq!(Season::from_ls(270.0, Hemisphere::North), Season::WinterSolstice);
+        assert_eq!(Season::from_ls(360.5, Hemisphere::North), Season::Unknown);
+    }
+
+    #[test]
+    pub fn season_is_not_truncated() {
+        assert_eq!(Season::from_ls(89.9, Hemisphere::North), Season::VernalEquinox);
+        assert_eq!(Season::from_ls(90.0, Hemisphere::North), Season::SummerSolstice);
+        assert_eq!(Season::from_ls(269.99, Hemisphere::North), Season::AutumnEquinox);
+    }
+
+    #[test]
+    pub fn date_season_str() {
+        let date = Mars.to_date(2451545.0);
+
+        assert_eq!(date.season, Season::WinterSolstice);
+        assert_eq!(date.season_str(), "Winter Solstice");
+    }
+
+    #[test]
+    pub fn southern_hemisphere_seasons() {
+        assert_eq!(Season::from_ls(0.0, Hemisphere::North), Season::VernalEquinox);
+        assert_eq!(Season::from_ls(0.0, Hemisphere::South), Season::AutumnEquinox);
+        assert_eq!(Season::from_ls(100.0, Hemisphere::South), Season::WinterSolstice);
+        assert_eq!(Season::from_ls(251.5, Hemisphere::South), Season::Perihelion);
+    }
+
+    #[test]
+    pub fn both_hemispheres_cycle_through_the_year() {
+        let seasons = [
+            Season::VernalEquinox,
+            Season::SummerSolstice,
+            Season::AutumnEquinox,
+            Season::WinterSolstice,
+        ];
+
+        for hemisphere in [Hemisphere::North, Hemisphere::South] {
+            let mut seen = Vec::new();
+
+            for sol in 0..669 {
+                let date = Mars.to_date_in(Mars.epoch() + sol as f64 * 1.0275, hemisphere);
+
+                if !seen.contains(&date.season) {
+                    seen.push(date.season);
+                }
+            }
+
+            assert!(seasons.iter().all(|season| seen.contains(season)));
+        }
+    }
+
+    #[test]
+    pub fn default_season_table_is_the_northern_seasons() {
+        let table = SeasonTable::default();
+
+        for tenth in 0..3600 {
+            let ls = tenth as f64 / 10.0;
+
+            assert_eq!(table.season(ls), Season::from_ls(ls, Hemisphere::North));
+        }
+    }
+
+    #[test]
+    pub fn custom_season_table() {
+        // Ls 274.3 and Ls 172.8
+        let dusty = dusty_mars().to_date(2451545.0);
+        let clear = dusty_mars().to_date(2458248.5);
+
+        assert_eq!(dusty.season_str(), "Dusty");
+        assert_eq!(clear.season_str(), "Clear");
+        assert_eq!(Mars.to_date(2451545.0).season, Season::WinterSolstice);
+    }
+
+    #[test]
+    pub fn invalid_season_tables() {
+        let gap = SeasonTable::new(vec![
+            (0.0, 170.0, Season::Custom("Clear")),
+            (180.0, 360.0, Season::Custom("Dusty")),
+        ]);
+        let overlap = SeasonTable::new(vec![
+            (0.0, 190.0, Season::Custom("Clear")),
+            (180.0, 360.0, Season::Custom("Dusty")),
+        ]);
+        let short = SeasonTable::new(vec![(0.0, 350.0, Season::Custom("Clear"))]);
+
+        assert!(dust_table().is_ok());
+        assert!(matches!(gap, Err(Error::InvalidSeasonTable(_))));
+        assert!(matches!(overlap, Err(Error::InvalidSeasonTable(_))));
+        assert!(matches!(short, Err(Error::InvalidSeasonTable(_))));
+    }
+
+    #[test]
+    pub fn jd_at_ls_reaches_the_solar_longitude() {
+        for target in [0.0, 71.5, 90.0, 180.0, 251.5, 359.5] {
+            let jd = Mars.jd_at_ls(target, 2451545.0).unwrap();
+            let ls = Mars.to_date(jd).ls;
+
+            assert!(jd >= 2451545.0);
+            assert!(jd < 2451545.0 + 687.0);
+            assert!((ls - target).abs() < 1e-4 || (ls - target).abs() > 359.9999);
+        }
+    }
+
+    #[test]
+    pub fn jd_at_ls_is_a_mars_year_apart() {
+        let first = Mars.jd_at_ls(90.0, 2451545.0).unwrap();
+        let second = Mars.jd_at_ls(90.0, first + 1.0).unwrap();
+
+        assert!((second - first - 668.6 * 88_775.245 / 86_400.0).abs() < 1e-6);
+    }
+
+    #[test]
+    pub fn jd_at_ls_rejects_invalid_input() {
+        assert_eq!(Err(Error::NonFinite), Mars.jd_at_ls(f64::NAN, 2451545.0));
+        assert_eq!(Err(Error::NonFinite), Mars.jd_at_ls(90.0, f64::INFINITY));
+        assert!(matches!(
+            Mars.jd_at_ls(360.0, 2451545.0),
+            Err(Error::InvalidDate(_))
+        ));
+        assert!(matches!(
+            Mars.jd_at_ls(-1.0, 2451545.0),
+            Err(Error::InvalidDate(_))
+        ));
+    }
+
+    #[test]
+    pub fn season_lengths() {
+        let lengths = Mars.season_lengths();
+        let total: f64 = lengths.iter().sum();
+
+        assert!((total - Mars.orbital_period_sols()).abs() < 1e-6, "{total}");
+
+        // the published lengths of the northern seasons of mars, in sols
+        for (length, published) in lengths.iter().zip([194.0, 178.0, 142.0, 154.0]) {
+            assert!((length - published).abs() < 1.5, "{lengths:?}");
+        }
+    }
+
+    #[test]
+    pub fn countdown_to_the_next_season() {
+        // (boundary, season before it, next season after it)
+        let boundaries = [
+            (0.0, Season::VernalEquinox, Season::Aphelion),
+            (90.0, Season::SummerSolstice, Season::AutumnEquinox),
+            (180.0, Season::AutumnEquinox, Season::Perihelion),
+            (270.0, Season::WinterSolstice, Season::VernalEquinox),
+        ];
+        // an hour
+        let hour = 1.0 / 24.0;
+
+        for (ls, starting, following) in boundaries {
+            let boundary = Mars.jd_at_ls(ls, 2459000.5).unwrap();
+
+            // just before the boundary, it comes within the hour
+            let (season, change) = Mars.next_season_change(boundary - hour).unwrap();
+            let sols = Mars
+                .sols_until_next_season(boundary - hour)
+                .unwrap()
+                .as_sols();
+
+            assert_eq!(starting, season, "{ls}");
+            assert!((change - boundary).abs() < 1e-6, "{ls}");
+            assert!(sols > 0.0 && sols < 0.05, "{ls} {sols}");
+
+            // just after it, the next one is a few sols to a season away
+            let (season, change) = Mars.next_season_change(boundary + hour).unwrap();
+            let sols = Mars
+                .sols_until_next_season(boundary + hour)
+                .unwrap()
+                .as_sols();
+
+            assert_eq!(following, season, "{ls}");
+            assert!(change > boundary + hour, "{ls}");
+            assert!(sols > 1.0 && sols < 200.0, "{ls} {sols}");
+        }
+    }
+
+    #[test]
+    pub fn countdown_wraps_into_the_next_orbit() {
+        // late in the northern winter, the spring is in the next orbit
+        let winter = Mars.jd_at_ls(359.0, 2459000.5).unwrap();
+        let (season, change) = Mars.next_season_change(winter).unwrap();
+
+        assert_eq!(Season::VernalEquinox, season);
+        assert!(Mars.to_date(change + 0.01).ls < 1.0);
+        assert!(Mars.sols_until_next_season(winter).unwrap().as_sols() > 0.0);
+
+        // the clear season runs from 330 through the start of the orbit to 180
+        let dusty_mars = dusty_mars();
+        let (season, change) = dusty_mars.next_season_change(winter).unwrap();
+
+        assert_eq!(Season::Custom("Dusty"), season);
+        assert!((dusty_mars.to_date(change).ls - 180.0).abs() < 1e-4);
+        assert_eq!(
+            Some((180.0, Season::Custom("Dusty"))),
+            dust_table().unwrap().next_change(340.0)
+        );
+        assert_eq!(
+            None,
+            SeasonTable::new(vec![(0.0, 360.0, Season::Custom("Always"))])
+                .unwrap()
+                .next_change(10.0)
+        );
+    }
+
+    #[test]
+    pub fn ls_stays_below_360_at_the_wrap() {
+        let wrap = Mars.jd_at_ls(0.0, 2459000.5).unwrap();
+
+        // a nanoday either side of the wrap, in steps of a hundredth of it
+        for step in -100..=100 {
+            let date = Mars.to_date(wrap + step as f64 * 1e-11);
+            let ls = date.ls_precise();
+
+            assert!((0.0..360.0).contains(&ls), "{step} {ls}");
+            assert!((1.0..=12.0).contains(&date.month), "{step} {}", date.month);
+            assert_eq!(Season::from_ls(ls, Hemisphere::North), date.season);
+            assert_eq!(date.ls, ls);
+        }
+
+        // a tenth of a degree before the wrap is shown as the wrap
+        let late = Date {
+            ls: 359.97,
+            ..Date::default()
+        };
+
+        assert!(late.to_string().contains("Ls 0.0"), "{late}");
+        assert_eq!(359.97, late.ls_precise());
+    }
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        julian::SplitJd,
+        kepler::{Time, TimeZone},
+        planets::mars::{mars_sol_date, Martian, MARS_CALIBRATION},
+    };
+
+    const SECONDS_PER_SOL: f64 = MARS_CALIBRATION.sol_length * 86400.0;
+
+    #[test]
+    pub fn split_jd_arithmetic() {
+        let jd = SplitJd::new(2451545.0, 1.25);
+
+        assert_eq!(
+            jd,
+            SplitJd {
+                day: 2451546.0,
+                frac: 0.25
+            }
+        );
+        assert_eq!(jd.value(), 2451546.25);
+        assert_eq!(
+            SplitJd::from(2451545.75),
+            SplitJd {
+                day: 2451545.0,
+                frac: 0.75
+            }
+        );
+        assert_eq!(f64::from(SplitJd::from(2451545.75)), 2451545.75);
+        assert_eq!(
+            jd.add_days(-0.5),
+            SplitJd {
+                day: 2451545.0,
+                frac: 0.75
+            }
+        );
+        assert_eq!(jd.add_days(2.5) - jd, 2.5);
+
+        // 100µs is kept to the nanosecond, next to a julian date of millions of days
+        let later = jd.add_seconds(1e-4);
+        assert!(((later - jd) * 86400.0 - 1e-4).abs() < 1e-9);
+    }
+
+    #[test]
+    pub fn split_jd_keeps_sub_millisecond_time() {
+        let start = SplitJd::new(2460000.0, 0.3);
+        let mut previous = Martian::MTC.at_split(start);
+
+        for step in 1..=100 {
+            let jd = start.add_seconds(f64::from(step) * 1e-4);
+            let elapsed = (mars_sol_date(jd) - mars_sol_date(start)) * SECONDS_PER_SOL;
+            let time = Martian::MTC.at_split(jd);
+
+            // a f64 julian date only resolves about 40µs here, so its steps are uneven and may collide
+            assert!(
+                (elapsed - f64::from(step) * 1e-4).abs() < 2e-6,
+                "{step} {elapsed}"
+            );
+
+            let tick = (i64::from(time.second) * 1000 + i64::from(time.millisecond))
+                - (i64::from(previous.second) * 1000 + i64::from(previous.millisecond));
+            assert!(tick == 0 || tick == 1, "{step} {time:?}");
+
+            previous = time;
+        }
+
+        // 10ms on earth is about 9.73ms on a mars clock, which ticks slower
+        let first = Martian::MTC.at_split(start);
+        let end = Martian::MTC.at_split(start.add_seconds(1e-2));
+        let ticks = (i64::from(end.second) * 1000 + i64::from(end.millisecond))
+            - (i64::from(first.second) * 1000 + i64::from(first.millisecond));
+
+        assert!(ticks == 9 || ticks == 10, "{ticks}");
+    }
+
+    #[test]
+    pub fn instants_100us_apart_get_their_own_millisecond() {
+        let millis = |time: Time| {
+            (i64::from(time.minute) * 60 + i64::from(time.second)) * 1000
+                + i64::from(time.millisecond)
+        };
+        let start = SplitJd::new(2460000.0, 0.3);
+
+        // the clock rounds to the nearest millisecond, so it flips halfway through one
+        let ms = mars_sol_date(start).fract() * 86_400_000.0;
+        let to_flip = (ms.floor() + 0.5 - ms) / 86_400_000.0 * MARS_CALIBRATION.sol_length;
+        let before = start.add_days(to_flip).add_seconds(-5e-5);
+        let after = before.add_seconds(1e-4);
+
+        assert_eq!(
+            1,
+            millis(Martian::MTC.at_split(after)) - millis(Martian::MTC.at_split(before))
+        );
+
+        // a f64 julian date puts both instants on a grid of about 40µs, so they may land on the same millisecond
+        let apart = (after.value() - before.value()) * 86_400.0;
+
+        assert!((apart - 1e-4).abs() > 1e-6, "{apart}");
+    }
+
+    #[test]
+    pub fn split_jd_matches_f64() {
+        for jd in [2440587.5, 2451545.0, 2459000.5, 2460000.123] {
+            assert!((mars_sol_date(SplitJd::from(jd)) - mars_sol_date(jd)).abs() < 1e-12);
+            assert_eq!(
+                format!("{:.3}", Martian::MTCp2.at(jd)),
+                format!("{:.3}", Martian::MTCp2.at_split(jd.into()))
+            );
+        }
+    }
+}
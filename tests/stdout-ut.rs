@@ -0,0 +1,47 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process::Command};
+
+    use rust_solar::{
+        julian::{days_since_j2000, get_jd},
+        kepler::{Body, TimeZone},
+        planets::mars::{Mars, Martian},
+    };
+
+    const CHILD: &str = "RUST_SOLAR_STDOUT_CHILD";
+
+    /// Runs the hot paths, only when spawned by [`hot_paths_are_silent`]
+    #[test]
+    pub fn hot_paths_child() {
+        if env::var(CHILD).is_err() {
+            return;
+        }
+
+        println!("<begin>");
+        Mars.to_date(2451545.0);
+        Mars.to_datetime(2451545.0, &Martian::MTCp3);
+        Martian::MTC.at(2451545.0);
+        #[cfg(feature = "std")]
+        Martian::MTC.new();
+        days_since_j2000(2024, 1, 1, 0.0);
+        get_jd(2024, 1, 1, 0.0);
+        println!("<end>");
+    }
+
+    #[test]
+    pub fn hot_paths_are_silent() {
+        let output = Command::new(env::current_exe().unwrap())
+            .args(["tests::hot_paths_child", "--exact", "--nocapture"])
+            .env(CHILD, "1")
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let begin = stdout.find("<begin>").unwrap() + "<begin>".len();
+        let end = stdout.find("<end>").unwrap();
+
+        assert!(output.status.success());
+        assert_eq!(stdout[begin..end].trim(), "");
+    }
+}
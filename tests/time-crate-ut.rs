@@ -0,0 +1,82 @@
+#![cfg(all(feature = "time", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        julian::{from_odt, to_odt, SplitJd},
+        kepler::{Body, TimeZone},
+        planets::mars::{Mars, Martian},
+    };
+    use time::{macros::datetime, Duration, OffsetDateTime};
+
+    fn instants() -> [OffsetDateTime; 4] {
+        [
+            datetime!(1969-07-20 20:17:40.5 UTC),
+            datetime!(2000-01-01 12:00 UTC),
+            datetime!(2012-08-06 05:17:57.123456789 UTC),
+            datetime!(2024-02-29 23:59:59.999999 -5),
+        ]
+    }
+
+    #[test]
+    pub fn unix_epoch_from_odt() {
+        assert_eq!(from_odt(OffsetDateTime::UNIX_EPOCH), 2440587.5);
+        assert_eq!(to_odt(2440587.5), Ok(OffsetDateTime::UNIX_EPOCH));
+    }
+
+    #[test]
+    pub fn odt_round_trip() {
+        for instant in instants() {
+            let split = to_odt(SplitJd::from(instant)).unwrap();
+            let single = to_odt(from_odt(instant)).unwrap();
+
+            // a split julian date keeps the nanoseconds, a f64 keeps about 40µs
+            assert!(
+                (split - instant).abs() <= Duration::nanoseconds(100),
+                "{split}"
+            );
+            assert!(
+                (single - instant).abs() <= Duration::microseconds(50),
+                "{single}"
+            );
+        }
+    }
+
+    #[test]
+    pub fn leap_second_day_converts() {
+        // 2016-12-31 ended with a leap second, which unix time doesn't count
+        let instant = datetime!(2016-12-31 23:59:59 UTC);
+        let jd = from_odt(instant);
+
+        assert!((jd - 2457754.499988426).abs() < 1e-9);
+        assert_eq!(to_odt(SplitJd::from(instant)), Ok(instant));
+        assert_eq!(Mars.date_at_odt(instant), Mars.to_date(jd));
+    }
+
+    #[test]
+    pub fn bodies_and_timezones_from_odt() {
+        for instant in instants() {
+            let jd = from_odt(instant);
+
+            assert_eq!(Mars.date_at_odt(instant), Mars.to_date(jd));
+            assert_eq!(
+                format!("{:.3}", Martian::MTCn3.at_odt(instant)),
+                format!("{:.3}", Martian::MTCn3.at_split(instant.into()))
+            );
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    pub fn time_and_chrono_agree() {
+        use rust_solar::julian::from_chrono;
+
+        for instant in instants() {
+            let chrono =
+                chrono::DateTime::from_timestamp(instant.unix_timestamp(), instant.nanosecond())
+                    .unwrap();
+
+            assert_eq!(from_odt(instant), from_chrono(chrono));
+        }
+    }
+}
@@ -0,0 +1,200 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        clock::ZoneInfo,
+        kepler::{Body, HourType, Time, TimeZone},
+        planets::mars::{Mars, Martian},
+    };
+
+    fn clock(hour: i32, minute: u8, second: u8, hours_per_day: u8) -> Time {
+        Time {
+            hour,
+            minute,
+            second,
+            hours_per_day,
+            ..Time::default()
+        }
+    }
+
+    #[test]
+    pub fn add_seconds_wraps_on_24_hour_body() {
+        let time = clock(23, 59, 30, 24).add_seconds(3661);
+
+        assert_eq!((time.hour, time.minute, time.second), (1, 0, 31));
+        assert_eq!(time.hour_type, HourType::AM);
+    }
+
+    #[test]
+    pub fn add_seconds_carries_on_25_hour_sol() {
+        // 25:00:31 is past the end of the sol
+        let time = clock(23, 59, 30, 25).add_seconds(3661);
+
+        assert_eq!((time.hour, time.minute, time.second), (0, 0, 31));
+
+        let time = clock(23, 59, 30, 25).add_seconds(60);
+
+        assert_eq!((time.hour, time.minute, time.second), (24, 0, 30));
+    }
+
+    #[test]
+    pub fn add_negative_seconds() {
+        let time = clock(0, 0, 10, 24).add_seconds(-3661);
+
+        assert_eq!((time.hour, time.minute, time.second), (22, 59, 9));
+
+        let time = clock(0, 0, 10, 25).add_minutes(-1);
+
+        assert_eq!((time.hour, time.minute, time.second), (24, 59, 10));
+    }
+
+    #[test]
+    pub fn add_minutes_and_hours() {
+        let time = clock(10, 30, 0, 24).add_minutes(45).add_hours(-11);
+
+        assert_eq!((time.hour, time.minute, time.second), (0, 15, 0));
+    }
+
+    #[test]
+    pub fn normalize_denormalized_time() {
+        let mut time = clock(5, 72, 61, 24);
+        time.normalize();
+
+        assert_eq!((time.hour, time.minute, time.second), (6, 13, 1));
+    }
+
+    #[test]
+    pub fn from_sol_fraction_carries_rounding() {
+        // 13:05:59.9996 rounds into the next minute
+        let time = Time::from_sol_fraction((13.0 * 3600.0 + 5.0 * 60.0 + 59.9996) / 86400.0);
+
+        assert_eq!(
+            (time.hour, time.minute, time.second, time.millisecond),
+            (13, 6, 0, 0)
+        );
+
+        // 23:59:59.9999 rounds into the next sol
+        let time = Time::from_sol_fraction(1.0 - 0.0001 / 86400.0);
+
+        assert_eq!(
+            (time.hour, time.minute, time.second, time.millisecond),
+            (0, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    pub fn mars_time_is_always_in_range() {
+        for step in 0..10_000 {
+            // steps of 0.0005 seconds so that some land on rounding boundaries
+            let time = Martian::MTC.at(2451545.0 + f64::from(step) * 0.0005 / 86400.0);
+
+            assert!((0..24).contains(&time.hour));
+            assert!(time.minute < 60);
+            assert!(time.second < 60);
+            assert!(time.millisecond < 1000);
+        }
+    }
+
+    #[test]
+    pub fn display_milliseconds() {
+        let time = Time {
+            millisecond: 123,
+            zone: Some(ZoneInfo {
+                code: "MTC",
+                name: "Mars Coordinated Time",
+                offset: 0.0,
+                east: -180.0,
+                west: 180.0,
+            }),
+            ..clock(13, 7, 42, 24)
+        };
+
+        assert_eq!(time.to_string(), "13:07:42 MTC (+00:00)");
+        assert_eq!(format!("{time:.3}"), "13:07:42.123 MTC (+00:00)");
+        assert_eq!(format!("{time:.1}"), "13:07:42.1 MTC (+00:00)");
+    }
+
+    #[test]
+    pub fn times_sort_across_zones() {
+        // from 00:53 NT, a 13th of a sol apart, so all of them are within the sol
+        let sol = Mars.rotational_period() / 86_400.0;
+        let start = 2451545.0 - 14.0 / 24.0 * sol;
+        let zones: Vec<Martian> = Martian::iter().collect();
+        let chronological: Vec<Time> = (0..12)
+            .map(|index| zones[index * 5 % zones.len()].at(start + index as f64 * sol / 13.0))
+            .collect();
+        let mut log = chronological.clone();
+
+        log.reverse();
+        log.rotate_left(5);
+        log.sort();
+
+        assert_eq!(chronological, log);
+        assert!(chronological.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // the same moment in two zones, and two clocks of different lengths
+        assert_eq!(Martian::MTCn5.at(start), Martian::MTCp4.at(start));
+        assert!(clock(23, 0, 0, 24) < clock(1, 0, 0, 25));
+    }
+
+    #[test]
+    pub fn times_are_copied_without_text() {
+        fn copied<T: Copy>(value: T) -> (T, T) {
+            (value, value)
+        }
+
+        // the four strings of the timezone alone were 96 bytes
+        assert!(std::mem::size_of::<Time>() < 4 * std::mem::size_of::<String>());
+
+        let (time, again) = copied(Martian::MTCp1.at(2451545.0));
+
+        assert_eq!(time.to_string(), again.to_string());
+        assert_eq!(
+            ("ABT", "Arabia Time", "MTCp1", 2.5),
+            (time.code(), time.name(), time.offset_name, time.offset())
+        );
+        assert_eq!(HourType::PM, time.hour_type);
+        assert_eq!("17:23:02 ABT (+02:30)", time.to_string());
+        assert_eq!(
+            ("", 0.0),
+            (Time::default().code(), Time::default().offset())
+        );
+    }
+
+    #[test]
+    pub fn fractional_hours_are_continuous() {
+        // an hour of the clock is a 24th of the sol, so a second of earth is a bit less than one
+        let second = 1.0 / 86_400.0;
+        let rate = 24.0 / Mars.rotational_period();
+
+        for zone in Martian::iter() {
+            for julian_date in [2451545.0, 2451545.123, 2459580.5, 2460000.987] {
+                let hours = zone.fractional_hours(julian_date);
+                let time = zone.at(julian_date);
+                let derivative = zone.fractional_hours(julian_date + second) - hours;
+
+                assert!((0.0..24.0).contains(&hours), "{hours}");
+                assert!((derivative / rate - 1.0).abs() < 1e-4, "{derivative}");
+                assert_eq!(
+                    (time.hour, time.minute, time.second),
+                    (
+                        hours.floor() as i32,
+                        ((hours * 60.0).floor() as i64 % 60) as u8,
+                        ((hours * 3600.0).floor() as i64 % 60) as u8
+                    ),
+                    "{julian_date} {zone:?}"
+                );
+            }
+        }
+
+        let julian_date = 2451545.0;
+
+        assert!(
+            (Martian::MTC.fractional_hours(julian_date) - Mars.sol_fraction(julian_date) * 24.0)
+                .abs()
+                < 1e-9
+        );
+        assert!((0.0..1.0).contains(&Mars.sol_fraction(julian_date)));
+    }
+}
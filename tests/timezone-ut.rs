@@ -0,0 +1,165 @@
+#![cfg(all(feature = "luna", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        bodies::find,
+        kepler::{Body, Time, TimeZone},
+        moons::luna::{Luna, Lunar},
+        planets::mars::{Mars, Martian},
+        Error,
+    };
+
+    #[test]
+    pub fn martian_zones_are_unchanged() {
+        // (code, name, offset, east, west) from before the zones used the macro
+        let props = [
+            ("AMT", "Amazonis Time", -12.5, -180.0, -162.0),
+            ("OT", "Olympus Time", -10.0, -162.0, -126.0),
+            ("TT", "Tharsis Time", -7.5, -126.0, -90.0),
+            ("MT", "Marineris Time", -5.0, -90.0, -54.0),
+            ("AGT", "Argyre Time", -2.5, -54.0, -18.0),
+            ("NT", "Noachis Time", 0.0, -18.0, 18.0),
+            ("ABT", "Arabia Time", 2.5, 18.0, 54.0),
+            ("HT", "Hellas Time", 5.0, 54.0, 90.0),
+            ("UT", "Utopia Time", 7.5, 90.0, 126.0),
+            ("ET", "Elysium Time", 10.0, 126.0, 162.0),
+            ("ACT", "Arcadia Time", 12.5, 162.0, 180.0),
+        ];
+
+        assert_eq!(Martian::iter().count(), props.len());
+
+        for (zone, (code, name, offset, east, west)) in Martian::iter().zip(props) {
+            let info = zone.info();
+            let time = zone.at(2451545.0);
+
+            assert_eq!(
+                (info.code, info.name, info.offset, info.east, info.west),
+                (code, name, offset, east, west)
+            );
+            assert_eq!((time.code(), time.name()), (code, name));
+            assert_eq!(time.offset_name, zone.as_ref());
+        }
+
+        assert_eq!(Martian::default(), Martian::MTC);
+        assert_eq!(
+            Martian::MTC.at(2451545.0).to_string(),
+            "14:53:02 NT (+00:00)"
+        );
+    }
+
+    #[test]
+    pub fn zones_from_longitude() {
+        assert_eq!(Martian::from_longitude(0.0), Some(Martian::MTC));
+        assert_eq!(Martian::from_longitude(137.4), Some(Martian::MTCp4));
+        assert_eq!(Martian::from_longitude(-137.4), Some(Martian::MTCn4));
+        assert_eq!(Martian::from_longitude(222.6), Some(Martian::MTCn4));
+        assert_eq!(Martian::from_longitude(180.0), Some(Martian::MTCn5));
+        assert_eq!(Martian::from_longitude(f64::NAN), None);
+        assert_eq!(Lunar::from_longitude(100.0), Some(Lunar::LTCp1));
+    }
+
+    #[test]
+    pub fn zones_from_str() {
+        assert_eq!("MTCp2".parse(), Ok(Martian::MTCp2));
+        assert_eq!("ht".parse(), Ok(Martian::MTCp2));
+        assert_eq!("LTC".parse(), Ok(Lunar::LTC));
+        assert_eq!("Mars".parse::<Martian>(), Err(Error::UnknownTimeZone));
+    }
+
+    #[test]
+    pub fn offsets_in_hh_mm() {
+        let rendered = [
+            "-12:30", "-10:00", "-07:30", "-05:00", "-02:30", "+00:00", "+02:30", "+05:00",
+            "+07:30", "+10:00", "+12:30",
+        ];
+
+        for (zone, offset) in Martian::iter().zip(rendered) {
+            let info = zone.info();
+
+            assert_eq!(offset, info.offset_string());
+            assert_eq!(Ok(zone), format!("MTC{offset}").parse());
+            assert_eq!(Ok(zone), offset.parse());
+            assert!(zone
+                .at(2451545.0)
+                .to_string()
+                .ends_with(&format!("{} ({offset})", info.code)));
+        }
+
+        assert_eq!("mtc+02:30".parse(), Ok(Martian::MTCp1));
+        assert_eq!("+04:48".parse(), Ok(Lunar::LTCp1));
+        assert_eq!("-09:36", Lunar::LTCn2.info().offset_string());
+        assert_eq!("LTC+02:30".parse::<Martian>(), Err(Error::UnknownTimeZone));
+        assert_eq!("MTC+03:00".parse::<Martian>(), Err(Error::UnknownTimeZone));
+        assert_eq!("MTC+2:3:0".parse::<Martian>(), Err(Error::UnknownTimeZone));
+        assert!(find("mars").unwrap().timezone("MTC-12:30").is_some());
+    }
+
+    #[test]
+    pub fn elapsed_between_zones() {
+        let amt = Time {
+            hour: 23,
+            zone: Some(Martian::MTCn5.info()),
+            ..Time::default()
+        };
+        let et = Time {
+            hour: 1,
+            minute: 30,
+            zone: Some(Martian::MTCp4.info()),
+            ..Time::default()
+        };
+
+        // 11:30 and 15:30 at the prime meridian
+        assert_eq!("11:30:00", amt.to_mtc().to_string());
+        assert_eq!("15:30:00", et.to_mtc().to_string());
+        assert_eq!(4.0 / 24.0, et.elapsed_since(&amt, None).as_sols());
+        assert_eq!(20.0 / 24.0, amt.elapsed_since(&et, None).as_sols());
+
+        // the same moment in two zones, and a sol and a bit later with the dates
+        let (start, end) = (
+            2459580.5,
+            2459580.5 + 1.2 * Mars.rotational_period() / 86_400.0,
+        );
+        let before = Martian::MTCn5.at(start);
+
+        assert_eq!(
+            0.0,
+            Martian::MTCp4
+                .at(start)
+                .elapsed_since(&before, None)
+                .as_sols()
+        );
+
+        let elapsed = Martian::MTCp4.at(end).elapsed_since(
+            &before,
+            Some((&Mars.to_date(end), &Mars.to_date(start), &Mars)),
+        );
+
+        assert_eq!(1, elapsed.sols);
+        assert!((elapsed.as_sols() - 1.2).abs() < 1e-6, "{elapsed}");
+        assert_eq!(Martian::MTC.at(start).offset(), 0.0);
+        assert_eq!(before.offset(), -12.5);
+    }
+
+    #[test]
+    pub fn lunar_time_follows_the_phases() {
+        // 2000 January 6 (New Moon) and 2000 January 21 (Full Moon)
+        let new = Lunar::LTC.at(2451550.26);
+        let full = Lunar::LTC.at(2451564.69);
+
+        assert!(new.hour == 23 || new.hour == 0, "{new}");
+        assert!(full.hour == 11 || full.hour == 12, "{full}");
+        assert_eq!(new.code(), "MDT");
+
+        // the far side is about half a sol away
+        let far = Lunar::LTCp2.at(2451550.26);
+        assert!(far.hour == 9 || far.hour == 10, "{far}");
+    }
+
+    #[test]
+    pub fn luna_is_a_body() {
+        assert_eq!(find("luna").unwrap().name(), "Luna");
+        assert_eq!(Luna.to_date(Luna.epoch()).year, 1.0);
+        assert!((Luna.orbital_period() - 12.37).abs() < 0.01);
+    }
+}
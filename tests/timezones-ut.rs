@@ -0,0 +1,98 @@
+#![cfg(all(feature = "std", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, sync::Once};
+
+    use rust_solar::{
+        bodies::{self, register},
+        clock::ZoneInfo,
+        kepler::{CustomBody, TimeZone},
+        orbit::Perihelion,
+        planets::mars::Martian,
+        timezones::{all, find, find_qualified},
+    };
+
+    /// A body with a timezone of the same code as one of mars, registered once for every test
+    fn register_vulcan() {
+        static ONCE: Once = Once::new();
+        static ZONES: [ZoneInfo; 1] = [ZoneInfo {
+            code: "AMT",
+            name: "Amber Time",
+            offset: 0.0,
+            east: -180.0,
+            west: 180.0,
+        }];
+
+        ONCE.call_once(|| {
+            register(
+                CustomBody::builder()
+                    .name("Vulcan")
+                    .orbital_eccentricity(0.02)
+                    .orbital_period(120.0)
+                    .rotational_period(100_000.0)
+                    .semimajor(0.3)
+                    .perihelion(Perihelion::new((40.0, 50.0), (90.0, 120.0), 100.0).unwrap())
+                    .timezones(&ZONES)
+                    .build()
+                    .unwrap(),
+            );
+        });
+    }
+
+    #[test]
+    pub fn body_and_code_are_unique() {
+        register_vulcan();
+
+        let zones = all();
+        let unique: HashSet<_> = zones
+            .iter()
+            .map(|zone| {
+                (
+                    zone.body.to_ascii_lowercase(),
+                    zone.code.to_ascii_lowercase(),
+                )
+            })
+            .collect();
+
+        assert_eq!(zones.len(), unique.len());
+
+        let martian: Vec<_> = zones.iter().filter(|zone| zone.body == "Mars").collect();
+
+        assert_eq!(Martian::ALL.len(), martian.len());
+
+        for (zone, entry) in Martian::iter().zip(martian) {
+            assert_eq!(
+                (zone.info().code, zone.offset()),
+                (entry.code, entry.offset)
+            );
+        }
+    }
+
+    #[test]
+    pub fn qualified_lookup_is_unambiguous() {
+        register_vulcan();
+
+        // both mars and vulcan have an AMT
+        let bodies: Vec<_> = find("amt").iter().map(|zone| zone.body).collect();
+
+        assert!(
+            bodies.contains(&"Mars") && bodies.contains(&"Vulcan"),
+            "{bodies:?}"
+        );
+
+        let mars = find_qualified("Mars/AMT").unwrap();
+        let vulcan = find_qualified("vulcan/amt").unwrap();
+
+        assert_eq!(("Amazonis Time", -12.5), (mars.name, mars.offset));
+        assert_eq!(("Amber Time", 0.0), (vulcan.name, vulcan.offset));
+        assert_eq!(
+            Martian::MTCn5.at(2451545.0),
+            mars.timezone().unwrap().at(2451545.0)
+        );
+        assert!(bodies::find("vulcan").unwrap().timezone("+00:00").is_some());
+        assert_eq!(None, find_qualified("AMT"));
+        assert_eq!(None, find_qualified("mars/LTC"));
+        assert!(find("nowhere").is_empty());
+    }
+}
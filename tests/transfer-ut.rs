@@ -0,0 +1,127 @@
+#![cfg(all(feature = "earth", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        define_body,
+        julian::{jd2greg, CalendarSystem},
+        planets::{earth::Earth, mars::Mars},
+        transfer::{hohmann, next_departure_window, phase_angle},
+        Error,
+    };
+
+    define_body! {
+        /// A comet of the jupiter family, its mass and radius aren't known
+        struct Comet {
+            name: "Comet",
+            epoch: 2_451_545.0,
+            orbital_eccentricity: 0.6,
+            orbital_period: 2_000.0,
+            rotational_period: 43_200.0,
+            semimajor: 3.1,
+            perihelion: ((0.0, 100.0), (0.0, 30.0), 10.0),
+        }
+    }
+
+    #[test]
+    pub fn earth_to_mars() {
+        let transfer = hohmann(&Earth, &Mars);
+
+        assert!((transfer.transfer_time.0 - 259.0).abs() < 2.0);
+        assert!((transfer.phase_angle - 44.0).abs() < 1.0);
+        assert!((transfer.semimajor.0 - 1.26).abs() < 0.01);
+        assert!((transfer.departure_speed - 32.7).abs() < 0.2);
+        assert!((transfer.arrival_speed - 21.5).abs() < 0.2);
+    }
+
+    #[test]
+    pub fn earth_to_mars_delta_v() {
+        let transfer = hohmann(&Earth, &Mars);
+        let (departure, arrival) = transfer.delta_v(200.0, 200.0).unwrap();
+
+        assert!((transfer.departure_excess - 2.94).abs() < 0.05);
+        assert!((transfer.arrival_excess - 2.65).abs() < 0.05);
+        assert!((departure / 3.6 - 1.0).abs() < 0.05, "{departure}");
+        assert!((arrival / 2.1 - 1.0).abs() < 0.05, "{arrival}");
+
+        // a geostationary parking orbit is higher in the well of earth, so its burn is smaller
+        let (high_departure, _) = transfer.delta_v(35_786.0, 200.0).unwrap();
+
+        assert!(high_departure < departure);
+        assert_eq!(
+            Err(Error::InvalidOrbit(
+                "the parking orbit is below the surface"
+            )),
+            transfer.delta_v(-1.0, 200.0)
+        );
+        assert_eq!(Err(Error::NonFinite), transfer.delta_v(200.0, f64::NAN));
+    }
+
+    #[test]
+    pub fn comet_delta_v() {
+        let transfer = hohmann(&Earth, &Comet);
+
+        assert!(transfer.departure_excess > 0.0 && transfer.arrival_excess > 0.0);
+        assert_eq!(
+            Err(Error::InvalidOrbit(
+                "the mass or the radius of the body isn't known"
+            )),
+            transfer.delta_v(200.0, 0.0)
+        );
+    }
+
+    #[test]
+    pub fn mars_to_earth() {
+        let back = hohmann(&Mars, &Earth);
+
+        assert_eq!(hohmann(&Earth, &Mars).transfer_time, back.transfer_time);
+        // earth has to trail behind mars
+        assert!(
+            (back.phase_angle + 76.0).abs() < 2.0,
+            "{}",
+            back.phase_angle
+        );
+    }
+
+    #[test]
+    pub fn departure_windows() {
+        let mut after = 2_451_545.0;
+        let mut windows = Vec::new();
+
+        for _ in 0..4 {
+            let window = next_departure_window(&Earth, &Mars, after).unwrap();
+            let miss =
+                phase_angle(&Earth, &Mars, window).unwrap() - hohmann(&Earth, &Mars).phase_angle;
+
+            assert!(miss.abs() < 1e-4, "{miss}");
+            windows.push(window);
+            after = window + 1.0;
+        }
+
+        // the window of mars odyssey, which left in 2001 april
+        let first = jd2greg(windows[0], CalendarSystem::Gregorian);
+
+        assert_eq!((2001, 3), (first.year, first.month));
+
+        for pair in windows.windows(2) {
+            let days = pair[1] - pair[0];
+
+            // 26 months, the synodic period of earth and mars
+            assert!((days - 780.0).abs() < 40.0, "{days}");
+        }
+    }
+
+    #[test]
+    pub fn same_orbit() {
+        assert_eq!(
+            Err(Error::InvalidOrbit(
+                "the bodies have the same orbital period"
+            )),
+            next_departure_window(&Mars, &Mars, 2_451_545.0)
+        );
+        assert_eq!(
+            Err(Error::NonFinite),
+            next_departure_window(&Earth, &Mars, f64::NAN)
+        );
+    }
+}
@@ -0,0 +1,91 @@
+#![cfg(all(feature = "luna", feature = "mars"))]
+
+#[cfg(test)]
+mod tests {
+    use rust_solar::{
+        bodies,
+        conversions::{au_to_km, Au, Days, Degrees, Radians, Seconds, JULIAN_YEAR_DAYS},
+        kepler::Body,
+        moons::luna::Luna,
+        planets::{mars::Mars, EARTH_ROTATIONAL_PERIOD},
+    };
+
+    #[test]
+    pub fn time_round_trips() {
+        for days in [0.0, 1.0, -2.5, 1.027_491_252, 668.6, 2_451_545.0] {
+            assert_eq!(Days(days), Days(days).to_seconds().to_days());
+            assert_eq!(Days(days), Days::from(Seconds::from(Days(days))));
+        }
+
+        assert_eq!(Seconds(EARTH_ROTATIONAL_PERIOD), Days(1.0).to_seconds());
+    }
+
+    #[test]
+    pub fn angle_round_trips() {
+        for degrees in [0.0, 45.0, 90.0, 180.0, 251.0, 359.9, -30.0] {
+            let back = Degrees(degrees).to_radians().to_degrees();
+
+            assert!((back.0 - degrees).abs() < 1e-12, "{degrees}");
+        }
+
+        assert_eq!(Radians(core::f64::consts::PI), Degrees(180.0).into());
+    }
+
+    #[test]
+    pub fn distance_round_trips() {
+        assert_eq!(149_597_870.7, Au(1.0).to_km());
+        assert_eq!(au_to_km(1.5), Au(1.5).to_km());
+        assert!((Au::from_km(Au(1.5237).to_km()).0 - 1.5237).abs() < 1e-15);
+    }
+
+    #[test]
+    pub fn arithmetic() {
+        let mut days = Days(1.0) + Days(2.0) - Days(0.5);
+
+        days += Days(0.5);
+        days -= Days(1.0);
+
+        assert_eq!(Days(2.0), days);
+        assert_eq!(Days(-2.0), -days);
+        assert_eq!(Seconds(30.0), Seconds(10.0) * 3.0);
+        assert_eq!(Degrees(45.0), Degrees(90.0) / 2.0);
+        assert_eq!(4.0, Au(2.0) / Au(0.5));
+        assert!(Days(1.0) < Days(2.0));
+    }
+
+    #[test]
+    pub fn typed_accessors() {
+        let sol = Mars.rotational_period_seconds().to_days();
+
+        assert_eq!(
+            Seconds(Mars.rotational_period()),
+            Mars.rotational_period_seconds()
+        );
+        assert_eq!(Au(Mars.semimajor()), Mars.semimajor_au());
+        assert_eq!(Degrees(Mars.axial_tilt()), Mars.axial_tilt_degrees());
+        assert!((Mars.orbital_period_days().0 - 686.97).abs() < 0.1);
+        assert!((Luna.orbital_period_days().0 - 365.25).abs() < 1.0);
+        assert!((Days(10.0).sols(Mars.rotational_period_seconds()) * sol.0 - 10.0).abs() < 1e-12);
+    }
+
+    #[test]
+    pub fn orbital_period_units() {
+        assert_eq!(668.6, Mars.orbital_period_sols());
+        assert!((Mars.orbital_period_earth_days() - 686.9807).abs() < 1e-4);
+        assert!((Mars.orbital_period_years() - 1.88085).abs() < 1e-5);
+        assert!((Luna.orbital_period_years() - 1.0).abs() < 1e-12);
+
+        for body in bodies::registry() {
+            let seconds = body.orbital_period_sols() * body.rotational_period();
+            let days = body.orbital_period_earth_days();
+
+            assert!(
+                (seconds - days * EARTH_ROTATIONAL_PERIOD).abs() < 1e-6,
+                "{}",
+                body.name()
+            );
+            assert!((days - body.orbital_period_years() * JULIAN_YEAR_DAYS).abs() < 1e-9);
+            assert_eq!(body.orbital_period_days().0, days, "{}", body.name());
+        }
+    }
+}
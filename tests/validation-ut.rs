@@ -0,0 +1,220 @@
+#![cfg(feature = "mars")]
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use rust_solar::{
+        kepler::{Body, Date, Time},
+        orbit::{Hemisphere, Perihelion, SolarLongitude, Type},
+        planets::mars::Mars,
+        Error,
+    };
+
+    const BAD: [f64; 4] = [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 0.0];
+
+    /// Mars, but with one of its elements replaced
+    #[derive(Clone, Copy)]
+    enum Broken {
+        Epoch(f64),
+        OrbitalEccentricity(f64),
+        OrbitalPeriod(f64),
+        RotationalPeriod(f64),
+        Semimajor(f64),
+    }
+
+    impl Body for Broken {
+        fn name(&self) -> &'static str {
+            "Broken"
+        }
+
+        fn epoch(&self) -> f64 {
+            match self {
+                Self::Epoch(epoch) => *epoch,
+                _ => Mars.epoch(),
+            }
+        }
+
+        fn orbital_eccentricity(&self) -> f64 {
+            match self {
+                Self::OrbitalEccentricity(orbital_eccentricity) => *orbital_eccentricity,
+                _ => Mars.orbital_eccentricity(),
+            }
+        }
+
+        fn orbital_period(&self) -> f64 {
+            match self {
+                Self::OrbitalPeriod(orbital_period) => *orbital_period,
+                _ => Mars.orbital_period(),
+            }
+        }
+
+        fn rotational_period(&self) -> f64 {
+            match self {
+                Self::RotationalPeriod(rotational_period) => *rotational_period,
+                _ => Mars.rotational_period(),
+            }
+        }
+
+        fn perihelion(&self) -> Perihelion {
+            Mars.perihelion()
+        }
+
+        fn semimajor(&self) -> f64 {
+            match self {
+                Self::Semimajor(semimajor) => *semimajor,
+                _ => Mars.semimajor(),
+            }
+        }
+
+        fn to_time(&self, date: Date) -> Time {
+            Mars.to_time(date)
+        }
+    }
+
+    /// Asserts the body returns an error quickly instead of hanging
+    fn assert_rejected(body: impl Body, julian_date: f64) {
+        let start = Instant::now();
+        let date = body.try_to_date(julian_date);
+
+        assert!(date.is_err(), "{date:?}");
+        assert!(start.elapsed() < Duration::from_millis(100));
+    }
+
+    #[test]
+    pub fn rejects_bad_julian_dates() {
+        for bad in &BAD[..3] {
+            assert_rejected(Mars, *bad);
+        }
+
+        assert_eq!(Mars.try_to_date(f64::NAN), Err(Error::NonFinite));
+    }
+
+    #[test]
+    pub fn rejects_bad_bodies() {
+        for bad in BAD {
+            for body in [Broken::OrbitalPeriod(bad), Broken::RotationalPeriod(bad)] {
+                assert_rejected(body, 2451545.0);
+            }
+        }
+
+        for bad in BAD[..3].iter().copied() {
+            let bodies = [
+                Broken::Epoch(bad),
+                Broken::OrbitalEccentricity(bad),
+                Broken::Semimajor(bad),
+            ];
+
+            for body in bodies {
+                assert_rejected(body, 2451545.0);
+            }
+        }
+
+        let negative = Broken::OrbitalEccentricity(-0.1);
+
+        assert_eq!(
+            negative.try_to_date(2451545.0),
+            Err(Error::InvalidEccentricity(-0.1))
+        );
+    }
+
+    #[test]
+    pub fn compute_rejects_bad_input() {
+        let date = Date::default().compute(
+            2451545.0,
+            Mars.epoch(),
+            12.0,
+            0.0,
+            Mars.perihelion(),
+            Mars.semimajor(),
+            Mars.orbital_eccentricity(),
+            Mars.orbital_period(),
+            Hemisphere::North,
+        );
+
+        assert_eq!(
+            date,
+            Err(Error::InvalidOrbit("the rotational period isn't positive"))
+        );
+
+        let ls = SolarLongitude.compute(
+            Type::Elliptical,
+            f64::NAN,
+            Mars.orbital_eccentricity(),
+            Mars.perihelion(),
+            Mars.orbital_period(),
+            Mars.semimajor(),
+        );
+
+        assert_eq!(ls, Err(Error::NonFinite));
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn to_date_panics_on_bad_input() {
+        Mars.to_date(f64::NAN);
+    }
+
+    #[test]
+    pub fn rejects_bad_perihelions() {
+        let cases = [
+            (
+                Perihelion::new((468.5, 514.6), (240.0, 240.0), 251.0),
+                "the ls window is empty or reversed",
+            ),
+            (
+                Perihelion::new((468.5, 514.6), (270.0, 240.0), 251.0),
+                "the ls window is empty or reversed",
+            ),
+            (
+                Perihelion::new((514.6, 468.5), (240.0, 270.0), 251.0),
+                "the month window is empty or reversed",
+            ),
+            (
+                Perihelion::new((468.5, 514.6), (240.0, 270.0), 360.0),
+                "the perihelion is outside of 0 to 360",
+            ),
+            (
+                Perihelion::new((468.5, 514.6), (240.0, 270.0), -1.0),
+                "the perihelion is outside of 0 to 360",
+            ),
+        ];
+
+        for (peri, reason) in cases {
+            assert_eq!(peri.unwrap_err(), Error::InvalidPerihelion(reason));
+        }
+
+        assert_eq!(
+            Perihelion::new((468.5, 514.6), (240.0, f64::NAN), 251.0).unwrap_err(),
+            Error::NonFinite
+        );
+        assert!(Perihelion::new((468.5, 514.6), (240.0, 270.0), 251.0).is_ok());
+        assert!(Mars.perihelion().validate().is_ok());
+    }
+
+    #[test]
+    pub fn compute_rejects_bad_perihelion() {
+        let peri = Perihelion {
+            ls: (240.0, 240.0),
+            ..Mars.perihelion()
+        };
+        let date = Date::default().compute(
+            2451545.0,
+            Mars.epoch(),
+            12.0,
+            Mars.rotational_period(),
+            peri,
+            Mars.semimajor(),
+            Mars.orbital_eccentricity(),
+            Mars.orbital_period(),
+            Hemisphere::North,
+        );
+
+        assert_eq!(
+            date,
+            Err(Error::InvalidPerihelion(
+                "the ls window is empty or reversed"
+            ))
+        );
+    }
+}
@@ -0,0 +1,49 @@
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+#[cfg(test)]
+mod tests {
+    use js_sys::{Array, Reflect};
+    use rust_solar::wasm::{list_timezones, mars_date, mars_time};
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn field(value: &JsValue, name: &str) -> JsValue {
+        Reflect::get(value, &JsValue::from_str(name)).unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    pub fn mars_date_is_an_object() {
+        let date = mars_date(2451545.0).unwrap();
+
+        assert_eq!(field(&date, "era").as_string().unwrap(), "AD");
+        assert_eq!(field(&date, "year").as_f64().unwrap().floor(), 24.0);
+    }
+
+    #[wasm_bindgen_test]
+    pub fn mars_date_throws_on_non_finite() {
+        assert!(mars_date(f64::NAN).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn mars_time_is_in_the_zone() {
+        let time = mars_time(2451545.0, "AMT").unwrap();
+
+        assert_eq!(field(&time, "code").as_string().unwrap(), "AMT");
+        assert!(field(&time, "hour").as_f64().unwrap() < 25.0);
+    }
+
+    #[wasm_bindgen_test]
+    pub fn mars_time_throws_on_unknown_zone() {
+        assert!(mars_time(2451545.0, "Nowhere").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn list_timezones_lists_every_zone() {
+        let zones = Array::from(&list_timezones().unwrap());
+
+        assert_eq!(zones.length(), 11);
+        assert_eq!(field(&zones.get(0), "code").as_string().unwrap(), "AMT");
+    }
+}